@@ -33,8 +33,9 @@ cp target/release/${PACKAGE_NAME}       ${BUILD_DIR}/opt/im.satellite/${PACKAGE_
 
 cp -r ./ui/extra/assets                 ${BUILD_DIR}/opt/im.satellite/extra
 cp -r ./ui/extra/images                 ${BUILD_DIR}/opt/im.satellite/extra
-cp -r ./ui/extra/prism_langs            ${BUILD_DIR}/opt/im.satellite/extra
 cp -r ./ui/extra/themes                 ${BUILD_DIR}/opt/im.satellite/extra
+bash fetch_emoji_keywords.sh
+cp -r ./ui/extra/emoji_keywords         ${BUILD_DIR}/opt/im.satellite/extra
 
 cp ./ui/extra/images/logo.png           ${BUILD_DIR}/usr/share/icons/im.satellite/uplink_logo.png
 
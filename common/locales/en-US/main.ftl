@@ -22,6 +22,8 @@ uplink = Uplink
     .leave-group = Leave Group
     .delete-conversation = Delete Chat
     .hide-chat = Hide Chat
+    .enable-link-previews = Show Link Previews
+    .disable-link-previews = Hide Link Previews
     .search-placeholder = Search...
     .nothing-here = Nothing Here...
     .end = End
@@ -50,6 +52,7 @@ uplink = Uplink
     .download-queue = Download Queue
     .copy-seed = Copy to Clipboard
     .copied-seed = Copied to Clipboard
+    .copied-code = Code copied to clipboard
     
 community = Community
     .invited = You're Invited!
@@ -105,15 +108,71 @@ messages = Messages
     .pin-button-unpin = Unpin
     .pinned-none = There are no pinned messages in this chat
     .pinned-max = You reached the maximum amount of pinned messages for this chat
+    .reply-in-thread = Reply in Thread
+    .thread = Thread
+    .thread-replies = Replies: { $amount }
+    .view-thread = View thread
+    .seen-by = Seen by { $names }
+    .thread-placeholder = Reply in thread…
+    .thread-loading-failed = The thread could not be loaded
+    .edited = (edited)
+    .edited-at = Edited { $date }
+    .edit-history = Edit History
+    .edit-history-current = Current
+    .edit-history-original = Original
+    .edit-history-unknown = Earlier versions were edited before this device received the message.
+    .forward = Forward
+    .forward-to = Forward to
+    .forward-failed = The message could not be forwarded
+    .forwarded-from = Forwarded, says it's from { $user }
+    .forwarded-from-chat = Forwarded, says it's from { $user } in { $chat }
+    .forwarded-unverified = The sender of a forwarded message writes where it came from, this can't be checked
     .missing-emoji-picker = Emoji extension is disabled
     .unknown-identity = User not found:
     .emoji-suggestion = Suggested Emoji
     .username-suggestion = Suggested Users
+    .command-suggestion = Commands
+    .mention-everyone = Notify everyone in this group
+    .mention-here = Notify the members of this group
+    .allow-mention-everyone = Allow anyone to mention @everyone and @here
+    .mention-everyone-allowed = Anyone can now mention @everyone and @here.
+    .mention-everyone-creator-only = Only the group creator can now mention @everyone and @here.
+    .mentions-filter = Show chats with unread mentions
+    .no-mentions = No unread mentions
     .control-group = More
     .no-chats = No chats available
     .attachments-fail = Upload Failed!
     .attachments-fail-msg = Upload failed: { $reason }
     .attachments-fail-no-storage = No more storage left!
+    .load-embed = Load { $provider }
+    .embed-failed = Nothing to embed here
+    .voice-message-hold = Hold to record a voice message
+    .voice-message-cancel = Slide left to cancel
+    .voice-message-failed = Couldn't record a voice message, check your microphone in the audio settings.
+    .voice-message-load-failed = Couldn't load
+    .gif-load-failed = Couldn't load, click to retry
+    .paste-long = This paste is { $num } characters long
+    .paste-as-file = Send as file
+    .paste-as-text = Paste as text
+    .paste-as-file-failed = Couldn't save the paste as a file
+    .custom-emoji = Custom
+    .emoji-packs = Emoji packs
+    .emoji-pack-count = { $num } emoji
+    .emoji-pack-folder = Add folder
+    .emoji-pack-zip = Add zip
+    .emoji-pack-remove = Remove pack
+    .emoji-pack-shared = Shared the emoji pack { $name }
+    .emoji-pack-failed = Couldn't share the emoji pack
+    .skin-tone = Skin tone
+    .no-emoji-found = No emoji found
+    .gifs = GIFs
+    .search-gifs = Search GIFs
+    .no-gifs-found = No GIFs found
+    .gif-folder-hint = Put GIFs in the GIF folder to find them here. They are found by their names, their folders and the tags in its tags.json.
+    .open-gif-folder = Open the GIF folder
+    .gif-search-failed = Couldn't search GIFs
+    .gif-failed = Couldn't send the GIF
+    .attachments-not-prepared = The message wasn't sent, the location data couldn't be removed from its images
     
 favorites = Favorites
     .favorites = Favorites
@@ -179,6 +238,13 @@ files = Files
     .files-to-upload = Files to Upload { $num }!
     .no-thumbnail-preview = No Thumbnail available for preview
     .not-possible-to-preview-file = This file type is currently not supported in preview
+    .pdf-page = Page { $page } of { $count }
+    .pdf-preview-unavailable = Install poppler to preview the pages, the PDF is shown as is
+    .preview-truncated = Only the first { $size } are shown
+    .archive-truncated = Only the first { $count } entries are shown
+    .extract = Extract
+    .extracted = Extracted { $name }
+    .extract-failed = Couldn't extract { $name }
     .file-already-opened = File already opened
     .directory-already-with-name = There is already a directory with this name
     .no-size-available = No size available for file: { $file }
@@ -251,6 +317,27 @@ settings-profile = Profile Settings
     .hide-recovery-seed = Hide Recovery Seed
     .store-on-account = Store recovery seed on account (disable this for increased security)
     .remove-recovery-seed = Remove Seed Phrase
+    .backup = Backup Account
+    .backup-description = Save your account, settings, themes and fonts to a single file protected by a passphrase. It can be restored when creating an account.
+    .backup-pin = Your Pin
+    .backup-passphrase = Backup Passphrase
+    .backup-created = Backup saved
+    .backup-failed = Failed to create backup
+    .change-passphrase = Change Passphrase
+    .change-passphrase-description = Choose a new passphrase for unlocking Uplink. Your current one is needed to confirm the change.
+    .current-passphrase = Current passphrase
+    .new-passphrase = New passphrase
+    .passphrase-changed = Passphrase changed
+    .wrong-passphrase = The current passphrase is wrong
+    .passphrase-change-failed = Failed to change passphrase
+    .auto-lock = Auto-Lock
+    .auto-lock-description = Lock Uplink after a while without any input. Messages still arrive while it is locked.
+    .auto-lock-never = Never
+    .auto-lock-minutes = After { $minutes } min
+    .lock-on-suspend = Lock On Suspend
+    .lock-on-suspend-description = Lock Uplink when your computer wakes up from sleep.
+    .lock-now = Lock Now
+    .lock-now-description = Lock Uplink right away. Your passphrase is needed to get back in.
     .remove-recovery-seed-description = Removing the seed phrase from storage can increase security on your account. However, this action is irreversable and if you have not backed up your seed phrase yet you should select 'Cancel' below.
 
 settings-general = General Settings
@@ -271,11 +358,21 @@ settings-messages = Message Settings
     .emoji-conversion-description = Convert Emoji text like ':)' into an emoji symbol like '😊'.
     .markdown-support = Markdown support
     .markdown-support-description = Enables the support of the Markdown markup language in messaging. 
+    .gifs = GIF Search
+    .gifs-description = Where the GIF picker searches. Tenor and Giphy need an API key, the GIF folder is searched whenever they can't be reached.
+    .gif-service-folder = GIF folder
+    .gif-service-tenor = Tenor
+    .gif-service-giphy = Giphy
+    .gif-api-key = API key
 
 settings-privacy = Settings Privacy 
     .backup-recovery-phrase = Backup Recovery Phrase
     .backup-phrase = Backup Phrase
     .backup-phrase-description = Back this phrase up! Along with your password this represents your account. If you lose it, we can't help you get it back.
+    .read-receipts = Send Read Receipts
+    .read-receipts-description = Let others in your chats know when you have read their messages. You will still see who read yours.
+    .link-previews = Link Previews
+    .link-previews-description = Fetch a preview of the links in your chats and send one along with your messages. This visits the linked sites. You can also turn previews on for a single chat from its menu.
 
 settings-audio = Audio & Sound Settings 
     .input-device = Input Device
@@ -304,6 +401,11 @@ settings-files = Files Settings
     .local-sync-description = When enabled, files will be synced to your local machine.
     .open-sync-folder = Open Sync Folder
     .open-sync-folder-description = Open the folder where your files are synced to.
+    .compression = Compression
+    .compression-description = How attachments are compressed before they are sent. Images are scaled down and sent as WebP, videos are scaled down if ffmpeg is installed. This can be changed for each message.
+    .compression-original = Original
+    .compression-balanced = Balanced
+    .compression-small = Small
 
 settings-keybinds = Keybind Settings
     .reset = Revert
@@ -409,11 +511,23 @@ unlock = Unlock
     .error-unknown-pin = An unknown error occurred.
     .help = Help (right-click)
     .logging-in = Logging in...
+    .new-account = New Account
+    .add-account = Add Account
+    .remove-account = Remove Account
 
 create-or-recover = Account Creation
     .create = Create New Account
     .instructions = We're going to create an account for you. On the next screen, you'll see a set of words. Screenshot this or write it down. This is the only way to backup your account.
     .recover = Import an Account
+    .restore-backup = Restore from Backup
+
+restore-backup = Restore Backup
+    .instructions = Pick the backup file and enter the passphrase it was protected with. The restored account will unlock with the pin you just entered.
+    .select-file = Select Backup File
+    .passphrase = Backup Passphrase
+    .submit = Restore Account
+    .wrong-passphrase = Hmm, that passphrase didn't work with this backup.
+    .failed = Something went wrong while restoring the backup.
 
 copy-seed-words = Recovery Seed
     .instructions = Write these words down in the order that they appear. Having the correct order is crucial when you are recovering your account.
@@ -451,3 +565,93 @@ toast_actions = Toast Actions
     .DisplayChat = Open Chat
     .FriendListPending = Friend List
     .Dummy = Dummy Action
+    .DisplaySettings = Open Settings
+    .MarkChatRead = Mark as Read
+
+quick_actions = Quick Actions
+    .Reply = Reply
+    .MarkRead = Mark as Read
+    .AcceptRequest = Accept
+    .DenyRequest = Decline
+    .reply-placeholder = Reply…
+
+external-link = Link
+    .title = You are leaving Uplink
+    .description = This link opens in your browser. Only continue if you trust where it leads.
+    .open = Open Link
+
+polls = Polls
+    .create = Create Poll
+    .question = Ask a question
+    .option = Option
+    .add-option = Add Option
+    .multiple-choice = Multiple choice
+    .single-choice = Single choice
+    .anonymous = Anonymous
+    .close-after = Close after
+    .never = Never
+    .one-hour = 1 hour
+    .one-day = 1 day
+    .one-week = 1 week
+    .send = Send Poll
+    .votes = Votes: { $amount }
+    .closes = Closes { $date }
+    .closed = Closed
+    .close = Close Poll
+
+bookmarks = Saved Messages
+    .title = Saved Messages
+    .save = Save Message
+    .edit = Edit Saved Message
+    .save-button = Save
+    .remove = Remove
+    .note = Add a note
+    .tags = Tags, separated by commas
+    .search = Search saved messages
+    .all-tags = All
+    .empty = Messages you save show up here
+    .no-results = No saved messages match
+    .deleted = This message was deleted
+    .unknown-chat = Chat not available
+    .go-to = Go to
+    .saved = Saved
+
+commands = Commands
+    .shrug = Appends ¯\_(ツ)_/¯ to your message
+    .me = Describes what you are doing
+    .poll = Creates a poll
+    .call = Calls everyone in this chat
+    .mute = Mutes or unmutes you in the current call
+    .missing-arg = Missing { $arg }
+    .too-many-args = Too many arguments
+    .already-in-call = You are already in a call
+    .not-in-call = You are not in a call
+
+notification-center = Notifications
+    .title = Notifications
+    .user-mentioned-you = { $user } mentioned you
+    .unknown-mentioned-you = You were mentioned
+    .user-replied-in-thread = { $user } replied in a thread
+    .unknown-replied-in-thread = New reply in a thread
+    .missed-call = Missed call
+    .missed-call-from = Missed call from { $name }
+    .missed-call-unknown = Missed call from an unknown caller
+    .update = Update available
+    .mark-all-read = Mark all as read
+    .empty = You're all caught up.
+    .general = General
+
+image-editor = Edit Image
+    .edit = Edit Image
+    .crop = Crop
+    .blur = Blur
+    .redact = Redact
+    .arrow = Arrow
+    .text = Text
+    .text-placeholder = Type, then click the image to place it
+    .rotate-left = Rotate Left
+    .rotate-right = Rotate Right
+    .undo = Undo
+    .size = Size
+    .save = Save
+    .failed = The image couldn't be edited
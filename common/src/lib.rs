@@ -1,6 +1,7 @@
 pub mod language;
 pub mod notifications;
 pub mod profile_update_channel;
+pub mod profiles;
 pub mod sounds;
 pub mod state;
 pub mod testing;
@@ -88,30 +89,18 @@ impl std::str::FromStr for DiscoveryMode {
 #[derive(Debug)]
 pub struct StaticArgs {
     /// ~/.uplink
-    /// contains the following: extra (folder), extensions (folder), themes (folder), fonts (folder), .user
+    /// contains the following: extra (folder), extensions (folder), themes (folder), fonts (folder), gifs (folder), .user, profiles (folder), profiles.json
     pub dot_uplink: PathBuf,
-    /// ~/.uplink/.user
-    /// contains the following: warp (folder), state.json, debug.log
-    pub uplink_path: PathBuf,
     /// Directory for temporary files and deleted everytime app is closed or opened
     pub temp_files: PathBuf,
     /// custom themes for the user
     pub themes_path: PathBuf,
     /// custom fonts for the user
     pub fonts_path: PathBuf,
-    /// state.json: a serialized version of State which gets saved every time state is modified
-    pub cache_path: PathBuf,
-    /// a fake tesseract_path to prevent anything from mutating the tesseract keypair after it has been created (probably not necessary)
-    pub mock_cache_path: PathBuf,
-    /// houses warp specific data
-    pub warp_path: PathBuf,
-    /// a debug log which is only written to when the settings are enabled. otherwise logs are only sent to stdout
-    pub logger_path: PathBuf,
+    /// the GIFs the user collected for the GIF picker, see warp_runner/gifs.rs
+    pub gifs_path: PathBuf,
     /// contains the keypair used for IPFS
     pub tesseract_file: String,
-    /// the unlock and auth pages don't have access to State but need to know if they should play a notification.
-    /// part of state is serialized and saved here
-    pub login_config_path: PathBuf,
     /// path to custom plugins
     pub extensions_path: PathBuf,
     /// crash logs
@@ -147,25 +136,18 @@ pub static STATIC_ARGS: Lazy<StaticArgs> = Lazy::new(|| {
         _ => dirs::home_dir().unwrap_or_default().join(".uplink"),
     };
 
-    let uplink_path = uplink_container.join(".user");
-    let warp_path = uplink_path.join("warp");
     StaticArgs {
         dot_uplink: uplink_container.clone(),
-        uplink_path: uplink_path.clone(), // TODO: Should this be "User path" instead?
         temp_files: uplink_container.join("temp_files"),
         themes_path: uplink_container.join("themes"),
         fonts_path: uplink_container.join("fonts"),
-        cache_path: uplink_path.join("state.json"),
+        gifs_path: uplink_container.join("gifs"),
         extensions_path: uplink_container.join("extensions"),
         crash_logs: uplink_container.join("crash-logs"),
         recordings: uplink_container.join("recordings"),
-        mock_cache_path: uplink_path.join("mock-state.json"),
-        warp_path: warp_path.clone(),
-        logger_path: uplink_path.join("debug.log"),
         typing_indicator_refresh: 5,
         typing_indicator_timeout: 6,
         tesseract_file: "tesseract.json".into(),
-        login_config_path: uplink_path.join("login_config.json"),
         use_mock,
         discovery: args.discovery.unwrap_or_default(),
         disable_quic: args.disable_quic,
@@ -173,6 +155,44 @@ pub static STATIC_ARGS: Lazy<StaticArgs> = Lazy::new(|| {
     }
 });
 
+// these paths belong to the active profile. see profiles.rs
+impl StaticArgs {
+    /// ~/.uplink/.user for the default profile, ~/.uplink/profiles/<id> otherwise
+    /// contains the following: warp (folder), state.json, debug.log
+    pub fn uplink_path(&self) -> PathBuf {
+        profiles::profile_path(&profiles::active_profile())
+    }
+    /// state.json: a serialized version of State which gets saved every time state is modified
+    pub fn cache_path(&self) -> PathBuf {
+        self.uplink_path().join("state.json")
+    }
+    /// a fake tesseract_path to prevent anything from mutating the tesseract keypair after it has been created (probably not necessary)
+    pub fn mock_cache_path(&self) -> PathBuf {
+        self.uplink_path().join("mock-state.json")
+    }
+    /// houses warp specific data
+    pub fn warp_path(&self) -> PathBuf {
+        self.uplink_path().join("warp")
+    }
+    /// a debug log which is only written to when the settings are enabled. otherwise logs are only sent to stdout
+    pub fn logger_path(&self) -> PathBuf {
+        self.uplink_path().join("debug.log")
+    }
+    /// the unlock and auth pages don't have access to State but need to know if they should play a notification.
+    /// part of state is serialized and saved here
+    pub fn login_config_path(&self) -> PathBuf {
+        self.uplink_path().join("login_config.json")
+    }
+    /// the cached link previews, see warp_runner/link_previews.rs
+    pub fn link_previews_path(&self) -> PathBuf {
+        self.uplink_path().join("link_previews.json")
+    }
+    /// the list of profiles and which one was used last
+    pub fn profiles_file(&self) -> PathBuf {
+        self.dot_uplink.join("profiles.json")
+    }
+}
+
 // allows the UI to send commands to Warp
 pub static WARP_CMD_CH: Lazy<WarpCmdChannels> = Lazy::new(|| {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
@@ -196,13 +216,19 @@ pub const ROOT_DIR_NAME: &str = "root";
 pub const VIDEO_FILE_EXTENSIONS: &[&str] =
     &[".mp4", ".mov", ".avi", ".flv", ".wmv", ".m4v", ".3gp"];
 
-pub const IMAGE_FILE_EXTENSIONS: &[&str] =
-    &[".jpg", ".jpeg", ".png", ".gif", ".bmp", ".tiff", ".svg"];
+pub const IMAGE_FILE_EXTENSIONS: &[&str] = &[
+    ".jpg", ".jpeg", ".png", ".gif", ".bmp", ".tiff", ".svg", ".webp",
+];
 
-pub const AUDIO_FILE_EXTENSIONS: &[&str] = &[".mp3", ".wav", ".flac", ".aac", ".m4a"];
+pub const AUDIO_FILE_EXTENSIONS: &[&str] = &[".mp3", ".wav", ".flac", ".aac", ".m4a", ".opus"];
 
 pub const DOC_EXTENSIONS: &[&str] = &[".doc", ".docx", ".pdf", ".txt", ".csv", ".tsv"];
 
+// shown as plain text in the file preview. programming languages are highlighted, see PROGRAMMING_LANG_FILES
+pub const TEXT_EXTENSIONS: &[&str] = &[".txt", ".csv", ".tsv", ".log", ".ini", ".cfg", ".conf"];
+
+pub const ARCHIVE_EXTENSIONS: &[&str] = &[".zip", ".tar", ".tar.gz", ".tgz"];
+
 pub const PRODUCTIVITY_DOCS: &[&str] = &[
     ".xls", ".xlsx", ".xlsm", ".xlsb", // Excel extensions
     ".ppt", ".pptx", ".pps", ".ppsx", // PowerPoint extensions
@@ -215,7 +241,9 @@ pub enum FileType {
     Image,
     Audio,
     Doc,
+    Text,
     Code,
+    Archive,
     Unkwnown,
 }
 
@@ -224,7 +252,9 @@ pub fn is_file_available_to_preview(file_name: &str) -> bool {
         || is_video(file_name)
         || is_audio(file_name)
         || is_pdf_file(file_name)
+        || is_text_file(file_name)
         || is_lang_file(file_name)
+        || is_archive(file_name)
 }
 
 pub fn is_image(file_name: &str) -> bool {
@@ -239,6 +269,14 @@ pub fn is_video(file_name: &str) -> bool {
         .any(|x| file_name.to_lowercase().ends_with(x))
 }
 
+/// GIFs up to this size are downloaded when they're searched for or hovered in a message
+pub const MAX_GIF_SIZE: usize = 20 * 1024 * 1024;
+
+// played on hover in messages, see GifPlayer
+pub fn is_gif(file_name: &str) -> bool {
+    file_name.to_lowercase().ends_with(".gif")
+}
+
 pub fn is_audio(file_name: &str) -> bool {
     AUDIO_FILE_EXTENSIONS
         .iter()
@@ -255,6 +293,18 @@ pub fn is_pdf_file(file_name: &str) -> bool {
     file_name.to_lowercase().ends_with(".pdf")
 }
 
+pub fn is_text_file(file_name: &str) -> bool {
+    TEXT_EXTENSIONS
+        .iter()
+        .any(|x| file_name.to_lowercase().ends_with(x))
+}
+
+pub fn is_archive(file_name: &str) -> bool {
+    ARCHIVE_EXTENSIONS
+        .iter()
+        .any(|x| file_name.to_lowercase().ends_with(x))
+}
+
 pub fn is_productivity_doc(file_name: &str) -> bool {
     PRODUCTIVITY_DOCS
         .iter()
@@ -291,6 +341,10 @@ pub fn return_correct_icon(file_name: &str) -> Icon {
         return Icon::Document;
     }
 
+    if is_archive(file_name) {
+        return Icon::ArchiveBox;
+    }
+
     Icon::DocumentQuestion
 }
 
@@ -299,12 +353,16 @@ pub fn get_file_type(file_name: &str) -> FileType {
         FileType::Video
     } else if is_audio(file_name) {
         FileType::Audio
+    } else if is_text_file(file_name) {
+        FileType::Text
     } else if is_doc(file_name) {
         FileType::Doc
     } else if is_lang_file(file_name) {
         FileType::Code
     } else if is_image(file_name) {
         FileType::Image
+    } else if is_archive(file_name) {
+        FileType::Archive
     } else {
         FileType::Unkwnown
     }
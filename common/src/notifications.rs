@@ -5,9 +5,19 @@ use crate::language::get_local_text;
 use super::sounds::{Play, Sounds};
 use derive_more::Display;
 use notify_rust::Notification;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 use tracing::log;
 use uuid::Uuid;
+use warp::crypto::DID;
+
+use crate::{
+    warp_runner::{MultiPassCmd, RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
 
 use once_cell::sync::Lazy;
 use tokio::sync::{
@@ -16,20 +26,122 @@ use tokio::sync::{
     Mutex,
 };
 
+#[cfg(target_os = "linux")]
+mod linux;
+
 #[cfg(target_os = "windows")]
 pub const POWERSHELL_APP_ID: &str = "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\
 \\WindowsPowerShell\\v1.0\\powershell.exe";
 
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Display, PartialEq, Eq, Deserialize, Serialize)]
 pub enum NotificationAction {
     #[display(fmt = "DisplayChat")]
     DisplayChat(Uuid),
+    /// conversation id, id of the message which started the thread
+    #[display(fmt = "DisplayThread")]
+    DisplayThread(Uuid, Uuid),
     #[display(fmt = "FriendListPending")]
     FriendListPending,
+    #[display(fmt = "DisplaySettings")]
+    DisplaySettings,
+    #[display(fmt = "MarkChatRead")]
+    MarkChatRead(Uuid),
     #[display(fmt = "Dummy")]
     Dummy,
 }
 
+// quick actions are carried out without the app, they mustn't get around the lock screen
+static APP_LOCKED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_app_locked(locked: bool) {
+    APP_LOCKED.store(locked, Ordering::Relaxed);
+}
+
+/// Extra actions offered on a desktop notification where the platform supports them.
+/// Unlike [`NotificationAction`] they are carried out without focusing the app.
+#[derive(Debug, Clone, Display, PartialEq, Eq)]
+pub enum QuickAction {
+    #[display(fmt = "Reply")]
+    Reply(Uuid),
+    #[display(fmt = "MarkRead")]
+    MarkRead(Uuid),
+    #[display(fmt = "AcceptRequest")]
+    AcceptRequest(DID),
+    #[display(fmt = "DenyRequest")]
+    DenyRequest(DID),
+}
+
+impl QuickAction {
+    pub fn locale_id(&self) -> String {
+        format!("quick_actions.{}", self)
+    }
+
+    /// Sends the matching warp command. `reply` is the text typed into an inline reply. Nothing is
+    /// done while the app is locked.
+    pub fn dispatch(self, reply: Option<String>) {
+        if APP_LOCKED.load(Ordering::Relaxed) {
+            log::info!("ignoring quick action {} while the app is locked", self);
+            return;
+        }
+        let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+        match self {
+            QuickAction::Reply(conv_id) => {
+                let msg: Vec<String> = match reply {
+                    Some(text) if !text.trim().is_empty() => {
+                        text.lines().map(|line| line.to_string()).collect()
+                    }
+                    _ => return,
+                };
+                let (rsp, rx) = futures::channel::oneshot::channel();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::SendMessage {
+                    conv_id,
+                    msg,
+                    attachments: vec![],
+                    rsp,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                    return;
+                }
+                if let Ok(Err(e)) = futures::executor::block_on(rx) {
+                    log::error!("failed to send reply from notification: {}", e);
+                }
+            }
+            QuickAction::MarkRead(conv_id) => {
+                if let Err(e) = NOTIFICATION_LISTENER
+                    .tx
+                    .send(NotificationAction::MarkChatRead(conv_id))
+                {
+                    log::error!("failed to send notification action {}", e);
+                }
+            }
+            QuickAction::AcceptRequest(did) => {
+                let (rsp, rx) = futures::channel::oneshot::channel();
+                if let Err(e) =
+                    warp_cmd_tx.send(WarpCmd::MultiPass(MultiPassCmd::AcceptRequest { did, rsp }))
+                {
+                    log::error!("failed to send warp command: {}", e);
+                    return;
+                }
+                if let Ok(Err(e)) = futures::executor::block_on(rx) {
+                    log::error!("failed to accept friend request from notification: {}", e);
+                }
+            }
+            QuickAction::DenyRequest(did) => {
+                let (rsp, rx) = futures::channel::oneshot::channel();
+                if let Err(e) =
+                    warp_cmd_tx.send(WarpCmd::MultiPass(MultiPassCmd::DenyRequest { did, rsp }))
+                {
+                    log::error!("failed to send warp command: {}", e);
+                    return;
+                }
+                if let Ok(Err(e)) = futures::executor::block_on(rx) {
+                    log::error!("failed to deny friend request from notification: {}", e);
+                }
+            }
+        }
+    }
+}
+
 pub struct NotificationChannel {
     pub tx: broadcast::Sender<NotificationAction>,
 }
@@ -61,20 +173,44 @@ pub fn push_notification(
     notification_sound: Option<Sounds>,
     timeout: notify_rust::Timeout,
     action: NotificationAction,
+) {
+    push_notification_with_quick_actions(
+        title,
+        content,
+        notification_sound,
+        timeout,
+        action,
+        vec![],
+    );
+}
+
+/// Same as [`push_notification`], also offering `quick_actions` on platforms that support them (currently Linux).
+pub fn push_notification_with_quick_actions(
+    title: String,
+    content: String,
+    notification_sound: Option<Sounds>,
+    timeout: notify_rust::Timeout,
+    action: NotificationAction,
+    quick_actions: Vec<QuickAction>,
 ) {
     let summary = format!("Uplink - {title}");
+    let plays_sound = notification_sound.is_some();
     thread::spawn(move || {
         let action_id = format!("toast_actions.{}", action);
-        show_with_action(
-            Notification::new()
-                .summary(summary.as_ref())
-                .body(&content)
-                .timeout(timeout)
-                .action(&action_id, &get_local_text(&action_id))
-                .finalize(),
-            action_id,
-            action,
-        );
+        let mut notification = Notification::new();
+        notification
+            .summary(summary.as_ref())
+            .body(&content)
+            .timeout(timeout)
+            .action(&action_id, &get_local_text(&action_id));
+        #[cfg(target_os = "linux")]
+        notification
+            .urgency(notify_rust::Urgency::Normal)
+            // the sound is played below, the server shouldn't play one too
+            .hint(notify_rust::Hint::SuppressSound(plays_sound));
+        #[cfg(not(target_os = "linux"))]
+        let _ = plays_sound;
+        show_with_action(notification.finalize(), action_id, action, quick_actions);
     });
 
     if let Some(sound) = notification_sound {
@@ -102,7 +238,15 @@ pub fn set_badge(count: u32) -> Result<(), String> {
 }
 
 // We need to handle them all differently as there isnt a single lib that covers it for all
-fn show_with_action(notification: Notification, action_id: String, action: NotificationAction) {
+fn show_with_action(
+    notification: Notification,
+    action_id: String,
+    action: NotificationAction,
+    quick_actions: Vec<QuickAction>,
+) {
+    #[cfg(not(target_os = "linux"))]
+    let _ = quick_actions;
+
     #[cfg(target_os = "windows")]
     {
         // Notify-rust does not support windows actions so we use the underlying system directly
@@ -246,9 +390,9 @@ fn show_with_action(notification: Notification, action_id: String, action: Notif
 
     #[cfg(target_os = "linux")]
     {
-        match notification.show() {
-            Ok(handle) => handle.wait_for_action(|id| {
-                if action_id.eq(id) {
+        match linux::show(&notification, &action_id, &quick_actions) {
+            Ok(response) => match linux::resolve(response, &action_id, &quick_actions) {
+                linux::Resolved::Default => {
                     let tx = NOTIFICATION_LISTENER.tx.clone();
                     if let Err(e) = tx.send(action) {
                         log::error!("failed to send notification action {}", e);
@@ -257,8 +401,10 @@ fn show_with_action(notification: Notification, action_id: String, action: Notif
                     if let Err(e) = focus.send(()) {
                         log::error!("failed to send focus command {}", e);
                     }
-                };
-            }),
+                }
+                linux::Resolved::Quick(quick_action, reply) => quick_action.dispatch(reply),
+                linux::Resolved::Ignored => {}
+            },
             Err(err) => log::error!("Error handling notification {}", err),
         }
     }
@@ -0,0 +1,330 @@
+// notify-rust only reports which action was invoked, not the text typed into an inline reply,
+// so the notification is sent and tracked over D-Bus directly.
+// See https://specifications.freedesktop.org/notification-spec/latest/ and
+// https://invent.kde.org/plasma/plasma-workspace/-/merge_requests/1071 for the inline reply extension
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    arg::{PropMap, RefArg, Variant},
+    blocking::Connection,
+    message::MatchRule,
+};
+use notify_rust::{Hint, Notification, Timeout, Urgency};
+
+use super::QuickAction;
+
+pub const NOTIFICATIONS_BUS_NAME: &str = "org.freedesktop.Notifications";
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+const NOTIFICATIONS_INTERFACE: &str = "org.freedesktop.Notifications";
+
+// the action id notification servers render as a text field
+pub const INLINE_REPLY_ID: &str = "inline-reply";
+
+// servers which keep notifications in a history may never close them, so the answer is only
+// waited for this long, then the notification is closed
+const MAX_WAIT: Duration = Duration::from_secs(10 * 60);
+
+/// What the user did with a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Action(String),
+    Replied(String),
+    Closed,
+}
+
+/// The outcome of a [`Response`], resolved against the actions the notification was shown with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolved {
+    Default,
+    Quick(QuickAction, Option<String>),
+    Ignored,
+}
+
+pub fn resolve(response: Response, action_id: &str, quick_actions: &[QuickAction]) -> Resolved {
+    match response {
+        Response::Action(id) if id == action_id || id == "default" => Resolved::Default,
+        Response::Action(id) => quick_actions
+            .iter()
+            .find(|quick_action| quick_action_id(quick_action) == id)
+            .map_or(Resolved::Ignored, |quick_action| {
+                Resolved::Quick(quick_action.clone(), None)
+            }),
+        Response::Replied(text) => quick_actions
+            .iter()
+            .find(|quick_action| matches!(quick_action, QuickAction::Reply(_)))
+            .map_or(Resolved::Ignored, |quick_action| {
+                Resolved::Quick(quick_action.clone(), Some(text))
+            }),
+        Response::Closed => Resolved::Ignored,
+    }
+}
+
+pub fn quick_action_id(quick_action: &QuickAction) -> String {
+    match quick_action {
+        QuickAction::Reply(_) => INLINE_REPLY_ID.into(),
+        _ => quick_action.locale_id(),
+    }
+}
+
+fn timeout_ms(timeout: Timeout) -> i32 {
+    match timeout {
+        Timeout::Default => -1,
+        Timeout::Never => 0,
+        Timeout::Milliseconds(ms) => ms as i32,
+    }
+}
+
+// the hints notify-rust would have sent, see the "Hints" section of the spec
+fn hint_entry(hint: &Hint) -> Option<(String, Variant<Box<dyn RefArg>>)> {
+    let (name, value): (&str, Box<dyn RefArg>) = match hint {
+        Hint::Urgency(urgency) => (
+            "urgency",
+            Box::new(match urgency {
+                Urgency::Low => 0_u8,
+                Urgency::Normal => 1_u8,
+                Urgency::Critical => 2_u8,
+            }),
+        ),
+        Hint::Category(category) => ("category", Box::new(category.clone())),
+        Hint::DesktopEntry(entry) => ("desktop-entry", Box::new(entry.clone())),
+        Hint::SoundName(name) => ("sound-name", Box::new(name.clone())),
+        Hint::SoundFile(file) => ("sound-file", Box::new(file.clone())),
+        Hint::SuppressSound(suppress) => ("suppress-sound", Box::new(*suppress)),
+        Hint::Transient(transient) => ("transient", Box::new(*transient)),
+        Hint::Resident(resident) => ("resident", Box::new(*resident)),
+        _ => return None,
+    };
+    Some((name.into(), Variant(value)))
+}
+
+/// Shows the notification on the session bus and blocks until it is answered or closed.
+pub fn show(
+    notification: &Notification,
+    action_id: &str,
+    quick_actions: &[QuickAction],
+) -> Result<Response, dbus::Error> {
+    let conn = Connection::new_session()?;
+    show_on(
+        &conn,
+        NOTIFICATIONS_BUS_NAME,
+        notification,
+        action_id,
+        quick_actions,
+    )
+}
+
+/// Same as [`show`], but against any connection and bus name implementing the notification spec.
+pub fn show_on(
+    conn: &Connection,
+    destination: &str,
+    notification: &Notification,
+    action_id: &str,
+    quick_actions: &[QuickAction],
+) -> Result<Response, dbus::Error> {
+    let mut actions = vec![action_id.to_string(), super::get_local_text(action_id)];
+    for quick_action in quick_actions {
+        actions.push(quick_action_id(quick_action));
+        actions.push(super::get_local_text(&quick_action.locale_id()));
+    }
+
+    let mut hints: PropMap = notification.hints.iter().filter_map(hint_entry).collect();
+    if quick_actions
+        .iter()
+        .any(|quick_action| matches!(quick_action, QuickAction::Reply(_)))
+    {
+        hints.insert(
+            "x-kde-reply-placeholder-text".into(),
+            Variant(
+                Box::new(super::get_local_text("quick_actions.reply-placeholder"))
+                    as Box<dyn RefArg>,
+            ),
+        );
+    }
+
+    // subscribe before sending so a fast reply can't be missed
+    let (tx, rx) = mpsc::channel();
+    let action_tx = tx.clone();
+    conn.add_match(
+        MatchRule::new_signal(NOTIFICATIONS_INTERFACE, "ActionInvoked"),
+        move |(id, action): (u32, String), _, _| {
+            let _ = action_tx.send((id, Response::Action(action)));
+            true
+        },
+    )?;
+    let reply_tx = tx.clone();
+    conn.add_match(
+        MatchRule::new_signal(NOTIFICATIONS_INTERFACE, "NotificationReplied"),
+        move |(id, text): (u32, String), _, _| {
+            let _ = reply_tx.send((id, Response::Replied(text)));
+            true
+        },
+    )?;
+    conn.add_match(
+        MatchRule::new_signal(NOTIFICATIONS_INTERFACE, "NotificationClosed"),
+        move |(id, _reason): (u32, u32), _, _| {
+            let _ = tx.send((id, Response::Closed));
+            true
+        },
+    )?;
+
+    let proxy = conn.with_proxy(destination, NOTIFICATIONS_PATH, Duration::from_secs(5));
+    let (notification_id,): (u32,) = proxy.method_call(
+        NOTIFICATIONS_INTERFACE,
+        "Notify",
+        (
+            notification.appname.as_str(),
+            0_u32,
+            notification.icon.as_str(),
+            notification.summary.as_str(),
+            notification.body.as_str(),
+            actions,
+            hints,
+            timeout_ms(notification.timeout),
+        ),
+    )?;
+
+    let deadline = Instant::now() + MAX_WAIT;
+    while Instant::now() < deadline {
+        conn.process(Duration::from_millis(500))?;
+        while let Ok((id, response)) = rx.try_recv() {
+            if id == notification_id {
+                return Ok(response);
+            }
+        }
+    }
+    // nothing would handle its actions anymore
+    proxy.method_call::<(), _, _, _>(
+        NOTIFICATIONS_INTERFACE,
+        "CloseNotification",
+        (notification_id,),
+    )?;
+    Ok(Response::Closed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uuid::Uuid;
+    use warp::crypto::DID;
+
+    #[test]
+    fn resolve_responses() {
+        let conv_id = Uuid::new_v4();
+        let quick_actions = vec![QuickAction::Reply(conv_id), QuickAction::MarkRead(conv_id)];
+
+        assert_eq!(
+            resolve(
+                Response::Action("toast_actions.DisplayChat".into()),
+                "toast_actions.DisplayChat",
+                &quick_actions
+            ),
+            Resolved::Default
+        );
+        assert_eq!(
+            resolve(
+                Response::Replied("hello".into()),
+                "toast_actions.DisplayChat",
+                &quick_actions
+            ),
+            Resolved::Quick(QuickAction::Reply(conv_id), Some("hello".into()))
+        );
+        assert_eq!(
+            resolve(
+                Response::Action("quick_actions.MarkRead".into()),
+                "toast_actions.DisplayChat",
+                &quick_actions
+            ),
+            Resolved::Quick(QuickAction::MarkRead(conv_id), None)
+        );
+        assert_eq!(
+            resolve(
+                Response::Closed,
+                "toast_actions.DisplayChat",
+                &quick_actions
+            ),
+            Resolved::Ignored
+        );
+    }
+
+    #[test]
+    fn reply_without_reply_action_is_ignored() {
+        let did = DID::default();
+        let quick_actions = vec![
+            QuickAction::AcceptRequest(did.clone()),
+            QuickAction::DenyRequest(did.clone()),
+        ];
+        assert_eq!(
+            resolve(
+                Response::Replied("hello".into()),
+                "toast_actions.FriendListPending",
+                &quick_actions
+            ),
+            Resolved::Ignored
+        );
+        assert_eq!(
+            resolve(
+                Response::Action("quick_actions.DenyRequest".into()),
+                "toast_actions.FriendListPending",
+                &quick_actions
+            ),
+            Resolved::Quick(QuickAction::DenyRequest(did), None)
+        );
+    }
+
+    // Needs a session bus with a notification server stand-in that replies to every
+    // notification. Run with `cargo test -- --ignored` inside `dbus-run-session`.
+    #[test]
+    #[ignore]
+    fn reply_over_dbus() {
+        use dbus::{blocking::LocalConnection, channel::MatchingReceiver, message::Message};
+
+        let server_name = format!("im.satellite.test.Notifications{}", std::process::id());
+        let server = {
+            let server_name = server_name.clone();
+            std::thread::spawn(move || {
+                let conn = LocalConnection::new_session().unwrap();
+                conn.request_name(server_name.as_str(), false, true, false)
+                    .unwrap();
+                let done = std::rc::Rc::new(std::cell::Cell::new(false));
+                let done_cb = done.clone();
+                conn.start_receive(
+                    MatchRule::new_method_call(),
+                    Box::new(move |msg: Message, conn| {
+                        use dbus::channel::Sender;
+                        let _ = conn.send(msg.method_return().append1(7_u32));
+                        let signal = Message::new_signal(
+                            NOTIFICATIONS_PATH,
+                            NOTIFICATIONS_INTERFACE,
+                            "NotificationReplied",
+                        )
+                        .unwrap()
+                        .append2(7_u32, "hello");
+                        let _ = conn.send(signal);
+                        done_cb.set(true);
+                        true
+                    }),
+                );
+                while !done.get() {
+                    conn.process(Duration::from_millis(100)).unwrap();
+                }
+            })
+        };
+        std::thread::sleep(Duration::from_millis(200));
+
+        let conv_id = Uuid::new_v4();
+        let conn = Connection::new_session().unwrap();
+        let response = show_on(
+            &conn,
+            &server_name,
+            &Notification::new().summary("Uplink").finalize(),
+            "toast_actions.DisplayChat",
+            &[QuickAction::Reply(conv_id)],
+        )
+        .unwrap();
+        server.join().unwrap();
+        assert_eq!(response, Response::Replied("hello".into()));
+    }
+}
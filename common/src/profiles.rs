@@ -0,0 +1,145 @@
+//! Several identities can live under one install. Each profile has its own directory laid out like
+//! the original `.user` directory (warp, state.json, login_config.json, debug.log). The profile that
+//! existed before profiles were introduced keeps living in `.user` as the default profile.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::bail;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::log;
+use uuid::Uuid;
+
+use crate::STATIC_ARGS;
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+// the profile whose directory STATIC_ARGS currently points at
+static ACTIVE_PROFILE: Lazy<RwLock<String>> = Lazy::new(|| {
+    let profiles = Profiles::load();
+    RwLock::new(
+        profiles
+            .last_used
+            .filter(|id| profiles.profiles.iter().any(|p| &p.id == id))
+            .unwrap_or_else(|| DEFAULT_PROFILE.into()),
+    )
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+}
+
+impl Profile {
+    pub fn path(&self) -> PathBuf {
+        profile_path(&self.id)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profiles {
+    pub profiles: Vec<Profile>,
+    pub last_used: Option<String>,
+}
+
+impl Profiles {
+    /// Loads the profile list. The default profile is always part of it.
+    pub fn load() -> Self {
+        let mut profiles: Profiles = fs::read(STATIC_ARGS.profiles_file())
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        if !profiles.profiles.iter().any(|p| p.id == DEFAULT_PROFILE) {
+            profiles.profiles.insert(
+                0,
+                Profile {
+                    id: DEFAULT_PROFILE.into(),
+                    name: String::new(),
+                },
+            );
+        }
+        profiles
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(STATIC_ARGS.profiles_file(), contents)?;
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+
+    pub fn add(&mut self, name: String) -> anyhow::Result<Profile> {
+        let profile = Profile {
+            id: Uuid::new_v4().simple().to_string(),
+            name,
+        };
+        fs::create_dir_all(profile.path().join("warp"))?;
+        self.profiles.push(profile.clone());
+        self.save()?;
+        Ok(profile)
+    }
+
+    /// Remembers the username of a profile so the account picker has something to show.
+    pub fn set_name(&mut self, id: &str, name: String) -> anyhow::Result<()> {
+        match self.profiles.iter_mut().find(|p| p.id == id) {
+            Some(profile) if profile.name != name => {
+                profile.name = name;
+                self.save()
+            }
+            Some(_) => Ok(()),
+            None => bail!("profile {id} does not exist"),
+        }
+    }
+
+    /// Deletes a profile and all of its data. The default and the active profile can't be removed.
+    pub fn remove(&mut self, id: &str) -> anyhow::Result<()> {
+        if id == DEFAULT_PROFILE {
+            bail!("the default profile can't be removed");
+        }
+        if id == active_profile() {
+            bail!("the active profile can't be removed");
+        }
+        let profile = match self.get(id) {
+            Some(p) => p.clone(),
+            None => bail!("profile {id} does not exist"),
+        };
+        if let Err(e) = fs::remove_dir_all(profile.path()) {
+            log::warn!("failed to delete profile directory: {e}");
+        }
+        self.profiles.retain(|p| p.id != id);
+        if self.last_used.as_deref() == Some(id) {
+            self.last_used = None;
+        }
+        self.save()
+    }
+}
+
+pub fn profile_path(id: &str) -> PathBuf {
+    if id == DEFAULT_PROFILE {
+        STATIC_ARGS.dot_uplink.join(".user")
+    } else {
+        STATIC_ARGS.dot_uplink.join("profiles").join(id)
+    }
+}
+
+pub fn active_profile() -> String {
+    ACTIVE_PROFILE.read().clone()
+}
+
+/// Points STATIC_ARGS at another profile. The WarpRunner has to be restarted afterwards so that
+/// Tesseract and warp are opened from the new directory.
+pub fn set_active_profile(id: &str) -> anyhow::Result<()> {
+    let mut profiles = Profiles::load();
+    if profiles.get(id).is_none() {
+        bail!("profile {id} does not exist");
+    }
+    fs::create_dir_all(profile_path(id).join("warp"))?;
+    *ACTIVE_PROFILE.write() = id.to_string();
+    profiles.last_used = Some(id.to_string());
+    profiles.save()
+}
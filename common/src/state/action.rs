@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 use std::{collections::HashMap, rc::Weak};
 
+use chrono::{DateTime, Utc};
 use derive_more::Display;
 
 use dioxus_desktop::DesktopService;
@@ -10,14 +11,19 @@ use uuid::Uuid;
 use warp::crypto::DID;
 use warp::raygun::Location;
 
-use crate::warp_runner::ui_adapter;
+use crate::{
+    utils::media_compression::Compression,
+    warp_runner::{gifs::GifService, ui_adapter},
+};
 
 use super::{
+    bookmarks::Bookmark,
     call,
+    chats::Thread,
     identity::Identity,
     notifications::NotificationKind,
     route::To,
-    ui::{EmojiDestination, Font, Theme, ToastNotification, WindowMeta},
+    ui::{EmojiDestination, Font, SkinTone, Theme, ToastNotification, WindowMeta},
 };
 
 /// used exclusively by State::mutate
@@ -57,6 +63,10 @@ pub enum Action<'a> {
     TrackEmojiUsage(String),
     #[display(fmt = "SetEmojiPickerVisible")]
     SetEmojiPickerVisible(bool),
+    #[display(fmt = "ToggleFavoriteEmoji")]
+    ToggleFavoriteEmoji(String),
+    #[display(fmt = "SetEmojiSkinTone")]
+    SetEmojiSkinTone(SkinTone),
     #[display(fmt = "SetTransformMarkdownText")]
     SetTransformMarkdownText(bool),
     #[display(fmt = "SetTransformAsciiEmojis")]
@@ -105,6 +115,19 @@ pub enum Action<'a> {
     ClearAllNotifications,
     #[display(fmt = "DismissUpdate")]
     DismissUpdate,
+    /// Marks an entry of the notification center as read
+    #[display(fmt = "MarkNotificationRead")]
+    MarkNotificationRead(Uuid),
+    #[display(fmt = "MarkAllNotificationsRead")]
+    MarkAllNotificationsRead,
+    #[display(fmt = "ClearNotificationHistory")]
+    ClearNotificationHistory,
+    // Lock screen
+    /// Hides the app behind the lock screen. Warp keeps running.
+    #[display(fmt = "LockApp")]
+    LockApp,
+    #[display(fmt = "UnlockApp")]
+    UnlockApp,
     // Settings
     /// Sets the selected language.
     #[display(fmt = "SetLanguage")]
@@ -180,6 +203,9 @@ pub enum Action<'a> {
     /// Sets a files attached to send
     #[display(fmt = "SetChatAttachments")]
     SetChatAttachments(Uuid, Vec<Location>),
+    /// How the attachments of the chat are compressed when they are sent
+    #[display(fmt = "SetChatCompression")]
+    SetChatCompression(Uuid, Compression),
     /// Similar to SetChatAttachments, but appends to the existing attachments
     #[display(fmt = "AppendChatAttachments")]
     AppendChatAttachments(Uuid, Vec<PathBuf>),
@@ -192,6 +218,32 @@ pub enum Action<'a> {
     /// Clears the reply for a given chat
     #[display(fmt = "CancelReply")]
     CancelReply(Uuid),
+    /// conversation id, threads by the id of the message which started them. unread replies which
+    /// were already tracked are kept
+    #[display(fmt = "SetThreads")]
+    SetThreads(Uuid, HashMap<Uuid, Thread>),
+    /// conversation id, id of the message which started the thread
+    #[display(fmt = "OpenThread")]
+    OpenThread(Uuid, Uuid),
+    #[display(fmt = "CloseThread")]
+    CloseThread(Uuid),
+    /// opens the chat at a message. conversation id, message id, date of the message
+    #[display(fmt = "GoToMessage")]
+    GoToMessage(Uuid, Uuid, DateTime<Utc>),
+    #[display(fmt = "ClearGoToMessage")]
+    ClearGoToMessage(Uuid),
+    /// saves a message privately, or updates the note and tags of a saved one
+    #[display(fmt = "SaveBookmark")]
+    SaveBookmark(Bookmark),
+    /// message id
+    #[display(fmt = "RemoveBookmark")]
+    RemoveBookmark(Uuid),
+    /// allows or stops visiting links to show their previews in a chat
+    #[display(fmt = "SetChatLinkPreviews")]
+    SetChatLinkPreviews(Uuid, bool),
+    /// chat id, message id and its date. our read receipt moved to that message
+    #[display(fmt = "SetReadReceipt")]
+    SetReadReceipt(Uuid, Uuid, DateTime<Utc>),
     /// fakes sending a message to the specified chat
     /// for normal operation, warp sends a message, Uplink receives an event when that message was sent, and state is updated accordingly.
     /// for mock data, warp is not used and this is needed to fake sending a message
@@ -238,4 +290,19 @@ pub enum ConfigAction {
     SetAutoEnableExtensions(bool),
     #[display(fmt = "SetEchoCancellation {_0}")]
     SetEchoCancellation(bool),
+    #[display(fmt = "SetAutoLockMinutes {_0}")]
+    SetAutoLockMinutes(u32),
+    #[display(fmt = "SetLockOnSuspend {_0}")]
+    SetLockOnSuspend(bool),
+    #[display(fmt = "SetSendReadReceipts {_0}")]
+    SetSendReadReceipts(bool),
+    #[display(fmt = "SetLinkPreviews {_0}")]
+    SetLinkPreviews(bool),
+    #[display(fmt = "SetCompression {_0}")]
+    SetCompression(Compression),
+    #[display(fmt = "SetGifService {_0}")]
+    SetGifService(GifService),
+    // the key isn't logged
+    #[display(fmt = "SetGifApiKey")]
+    SetGifApiKey(String),
 }
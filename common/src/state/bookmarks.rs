@@ -0,0 +1,184 @@
+//! Bookmarks are saved messages only this device knows about, unlike pins which every member of a
+//! chat sees. They keep a copy of the message, so they still show it after it was deleted.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use warp::{crypto::DID, raygun};
+
+use super::utils::typed_lines;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub conversation_id: Uuid,
+    pub message_id: Uuid,
+    pub sender: DID,
+    pub lines: Vec<String>,
+    // names of the attached files
+    #[serde(default)]
+    pub attachments: Vec<String>,
+    // when the message was sent
+    pub date: DateTime<Utc>,
+    pub saved: DateTime<Utc>,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    // the message was deleted after it was saved
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+impl Bookmark {
+    pub fn new(message: &raygun::Message, note: String, tags: Vec<String>) -> Self {
+        Self {
+            conversation_id: message.conversation_id(),
+            message_id: message.id(),
+            sender: message.sender(),
+            lines: typed_lines(&message.lines()).to_vec(),
+            attachments: message.attachments().iter().map(|f| f.name()).collect(),
+            date: message.date(),
+            saved: Utc::now(),
+            note,
+            tags,
+            deleted: false,
+        }
+    }
+
+    /// Whether the text, note or tags contain `query`, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.lines
+            .iter()
+            .chain(self.attachments.iter())
+            .chain(self.tags.iter())
+            .chain(std::iter::once(&self.note))
+            .any(|text| text.to_lowercase().contains(&query))
+    }
+}
+
+/// Splits comma separated tags. Empty and repeated tags are left out.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+    for tag in input
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    all: Vec<Bookmark>,
+}
+
+impl Bookmarks {
+    pub fn is_empty(&self) -> bool {
+        self.all.is_empty()
+    }
+
+    pub fn get(&self, message_id: &Uuid) -> Option<&Bookmark> {
+        self.all.iter().find(|b| b.message_id == *message_id)
+    }
+
+    /// Adds the bookmark, or updates the note and tags if the message was saved before.
+    pub fn save(&mut self, bookmark: Bookmark) {
+        match self
+            .all
+            .iter_mut()
+            .find(|b| b.message_id == bookmark.message_id)
+        {
+            Some(existing) => {
+                existing.note = bookmark.note;
+                existing.tags = bookmark.tags;
+            }
+            None => self.all.push(bookmark),
+        }
+    }
+
+    pub fn remove(&mut self, message_id: &Uuid) {
+        self.all.retain(|b| b.message_id != *message_id);
+    }
+
+    pub fn message_edited(&mut self, message_id: &Uuid, lines: &[String]) {
+        if let Some(bookmark) = self.all.iter_mut().find(|b| b.message_id == *message_id) {
+            bookmark.lines = typed_lines(lines).to_vec();
+        }
+    }
+
+    pub fn message_deleted(&mut self, message_id: &Uuid) {
+        if let Some(bookmark) = self.all.iter_mut().find(|b| b.message_id == *message_id) {
+            bookmark.deleted = true;
+        }
+    }
+
+    /// Every tag in use, sorted.
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.all.iter().flat_map(|b| b.tags.clone()).collect();
+        tags.sort_by_key(|tag| tag.to_lowercase());
+        tags.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        tags
+    }
+
+    /// The bookmarks matching `query` and having `tag`, most recently saved first.
+    pub fn filtered(&self, query: &str, tag: Option<&str>) -> Vec<&Bookmark> {
+        let mut bookmarks: Vec<&Bookmark> = self
+            .all
+            .iter()
+            .filter(|b| query.is_empty() || b.matches(query))
+            .filter(|b| match tag {
+                Some(tag) => b.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+                None => true,
+            })
+            .collect();
+        bookmarks.sort_by(|a, b| b.saved.cmp(&a.saved));
+        bookmarks
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::Duration;
+
+    fn bookmark(text: &str, tags: &str, saved: DateTime<Utc>) -> Bookmark {
+        Bookmark {
+            conversation_id: Uuid::new_v4(),
+            message_id: Uuid::new_v4(),
+            sender: DID::default(),
+            lines: vec![text.to_string()],
+            attachments: vec![],
+            date: saved,
+            saved,
+            note: String::new(),
+            tags: parse_tags(tags),
+            deleted: false,
+        }
+    }
+
+    #[test]
+    fn filters_by_text_and_tag() {
+        let now = Utc::now();
+        let mut bookmarks = Bookmarks::default();
+        let recipe = bookmark("pancake recipe", "Food, ,food", now - Duration::days(1));
+        let address = bookmark("the new address", "places", now);
+        bookmarks.save(recipe.clone());
+        bookmarks.save(address.clone());
+
+        assert_eq!(recipe.tags, vec!["Food".to_string()]);
+        assert_eq!(bookmarks.tags(), vec!["Food".to_string(), "places".into()]);
+        assert_eq!(bookmarks.filtered("", None), vec![&address, &recipe]);
+        assert_eq!(bookmarks.filtered("RECIPE", None), vec![&recipe]);
+        assert_eq!(bookmarks.filtered("", Some("food")), vec![&recipe]);
+
+        bookmarks.message_deleted(&recipe.message_id);
+        assert!(bookmarks.get(&recipe.message_id).unwrap().deleted);
+        bookmarks.remove(&recipe.message_id);
+        assert_eq!(bookmarks.filtered("", None), vec![&address]);
+    }
+}
@@ -1,8 +1,10 @@
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
     time::Instant,
 };
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use warp::{
@@ -10,9 +12,21 @@ use warp::{
     raygun::{self, ConversationSettings, ConversationType, Location},
 };
 
-use crate::{warp_runner::ui_adapter, STATIC_ARGS};
+use crate::{utils::media_compression::Compression, warp_runner::ui_adapter, STATIC_ARGS};
 
-use super::pending_message::{FileLocation, FileProgression, PendingMessage};
+use super::{
+    bookmarks::Bookmarks,
+    emoji_packs::{CustomEmoji, EmojiPack},
+    mentions::GroupMentionPermission,
+    pending_message::{FileLocation, FileProgression, PendingMessage},
+};
+
+// the most recent mentions kept per chat
+pub const MAX_MENTIONS: usize = 100;
+
+// warp has no event for read receipts, so they are a reaction with this emoji on the newest
+// message someone has read. each participant moves theirs along as they read on
+pub const READ_RECEIPT_REACTION: &str = "👁️‍🗨️";
 
 // let (p = window_bottom) be an index into Chat.messages
 // show messages from (p - window_size) to (p + window_extra)
@@ -55,10 +69,27 @@ pub struct Chat {
     // Unread count for this chat, should be cleared when we view the chat.
     #[serde(default)]
     unreads: HashSet<Uuid>,
-    // This tracks the messages that mentions the user. For future use
-    // E.g. displaying a list of mentions to the user in a pop up
-    #[serde(default, skip)]
+    // the user allowed visiting links sent in this chat to show their previews
+    #[serde(default)]
+    pub link_previews: bool,
+    // the message our read receipt is on, with when it was sent. warp doesn't report the receipts
+    // yet, so this is how the reaction is found again when it moves along
+    #[serde(default)]
+    pub read_receipt: Option<(Uuid, DateTime<Utc>)>,
+    // This tracks the most recent messages that mention the user, including @everyone and @here.
+    // Used for the mentions filter in the sidebar
+    #[serde(default)]
     pub mentions: VecDeque<ui_adapter::Message>,
+    // the newest group mention setting the creator pinned, with its date. kept so someone else
+    // unpinning the setting doesn't reset it
+    #[serde(default)]
+    mention_permission: Option<(DateTime<Utc>, GroupMentionPermission)>,
+    // the emoji packs the creator pinned and the images of them which were downloaded, so they
+    // aren't parsed and looked for on the disk whenever a message is shown
+    #[serde(skip)]
+    emoji_packs: Vec<(Uuid, EmojiPack)>,
+    #[serde(skip)]
+    downloaded_emoji: HashSet<PathBuf>,
     // If a value exists, we will render the message we're replying to above the chatbar
     #[serde(skip)]
     pub replying_to: Option<raygun::Message>,
@@ -75,11 +106,23 @@ pub struct Chat {
     pub pending_outgoing_messages: Vec<PendingMessage>,
     #[serde(skip)]
     pub files_attached_to_send: Vec<Location>,
+    // picked for the attachments above, the default from the settings is used otherwise
+    #[serde(skip)]
+    pub compression_to_send: Option<Compression>,
     // used to determine number of unread messages, for the active chat
     #[serde(skip)]
     pub is_scrolled: bool,
     #[serde(skip)]
     pub pinned_messages: Vec<raygun::Message>,
+    // threads in this chat, by the id of the message which started them
+    #[serde(skip)]
+    pub threads: HashMap<Uuid, Thread>,
+    // the thread shown next to the chat
+    #[serde(skip)]
+    pub open_thread: Option<Uuid>,
+    // a message to scroll to when the chat is shown, with the date it was sent
+    #[serde(skip)]
+    pub go_to_message: Option<(Uuid, DateTime<Utc>)>,
 }
 
 fn skip_chat_messages(_messages: &VecDeque<ui_adapter::Message>) -> bool {
@@ -99,15 +142,24 @@ impl Default for Chat {
             creator: Default::default(),
             messages: Default::default(),
             unreads: Default::default(),
+            link_previews: false,
+            read_receipt: None,
             mentions: Default::default(),
+            mention_permission: Default::default(),
+            emoji_packs: Default::default(),
+            downloaded_emoji: Default::default(),
             replying_to: Default::default(),
             typing_indicator: Default::default(),
             draft: Default::default(),
             has_more_messages: Default::default(),
             pending_outgoing_messages: Default::default(),
             files_attached_to_send: Default::default(),
+            compression_to_send: Default::default(),
             is_scrolled: false,
             pinned_messages: Default::default(),
+            threads: Default::default(),
+            open_thread: Default::default(),
+            go_to_message: Default::default(),
         }
     }
 }
@@ -126,7 +178,7 @@ impl Chat {
             ConversationSettings::Direct(_) => ConversationType::Direct,
             ConversationSettings::Group(_) => ConversationType::Group,
         };
-        Self {
+        let mut chat = Self {
             id,
             participants,
             conversation_type,
@@ -136,7 +188,9 @@ impl Chat {
             messages,
             pinned_messages,
             ..Default::default()
-        }
+        };
+        chat.pins_changed();
+        chat
     }
     pub fn append_pending_msg(
         &mut self,
@@ -172,6 +226,20 @@ impl Chat {
         }
     }
 
+    pub fn set_pending_msg_placeholders(
+        &mut self,
+        message_id: Uuid,
+        placeholders: HashMap<FileLocation, String>,
+    ) {
+        if let Some(m) = self
+            .pending_outgoing_messages
+            .iter_mut()
+            .find(|m| m.id().eq(&message_id))
+        {
+            m.placeholders = placeholders;
+        }
+    }
+
     pub fn remove_pending_msg_attachment(&mut self, message_id: Uuid, location: FileLocation) {
         if let Some(m) = &mut self
             .pending_outgoing_messages
@@ -205,6 +273,192 @@ impl Chat {
     pub fn add_unread(&mut self, id: Uuid) {
         self.unreads.insert(id);
     }
+
+    /// Adds a reply to its thread. Returns true if it is unread for `own`: someone else replied to
+    /// a thread `own` takes part in and the thread isn't open.
+    pub fn add_thread_reply(&mut self, message: &ui_adapter::Message, own: &DID) -> bool {
+        let root = match message.thread_id {
+            Some(root) => root,
+            None => return false,
+        };
+        let open = self.open_thread == Some(root);
+        let parent_sender = message.in_reply_to.as_ref().map(|(_, _, sender)| sender);
+        let thread = self.threads.entry(root).or_default();
+        if let Some(sender) = parent_sender {
+            thread.participants.insert(sender.clone());
+        }
+        let participating = thread.participants.contains(own);
+        let sender = message.inner.sender();
+        let unread = participating && !open && sender != *own;
+        thread.add_reply(message.inner.id(), sender, message.inner.date());
+        if unread {
+            thread.add_unread(message.inner.id());
+        }
+        unread
+    }
+
+    pub fn remove_thread_reply(&mut self, id: &Uuid) {
+        self.threads.retain(|_, thread| {
+            thread.remove_reply(id);
+            !thread.replies.is_empty()
+        });
+    }
+
+    pub fn add_mention(&mut self, message: ui_adapter::Message) {
+        self.mentions.push_back(message);
+        if self.mentions.len() > MAX_MENTIONS {
+            self.mentions.pop_front();
+        }
+    }
+
+    /// The mentions which are still unread
+    pub fn unread_mentions(&self) -> usize {
+        self.mentions
+            .iter()
+            .filter(|m| self.unreads.contains(&m.inner.id()))
+            .count()
+    }
+
+    /// Who may use @everyone and @here, as set by the most recent setting the creator pinned.
+    pub fn group_mention_permission(&self) -> GroupMentionPermission {
+        self.newest_mention_permission()
+            .map(|(_, permission)| permission)
+            .unwrap_or_default()
+    }
+
+    fn newest_mention_permission(&self) -> Option<(DateTime<Utc>, GroupMentionPermission)> {
+        self.pinned_messages
+            .iter()
+            .filter(|m| self.creator.as_ref() == Some(&m.sender()))
+            .filter_map(|m| GroupMentionPermission::from_lines(&m.lines()).map(|p| (m.date(), p)))
+            .chain(self.mention_permission)
+            .max_by_key(|(date, _)| *date)
+    }
+
+    /// Saves the group mention setting and the emoji packs from the pinned messages, to be called
+    /// when they change.
+    pub fn pins_changed(&mut self) {
+        self.mention_permission = self.newest_mention_permission();
+        let mut packs: Vec<_> = self
+            .pinned_messages
+            .iter()
+            .filter(|m| self.creator.as_ref() == Some(&m.sender()))
+            .filter_map(|m| EmojiPack::from_lines(&m.lines()).map(|p| (m.date(), m.id(), p)))
+            .collect();
+        packs.sort_by_key(|(date, _, _)| *date);
+        self.emoji_packs = packs.into_iter().map(|(_, id, pack)| (id, pack)).collect();
+        self.emoji_downloaded();
+    }
+
+    /// Looks for the images of the emoji packs again, to be called once some were downloaded.
+    pub fn emoji_downloaded(&mut self) {
+        self.downloaded_emoji = self
+            .emoji_packs
+            .iter()
+            .flat_map(|(_, pack)| pack.emojis.iter().filter_map(|emoji| pack.path(emoji)))
+            .filter(|path| path.exists())
+            .collect();
+    }
+
+    /// Whether an @everyone or @here from `sender` mentions the members of this chat
+    pub fn can_mention_group(&self, sender: &DID) -> bool {
+        self.conversation_type == ConversationType::Group
+            && self
+                .group_mention_permission()
+                .allows(sender, self.creator.as_ref())
+    }
+
+    /// The emoji packs the creator pinned, oldest first, with the id of the message holding each.
+    pub fn emoji_packs(&self) -> &[(Uuid, EmojiPack)] {
+        &self.emoji_packs
+    }
+
+    /// The emoji packs with images which weren't downloaded yet.
+    pub fn incomplete_emoji_packs(&self) -> Vec<(Uuid, EmojiPack)> {
+        self.emoji_packs
+            .iter()
+            .filter(|(_, pack)| {
+                pack.emojis
+                    .iter()
+                    .filter_map(|emoji| pack.path(emoji))
+                    .any(|path| !self.downloaded_emoji.contains(&path))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The custom emoji for a shortcode and its image, if the image was downloaded. The oldest
+    /// pack wins if several have the same shortcode.
+    pub fn custom_emoji(&self, shortcode: &str) -> Option<(CustomEmoji, PathBuf)> {
+        self.emoji_packs.iter().find_map(|(_, pack)| {
+            let emoji = pack.find(shortcode)?;
+            let path = pack.path(emoji)?;
+            self.downloaded_emoji
+                .contains(&path)
+                .then(|| (emoji.clone(), path))
+        })
+    }
+
+    /// The custom emoji whose shortcode starts with the pattern, as `(":shortcode:", shortcode)`
+    /// like [`super::ui::EmojiCounter::get_matching_emoji`].
+    pub fn matching_custom_emoji(&self, pattern: &str) -> Vec<(String, String)> {
+        let pattern = pattern.to_lowercase();
+        let mut matches: Vec<(String, String)> = vec![];
+        for (_, pack) in self.emoji_packs.iter() {
+            for emoji in pack.emojis.iter() {
+                if emoji.shortcode.starts_with(&pattern)
+                    && !matches
+                        .iter()
+                        .any(|(_, shortcode)| *shortcode == emoji.shortcode)
+                {
+                    matches.push((emoji.code(), emoji.shortcode.clone()));
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// The replies to a message. See [`ui_adapter::Message::thread_id`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Thread {
+    pub replies: HashSet<Uuid>,
+    // everyone who replied, and the sender of the message which started the thread
+    pub participants: HashSet<DID>,
+    pub last_reply: Option<DateTime<Utc>>,
+    // tracked separately from the chat so a thread can be marked as read on its own
+    unreads: HashSet<Uuid>,
+}
+
+impl Thread {
+    pub fn add_reply(&mut self, id: Uuid, sender: DID, date: DateTime<Utc>) {
+        self.replies.insert(id);
+        self.participants.insert(sender);
+        if self.last_reply.map(|last| last < date).unwrap_or(true) {
+            self.last_reply = Some(date);
+        }
+    }
+
+    pub fn remove_reply(&mut self, id: &Uuid) -> bool {
+        self.unreads.remove(id);
+        self.replies.remove(id)
+    }
+
+    pub fn unreads(&self) -> u32 {
+        self.unreads.len() as _
+    }
+
+    pub fn unread_replies(&self) -> impl Iterator<Item = &Uuid> {
+        self.unreads.iter()
+    }
+
+    pub fn add_unread(&mut self, id: Uuid) {
+        self.unreads.insert(id);
+    }
+
+    pub fn clear_unreads(&mut self) {
+        self.unreads.clear();
+    }
 }
 
 // warning: Chats implements Serialize
@@ -225,9 +479,67 @@ pub struct Chats {
     pub favorites: Vec<Uuid>,
     // If there was a problem with loading state or state was deleted we readd all existing chats to the sidebar.
     pub readd_sidebars: bool,
+    // Prior versions of edited messages by conversation and message, oldest first. Only edits to
+    // messages this client had loaded are known.
+    #[serde(default)]
+    pub edit_history: HashMap<Uuid, HashMap<Uuid, Vec<MessageRevision>>>,
+    // messages saved privately by the user, across all chats
+    #[serde(default)]
+    pub bookmarks: Bookmarks,
+}
+
+// older revisions of a message are dropped past this amount
+pub const MAX_MESSAGE_REVISIONS: usize = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageRevision {
+    pub lines: Vec<String>,
+    // when this version of the message was written
+    pub date: DateTime<Utc>,
+}
+
+impl MessageRevision {
+    /// The version of the message `edited` replaced, if its text changed.
+    pub fn of_edit(previous: &raygun::Message, edited: &raygun::Message) -> Option<Self> {
+        (previous.lines() != edited.lines()).then(|| MessageRevision {
+            lines: previous.lines(),
+            date: previous.modified().unwrap_or(previous.date()),
+        })
+    }
 }
 
 impl Chats {
+    /// Keeps `revision` as the version of the message before its latest edit. The same edit may be
+    /// seen in more than one copy of the message, it is only kept once.
+    pub fn add_revision(
+        &mut self,
+        conversation_id: Uuid,
+        message_id: Uuid,
+        revision: MessageRevision,
+    ) {
+        let revisions = self
+            .edit_history
+            .entry(conversation_id)
+            .or_default()
+            .entry(message_id)
+            .or_default();
+        if revisions.last().map(|r| r.lines == revision.lines) == Some(true) {
+            return;
+        }
+        revisions.push(revision);
+        if revisions.len() > MAX_MESSAGE_REVISIONS {
+            revisions.remove(0);
+        }
+    }
+
+    pub fn revisions(&self, conversation_id: Uuid, message_id: Uuid) -> &[MessageRevision] {
+        self.edit_history
+            .get(&conversation_id)
+            .and_then(|messages| messages.get(&message_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
     pub fn active_chat_has_unreads(&self) -> bool {
         let id = match self.active {
             Some(c) => c,
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use tracing::log;
 
-use crate::STATIC_ARGS;
+use crate::{utils::media_compression::Compression, warp_runner::gifs::GifService, STATIC_ARGS};
 
 use super::action::ConfigAction;
 
@@ -31,6 +31,18 @@ pub struct Configuration {
     /// Notification-related configuration options.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// Options for locking the app while the user is away.
+    #[serde(default)]
+    pub security: Security,
+
+    /// File-related configuration options.
+    #[serde(default)]
+    pub files: Files,
+
+    /// Where the GIF picker searches.
+    #[serde(default)]
+    pub gifs: Gifs,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -47,12 +59,30 @@ pub struct General {
     pub enable_overlay: bool,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
 pub struct Privacy {
     #[serde(default)]
     pub satellite_sync_nodes: bool,
     #[serde(default)]
     pub safer_file_scanning: bool,
+    /// Lets the other participants know which messages were read.
+    #[serde(default = "bool_true")]
+    pub send_read_receipts: bool,
+    /// Visits links in every chat to show their previews. Otherwise they are only visited in the
+    /// chats where the user allowed it.
+    #[serde(default)]
+    pub link_previews: bool,
+}
+
+impl Default for Privacy {
+    fn default() -> Self {
+        Self {
+            satellite_sync_nodes: false,
+            safer_file_scanning: false,
+            send_read_receipts: true,
+            link_previews: false,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
@@ -92,6 +122,31 @@ pub struct Developer {
     pub experimental_features: bool,
 }
 
+#[derive(Debug, Default, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+pub struct Security {
+    /// Minutes without any input before the app locks itself. 0 turns auto-lock off.
+    #[serde(default)]
+    pub auto_lock_minutes: u32,
+    #[serde(default)]
+    pub lock_on_suspend: bool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+pub struct Files {
+    /// How attachments are compressed, unless another option is picked when sending them.
+    #[serde(default)]
+    pub compression: Compression,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct Gifs {
+    #[serde(default)]
+    pub service: GifService,
+    /// For the service. The folder of GIFs is searched until one is set.
+    #[serde(default)]
+    pub api_key: String,
+}
+
 fn bool_true() -> bool {
     true
 }
@@ -130,7 +185,7 @@ impl Configuration {
 
 impl Configuration {
     pub fn load_or_default() -> Self {
-        if let Ok(b) = std::fs::read(&STATIC_ARGS.login_config_path) {
+        if let Ok(b) = std::fs::read(STATIC_ARGS.login_config_path()) {
             if let Ok(n) = serde_json::from_slice(&b) {
                 return n;
             }
@@ -168,6 +223,13 @@ impl Configuration {
                 self.extensions.enable_automatically = flag
             }
             ConfigAction::SetEchoCancellation(flag) => self.audiovideo.echo_cancellation = flag,
+            ConfigAction::SetAutoLockMinutes(minutes) => self.security.auto_lock_minutes = minutes,
+            ConfigAction::SetLockOnSuspend(flag) => self.security.lock_on_suspend = flag,
+            ConfigAction::SetSendReadReceipts(flag) => self.privacy.send_read_receipts = flag,
+            ConfigAction::SetLinkPreviews(flag) => self.privacy.link_previews = flag,
+            ConfigAction::SetCompression(compression) => self.files.compression = compression,
+            ConfigAction::SetGifService(service) => self.gifs.service = service,
+            ConfigAction::SetGifApiKey(key) => self.gifs.api_key = key,
         }
 
         if self.audiovideo != old_audiovideo {
@@ -178,7 +240,7 @@ impl Configuration {
                     return;
                 }
             };
-            if let Err(e) = std::fs::write(&STATIC_ARGS.login_config_path, contents) {
+            if let Err(e) = std::fs::write(STATIC_ARGS.login_config_path(), contents) {
                 log::error!("failed to save login_config: {e}");
             }
         }
@@ -0,0 +1,371 @@
+//! Custom emoji and stickers come in packs of images, imported from a folder or a zip. The creator
+//! of a group chat shares a pack by sending a message with the images attached and the pack on its
+//! last line, then pins it. The attachments are stored in Constellation, participants download them
+//! into their own copy of the pack. In text and reactions they are written as `:shortcode:`.
+
+use std::{
+    collections::HashSet,
+    fs,
+    io::{Cursor, Read},
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::bail;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use walkdir::WalkDir;
+use zip::ZipArchive;
+
+use super::message_marker::Marker;
+use crate::{utils::local_file_path::get_fixed_path_to_load_local_file, STATIC_ARGS};
+
+// images in a folder with this name are stickers, the others are emoji
+const STICKERS_DIR: &str = "stickers";
+const IMAGE_EXTENSIONS: [&str; 5] = ["png", "gif", "webp", "jpg", "jpeg"];
+pub const MAX_EMOJIS_PER_PACK: usize = crate::MAX_FILES_PER_MESSAGE;
+pub const MAX_IMAGE_SIZE: u64 = 512 * 1024;
+
+static SHORTCODE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r":([a-z0-9_]{2,32}):").expect("valid regex"));
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmojiPack {
+    pub id: Uuid,
+    pub name: String,
+    pub emojis: Vec<CustomEmoji>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomEmoji {
+    pub shortcode: String,
+    // the name of the image in the folder of the pack, and of the attachment it is shared as
+    pub file: String,
+    #[serde(default)]
+    pub sticker: bool,
+}
+
+impl CustomEmoji {
+    /// How it's written in text and reactions
+    pub fn code(&self) -> String {
+        format!(":{}:", self.shortcode)
+    }
+
+    // packs come from other participants, the file must stay in the folder of the pack
+    fn is_valid(&self) -> bool {
+        let mut components = Path::new(&self.file).components();
+        let is_file_name = matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        );
+        is_shortcode(&self.shortcode)
+            && is_file_name
+            && image_extension(Path::new(&self.file)).is_some()
+    }
+}
+
+/// Whether it's 2 to 32 lowercase letters, digits and underscores.
+pub fn is_shortcode(shortcode: &str) -> bool {
+    (2..=32).contains(&shortcode.len())
+        && shortcode
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Where the images of all packs are kept, a folder per pack.
+pub fn packs_dir() -> PathBuf {
+    STATIC_ARGS.uplink_path().join("emoji_packs")
+}
+
+impl EmojiPack {
+    /// The pack on the last line, without the emoji whose shortcode or file isn't valid.
+    pub fn from_lines(lines: &[String]) -> Option<Self> {
+        let mut pack: Self = Marker::EmojiPack.from_lines(lines)?;
+        pack.emojis.retain(CustomEmoji::is_valid);
+        Some(pack)
+    }
+
+    /// The line holding the pack. Clients which don't know about it show the lines before it.
+    pub fn to_line(&self) -> String {
+        Marker::EmojiPack.to_line(self)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        packs_dir().join(self.id.to_string())
+    }
+
+    /// Where the image is kept, None if its file name would put it anywhere else.
+    pub fn path(&self, emoji: &CustomEmoji) -> Option<PathBuf> {
+        emoji.is_valid().then(|| self.dir().join(&emoji.file))
+    }
+
+    pub fn find(&self, shortcode: &str) -> Option<&CustomEmoji> {
+        self.emojis.iter().find(|e| e.shortcode == shortcode)
+    }
+
+    /// The images which haven't been downloaded yet, and where they go.
+    pub fn missing(&self) -> Vec<(&CustomEmoji, PathBuf)> {
+        self.emojis
+            .iter()
+            .filter_map(|emoji| Some((emoji, self.path(emoji)?)))
+            .filter(|(_, path)| !path.exists())
+            .collect()
+    }
+}
+
+/// Imports the images of a folder or a zip as a new pack, copying them to [`packs_dir`].
+pub fn import(path: &Path) -> anyhow::Result<EmojiPack> {
+    import_into(path, &packs_dir())
+}
+
+fn import_into(path: &Path, packs_dir: &Path) -> anyhow::Result<EmojiPack> {
+    let name = path
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut pack = EmojiPack {
+        id: Uuid::new_v4(),
+        name,
+        emojis: vec![],
+    };
+    let dir = packs_dir.join(pack.id.to_string());
+    fs::create_dir_all(&dir)?;
+    let mut shortcodes = HashSet::new();
+    let mut add = |relative: &Path, contents: Vec<u8>| -> anyhow::Result<()> {
+        let Some(extension) = image_extension(relative) else {
+            return Ok(());
+        };
+        if contents.len() as u64 > MAX_IMAGE_SIZE || pack.emojis.len() >= MAX_EMOJIS_PER_PACK {
+            return Ok(());
+        }
+        let stem = relative
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut shortcode = to_shortcode(&stem);
+        if shortcode.len() < 2 {
+            return Ok(());
+        }
+        // the same name in two folders
+        let base = shortcode.clone();
+        let mut n = 1;
+        while !shortcodes.insert(shortcode.clone()) {
+            n += 1;
+            shortcode = format!("{base}_{n}");
+        }
+        let file = format!("{shortcode}.{extension}");
+        fs::write(dir.join(&file), contents)?;
+        pack.emojis.push(CustomEmoji {
+            shortcode,
+            file,
+            sticker: relative
+                .parent()
+                .is_some_and(|parent| parent.components().any(|c| c.as_os_str() == STICKERS_DIR)),
+        });
+        Ok(())
+    };
+
+    let imported = if path.is_dir() {
+        let mut entries: Vec<_> = WalkDir::new(path)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_file())
+            .collect();
+        entries.sort_by(|a, b| a.path().cmp(b.path()));
+        entries.iter().try_for_each(|entry| {
+            let relative = entry.path().strip_prefix(path)?;
+            if image_extension(relative).is_none() || entry.metadata()?.len() > MAX_IMAGE_SIZE {
+                return Ok(());
+            }
+            add(relative, fs::read(entry.path())?)
+        })
+    } else {
+        fs::read(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|archive| {
+                let mut zip = ZipArchive::new(Cursor::new(archive))?;
+                let mut names: Vec<String> = zip.file_names().map(String::from).collect();
+                names.sort();
+                names.iter().try_for_each(|name| {
+                    let mut file = zip.by_name(name)?;
+                    // enclosed_name rejects absolute paths and paths escaping the archive
+                    let Some(relative) = file.enclosed_name().map(Path::to_path_buf) else {
+                        return Ok(());
+                    };
+                    if !file.is_file() || file.size() > MAX_IMAGE_SIZE {
+                        return Ok(());
+                    }
+                    let mut contents = vec![];
+                    file.read_to_end(&mut contents)?;
+                    add(&relative, contents)
+                })
+            })
+    };
+    if let Err(e) = imported.and_then(|_| {
+        if pack.emojis.is_empty() {
+            bail!("no images found");
+        }
+        Ok(())
+    }) {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+    Ok(pack)
+}
+
+fn image_extension(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_string_lossy().to_lowercase();
+    IMAGE_EXTENSIONS
+        .iter()
+        .find(|e| **e == extension)
+        .map(|e| if *e == "jpeg" { "jpg" } else { e })
+}
+
+/// Lowercase letters, digits and underscores, e.g. "Party Parrot" becomes party_parrot.
+pub fn to_shortcode(name: &str) -> String {
+    let mut shortcode = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            shortcode.push(c.to_ascii_lowercase());
+        } else if !shortcode.is_empty() && !shortcode.ends_with('_') {
+            shortcode.push('_');
+        }
+    }
+    shortcode.truncate(32);
+    shortcode.trim_end_matches('_').to_string()
+}
+
+/// Replaces every `:shortcode:` in the text `replace` returns something for.
+pub fn replace_shortcodes(text: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
+    SHORTCODE_REGEX
+        .replace_all(text, |captures: &regex::Captures| {
+            replace(&captures[1]).unwrap_or_else(|| captures[0].to_string())
+        })
+        .to_string()
+}
+
+/// Whether an image may be shown in a message, only the images of the packs are.
+pub fn is_pack_image(src: &str) -> bool {
+    is_image_in(src, &get_fixed_path_to_load_local_file(packs_dir()))
+}
+
+fn is_image_in(src: &str, dir: &str) -> bool {
+    src.strip_prefix(dir).is_some_and(|rest| {
+        rest.starts_with('/')
+            && Path::new(rest)
+                .components()
+                .all(|c| matches!(c, Component::Normal(_) | Component::RootDir))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn imports_folders_and_zips() {
+        let src = tempfile::tempdir().unwrap();
+        fs::create_dir_all(src.path().join("stickers")).unwrap();
+        fs::write(src.path().join("Party Parrot.png"), b"png").unwrap();
+        fs::write(src.path().join("stickers/wave.GIF"), b"gif").unwrap();
+        fs::write(src.path().join("readme.txt"), b"text").unwrap();
+        let packs = tempfile::tempdir().unwrap();
+
+        let pack = import_into(src.path(), packs.path()).unwrap();
+        assert_eq!(
+            pack.emojis,
+            vec![
+                CustomEmoji {
+                    shortcode: "party_parrot".into(),
+                    file: "party_parrot.png".into(),
+                    sticker: false,
+                },
+                CustomEmoji {
+                    shortcode: "wave".into(),
+                    file: "wave.gif".into(),
+                    sticker: true,
+                },
+            ]
+        );
+        let dir = packs.path().join(pack.id.to_string());
+        assert_eq!(fs::read(dir.join("wave.gif")).unwrap(), b"gif");
+
+        let archive = src.path().join("pack.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        for name in ["a/cat.png", "b/cat.webp", "../escape.png"] {
+            zip.start_file(name, Default::default()).unwrap();
+            zip.write_all(b"img").unwrap();
+        }
+        zip.finish().unwrap();
+        let pack = import_into(&archive, packs.path()).unwrap();
+        assert_eq!(pack.name, "pack");
+        let shortcodes: Vec<_> = pack.emojis.iter().map(|e| e.shortcode.as_str()).collect();
+        assert_eq!(shortcodes, vec!["cat", "cat_2"]);
+
+        let empty = tempfile::tempdir().unwrap();
+        assert!(import_into(empty.path(), packs.path()).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_message_lines() {
+        let pack = EmojiPack {
+            id: Uuid::new_v4(),
+            name: "party".into(),
+            emojis: vec![CustomEmoji {
+                shortcode: "parrot".into(),
+                file: "parrot.gif".into(),
+                sticker: false,
+            }],
+        };
+        let lines = vec!["shared an emoji pack".to_string(), pack.to_line()];
+        assert_eq!(EmojiPack::from_lines(&lines), Some(pack));
+        assert_eq!(EmojiPack::from_lines(&lines[..1]), None);
+    }
+
+    #[test]
+    fn drops_emoji_outside_of_the_pack() {
+        let emoji = |shortcode: &str, file: &str| CustomEmoji {
+            shortcode: shortcode.into(),
+            file: file.into(),
+            sticker: false,
+        };
+        let pack = EmojiPack {
+            id: Uuid::new_v4(),
+            name: "party".into(),
+            emojis: vec![
+                emoji("parrot", "parrot.gif"),
+                emoji("escape", "../../config.png"),
+                emoji("nested", "a/nested.png"),
+                emoji("absolute", "/etc/absolute.png"),
+                emoji("script", "script.html"),
+                emoji("Bad Code", "bad.png"),
+                emoji("x", "x.png"),
+            ],
+        };
+        let parsed = EmojiPack::from_lines(&[pack.to_line()]).unwrap();
+        assert_eq!(parsed.emojis, vec![emoji("parrot", "parrot.gif")]);
+        assert_eq!(pack.path(&pack.emojis[1]), None);
+        assert!(is_shortcode("party_parrot_2"));
+        assert!(!is_shortcode(&"a".repeat(33)));
+    }
+
+    #[test]
+    fn replaces_known_shortcodes() {
+        let replaced = replace_shortcodes(":parrot: at 10:30: :unknown:", |code| {
+            (code == "parrot").then(|| "🦜".to_string())
+        });
+        assert_eq!(replaced, "🦜 at 10:30: :unknown:");
+        assert_eq!(to_shortcode("  Party--Parrot! "), "party_parrot");
+    }
+
+    #[test]
+    fn only_shows_pack_images() {
+        let dir = "/home/user/.uplink/emoji_packs";
+        assert!(is_image_in(&format!("{dir}/pack/parrot.gif"), dir));
+        assert!(!is_image_in(&format!("{dir}/../secret.png"), dir));
+        assert!(!is_image_in(&format!("{dir}_evil/parrot.gif"), dir));
+        assert!(!is_image_in("https://example.com/parrot.gif", dir));
+    }
+}
@@ -0,0 +1,64 @@
+//! Forwarded messages are re-sent copies of a message. Their first line says where the message
+//! came from, so the rest reads like the original on clients which don't know about forwarding.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use warp::crypto::DID;
+
+use super::message_marker::Marker;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Forwarded {
+    pub sender: DID,
+    pub sender_name: String,
+    // only set when the message was sent in a group
+    #[serde(default)]
+    pub conversation_name: Option<String>,
+    pub date: DateTime<Utc>,
+}
+
+impl Forwarded {
+    /// Splits the lines of a message into where it was forwarded from, if it was, and its text.
+    pub fn split(lines: &[String]) -> (Option<Self>, &[String]) {
+        Marker::Forwarded.split(lines)
+    }
+
+    /// The lines to send when forwarding `lines`. A message which was forwarded before keeps
+    /// where it originally came from.
+    pub fn to_lines(&self, lines: &[String]) -> Vec<String> {
+        let (original, text) = Self::split(lines);
+        let forwarded = original.as_ref().unwrap_or(self);
+        let mut lines = vec![Marker::Forwarded.to_line(forwarded)];
+        lines.extend_from_slice(text);
+        lines
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_the_original_sender() {
+        let original = Forwarded {
+            sender: DID::default(),
+            sender_name: "alice".into(),
+            conversation_name: Some("friends".into()),
+            date: Utc::now(),
+        };
+        let text = vec!["hello".to_string()];
+        let lines = original.to_lines(&text);
+        assert_eq!(
+            Forwarded::split(&lines),
+            (Some(original.clone()), &text[..])
+        );
+
+        let again = Forwarded {
+            sender_name: "bob".into(),
+            conversation_name: None,
+            ..original.clone()
+        };
+        assert_eq!(again.to_lines(&lines), lines);
+        assert_eq!(Forwarded::split(&text), (None, &text[..]));
+    }
+}
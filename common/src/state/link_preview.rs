@@ -0,0 +1,85 @@
+//! A link preview is the title, description and a small thumbnail of the first link in a message.
+//! The sender fetches it and adds it to the last line of the message by editing it once it was
+//! sent, so the receivers never have to visit the link themselves.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::message_marker::Marker;
+
+// anything else would be loaded from wherever the sender pointed it to
+const THUMBNAIL_PREFIX: &str = "data:image/";
+// fetching a preview takes at most a couple of requests, each with a timeout
+const PREVIEW_EDIT_SECS: i64 = 30;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkPreview {
+    pub url: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    // a data url, empty if the page has no image
+    #[serde(default)]
+    pub thumbnail: String,
+    // whether the page links to an oEmbed endpoint, so it can be embedded
+    #[serde(default)]
+    pub oembed: bool,
+}
+
+impl LinkPreview {
+    /// Splits the lines of a message into its link preview, if it has one, and its text.
+    pub fn split(lines: &[String]) -> (Option<Self>, &[String]) {
+        let (preview, text) = Marker::LinkPreview.split::<Self>(lines);
+        (preview.map(Self::without_remote_thumbnail), text)
+    }
+
+    /// Whether a message with a preview, edited at `modified`, was likely only edited to add it.
+    pub fn is_preview_edit(sent: DateTime<Utc>, modified: DateTime<Utc>) -> bool {
+        (modified - sent).num_seconds() <= PREVIEW_EDIT_SECS
+    }
+
+    /// Drops a thumbnail which isn't a data url.
+    pub fn without_remote_thumbnail(mut self) -> Self {
+        if !self.thumbnail.starts_with(THUMBNAIL_PREFIX) {
+            self.thumbnail.clear();
+        }
+        self
+    }
+
+    /// The line to add to a message to send the preview with it.
+    pub fn to_line(&self) -> String {
+        Marker::LinkPreview.to_line(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn splits_the_preview_line() {
+        let preview = LinkPreview {
+            url: "https://example.com".into(),
+            title: "Example".into(),
+            ..Default::default()
+        };
+        let text = vec!["look at https://example.com".to_string()];
+        let mut lines = text.clone();
+        lines.push(preview.to_line());
+        assert_eq!(LinkPreview::split(&lines), (Some(preview), &text[..]));
+        assert_eq!(LinkPreview::split(&text), (None, &text[..]));
+        assert_eq!(LinkPreview::split(&[]), (None, &[][..]));
+    }
+
+    #[test]
+    fn drops_remote_thumbnails() {
+        let preview = LinkPreview {
+            url: "https://example.com".into(),
+            title: "Example".into(),
+            thumbnail: "https://tracker.example.com/pixel.png".into(),
+            ..Default::default()
+        };
+        let (split, _) = LinkPreview::split(std::slice::from_ref(&preview.to_line()));
+        assert_eq!(split.unwrap().thumbnail, "");
+    }
+}
@@ -0,0 +1,54 @@
+//! `@everyone` and `@here` mention every member of a group. Warp's group settings can't carry
+//! who may use them, so the creator sends the setting as a message and pins it. Pinned messages
+//! are loaded with the conversation, which lets members who join later see the setting too.
+
+use serde::{Deserialize, Serialize};
+use warp::crypto::DID;
+
+use super::message_marker::Marker;
+
+/// The group wide mentions, without the leading `@`.
+pub const GROUP_MENTIONS: [&str; 2] = ["everyone", "here"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GroupMentionPermission {
+    #[default]
+    Everyone,
+    CreatorOnly,
+}
+
+impl GroupMentionPermission {
+    pub fn from_lines(lines: &[String]) -> Option<Self> {
+        Marker::GroupMentions.from_lines(lines)
+    }
+
+    /// The line holding the setting. Clients which don't know about it show the lines before it.
+    pub fn to_line(&self) -> String {
+        Marker::GroupMentions.to_line(self)
+    }
+
+    pub fn allows(&self, sender: &DID, creator: Option<&DID>) -> bool {
+        match self {
+            Self::Everyone => true,
+            Self::CreatorOnly => creator == Some(sender),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_message_lines() {
+        let lines = vec![
+            "only the creator can mention everyone".to_string(),
+            GroupMentionPermission::CreatorOnly.to_line(),
+        ];
+        assert_eq!(
+            GroupMentionPermission::from_lines(&lines),
+            Some(GroupMentionPermission::CreatorOnly)
+        );
+        assert_eq!(GroupMentionPermission::from_lines(&lines[..1]), None);
+    }
+}
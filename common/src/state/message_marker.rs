@@ -0,0 +1,120 @@
+//! Uplink sends what warp messages have no field for as marker lines: a prefix followed by JSON.
+//! Clients which don't know about a marker show it as text, the lines around it still read like a
+//! regular message. The forwarded-from header is the first line of a message, the other markers
+//! are on the last one.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Marker {
+    Forwarded,
+    LinkPreview,
+    Poll,
+    GroupMentions,
+    EmojiPack,
+}
+
+impl Marker {
+    /// Every marker, which is how the lines the sender typed are told apart from them.
+    pub const ALL: [Marker; 5] = [
+        Marker::Forwarded,
+        Marker::LinkPreview,
+        Marker::Poll,
+        Marker::GroupMentions,
+        Marker::EmojiPack,
+    ];
+
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Marker::Forwarded => "uplink-forwarded:",
+            Marker::LinkPreview => "uplink-link-preview:",
+            Marker::Poll => "uplink-poll:",
+            Marker::GroupMentions => "uplink-group-mentions:",
+            Marker::EmojiPack => "uplink-emoji-pack:",
+        }
+    }
+
+    // polls and settings keep their data on the last line, so the header can't go there
+    fn is_first_line(self) -> bool {
+        self == Marker::Forwarded
+    }
+
+    /// The line holding `value`.
+    pub fn to_line<T: Serialize>(self, value: &T) -> String {
+        format!(
+            "{}{}",
+            self.prefix(),
+            serde_json::to_string(value).unwrap_or_default()
+        )
+    }
+
+    /// Whether `line` is this marker, whatever it holds.
+    pub fn is_line(self, line: &str) -> bool {
+        line.starts_with(self.prefix())
+    }
+
+    /// Splits the lines of a message into the value of this marker, if they have it, and the
+    /// other lines.
+    pub fn split<T: DeserializeOwned>(self, lines: &[String]) -> (Option<T>, &[String]) {
+        let split = if self.is_first_line() {
+            lines.split_first()
+        } else {
+            lines.split_last()
+        };
+        let value = split.and_then(|(line, rest)| {
+            let json = line.strip_prefix(self.prefix())?;
+            Some((serde_json::from_str(json).ok()?, rest))
+        });
+        match value {
+            Some((value, rest)) => (Some(value), rest),
+            None => (None, lines),
+        }
+    }
+
+    /// The value of this marker, if the lines of a message have it.
+    pub fn from_lines<T: DeserializeOwned>(self, lines: &[String]) -> Option<T> {
+        self.split(lines).0
+    }
+}
+
+/// The lines of a message without any marker lines, as the sender typed them. A link preview is
+/// added after the other markers.
+pub fn strip(lines: &[String]) -> &[String] {
+    let mut lines = lines;
+    for marker in Marker::ALL {
+        let split = if marker.is_first_line() {
+            lines.split_first()
+        } else {
+            lines.split_last()
+        };
+        if let Some((line, rest)) = split {
+            if marker.is_line(line) {
+                lines = rest;
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn splits_markers_off_their_line() {
+        let text = vec!["hello".to_string()];
+        let mut lines = vec![Marker::Forwarded.to_line(&1)];
+        lines.extend(text.clone());
+        lines.push(Marker::Poll.to_line(&2));
+        lines.push(Marker::LinkPreview.to_line(&3));
+
+        assert_eq!(Marker::Forwarded.from_lines::<i32>(&lines), Some(1));
+        assert_eq!(Marker::LinkPreview.from_lines::<i32>(&lines), Some(3));
+        assert_eq!(Marker::LinkPreview.from_lines::<String>(&lines), None);
+        // only the first or the last line is a marker
+        assert_eq!(Marker::Poll.from_lines::<i32>(&lines), None);
+        assert_eq!(Marker::Poll.split::<i32>(&text), (None, &text[..]));
+        assert_eq!(strip(&lines), &text[..]);
+        assert_eq!(strip(&text), &text[..]);
+    }
+}
@@ -1,13 +1,20 @@
 pub mod action;
+pub mod bookmarks;
 pub mod call;
 pub mod chats;
 pub mod configuration;
 pub mod data_transfer;
 pub mod default_keybinds;
+pub mod emoji_packs;
+pub mod forwarded;
 pub mod friends;
 pub mod identity;
+pub mod link_preview;
+pub mod mentions;
+pub mod message_marker;
 pub mod notifications;
 pub mod pending_message;
+pub mod poll;
 pub mod route;
 pub mod scope_ids;
 pub mod settings;
@@ -16,13 +23,14 @@ pub mod ui;
 pub mod utils;
 
 use crate::language::{change_language, get_local_text_with_args};
-use crate::notifications::NotificationAction;
+use crate::notifications::{NotificationAction, QuickAction};
 use crate::warp_runner::WarpCmdTx;
 // export specific structs which the UI expects. these structs used to be in src/state.rs, before state.rs was turned into the `state` folder
 use crate::{language::get_local_text, warp_runner::ui_adapter};
 pub use action::Action;
-pub use chats::{Chat, Chats};
+pub use chats::{Chat, Chats, MessageRevision};
 use dioxus_desktop::tao::window::WindowId;
+use extensions::embeds;
 pub use friends::Friends;
 pub use identity::Identity;
 pub use route::Route;
@@ -55,7 +63,9 @@ use warp::{crypto::DID, multipass::identity::IdentityStatus, raygun};
 use tracing::log;
 
 use self::call::Call;
+use self::notifications::{NotificationEntry, NotificationEntryKind};
 use self::pending_message::{FileLocation, FileProgression, PendingMessage};
+use self::utils::typed_lines;
 
 use self::storage::Storage;
 use self::ui::{Font, Layout};
@@ -194,6 +204,19 @@ impl State {
                     .notifications
                     .decrement(notifications::NotificationKind::Settings, 1);
             }
+            Action::MarkNotificationRead(id) => {
+                self.ui.notification_history.mark_read(id);
+            }
+            Action::MarkAllNotificationsRead => self.ui.notification_history.mark_all_read(),
+            Action::ClearNotificationHistory => self.ui.notification_history.clear(),
+            Action::LockApp => {
+                self.ui.locked = true;
+                crate::notifications::set_app_locked(true);
+            }
+            Action::UnlockApp => {
+                self.ui.locked = false;
+                crate::notifications::set_app_locked(false);
+            }
             // ===== Friends =====
             Action::SendRequest(identity) => self.new_outgoing_request(&identity),
             Action::RequestAccepted(identity) => self.complete_request(&identity),
@@ -234,6 +257,8 @@ impl State {
             Action::TrackEmojiUsage(emoji) => self.ui.track_emoji_usage(emoji),
             Action::SetEmojiDestination(destination) => self.ui.emoji_destination = destination,
             Action::SetEmojiPickerVisible(visible) => self.ui.emoji_picker_visible = visible,
+            Action::ToggleFavoriteEmoji(emoji) => self.ui.emojis.toggle_favorite(emoji),
+            Action::SetEmojiSkinTone(skin_tone) => self.ui.emojis.set_skin_tone(skin_tone),
             Action::SetTransformMarkdownText(flag) => self.ui.transform_markdown_text(flag),
             Action::SetTransformAsciiEmojis(flag) => self.ui.transform_ascii_emojis(flag),
             // ===== Settings =====
@@ -259,6 +284,56 @@ impl State {
             Action::StartReplying(chat, message) => self.start_replying(chat, message),
             Action::CancelReply(chat_id) => self.cancel_reply(chat_id),
             Action::ClearUnreads(id) => self.clear_unreads(id),
+            Action::SetThreads(chat_id, mut threads) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    for (root, thread) in threads.iter_mut() {
+                        if let Some(old) = chat.threads.get(root) {
+                            for id in old.unread_replies() {
+                                if thread.replies.contains(id) {
+                                    thread.add_unread(*id);
+                                }
+                            }
+                        }
+                    }
+                    chat.threads = threads;
+                }
+            }
+            Action::OpenThread(chat_id, root) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.open_thread = Some(root);
+                    if let Some(thread) = chat.threads.get_mut(&root) {
+                        thread.clear_unreads();
+                    }
+                }
+            }
+            Action::CloseThread(chat_id) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.open_thread = None;
+                }
+            }
+            Action::GoToMessage(chat_id, message_id, date) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.go_to_message = Some((message_id, date));
+                    self.set_active_chat(&chat_id, false);
+                }
+            }
+            Action::ClearGoToMessage(chat_id) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.go_to_message = None;
+                }
+            }
+            Action::SaveBookmark(bookmark) => self.chats.bookmarks.save(bookmark),
+            Action::RemoveBookmark(message_id) => self.chats.bookmarks.remove(&message_id),
+            Action::SetChatLinkPreviews(chat_id, enabled) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.link_previews = enabled;
+                }
+            }
+            Action::SetReadReceipt(chat_id, message_id, date) => {
+                if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+                    chat.read_receipt = Some((message_id, date));
+                }
+            }
             Action::ClearActiveUnreads => {
                 if let Some(id) = self.chats.active {
                     self.clear_unreads(id);
@@ -270,6 +345,11 @@ impl State {
             Action::SetChatAttachments(chat_id, value) => {
                 self.set_chat_attachments(&chat_id, value)
             }
+            Action::SetChatCompression(chat_id, compression) => {
+                if let Some(c) = self.chats.all.get_mut(&chat_id) {
+                    c.compression_to_send = Some(compression);
+                }
+            }
             Action::AppendChatAttachments(chat_id, value) => {
                 if value.is_empty() {
                     return;
@@ -300,7 +380,7 @@ impl State {
                 m.set_sender(sender);
                 m.set_lines(msg);
                 let m = ui_adapter::Message::new(m, None, Uuid::new_v4().to_string());
-                self.add_msg_to_chat(id, m);
+                self.add_msg_to_chat(id, m, false);
             }
             // ===== Media =====
             Action::ToggleMute => self.toggle_mute(),
@@ -379,6 +459,16 @@ impl State {
             MultiPassEvent::None => {}
             MultiPassEvent::FriendRequestReceived(identity) => {
                 self.new_incoming_request(&identity);
+                self.ui.notification_history.push(NotificationEntry::new(
+                    NotificationEntryKind::FriendRequest,
+                    get_local_text("friends.new-request"),
+                    get_local_text_with_args(
+                        "friends.new-request-name",
+                        vec![("name", identity.username())],
+                    ),
+                    None,
+                    NotificationAction::FriendListPending,
+                ));
 
                 self.mutate(Action::AddNotification(
                     notifications::NotificationKind::FriendRequest,
@@ -390,8 +480,9 @@ impl State {
                 // Dispatch notifications only when we're not already focused on the application.
                 let notifications_enabled = self.configuration.notifications.friends_notifications;
 
-                if !self.ui.metadata.focused && notifications_enabled {
-                    crate::notifications::push_notification(
+                // the lock screen would give away who sent it
+                if !self.ui.metadata.focused && notifications_enabled && !self.ui.locked {
+                    crate::notifications::push_notification_with_quick_actions(
                         get_local_text("friends.new-request"),
                         get_local_text_with_args(
                             "friends.new-request-name",
@@ -400,6 +491,10 @@ impl State {
                         Some(crate::sounds::Sounds::Notification),
                         notify_rust::Timeout::Milliseconds(4),
                         NotificationAction::FriendListPending,
+                        vec![
+                            QuickAction::AcceptRequest(identity.did_key()),
+                            QuickAction::DenyRequest(identity.did_key()),
+                        ],
                     );
                 }
             }
@@ -451,8 +546,10 @@ impl State {
                 self.chats.all.insert(chat.inner.id, chat.inner);
             }
             RayGunEvent::ConversationDeleted(id) => {
+                self.ui.notification_history.remove_conversation(id);
                 self.chats.in_sidebar.retain(|x| *x != id);
                 self.chats.all.remove(&id);
+                self.chats.edit_history.remove(&id);
                 if self.chats.active == Some(id) {
                     self.chats.active = None;
                 }
@@ -467,11 +564,55 @@ impl State {
                 mut message,
             } => {
                 let own = self.get_own_identity().did_key();
-                let ping = message.is_mention_self(&own);
+                let group_mentions = self
+                    .chats
+                    .all
+                    .get(&conversation_id)
+                    .map(|chat| chat.can_mention_group(&message.inner.sender()))
+                    .unwrap_or_default();
+                let ping = message.is_mention_self(&own, group_mentions);
                 self.update_identity_status_hack(&message.inner.sender());
                 let id = self.identities.get(&message.inner.sender()).cloned();
+                let thread_unread = self
+                    .chats
+                    .all
+                    .get_mut(&conversation_id)
+                    .map(|chat| chat.add_thread_reply(&message, &own))
+                    .unwrap_or_default();
+                if let (Some(root), true, false) = (message.thread_id, thread_unread, ping) {
+                    let text = match id.as_ref() {
+                        Some(id) => get_local_text_with_args(
+                            "notification-center.user-replied-in-thread",
+                            vec![("user", id.username())],
+                        ),
+                        None => get_local_text("notification-center.unknown-replied-in-thread"),
+                    };
+                    self.ui.notification_history.push(NotificationEntry::new(
+                        NotificationEntryKind::ThreadReply,
+                        text,
+                        typed_lines(&message.inner.lines()).join("\n"),
+                        Some(conversation_id),
+                        NotificationAction::DisplayThread(conversation_id, root),
+                    ));
+                }
+                if ping {
+                    let text = match id.as_ref() {
+                        Some(id) => get_local_text_with_args(
+                            "notification-center.user-mentioned-you",
+                            vec![("user", id.username())],
+                        ),
+                        None => get_local_text("notification-center.unknown-mentioned-you"),
+                    };
+                    self.ui.notification_history.push(NotificationEntry::new(
+                        NotificationEntryKind::Mention,
+                        text,
+                        typed_lines(&message.inner.lines()).join("\n"),
+                        Some(conversation_id),
+                        NotificationAction::DisplayChat(conversation_id),
+                    ));
+                }
                 // todo: don't load all the messages by default. if the user scrolled up, for example, this incoming message may not need to be fetched yet.
-                self.add_msg_to_chat(conversation_id, message);
+                self.add_msg_to_chat(conversation_id, message, ping);
 
                 //if self.chats.in_sidebar.contains(&conversation_id) {
                 self.send_chat_to_top_of_sidebar(conversation_id);
@@ -489,8 +630,10 @@ impl State {
                 let notifications_enabled = self.configuration.notifications.enabled;
                 let should_play_sound = self.ui.current_layout != Layout::Compose
                     && self.configuration.audiovideo.message_sounds;
-                let should_dispatch_notification =
-                    should_play_sound && message_notifications_enabled && notifications_enabled;
+                let should_dispatch_notification = should_play_sound
+                    && message_notifications_enabled
+                    && notifications_enabled
+                    && !self.ui.locked;
 
                 // This should be called if we have notifications enabled for new messages
                 if should_dispatch_notification {
@@ -506,12 +649,16 @@ impl State {
                         ),
                         None => get_local_text("messages.unknown-sent-message"),
                     };
-                    crate::notifications::push_notification(
+                    crate::notifications::push_notification_with_quick_actions(
                         get_local_text("messages.new"),
                         text,
                         sound,
                         notify_rust::Timeout::Milliseconds(4),
                         NotificationAction::DisplayChat(conversation_id),
+                        vec![
+                            QuickAction::Reply(conversation_id),
+                            QuickAction::MarkRead(conversation_id),
+                        ],
                     );
                 }
             }
@@ -521,7 +668,9 @@ impl State {
             } => {
                 // todo: don't load all the messages by default. if the user scrolled up, for example, this incoming message may not need to be fetched yet.
                 let message_clone = message.clone();
+                let own = self.get_own_identity().did_key();
                 if let Some(chat) = self.chats.all.get_mut(&conversation_id) {
+                    chat.add_thread_reply(&message, &own);
                     chat.messages.push_back(message);
                 }
                 self.send_chat_to_top_of_sidebar(conversation_id);
@@ -533,8 +682,21 @@ impl State {
             } => {
                 self.update_identity_status_hack(&message.inner.sender());
                 let own = self.get_own_identity().did_key();
+                let mut revision = None;
                 if let Some(chat) = self.chats.all.get_mut(&conversation_id) {
                     let id = message.inner.id();
+                    // the chat only holds a few of its messages, the ones which are loaded in the
+                    // chat record their edits too, see `State::add_message_revision`
+                    let previous = chat
+                        .messages
+                        .iter()
+                        .map(|msg| &msg.inner)
+                        .chain(chat.mentions.iter().map(|msg| &msg.inner))
+                        .chain(chat.pinned_messages.iter())
+                        .find(|msg| msg.id() == id);
+                    if let Some(previous) = previous {
+                        revision = MessageRevision::of_edit(previous, &message.inner);
+                    }
                     if let Some(msg) = chat.messages.iter_mut().find(|msg| msg.inner.id() == id) {
                         *msg = message.clone();
                     }
@@ -547,16 +709,26 @@ impl State {
 
                     if let Some(msg) = chat.pinned_messages.iter_mut().find(|m| m.id() == id) {
                         *msg = message.inner.clone();
+                        chat.pins_changed();
                     }
 
-                    if message.is_mention_self(&own) {
-                        if let Some(msg) = chat.mentions.iter_mut().find(|m| m.inner.id() == id) {
-                            *msg = message.clone();
+                    let group_mentions = chat.can_mention_group(&message.inner.sender());
+                    if message.is_mention_self(&own, group_mentions) {
+                        match chat.mentions.iter_mut().find(|m| m.inner.id() == id) {
+                            Some(msg) => *msg = message.clone(),
+                            None => chat.add_mention(message.clone()),
                         }
                     } else {
                         chat.mentions.retain(|m| m.inner.id() != id);
                     }
                 }
+                if let Some(revision) = revision {
+                    self.chats
+                        .add_revision(conversation_id, message.inner.id(), revision);
+                }
+                self.chats
+                    .bookmarks
+                    .message_edited(&message.inner.id(), &message.inner.lines());
             }
             MessageEvent::Deleted {
                 conversation_id,
@@ -571,7 +743,13 @@ impl State {
                     }
                     chat.messages.retain(|msg| msg.inner.id() != message_id);
                     chat.pinned_messages.retain(|msg| msg.id() != message_id);
+                    chat.pins_changed();
                     chat.mentions.retain(|msg| msg.inner.id() != message_id);
+                    chat.remove_thread_reply(&message_id);
+                    if let Some(revisions) = self.chats.edit_history.get_mut(&conversation_id) {
+                        revisions.remove(&message_id);
+                    }
+                    self.chats.bookmarks.message_deleted(&message_id);
 
                     if let Some(msg) = most_recent_message {
                         if chat.messages.is_empty() {
@@ -691,6 +869,16 @@ impl State {
                 }
             }
             BlinkEventKind::CallCancelled { call_id } => {
+                // the call was still pending, so nobody answered it
+                if let Some(call) = self
+                    .ui
+                    .call_info
+                    .pending_calls()
+                    .into_iter()
+                    .find(|c| c.id == call_id)
+                {
+                    self.add_missed_call(call.conversation_id);
+                }
                 self.ui.call_info.remove_pending_call(call_id);
             }
             BlinkEventKind::ParticipantJoined { call_id, peer_id } => {
@@ -769,9 +957,9 @@ impl State {
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         let serialized = serde_json::to_string_pretty(self)?;
         let path = if STATIC_ARGS.use_mock {
-            &STATIC_ARGS.mock_cache_path
+            STATIC_ARGS.mock_cache_path()
         } else {
-            &STATIC_ARGS.cache_path
+            STATIC_ARGS.cache_path()
         };
         fs::write(path, serialized)?;
         Ok(())
@@ -790,7 +978,7 @@ impl State {
         let mut success = true;
 
         let mut state = {
-            match fs::read_to_string(&STATIC_ARGS.cache_path) {
+            match fs::read_to_string(STATIC_ARGS.cache_path()) {
                 Ok(contents) => match serde_json::from_str(&contents) {
                     Ok(s) => s,
                     Err(e) => {
@@ -858,7 +1046,7 @@ impl State {
     fn load_mock() -> Self {
         generate_mock()
         // the following doesn't work anymore now that Identities are centralized
-        // let contents = match fs::read_to_string(&STATIC_ARGS.mock_cache_path) {
+        // let contents = match fs::read_to_string(&STATIC_ARGS.mock_cache_path()) {
         //     Ok(r) => r,
         //     Err(_) => {
         //         return generate_mock();
@@ -882,6 +1070,7 @@ impl State {
                 conv.conversation_name = chat.conversation_name;
                 conv.creator = chat.creator;
                 conv.pinned_messages = chat.pinned_messages;
+                conv.pins_changed();
             } else {
                 self.chats.all.insert(id, chat);
             }
@@ -956,13 +1145,20 @@ impl State {
 
         has_favs || is_minimal_view || sidebar_hidden || experimental_features
     }
-    fn add_msg_to_chat(&mut self, conversation_id: Uuid, message: ui_adapter::Message) {
+    fn add_msg_to_chat(
+        &mut self,
+        conversation_id: Uuid,
+        message: ui_adapter::Message,
+        is_mention: bool,
+    ) {
         let msg_id = message.inner.id();
         let is_active_scrolled = self.chats.active_chat_is_scrolled();
         if let Some(chat) = self.chats.all.get_mut(&conversation_id) {
             chat.typing_indicator.remove(&message.inner.sender());
             chat.messages.push_back(message.clone());
-            chat.mentions.push_back(message);
+            if is_mention {
+                chat.add_mention(message);
+            }
             // only care about the most recent message, for the sidebar
             if chat.messages.len() > 1 {
                 chat.messages.pop_front();
@@ -1052,6 +1248,7 @@ impl State {
     fn clear_chat_attachments(&mut self, chat_id: &Uuid) {
         if let Some(c) = self.chats.all.get_mut(chat_id) {
             c.files_attached_to_send.clear();
+            c.compression_to_send = None;
         }
     }
 
@@ -1065,6 +1262,7 @@ impl State {
         if let Some(chat) = self.chats.all.get_mut(&chat_id) {
             chat.clear_unreads();
         }
+        self.ui.notification_history.mark_conversation_read(chat_id);
     }
     /// Adds the given chat to the user's favorites.
     fn favorite(&mut self, chat: &Uuid) {
@@ -1121,6 +1319,28 @@ impl State {
         self.chats.favorites.contains(&chat.id)
     }
 
+    /// Whether links sent in the chat may be visited to show their previews.
+    pub fn link_previews_enabled(&self, chat: &Uuid) -> bool {
+        self.configuration.privacy.link_previews
+            || self
+                .chats
+                .all
+                .get(chat)
+                .map(|chat| chat.link_previews)
+                .unwrap_or_default()
+    }
+
+    /// The built in embed providers, followed by those of the enabled extensions.
+    pub fn embed_registry(&self) -> embeds::Registry {
+        let mut registry = embeds::Registry::default();
+        for (_, ext) in self.ui.extensions.values().filter(|(enabled, _)| *enabled) {
+            for provider in ext.embed_providers() {
+                registry.register(provider.clone());
+            }
+        }
+        registry
+    }
+
     pub fn reached_max_pinned(&self, chat: &Uuid) -> bool {
         let conv = match self.chats.all.get(chat) {
             Some(c) => c,
@@ -1145,6 +1365,7 @@ impl State {
         conv.pinned_messages.push(message);
         conv.pinned_messages
             .sort_by_key(|r| std::cmp::Reverse(r.date()));
+        conv.pins_changed();
 
         if let Some(msg) = conv
             .messages
@@ -1166,6 +1387,7 @@ impl State {
         };
 
         conv.pinned_messages.retain(|x| x.id() != message_id);
+        conv.pins_changed();
 
         if let Some(msg) = conv
             .messages
@@ -1232,6 +1454,21 @@ impl State {
         self.chats.in_sidebar.push_front(chat_id);
     }
 
+    /// Shows the custom emoji whose images were downloaded.
+    pub fn emoji_downloaded(&mut self, chat_id: Uuid) {
+        if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+            chat.emoji_downloaded();
+        }
+    }
+
+    /// Keeps the version of a message loaded in the chat, `previous`, which `edited` replaced.
+    pub fn add_message_revision(&mut self, previous: &raygun::Message, edited: &raygun::Message) {
+        if let Some(revision) = MessageRevision::of_edit(previous, edited) {
+            self.chats
+                .add_revision(edited.conversation_id(), edited.id(), revision);
+        }
+    }
+
     pub fn set_chat_scrolled(&mut self, chat_id: Uuid, val: bool) {
         if let Some(chat) = self.chats.all.get_mut(&chat_id) {
             chat.is_scrolled = val;
@@ -1260,6 +1497,17 @@ impl State {
         }
     }
 
+    pub fn set_outgoing_placeholders(
+        &mut self,
+        chat_id: Uuid,
+        message_id: Uuid,
+        placeholders: HashMap<FileLocation, String>,
+    ) {
+        if let Some(chat) = self.chats.all.get_mut(&chat_id) {
+            chat.set_pending_msg_placeholders(message_id, placeholders);
+        }
+    }
+
     pub fn update_outgoing_messages(
         &mut self,
         conv_id: Uuid,
@@ -1470,6 +1718,13 @@ impl State {
 
     pub fn update_available(&mut self, version: String) {
         if self.settings.update_available != Some(version.clone()) {
+            self.ui.notification_history.push(NotificationEntry::new(
+                NotificationEntryKind::Update,
+                get_local_text("notification-center.update"),
+                get_local_text_with_args("uplink.update-available", vec![("version", &version)]),
+                None,
+                NotificationAction::DisplaySettings,
+            ));
             self.settings.update_available = Some(version);
             self.ui.notifications.increment(
                 &self.configuration,
@@ -1500,6 +1755,31 @@ impl State {
             false
         }
     }
+    fn add_missed_call(&mut self, conversation_id: Uuid) {
+        let name = self.chats.all.get(&conversation_id).and_then(|chat| {
+            chat.conversation_name.clone().or_else(|| {
+                chat.participants
+                    .iter()
+                    .find(|did| **did != self.did_key())
+                    .and_then(|did| self.identities.get(did))
+                    .map(|id| id.username())
+            })
+        });
+        let content = match name {
+            Some(name) => get_local_text_with_args(
+                "notification-center.missed-call-from",
+                vec![("name", name)],
+            ),
+            None => get_local_text("notification-center.missed-call-unknown"),
+        };
+        self.ui.notification_history.push(NotificationEntry::new(
+            NotificationEntryKind::MissedCall,
+            get_local_text("notification-center.missed-call"),
+            content,
+            Some(conversation_id),
+            NotificationAction::DisplayChat(conversation_id),
+        ));
+    }
     pub fn has_toasts(&self) -> bool {
         !self.ui.toast_notifications.is_empty()
     }
@@ -1,6 +1,10 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::notifications::set_badge;
+use crate::notifications::{set_badge, NotificationAction};
 
 use super::configuration::Configuration;
 
@@ -141,3 +145,187 @@ impl Notifications {
         let _ = set_badge(self.badge);
     }
 }
+
+// older entries are dropped once the history grows past this size
+pub const MAX_NOTIFICATION_HISTORY: usize = 200;
+
+// Used by the notification center to pick an icon and a label for each entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum NotificationEntryKind {
+    Mention,
+    ThreadReply,
+    FriendRequest,
+    MissedCall,
+    Update,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NotificationEntry {
+    pub id: Uuid,
+    pub kind: NotificationEntryKind,
+    pub title: String,
+    pub content: String,
+    // Entries are grouped by conversation in the notification center. Friend requests and updates have no conversation.
+    pub conversation_id: Option<Uuid>,
+    // Dispatched over the NOTIFICATION_LISTENER when the entry is clicked.
+    pub action: NotificationAction,
+    pub date: DateTime<Utc>,
+    pub read: bool,
+}
+
+impl NotificationEntry {
+    pub fn new(
+        kind: NotificationEntryKind,
+        title: String,
+        content: String,
+        conversation_id: Option<Uuid>,
+        action: NotificationAction,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            title,
+            content,
+            conversation_id,
+            action,
+            date: Utc::now(),
+            read: false,
+        }
+    }
+}
+
+// Persisted list of past notifications, most recent first.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NotificationHistory {
+    entries: VecDeque<NotificationEntry>,
+}
+
+impl NotificationHistory {
+    pub fn push(&mut self, entry: NotificationEntry) {
+        self.entries.push_front(entry);
+        self.entries.truncate(MAX_NOTIFICATION_HISTORY);
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &NotificationEntry> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|e| !e.read).count()
+    }
+
+    // Returns true if the entry was found and was previously unread.
+    pub fn mark_read(&mut self, id: Uuid) -> bool {
+        match self.entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) => !std::mem::replace(&mut entry.read, true),
+            None => false,
+        }
+    }
+
+    pub fn mark_all_read(&mut self) {
+        self.entries.iter_mut().for_each(|e| e.read = true);
+    }
+
+    // Opening a chat marks every entry belonging to it as read.
+    pub fn mark_conversation_read(&mut self, conversation_id: Uuid) {
+        self.entries
+            .iter_mut()
+            .filter(|e| e.conversation_id == Some(conversation_id))
+            .for_each(|e| e.read = true);
+    }
+
+    // Drops the entries of a deleted conversation.
+    pub fn remove_conversation(&mut self, conversation_id: Uuid) {
+        self.entries
+            .retain(|e| e.conversation_id != Some(conversation_id));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    // Groups entries by conversation. Groups are ordered by their most recent entry and
+    // entries without a conversation are grouped together under `None`.
+    pub fn grouped(&self) -> Vec<(Option<Uuid>, Vec<&NotificationEntry>)> {
+        let mut groups: Vec<(Option<Uuid>, Vec<&NotificationEntry>)> = vec![];
+        for entry in self.entries.iter() {
+            match groups
+                .iter_mut()
+                .find(|(id, _)| *id == entry.conversation_id)
+            {
+                Some((_, group)) => group.push(entry),
+                None => groups.push((entry.conversation_id, vec![entry])),
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(conversation_id: Option<Uuid>) -> NotificationEntry {
+        NotificationEntry::new(
+            NotificationEntryKind::Mention,
+            String::new(),
+            String::new(),
+            conversation_id,
+            NotificationAction::Dummy,
+        )
+    }
+
+    #[test]
+    fn history_is_capped_and_most_recent_first() {
+        let mut history = NotificationHistory::default();
+        let first = entry(None);
+        let first_id = first.id;
+        history.push(first);
+        for _ in 0..MAX_NOTIFICATION_HISTORY {
+            history.push(entry(None));
+        }
+        assert_eq!(history.entries().count(), MAX_NOTIFICATION_HISTORY);
+        assert!(history.entries().all(|e| e.id != first_id));
+    }
+
+    #[test]
+    fn mark_read() {
+        let mut history = NotificationHistory::default();
+        let chat = Uuid::new_v4();
+        let a = entry(Some(chat));
+        let a_id = a.id;
+        history.push(a);
+        history.push(entry(Some(chat)));
+        history.push(entry(None));
+        assert_eq!(history.unread_count(), 3);
+
+        assert!(history.mark_read(a_id));
+        assert!(!history.mark_read(a_id));
+        assert_eq!(history.unread_count(), 2);
+
+        history.mark_conversation_read(chat);
+        assert_eq!(history.unread_count(), 1);
+
+        history.mark_all_read();
+        assert_eq!(history.unread_count(), 0);
+    }
+
+    #[test]
+    fn grouped_by_conversation() {
+        let mut history = NotificationHistory::default();
+        let chat1 = Uuid::new_v4();
+        let chat2 = Uuid::new_v4();
+        history.push(entry(Some(chat1)));
+        history.push(entry(None));
+        history.push(entry(Some(chat2)));
+        history.push(entry(Some(chat1)));
+
+        let groups = history.grouped();
+        let order: Vec<_> = groups.iter().map(|(id, g)| (*id, g.len())).collect();
+        assert_eq!(order, vec![(Some(chat1), 2), (Some(chat2), 1), (None, 1)]);
+    }
+}
@@ -9,6 +9,8 @@ use crate::warp_runner::ui_adapter::Message;
 #[derive(Clone, Debug)]
 pub struct PendingMessage {
     pub attachments_progress: HashMap<FileLocation, FileProgression>,
+    /// blurred images shown while the attachments are uploaded, as data urls
+    pub placeholders: HashMap<FileLocation, String>,
     pub message: Message,
 }
 
@@ -50,6 +52,7 @@ impl PendingMessage {
         let message = Message::new(inner, None, Uuid::new_v4().to_string());
         PendingMessage {
             attachments_progress: HashMap::new(),
+            placeholders: HashMap::new(),
             message,
         }
     }
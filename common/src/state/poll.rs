@@ -0,0 +1,156 @@
+//! Polls are sent as regular messages. The message text is readable by clients which don't know
+//! about polls, its last line holds the poll itself. Votes are reactions with the emoji of an option,
+//! so they sync and update like any other reaction. Anonymous polls only hide the voters in the UI.
+//! Closing a poll saves who had voted, the votes still have to be there as reactions to count, so
+//! the creator can't make votes up.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use warp::crypto::DID;
+
+use super::message_marker::Marker;
+
+/// The reaction used to vote for each option, in order.
+pub const OPTION_EMOJIS: [&str; 10] = ["1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣", "6️⃣", "7️⃣", "8️⃣", "9️⃣", "🔟"];
+
+pub const MAX_POLL_OPTIONS: usize = OPTION_EMOJIS.len();
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Poll {
+    pub question: String,
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub multiple_choice: bool,
+    #[serde(default)]
+    pub anonymous: bool,
+    #[serde(default)]
+    pub closes_at: Option<DateTime<Utc>>,
+    // the voters of each option, saved by the creator when closing the poll. reactions added later
+    // don't count
+    #[serde(default)]
+    pub results: Option<Vec<Vec<DID>>>,
+}
+
+impl Poll {
+    pub fn from_lines(lines: &[String]) -> Option<Self> {
+        let poll: Poll = Marker::Poll.from_lines(lines)?;
+        (!poll.options.is_empty() && poll.options.len() <= MAX_POLL_OPTIONS).then_some(poll)
+    }
+
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("📊 {}", self.question)];
+        lines.extend(
+            self.options
+                .iter()
+                .zip(OPTION_EMOJIS)
+                .map(|(option, emoji)| format!("{emoji} {option}")),
+        );
+        lines.push(Marker::Poll.to_line(self));
+        lines
+    }
+
+    pub fn option_for_emoji(&self, emoji: &str) -> Option<usize> {
+        OPTION_EMOJIS
+            .iter()
+            .take(self.options.len())
+            .position(|e| *e == emoji)
+    }
+
+    pub fn is_closed(&self, now: DateTime<Utc>) -> bool {
+        self.results.is_some() || self.closes_at.map(|at| at <= now).unwrap_or_default()
+    }
+
+    /// The voters for each option. For single choice polls someone who reacted to several options
+    /// only counts for the first of them. Once the poll was closed only the voters saved then count.
+    pub fn voters<'a, S: AsRef<str>>(
+        &self,
+        reactions: impl IntoIterator<Item = (S, &'a Vec<DID>)>,
+    ) -> Vec<Vec<DID>> {
+        let mut voters = vec![vec![]; self.options.len()];
+        for (emoji, users) in reactions {
+            if let Some(option) = self.option_for_emoji(emoji.as_ref()) {
+                voters[option] = users.clone();
+            }
+        }
+        if let Some(results) = &self.results {
+            for (option, saved) in voters.iter_mut().zip(results) {
+                option.retain(|did| saved.contains(did));
+            }
+        }
+        if !self.multiple_choice {
+            let mut seen = vec![];
+            for option in voters.iter_mut() {
+                option.retain(|did| !seen.contains(did));
+                seen.extend(option.iter().cloned());
+            }
+        }
+        voters
+    }
+
+    /// Votes per option.
+    pub fn tally<'a, S: AsRef<str>>(
+        &self,
+        reactions: impl IntoIterator<Item = (S, &'a Vec<DID>)>,
+    ) -> Vec<usize> {
+        self.voters(reactions).iter().map(Vec::len).collect()
+    }
+
+    /// The poll as its creator sends it when closing it.
+    pub fn closed<'a, S: AsRef<str>>(
+        &self,
+        reactions: impl IntoIterator<Item = (S, &'a Vec<DID>)>,
+    ) -> Self {
+        Poll {
+            results: Some(self.voters(reactions)),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn poll(multiple_choice: bool) -> Poll {
+        Poll {
+            question: "lunch?".into(),
+            options: vec!["pizza".into(), "tacos".into()],
+            multiple_choice,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn round_trips_through_message_lines() {
+        let poll = poll(false);
+        let lines = poll.to_lines();
+        assert_eq!(lines[0], "📊 lunch?");
+        assert_eq!(lines[2], "2️⃣ tacos");
+        assert_eq!(Poll::from_lines(&lines), Some(poll));
+        assert_eq!(Poll::from_lines(&["hello".to_string()]), None);
+    }
+
+    #[test]
+    fn closed_polls_only_count_saved_votes() {
+        let did = DID::default();
+        let voters = vec![did.clone()];
+        let nobody = vec![];
+        let closed = poll(false).closed(vec![("1️⃣", &voters)]);
+        assert_eq!(closed.results, Some(vec![vec![did], vec![]]));
+        // a vote added after closing doesn't count, neither does a saved vote without a reaction
+        assert_eq!(
+            closed.tally(vec![("1️⃣", &voters), ("2️⃣", &voters)]),
+            vec![1, 0]
+        );
+        assert_eq!(closed.tally(vec![("1️⃣", &nobody)]), vec![0, 0]);
+    }
+
+    #[test]
+    fn single_choice_counts_one_vote() {
+        let did = DID::default();
+        let both = vec![did];
+        let reactions = vec![("1️⃣", &both), ("2️⃣", &both), ("👍", &both)];
+        assert_eq!(poll(false).tally(reactions.clone()), vec![1, 0]);
+        assert_eq!(poll(true).tally(reactions), vec![1, 1]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::icons::outline::Shape as Icon;
 
+use chrono::Utc;
 use dioxus_desktop::DesktopService;
 use dioxus_desktop::{tao::window::WindowId, DesktopContext};
 use extensions::UplinkExtension;
@@ -14,44 +15,145 @@ use std::{
 use tracing::log;
 use uuid::Uuid;
 
-use super::{call, notifications::Notifications};
+use super::{
+    call,
+    notifications::{NotificationHistory, Notifications},
+};
 
-pub type EmojiList = HashMap<String, u64>;
+pub type EmojiList = HashMap<String, EmojiUsage>;
 
 pub type EmojiFilter = HashMap<String, Rc<dyn Fn(&str, bool) -> Vec<(String, String)>>>;
 
+// the time it takes for a use of an emoji to weigh half as much, in seconds
+const EMOJI_USAGE_HALF_LIFE: f64 = 7.0 * 24.0 * 60.0 * 60.0;
+
+/// How much an emoji was used, recent uses weighing more than old ones. `score` is as of
+/// `last_used`, a unix timestamp, and halves every week after it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredEmojiUsage")]
+pub struct EmojiUsage {
+    score: f64,
+    last_used: i64,
+}
+
+impl EmojiUsage {
+    pub fn score_at(&self, now: i64) -> f64 {
+        let elapsed = now.saturating_sub(self.last_used).max(0) as f64;
+        self.score * 0.5_f64.powf(elapsed / EMOJI_USAGE_HALF_LIFE)
+    }
+
+    fn used_at(&mut self, now: i64) {
+        self.score = self.score_at(now) + 1.0;
+        self.last_used = self.last_used.max(now);
+    }
+}
+
+// the usage used to be a count of uses, those are taken as of when they're loaded
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredEmojiUsage {
+    Count(u64),
+    Usage { score: f64, last_used: i64 },
+}
+
+impl From<StoredEmojiUsage> for EmojiUsage {
+    fn from(stored: StoredEmojiUsage) -> Self {
+        match stored {
+            StoredEmojiUsage::Count(count) => EmojiUsage {
+                score: count as f64,
+                last_used: Utc::now().timestamp(),
+            },
+            StoredEmojiUsage::Usage { score, last_used } => EmojiUsage { score, last_used },
+        }
+    }
+}
+
+/// The skin tone people and hand emoji are shown and sent with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SkinTone {
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: [SkinTone; 6] = [
+        SkinTone::Default,
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+}
+
 #[derive(Clone, Deserialize, Serialize)]
 pub struct EmojiCounter {
     emoji_tracker: EmojiList,
+    // pinned to the top of the emoji selector, in the order they were added
+    #[serde(default)]
+    favorites: Vec<String>,
+    #[serde(default)]
+    skin_tone: SkinTone,
     #[serde(skip)]
     emoji_filters: EmojiFilter,
 }
 
 impl EmojiCounter {
     pub fn new() -> Self {
-        Self {
-            emoji_tracker: EmojiList::new(),
-            emoji_filters: HashMap::new(),
-        }
+        Self::new_with(EmojiList::new())
     }
 
     pub fn new_with(list: EmojiList) -> Self {
         Self {
             emoji_tracker: list,
+            favorites: vec![],
+            skin_tone: SkinTone::default(),
             emoji_filters: HashMap::new(),
         }
     }
 
     pub fn increment_emoji(&mut self, emoji: String) {
-        let count = self.emoji_tracker.entry(emoji).or_insert(0);
-        *count = count.saturating_add(1);
+        self.use_emoji_at(emoji, Utc::now().timestamp());
+    }
+
+    fn use_emoji_at(&mut self, emoji: String, now: i64) {
+        // custom emoji only exist in their chat, they aren't offered as quick reactions
+        if emoji.starts_with(':') {
+            return;
+        }
+        self.emoji_tracker.entry(emoji).or_default().used_at(now);
+    }
+
+    /// How often and how recently the emoji was used, 0 if it wasn't.
+    pub fn frecency(&self, emoji: &str) -> f64 {
+        self.frecency_at(emoji, Utc::now().timestamp())
+    }
+
+    fn frecency_at(&self, emoji: &str, now: i64) -> f64 {
+        self.emoji_tracker
+            .get(emoji)
+            .map(|usage| usage.score_at(now))
+            .unwrap_or_default()
     }
 
-    pub fn get_sorted_vec(&self, count: Option<usize>) -> Vec<(String, u64)> {
-        let mut emojis: Vec<_> = self.emoji_tracker.iter().collect();
+    /// The emoji used the most, recent uses weighing more.
+    pub fn get_sorted_vec(&self, count: Option<usize>) -> Vec<String> {
+        self.sorted_at(count, Utc::now().timestamp())
+    }
+
+    fn sorted_at(&self, count: Option<usize>, now: i64) -> Vec<String> {
+        let mut emojis: Vec<_> = self
+            .emoji_tracker
+            .iter()
+            .map(|(emoji, usage)| (emoji, usage.score_at(now)))
+            .collect();
 
-        // sort the list by the emoji with the most usage
-        emojis.sort_by(|a, b| match b.1.cmp(a.1) {
+        emojis.sort_by(|a, b| match b.1.total_cmp(&a.1) {
             Ordering::Equal => b.0.cmp(a.0),
             x => x,
         });
@@ -60,7 +162,7 @@ impl EmojiCounter {
         emojis
             .into_iter()
             .take(to_take)
-            .map(|(emoji, usage)| (emoji.clone(), *usage))
+            .map(|(emoji, _)| emoji.clone())
             .collect()
     }
 
@@ -88,10 +190,11 @@ impl EmojiCounter {
             .iter()
             .map(|(emoji, alias)| (emoji.clone(), alias.clone()))
             .collect();
+        let now = Utc::now().timestamp();
         matches.sort_by(|(emoji, _), (emoji2, _)| {
-            let first = self.emoji_tracker.get(emoji).unwrap_or(&0_u64);
-            let second = self.emoji_tracker.get(emoji2).unwrap_or(&0_u64);
-            match second.cmp(first) {
+            let first = self.frecency_at(emoji, now);
+            let second = self.frecency_at(emoji2, now);
+            match second.total_cmp(&first) {
                 Ordering::Equal => emoji.cmp(emoji2),
                 x => x,
             }
@@ -109,6 +212,30 @@ impl EmojiCounter {
             .entry(id)
             .or_insert_with(|| Rc::new(filter));
     }
+
+    pub fn favorites(&self) -> &[String] {
+        &self.favorites
+    }
+
+    pub fn is_favorite(&self, emoji: &str) -> bool {
+        self.favorites.iter().any(|favorite| favorite == emoji)
+    }
+
+    pub fn toggle_favorite(&mut self, emoji: String) {
+        if self.is_favorite(&emoji) {
+            self.favorites.retain(|favorite| *favorite != emoji);
+        } else {
+            self.favorites.push(emoji);
+        }
+    }
+
+    pub fn skin_tone(&self) -> SkinTone {
+        self.skin_tone
+    }
+
+    pub fn set_skin_tone(&mut self, skin_tone: SkinTone) {
+        self.skin_tone = skin_tone;
+    }
 }
 
 impl Default for EmojiCounter {
@@ -149,7 +276,7 @@ fn default_emojis() -> EmojiCounter {
     EmojiCounter::new_with(
         default_emoji_list()
             .into_iter()
-            .map(|(emoji, _)| (emoji, 0))
+            .map(|(emoji, _)| (emoji, EmojiUsage::default()))
             .collect(),
     )
 }
@@ -181,6 +308,9 @@ impl Default for EmojiDestination {
 #[derive(Deserialize, Serialize)]
 pub struct UI {
     pub notifications: Notifications,
+    // past notifications, displayed by the notification center
+    #[serde(default)]
+    pub notification_history: NotificationHistory,
     // stores information related to the current call
     #[serde(skip)]
     pub call_info: call::CallInfo,
@@ -229,12 +359,16 @@ pub struct UI {
     pub cached_username: Option<String>,
     #[serde(skip)]
     pub ignore_focus: bool,
+    // the lock screen is shown. not persisted, the app always starts on the unlock page anyway
+    #[serde(skip)]
+    pub locked: bool,
 }
 
 impl Default for UI {
     fn default() -> Self {
         Self {
             notifications: Default::default(),
+            notification_history: Default::default(),
             call_info: Default::default(),
             call_timer: true,
             current_debug_logger: Default::default(),
@@ -261,6 +395,7 @@ impl Default for UI {
             show_dev_settings: false,
             cached_username: Default::default(),
             ignore_focus: Default::default(),
+            locked: false,
             transform_markdown_text: true,
             transform_ascii_emojis: true,
         }
@@ -538,3 +673,35 @@ pub struct Font {
     pub name: String,
     pub path: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recent_emoji_come_first() {
+        let day = 24 * 60 * 60;
+        let mut counter = EmojiCounter::new();
+        for _ in 0..5 {
+            counter.use_emoji_at("👍".into(), 0);
+        }
+        counter.use_emoji_at("🎉".into(), 30 * day);
+        counter.use_emoji_at("🎉".into(), 30 * day);
+        counter.use_emoji_at(":parrot:".into(), 30 * day);
+        assert_eq!(counter.sorted_at(None, 30 * day), vec!["🎉", "👍"]);
+        // the same uses a week apart weigh half as much
+        assert_eq!(counter.frecency_at("👍", 7 * day), 2.5);
+    }
+
+    #[test]
+    fn loads_usage_counts() {
+        let counter: EmojiCounter =
+            serde_json::from_str(r#"{"emoji_tracker":{"👍":3,"😂":1}}"#).unwrap();
+        assert_eq!(counter.get_sorted_vec(None), vec!["👍", "😂"]);
+        assert!(counter.favorites().is_empty());
+
+        let saved = serde_json::to_string(&counter).unwrap();
+        let loaded: EmojiCounter = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.emoji_tracker, counter.emoji_tracker);
+    }
+}
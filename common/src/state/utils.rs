@@ -13,12 +13,14 @@ use warp::crypto::DID;
 
 use crate::{get_extras_dir, STATIC_ARGS};
 
-use super::{ui::Font, Identity, State, Theme};
+use super::{mentions::GROUP_MENTIONS, message_marker, ui::Font, Identity, State, Theme};
 
 pub static USER_NAME_TAGS_REGEX: Lazy<Regex> =
     Lazy::new(|| mention_regex_epattern("[A-z0-9]+#[A-z0-9]{8}"));
 pub static USER_DID_TAGS_REGEX: Lazy<Regex> =
     Lazy::new(|| mention_regex_epattern("did:key:[A-z0-9]{48}"));
+pub static GROUP_TAGS_REGEX: Lazy<Regex> =
+    Lazy::new(|| mention_regex_epattern(&format!("(?:{})", GROUP_MENTIONS.join("|"))));
 
 pub fn get_available_themes() -> Vec<Theme> {
     let mut themes = vec![];
@@ -141,6 +143,12 @@ impl<F: Fn(&Identity) -> String> Replacer for TagReplacer<'_, F> {
     }
 }
 
+/// The lines of a message as the sender typed them, without the forwarded-from header, the link
+/// preview and the other marker lines uplink adds.
+pub fn typed_lines(lines: &[String]) -> &[String] {
+    message_marker::strip(lines)
+}
+
 pub fn mention_regex_epattern(value: &str) -> Regex {
     // This detects codeblocks
     // When replacing this needs to be explicitly checked
@@ -193,6 +201,26 @@ pub fn parse_mentions(
     (result.to_string(), replacer.is_mention)
 }
 
+/// Whether the message contains `@everyone` or `@here` outside of a code block
+pub fn has_group_mention(message: &str) -> bool {
+    GROUP_TAGS_REGEX
+        .find_iter(message)
+        .any(|m| !m.as_str().starts_with('`'))
+}
+
+// Replace @everyone and @here outside of code blocks with a given function of the mention's name
+pub fn parse_group_mentions(message: &str, replacement: impl Fn(&str) -> String) -> String {
+    GROUP_TAGS_REGEX
+        .replace_all(message, |caps: &Captures<'_>| {
+            if caps[0].starts_with('`') {
+                caps[0].to_string()
+            } else {
+                format!("{}{}{}", &caps[1], replacement(&caps[2][1..]), &caps[3])
+            }
+        })
+        .to_string()
+}
+
 pub fn mention_to_did_key(id: &Identity) -> String {
     format!("@{}", id.did_key())
 }
@@ -207,9 +235,15 @@ pub fn mention_replacement_pattern(id: &Identity, visual: bool) -> String {
     )
 }
 
+// Replacement pattern converting @everyone or @here to a highlight div. There is no profile to show on click
+pub fn group_mention_replacement_pattern(name: &str) -> String {
+    format!(r#"<a class="message-user-tag visual-only">@{name}</a>"#)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::state::message_marker::Marker;
 
     #[test]
     fn test_get_pretty_name1() {
@@ -221,4 +255,20 @@ mod test {
             assert_eq!(r, String::from("name1"));
         }
     }
+
+    #[test]
+    fn test_has_group_mention() {
+        assert!(has_group_mention("hey @everyone"));
+        assert!(has_group_mention("`code` @here"));
+        assert!(!has_group_mention("`@everyone`"));
+        assert!(!has_group_mention("mail@here.com"));
+    }
+
+    #[test]
+    fn test_typed_lines_strips_markers() {
+        let lines: Vec<String> = vec!["📊 lunch?".into(), format!("{}{{}}", Marker::Poll.prefix())];
+        assert_eq!(typed_lines(&lines), &lines[..1]);
+        let lines: Vec<String> = vec!["hello".into(), "world".into()];
+        assert_eq!(typed_lines(&lines), &lines[..]);
+    }
 }
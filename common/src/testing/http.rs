@@ -0,0 +1,42 @@
+//! A local stand-in for a website or web service, for testing the code which visits one.
+
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+/// Answers each connection with the next response, None never answers. Returns the url of the
+/// server and the requests it got.
+pub fn serve(responses: Vec<Option<Vec<u8>>>) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let len = stream.read(&mut request).unwrap_or_default();
+            let _ = tx.send(String::from_utf8_lossy(&request[..len]).to_string());
+            match response {
+                Some(response) => {
+                    let _ = stream.write_all(&response);
+                }
+                None => thread::sleep(Duration::from_secs(2)),
+            }
+        }
+    });
+    (url, rx)
+}
+
+pub fn response(status: &str, content_type: &str, body: &[u8]) -> Option<Vec<u8>> {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .into_bytes();
+    response.extend_from_slice(body);
+    Some(response)
+}
@@ -80,6 +80,8 @@ pub fn generate_mock() -> State {
         in_sidebar,
         favorites: vec![],
         readd_sidebars: false,
+        edit_history: HashMap::new(),
+        bookmarks: Default::default(),
     };
     let friends = Friends {
         all: HashSet::from_iter(identities.iter().map(|x| x.did_key())),
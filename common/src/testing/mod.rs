@@ -1 +1,3 @@
+#[cfg(test)]
+pub mod http;
 pub mod mock;
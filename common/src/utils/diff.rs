@@ -0,0 +1,113 @@
+//! Word level diff, used to show what changed between revisions of an edited message.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSegment {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+// the diff is quadratic, longer texts are shown as entirely replaced
+const MAX_TOKENS: usize = 2_000;
+
+/// Splits `old` and `new` into words and whitespace and compares them. Adjacent segments of the
+/// same kind are merged.
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffSegment> {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+    if old_tokens.len() > MAX_TOKENS || new_tokens.len() > MAX_TOKENS {
+        let mut segments = vec![];
+        push(&mut segments, DiffSegment::Removed(old.into()));
+        push(&mut segments, DiffSegment::Added(new.into()));
+        return segments;
+    }
+
+    // lcs[i][j] is the length of the longest common subsequence of old_tokens[i..] and new_tokens[j..]
+    let (n, m) = (old_tokens.len(), new_tokens.len());
+    let mut lcs = vec![vec![0u16; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_tokens[i] == new_tokens[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut segments = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old_tokens[i] == new_tokens[j] {
+            push(&mut segments, DiffSegment::Same(old_tokens[i].into()));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            push(&mut segments, DiffSegment::Removed(old_tokens[i].into()));
+            i += 1;
+        } else {
+            push(&mut segments, DiffSegment::Added(new_tokens[j].into()));
+            j += 1;
+        }
+    }
+    segments
+}
+
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut start = 0;
+    let mut last_is_space = None;
+    for (idx, c) in text.char_indices() {
+        let is_space = c.is_whitespace();
+        if last_is_space == Some(!is_space) {
+            tokens.push(&text[start..idx]);
+            start = idx;
+        }
+        last_is_space = Some(is_space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push(segments: &mut Vec<DiffSegment>, segment: DiffSegment) {
+    match (segments.last_mut(), segment) {
+        (_, DiffSegment::Same(text) | DiffSegment::Added(text) | DiffSegment::Removed(text))
+            if text.is_empty() => {}
+        (Some(DiffSegment::Same(last)), DiffSegment::Same(text))
+        | (Some(DiffSegment::Added(last)), DiffSegment::Added(text))
+        | (Some(DiffSegment::Removed(last)), DiffSegment::Removed(text)) => last.push_str(&text),
+        (_, segment) => segments.push(segment),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_changed_words() {
+        assert_eq!(
+            diff_words("see you at 5 tomorrow", "see you at 6 tomorrow"),
+            vec![
+                DiffSegment::Same("see you at ".into()),
+                DiffSegment::Removed("5".into()),
+                DiffSegment::Added("6".into()),
+                DiffSegment::Same(" tomorrow".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn appended_text() {
+        assert_eq!(
+            diff_words("hello", "hello there"),
+            vec![
+                DiffSegment::Same("hello".into()),
+                DiffSegment::Added(" there".into()),
+            ]
+        );
+        assert_eq!(diff_words("", ""), vec![]);
+    }
+}
@@ -0,0 +1,329 @@
+//! Reads local files for the file preview: the pages of a pdf, the start of a text file, the
+//! waveform of an audio file and the entries of an archive. All of these block, so call them with
+//! `spawn_blocking`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{bail, Context};
+use flate2::read::GzDecoder;
+
+use super::voice_message;
+
+/// Only the start of larger text files is shown
+pub const MAX_TEXT_PREVIEW_SIZE: usize = 512 * 1024;
+/// Only the first entries of larger archives are listed
+pub const MAX_ARCHIVE_ENTRIES: usize = 1000;
+// the waveform is made of the peaks of this many samples each
+const WAVEFORM_CHUNK: usize = 256;
+
+/// The start of a text file, and whether it was cut off.
+pub fn read_text(path: &Path) -> io::Result<(String, bool)> {
+    let mut bytes = Vec::new();
+    File::open(path)?
+        .take(MAX_TEXT_PREVIEW_SIZE as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    let truncated = bytes.len() > MAX_TEXT_PREVIEW_SIZE;
+    bytes.truncate(MAX_TEXT_PREVIEW_SIZE);
+    Ok((String::from_utf8_lossy(&bytes).into_owned(), truncated))
+}
+
+/// Uses `pdfinfo` from poppler, the document thumbnails are made with its `pdftoppm`.
+pub fn pdf_page_count(path: &Path) -> anyhow::Result<u32> {
+    let output = Command::new("pdfinfo").arg(path).output()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("Pages:"))
+        .and_then(|pages| pages.trim().parse().ok())
+        .context("pdfinfo found no pages")
+}
+
+/// Renders a page, counting from 1, to a png in `dest_dir`. Pages which were rendered before
+/// aren't rendered again.
+pub fn render_pdf_page(path: &Path, page: u32, dest_dir: &Path) -> anyhow::Result<PathBuf> {
+    let out = dest_dir.join(format!("pdf-{:016x}-page-{page}", pdf_key(path)?));
+    let png = out.with_extension("png");
+    if png.exists() {
+        return Ok(png);
+    }
+    let page = page.to_string();
+    let status = Command::new("pdftoppm")
+        .args(["-png", "-singlefile", "-scale-to", "1200"])
+        .args(["-f", &page, "-l", &page])
+        .arg(path)
+        .arg(&out)
+        .status()?;
+    if !status.success() {
+        bail!("pdftoppm failed: {status}");
+    }
+    Ok(png)
+}
+
+// pdfs with the same name, or a file which was replaced, must not share rendered pages
+fn pdf_key(path: &Path) -> io::Result<u64> {
+    let metadata = path.metadata()?;
+    let mut hasher = DefaultHasher::new();
+    path.canonicalize()?.hash(&mut hasher);
+    metadata.len().hash(&mut hasher);
+    metadata.modified().ok().hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+/// The loudest sample of each of `bars` equal parts of an audio file, from 0 to 1 relative to
+/// the loudest part.
+pub fn waveform(path: &Path, bars: usize) -> anyhow::Result<Vec<f32>> {
+    if voice_message::is_voice_message(&path.to_string_lossy()) {
+        return peaks(voice_message::decode(path)?.into_iter(), bars);
+    }
+    let decoder = rodio::Decoder::new(BufReader::new(File::open(path)?))?;
+    peaks(decoder, bars)
+}
+
+fn peaks(samples: impl Iterator<Item = i16>, bars: usize) -> anyhow::Result<Vec<f32>> {
+    let mut peaks: Vec<u16> = vec![];
+    for (i, sample) in samples.enumerate() {
+        if i % WAVEFORM_CHUNK == 0 {
+            peaks.push(0);
+        }
+        if let Some(peak) = peaks.last_mut() {
+            *peak = (*peak).max(sample.unsigned_abs());
+        }
+    }
+    if peaks.is_empty() || bars == 0 {
+        bail!("no samples");
+    }
+
+    let bars: Vec<u16> = (0..bars)
+        .map(|bar| {
+            let start = bar * peaks.len() / bars;
+            let end = ((bar + 1) * peaks.len() / bars).max(start + 1);
+            peaks[start..end.min(peaks.len())]
+                .iter()
+                .copied()
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let loudest = bars.iter().copied().max().unwrap_or_default().max(1) as f32;
+    Ok(bars.into_iter().map(|peak| peak as f32 / loudest).collect())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    pub fn from_name(file_name: &str) -> Option<Self> {
+        let name = file_name.to_lowercase();
+        if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    /// the path inside of the archive
+    pub name: String,
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+/// The entries of an archive, and whether there were more than [`MAX_ARCHIVE_ENTRIES`].
+pub fn list_archive(path: &Path, kind: ArchiveKind) -> anyhow::Result<(Vec<ArchiveEntry>, bool)> {
+    let file = File::open(path)?;
+    match kind {
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(file)?;
+            let entries = (0..archive.len().min(MAX_ARCHIVE_ENTRIES))
+                .map(|i| {
+                    let entry = archive.by_index(i)?;
+                    Ok(ArchiveEntry {
+                        name: entry.name().to_string(),
+                        size: entry.size(),
+                        is_dir: entry.is_dir(),
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok((entries, archive.len() > MAX_ARCHIVE_ENTRIES))
+        }
+        ArchiveKind::Tar => list_tar(file),
+        ArchiveKind::TarGz => list_tar(GzDecoder::new(file)),
+    }
+}
+
+fn list_tar(reader: impl Read) -> anyhow::Result<(Vec<ArchiveEntry>, bool)> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = vec![];
+    for entry in archive.entries()? {
+        if entries.len() == MAX_ARCHIVE_ENTRIES {
+            return Ok((entries, true));
+        }
+        let entry = entry?;
+        entries.push(ArchiveEntry {
+            name: entry.path()?.to_string_lossy().to_string(),
+            size: entry.header().size()?,
+            is_dir: entry.header().entry_type().is_dir(),
+        });
+    }
+    Ok((entries, false))
+}
+
+/// Writes a single entry of an archive to `dest`, which is the path of the new file.
+pub fn extract_entry(
+    path: &Path,
+    kind: ArchiveKind,
+    name: &str,
+    dest: &Path,
+) -> anyhow::Result<()> {
+    let file = File::open(path)?;
+    match kind {
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(file)?;
+            let mut entry = archive.by_name(name)?;
+            io::copy(&mut entry, &mut File::create(dest)?)?;
+            Ok(())
+        }
+        ArchiveKind::Tar => extract_tar(file, name, dest),
+        ArchiveKind::TarGz => extract_tar(GzDecoder::new(file), name, dest),
+    }
+}
+
+fn extract_tar(reader: impl Read, name: &str, dest: &Path) -> anyhow::Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.to_string_lossy() == name {
+            io::copy(&mut entry, &mut File::create(dest)?)?;
+            return Ok(());
+        }
+    }
+    bail!("{name} is not in the archive")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn reads_the_start_of_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.txt");
+        std::fs::write(&path, "a".repeat(MAX_TEXT_PREVIEW_SIZE + 10)).unwrap();
+        let (text, truncated) = read_text(&path).unwrap();
+        assert_eq!(text.len(), MAX_TEXT_PREVIEW_SIZE);
+        assert!(truncated);
+
+        std::fs::write(&path, "short").unwrap();
+        assert_eq!(read_text(&path).unwrap(), ("short".into(), false));
+    }
+
+    #[test]
+    fn pdfs_with_the_same_name_have_different_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|sub| {
+                let path = dir.path().join(sub).join("doc.pdf");
+                std::fs::create_dir(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, sub).unwrap();
+                path
+            })
+            .collect();
+        assert_ne!(pdf_key(&paths[0]).unwrap(), pdf_key(&paths[1]).unwrap());
+        assert_eq!(pdf_key(&paths[0]).unwrap(), pdf_key(&paths[0]).unwrap());
+    }
+
+    #[test]
+    fn lists_and_extracts_archives() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let zip_path = dir.path().join("files.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        let options = zip::write::FileOptions::default();
+        zip.add_directory("docs/", options).unwrap();
+        zip.start_file("docs/readme.txt", options).unwrap();
+        zip.write_all(b"hello").unwrap();
+        zip.finish().unwrap();
+
+        let tar_path = dir.path().join("files.tar.gz");
+        let gz = flate2::write::GzEncoder::new(
+            File::create(&tar_path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut tar = tar::Builder::new(gz);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(5);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, "docs/readme.txt", &b"hello"[..])
+            .unwrap();
+        tar.into_inner().unwrap().finish().unwrap();
+
+        for path in [zip_path, tar_path] {
+            let kind = ArchiveKind::from_name(&path.to_string_lossy()).unwrap();
+            let (entries, truncated) = list_archive(&path, kind).unwrap();
+            assert!(!truncated);
+            let file = entries.iter().find(|entry| !entry.is_dir).unwrap();
+            assert_eq!(file.name, "docs/readme.txt");
+            assert_eq!(file.size, 5);
+
+            let dest = dir.path().join("readme.txt");
+            extract_entry(&path, kind, "docs/readme.txt", &dest).unwrap();
+            assert_eq!(std::fs::read(&dest).unwrap(), b"hello");
+            assert!(extract_entry(&path, kind, "missing", &dest).is_err());
+        }
+        assert_eq!(ArchiveKind::from_name("photo.png"), None);
+    }
+
+    #[test]
+    fn waveform_of_a_wav() {
+        // a second of silence followed by a second of a square wave, mono 16 bit at 8kHz
+        let samples: Vec<i16> = (0..16000)
+            .map(|i| match i {
+                0..=7999 => 0,
+                _ if i % 20 < 10 => 10000,
+                _ => -10000,
+            })
+            .collect();
+        let mut wav = vec![];
+        let data_len = samples.len() as u32 * 2;
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&8000u32.to_le_bytes());
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sound.wav");
+        std::fs::write(&path, wav).unwrap();
+
+        let bars = waveform(&path, 4).unwrap();
+        assert_eq!(bars, vec![0.0, 0.0, 1.0, 1.0]);
+    }
+}
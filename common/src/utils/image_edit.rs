@@ -0,0 +1,426 @@
+//! Edits images before they are sent: crop, rotate, resize, blur or redact parts of them and draw
+//! arrows and text on them. Edited images are encoded again, which drops their EXIF data, including
+//! where a photo was taken. All of these block, so call them with `spawn_blocking`.
+
+use std::path::Path;
+
+use ab_glyph::{FontRef, PxScale};
+use anyhow::Context;
+use base64::{engine::general_purpose, Engine};
+use image::{
+    codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, GenericImageView, ImageDecoder,
+    ImageFormat, ImageReader, Rgba, RgbaImage,
+};
+use imageproc::{
+    drawing::{draw_filled_circle_mut, draw_polygon_mut, draw_text_mut},
+    point::Point,
+};
+use once_cell::sync::Lazy;
+
+/// Images with these extensions can be edited. Other formats, like animated gifs, are sent as is.
+pub const EDITABLE_EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "webp"];
+const JPEG_QUALITY: u8 = 90;
+
+static FONT: Lazy<FontRef<'static>> = Lazy::new(|| {
+    FontRef::try_from_slice(include_bytes!("../../../kit/src/fonts/Roboto-Regular.ttf"))
+        .expect("the bundled font is valid")
+});
+
+pub fn is_editable(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .is_some_and(|ext| EDITABLE_EXTENSIONS.contains(&ext.as_str()))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    /// The rect between two opposite corners, in any order.
+    pub fn from_corners(a: (u32, u32), b: (u32, u32)) -> Self {
+        Self {
+            x: a.0.min(b.0),
+            y: a.1.min(b.1),
+            width: a.0.abs_diff(b.0),
+            height: a.1.abs_diff(b.1),
+        }
+    }
+
+    // the part of the rect inside of the image, None if that is empty
+    fn clamp(self, width: u32, height: u32) -> Option<Self> {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+        let rect = Self {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y),
+        };
+        (rect.width > 0 && rect.height > 0).then_some(rect)
+    }
+}
+
+/// Clockwise
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Quarter,
+    Half,
+    ThreeQuarters,
+}
+
+/// Positions are in pixels of the image as it is when the edit is applied, so they depend on the
+/// crops, rotations and resizes before them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Edit {
+    Crop(Rect),
+    Rotate(Rotation),
+    /// fits the image into the size, keeping its aspect ratio. images are never made larger
+    Resize {
+        width: u32,
+        height: u32,
+    },
+    Blur(Rect),
+    /// paints over the rect, unlike a blur this can't be undone by sharpening the image
+    Redact(Rect),
+    Arrow {
+        from: (u32, u32),
+        to: (u32, u32),
+        color: [u8; 3],
+    },
+    /// `at` is the top left corner of the text
+    Text {
+        at: (u32, u32),
+        text: String,
+        color: [u8; 3],
+    },
+}
+
+// arrows and text are sized relative to the image, so they look the same on small and large images
+fn stroke_width(image: &DynamicImage) -> u32 {
+    (image.width().max(image.height()) / 200).max(2)
+}
+
+fn font_size(image: &DynamicImage) -> f32 {
+    (image.width().max(image.height()) as f32 / 20.0).max(16.0)
+}
+
+pub fn apply(image: DynamicImage, edit: &Edit) -> DynamicImage {
+    let (width, height) = image.dimensions();
+    match edit {
+        Edit::Crop(rect) => match rect.clamp(width, height) {
+            Some(rect) => image.crop_imm(rect.x, rect.y, rect.width, rect.height),
+            None => image,
+        },
+        Edit::Rotate(Rotation::Quarter) => image.rotate90(),
+        Edit::Rotate(Rotation::Half) => image.rotate180(),
+        Edit::Rotate(Rotation::ThreeQuarters) => image.rotate270(),
+        Edit::Resize {
+            width: max_width,
+            height: max_height,
+        } => {
+            if *max_width >= width && *max_height >= height {
+                image
+            } else {
+                image.resize(
+                    (*max_width).max(1),
+                    (*max_height).max(1),
+                    FilterType::Lanczos3,
+                )
+            }
+        }
+        Edit::Blur(rect) => {
+            let Some(rect) = rect.clamp(width, height) else {
+                return image;
+            };
+            let mut canvas = image.into_rgba8();
+            let part = image::imageops::crop_imm(&canvas, rect.x, rect.y, rect.width, rect.height)
+                .to_image();
+            let sigma = (rect.width.max(rect.height) as f32 / 8.0).max(4.0);
+            let blurred = image::imageops::blur(&part, sigma);
+            image::imageops::replace(&mut canvas, &blurred, rect.x as i64, rect.y as i64);
+            canvas.into()
+        }
+        Edit::Redact(rect) => {
+            let Some(rect) = rect.clamp(width, height) else {
+                return image;
+            };
+            let mut canvas = image.into_rgba8();
+            let black = RgbaImage::from_pixel(rect.width, rect.height, Rgba([0, 0, 0, 255]));
+            image::imageops::replace(&mut canvas, &black, rect.x as i64, rect.y as i64);
+            canvas.into()
+        }
+        Edit::Arrow { from, to, color } => {
+            let stroke = stroke_width(&image) as f32;
+            let mut canvas = image.into_rgba8();
+            draw_arrow(&mut canvas, *from, *to, stroke, rgba(*color));
+            canvas.into()
+        }
+        Edit::Text { at, text, color } => {
+            let scale = PxScale::from(font_size(&image));
+            let mut canvas = image.into_rgba8();
+            for (i, line) in text.lines().enumerate() {
+                let y = at.1 as f32 + i as f32 * scale.y;
+                draw_text_mut(
+                    &mut canvas,
+                    rgba(*color),
+                    at.0 as i32,
+                    y as i32,
+                    scale,
+                    &*FONT,
+                    line,
+                );
+            }
+            canvas.into()
+        }
+    }
+}
+
+fn rgba([r, g, b]: [u8; 3]) -> Rgba<u8> {
+    Rgba([r, g, b, 255])
+}
+
+fn draw_arrow(
+    canvas: &mut RgbaImage,
+    from: (u32, u32),
+    to: (u32, u32),
+    stroke: f32,
+    color: Rgba<u8>,
+) {
+    let (x1, y1) = (from.0 as f32, from.1 as f32);
+    let (x2, y2) = (to.0 as f32, to.1 as f32);
+    let length = (x2 - x1).hypot(y2 - y1);
+    if length < 1.0 {
+        return;
+    }
+    let (dx, dy) = ((x2 - x1) / length, (y2 - y1) / length);
+    let head = (stroke * 4.0).min(length);
+
+    // the line is made of overlapping dots, which stops short of the tip of the head
+    let line_end = length - head;
+    let radius = (stroke / 2.0).round() as i32;
+    let mut t = 0.0;
+    while t <= line_end {
+        let center = ((x1 + dx * t).round() as i32, (y1 + dy * t).round() as i32);
+        draw_filled_circle_mut(canvas, center, radius, color);
+        t += (stroke / 2.0).max(1.0);
+    }
+
+    let (bx, by) = (x2 - dx * head, y2 - dy * head);
+    let (nx, ny) = (-dy * head / 2.0, dx * head / 2.0);
+    let point = |x: f32, y: f32| Point::new(x.round() as i32, y.round() as i32);
+    let tip = point(x2, y2);
+    let left = point(bx + nx, by + ny);
+    let right = point(bx - nx, by - ny);
+    // draw_polygon_mut panics if the first and last points are the same
+    if tip != left && left != right && right != tip {
+        draw_polygon_mut(canvas, &[tip, left, right], color);
+    }
+}
+
+/// Opens an image the right way up, photos are often stored sideways with their orientation in
+/// the EXIF data.
+pub fn open(path: &Path) -> anyhow::Result<DynamicImage> {
+    let mut decoder = ImageReader::open(path)?
+        .with_guessed_format()?
+        .into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// A png data url of the image, scaled down to fit into `max_width` by `max_height`, and the size
+/// of the scaled image.
+pub fn preview(
+    image: &DynamicImage,
+    max_width: u32,
+    max_height: u32,
+) -> anyhow::Result<(String, u32, u32)> {
+    let scaled = if image.width() > max_width || image.height() > max_height {
+        image.resize(max_width, max_height, FilterType::Triangle)
+    } else {
+        image.clone()
+    };
+    let mut png = std::io::Cursor::new(vec![]);
+    scaled.write_to(&mut png, ImageFormat::Png)?;
+    let src = format!(
+        "data:image/png;base64,{}",
+        general_purpose::STANDARD.encode(png.into_inner())
+    );
+    Ok((src, scaled.width(), scaled.height()))
+}
+
+/// Saves the image in the format of the extension of `dest`. Nothing but the pixels is saved.
+pub fn save(image: &DynamicImage, dest: &Path) -> anyhow::Result<()> {
+    let ext = dest
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        // jpegs have no transparency, and the default quality of the encoder is low
+        "jpg" | "jpeg" => {
+            let file = std::fs::File::create(dest)?;
+            let mut writer = std::io::BufWriter::new(file);
+            JpegEncoder::new_with_quality(&mut writer, JPEG_QUALITY)
+                .encode_image(&image.to_rgb8())
+                .context("failed to encode jpeg")?;
+        }
+        _ => image.save(dest)?,
+    }
+    Ok(())
+}
+
+/// Opens the image at `src`, applies the edits in order and saves the result to `dest`.
+pub fn edit_file(src: &Path, edits: &[Edit], dest: &Path) -> anyhow::Result<()> {
+    let image = edits.iter().fold(open(src)?, apply);
+    save(&image, dest)
+}
+
+/// Writes a copy of the image without its EXIF data to `dest`, turned the way the EXIF data said
+/// to show it. Returns false, without writing anything, if the image had no EXIF data.
+pub fn strip_metadata(src: &Path, dest: &Path) -> anyhow::Result<bool> {
+    let mut decoder = ImageReader::open(src)?
+        .with_guessed_format()?
+        .into_decoder()?;
+    if decoder.exif_metadata()?.is_none() {
+        return Ok(false);
+    }
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+    save(&image, dest)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gradient(width: u32, height: u32) -> DynamicImage {
+        RgbaImage::from_fn(width, height, |x, y| Rgba([x as u8, y as u8, 0, 255])).into()
+    }
+
+    #[test]
+    fn crops_rotates_and_resizes() {
+        let image = gradient(100, 50);
+        let edits = [
+            Edit::Crop(Rect::from_corners((60, 40), (20, 10))),
+            Edit::Rotate(Rotation::Quarter),
+            Edit::Resize {
+                width: 15,
+                height: 100,
+            },
+        ];
+        let cropped = apply(image.clone(), &edits[0]);
+        assert_eq!(cropped.dimensions(), (40, 30));
+        assert_eq!(cropped.get_pixel(0, 0), Rgba([20, 10, 0, 255]));
+
+        let rotated = apply(cropped, &edits[1]);
+        assert_eq!(rotated.dimensions(), (30, 40));
+        // the bottom left corner is now the top left one
+        assert_eq!(rotated.get_pixel(0, 0), Rgba([20, 39, 0, 255]));
+
+        let resized = edits.iter().fold(image.clone(), apply);
+        assert_eq!(resized.dimensions(), (15, 20));
+        let (src, width, height) = preview(&image, 50, 50).unwrap();
+        assert!(src.starts_with("data:image/png;base64,"));
+        assert_eq!((width, height), (50, 25));
+
+        // crops outside of the image are clamped, and resizes never enlarge
+        let edit = Edit::Crop(Rect::from_corners((90, 40), (200, 200)));
+        assert_eq!(apply(image.clone(), &edit).dimensions(), (10, 10));
+        let edit = Edit::Resize {
+            width: 1000,
+            height: 1000,
+        };
+        assert_eq!(apply(image, &edit).dimensions(), (100, 50));
+    }
+
+    #[test]
+    fn blurs_redacts_and_annotates_regions() {
+        let image: DynamicImage = RgbaImage::from_fn(64, 64, |x, _| match x % 2 {
+            0 => Rgba([255, 255, 255, 255]),
+            _ => Rgba([0, 0, 0, 255]),
+        })
+        .into();
+        let region = Rect::from_corners((0, 0), (32, 32));
+
+        let redacted = apply(image.clone(), &Edit::Redact(region));
+        assert_eq!(redacted.get_pixel(10, 10), Rgba([0, 0, 0, 255]));
+        assert_eq!(redacted.get_pixel(40, 40), image.get_pixel(40, 40));
+
+        let blurred = apply(image.clone(), &Edit::Blur(region));
+        let Rgba([r, ..]) = blurred.get_pixel(16, 16);
+        assert!((100..160).contains(&r), "{r}");
+        assert_eq!(blurred.get_pixel(40, 40), image.get_pixel(40, 40));
+
+        let red = [255, 0, 0];
+        let arrow = Edit::Arrow {
+            from: (5, 50),
+            to: (60, 50),
+            color: red,
+        };
+        let drawn = apply(image.clone(), &arrow);
+        assert_eq!(drawn.get_pixel(20, 50), Rgba([255, 0, 0, 255]));
+        assert_eq!(drawn.get_pixel(20, 20), image.get_pixel(20, 20));
+
+        let text = Edit::Text {
+            at: (0, 0),
+            text: "Hi".into(),
+            color: red,
+        };
+        let written = apply(gradient(64, 64), &text);
+        assert_ne!(written, gradient(64, 64));
+        // the same edits always give the same image
+        assert_eq!(written, apply(gradient(64, 64), &text));
+    }
+
+    // a jpeg with an EXIF block which says to turn it a quarter clockwise
+    fn sideways_jpeg(path: &Path) {
+        let mut jpeg = vec![];
+        JpegEncoder::new(&mut jpeg)
+            .encode_image(&gradient(40, 20).to_rgb8())
+            .unwrap();
+        let mut exif = b"Exif\0\0II*\0".to_vec();
+        exif.extend_from_slice(&8u32.to_le_bytes());
+        exif.extend_from_slice(&1u16.to_le_bytes());
+        // the orientation tag, a short with the value 6
+        exif.extend_from_slice(&0x0112u16.to_le_bytes());
+        exif.extend_from_slice(&3u16.to_le_bytes());
+        exif.extend_from_slice(&1u32.to_le_bytes());
+        exif.extend_from_slice(&6u32.to_le_bytes());
+        exif.extend_from_slice(&0u32.to_le_bytes());
+        let mut segment = vec![0xFF, 0xE1];
+        segment.extend_from_slice(&(exif.len() as u16 + 2).to_be_bytes());
+        segment.extend_from_slice(&exif);
+        jpeg.splice(2..2, segment);
+        std::fs::write(path, jpeg).unwrap();
+    }
+
+    #[test]
+    fn strips_exif() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("photo.jpg");
+        let dest = dir.path().join("stripped.jpg");
+        sideways_jpeg(&src);
+        assert_eq!(open(&src).unwrap().dimensions(), (20, 40));
+
+        assert!(strip_metadata(&src, &dest).unwrap());
+        let mut decoder = ImageReader::open(&dest).unwrap().into_decoder().unwrap();
+        assert_eq!(decoder.exif_metadata().unwrap(), None);
+        assert_eq!(decoder.dimensions(), (20, 40));
+
+        // images without EXIF data aren't copied
+        let plain = dir.path().join("plain.png");
+        gradient(4, 4).save(&plain).unwrap();
+        assert!(!strip_metadata(&plain, &dir.path().join("copy.png")).unwrap());
+
+        edit_file(&src, &[Edit::Rotate(Rotation::Half)], &plain).unwrap();
+        assert_eq!(image::open(&plain).unwrap().dimensions(), (20, 40));
+    }
+}
@@ -0,0 +1,246 @@
+//! Makes attachments smaller before they are uploaded. Images are scaled down and encoded as lossy
+//! WebP, videos are scaled down and encoded again with `ffmpeg`, if it is installed. AVIF would be
+//! smaller still, but encoding it takes seconds for a single photo. All of these block, so call
+//! them with `spawn_blocking`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Context};
+use base64::{engine::general_purpose, Engine};
+use derive_more::Display;
+use image::{imageops::FilterType, DynamicImage, ImageFormat, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+use super::image_edit;
+
+// the placeholder is decoded at this size, it is stretched to fit the attachment anyway
+const PLACEHOLDER_SIZE: u32 = 32;
+
+#[derive(Debug, Default, Display, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+pub enum Compression {
+    /// files are sent as they are
+    #[default]
+    #[display(fmt = "original")]
+    Original,
+    #[display(fmt = "balanced")]
+    Balanced,
+    #[display(fmt = "small")]
+    Small,
+}
+
+impl Compression {
+    pub const ALL: [Compression; 3] = [
+        Compression::Original,
+        Compression::Balanced,
+        Compression::Small,
+    ];
+
+    // the longest side of images
+    fn max_image_size(&self) -> u32 {
+        match self {
+            Compression::Original => u32::MAX,
+            Compression::Balanced => 2560,
+            Compression::Small => 1280,
+        }
+    }
+
+    fn webp_quality(&self) -> f32 {
+        match self {
+            Compression::Original => 100.0,
+            Compression::Balanced => 85.0,
+            Compression::Small => 70.0,
+        }
+    }
+
+    // the height of videos and the constant rate factor of x264, higher is smaller
+    fn video_settings(&self) -> (u32, u32) {
+        match self {
+            Compression::Original => (u32::MAX, 0),
+            Compression::Balanced => (1080, 26),
+            Compression::Small => (720, 32),
+        }
+    }
+}
+
+/// Writes a smaller copy of the file to `dest_dir`, keeping its name but not always its extension.
+/// Returns None if the file can't be compressed, or if the copy wouldn't be smaller.
+pub fn compress(
+    path: &Path,
+    compression: Compression,
+    dest_dir: &Path,
+) -> anyhow::Result<Option<PathBuf>> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    if compression == Compression::Original {
+        return Ok(None);
+    }
+    let dest = if image_edit::is_editable(path) {
+        let dest = dest_dir.join(path.with_extension("webp").file_name().unwrap_or_default());
+        compress_image(path, compression, &dest)?;
+        dest
+    } else if crate::is_video(&file_name) {
+        let dest = dest_dir.join(path.with_extension("mp4").file_name().unwrap_or_default());
+        compress_video(path, compression, &dest)?;
+        dest
+    } else {
+        return Ok(None);
+    };
+
+    if fs::metadata(&dest)?.len() >= fs::metadata(path)?.len() {
+        fs::remove_file(&dest)?;
+        return Ok(None);
+    }
+    Ok(Some(dest))
+}
+
+fn compress_image(path: &Path, compression: Compression, dest: &Path) -> anyhow::Result<()> {
+    let image = image_edit::open(path)?;
+    let max = compression.max_image_size();
+    let image = if image.width() > max || image.height() > max {
+        image.resize(max, max, FilterType::Lanczos3)
+    } else {
+        image
+    };
+    // the encoder only takes 8 bit rgb(a)
+    let image = if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(image.into_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.into_rgb8())
+    };
+    let webp = webp::Encoder::from_image(&image)
+        .map_err(|e| anyhow::anyhow!("failed to encode webp: {e}"))?
+        .encode(compression.webp_quality());
+    fs::write(dest, &*webp)?;
+    Ok(())
+}
+
+fn compress_video(path: &Path, compression: Compression, dest: &Path) -> anyhow::Result<()> {
+    let (height, crf) = compression.video_settings();
+    // without -nostdin ffmpeg reads from the terminal uplink was started from
+    let status = Command::new("ffmpeg")
+        .args(["-nostdin", "-y"])
+        .arg("-i")
+        .arg(path)
+        // never scale up, and keep the width even, which x264 needs
+        .args(["-vf", &format!("scale=-2:'min({height},ih)'")])
+        .args(["-c:v", "libx264", "-preset", "veryfast"])
+        .args(["-crf", &crf.to_string()])
+        .args(["-c:a", "aac", "-b:a", "128k"])
+        .args(["-movflags", "+faststart"])
+        .arg(dest)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .context("failed to run ffmpeg")?;
+    if !status.success() {
+        let _ = fs::remove_file(dest);
+        bail!("ffmpeg failed: {status}");
+    }
+    Ok(())
+}
+
+/// A blurhash of the image and the size of the thumbnail it was made from, which has the aspect
+/// ratio of the image.
+pub fn blurhash(path: &Path) -> anyhow::Result<(String, u32, u32)> {
+    let thumbnail = image_edit::open(path)?
+        .resize(64, 64, FilterType::Triangle)
+        .into_rgba8();
+    let hash = blurhash::encode(4, 3, thumbnail.width(), thumbnail.height(), &thumbnail)
+        .map_err(|e| anyhow::anyhow!("failed to encode blurhash: {e}"))?;
+    Ok((hash, thumbnail.width(), thumbnail.height()))
+}
+
+/// A png data url of a blurhash, to show in place of an image which is still being uploaded.
+pub fn blurhash_data_url(hash: &str, width: u32, height: u32) -> anyhow::Result<String> {
+    let scale = PLACEHOLDER_SIZE as f32 / width.max(height).max(1) as f32;
+    let width = ((width as f32 * scale).round() as u32).max(1);
+    let height = ((height as f32 * scale).round() as u32).max(1);
+    let pixels = blurhash::decode(hash, width, height, 1.0)
+        .map_err(|e| anyhow::anyhow!("failed to decode blurhash: {e}"))?;
+    let image = RgbaImage::from_raw(width, height, pixels).context("wrong blurhash size")?;
+    let mut png = std::io::Cursor::new(vec![]);
+    image.write_to(&mut png, ImageFormat::Png)?;
+    Ok(format!(
+        "data:image/png;base64,{}",
+        general_purpose::STANDARD.encode(png.into_inner())
+    ))
+}
+
+/// The first frame of an image data url as a png data url, so an animated GIF holds still.
+pub fn still_data_url(data_url: &str) -> anyhow::Result<String> {
+    let (_, data) = data_url.split_once(";base64,").context("not a data url")?;
+    let image = image::load_from_memory(&general_purpose::STANDARD.decode(data)?)?;
+    let mut png = std::io::Cursor::new(vec![]);
+    image.write_to(&mut png, ImageFormat::Png)?;
+    Ok(format!(
+        "data:image/png;base64,{}",
+        general_purpose::STANDARD.encode(png.into_inner())
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use image::Rgba;
+
+    // noise doesn't compress well as a png, like a photo
+    fn noisy_png(path: &Path, width: u32, height: u32) {
+        let mut seed: u32 = 1;
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let noise = (seed >> 24) as u8 / 8;
+            Rgba([(x / 8) as u8 + noise, (y / 8) as u8 + noise, 128, 255])
+        });
+        image.save(path).unwrap();
+    }
+
+    #[test]
+    fn compresses_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("screenshot.png");
+        noisy_png(&src, 1600, 400);
+        let out = dir.path().join("out");
+        fs::create_dir(&out).unwrap();
+
+        assert_eq!(compress(&src, Compression::Original, &out).unwrap(), None);
+
+        let small = compress(&src, Compression::Small, &out).unwrap().unwrap();
+        assert_eq!(small, out.join("screenshot.webp"));
+        assert_eq!(image::image_dimensions(&small).unwrap(), (1280, 320));
+        assert!(fs::metadata(&small).unwrap().len() < fs::metadata(&src).unwrap().len());
+
+        // the balanced setting doesn't scale it down, but still makes it smaller
+        let balanced = compress(&src, Compression::Balanced, &out)
+            .unwrap()
+            .unwrap();
+        assert_eq!(image::image_dimensions(balanced).unwrap(), (1600, 400));
+
+        let text = dir.path().join("notes.txt");
+        fs::write(&text, "hello").unwrap();
+        assert_eq!(compress(&text, Compression::Small, &out).unwrap(), None);
+    }
+
+    #[test]
+    fn blurhash_placeholders() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("photo.png");
+        noisy_png(&src, 200, 100);
+
+        let (hash, width, height) = blurhash(&src).unwrap();
+        // 4 by 3 components
+        assert_eq!(hash.len(), 4 + 2 * 4 * 3);
+        assert_eq!((width, height), (64, 32));
+        assert_eq!(blurhash(&src).unwrap().0, hash);
+
+        let url = blurhash_data_url(&hash, width, height).unwrap();
+        let png = general_purpose::STANDARD
+            .decode(url.strip_prefix("data:image/png;base64,").unwrap())
+            .unwrap();
+        let placeholder = image::load_from_memory(&png).unwrap();
+        assert_eq!((placeholder.width(), placeholder.height()), (32, 16));
+    }
+}
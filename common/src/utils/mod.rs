@@ -1,4 +1,9 @@
 pub mod clear_temp_files_dir;
+pub mod diff;
+pub mod file_preview;
+pub mod image_edit;
 pub mod img_dimensions_preview;
 pub mod lifecycle;
 pub mod local_file_path;
+pub mod media_compression;
+pub mod voice_message;
@@ -0,0 +1,313 @@
+//! Voice messages recorded from the chatbar. The microphone is read with cpal, which rodio comes
+//! with, and the clip is encoded as Opus in an Ogg container. rodio can't decode Opus, so the
+//! waveform of a voice message is read by decoding it here.
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        mpsc, Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use anyhow::{bail, Context};
+use ogg::{PacketReader, PacketWriteEndInfo, PacketWriter};
+use opus::{Application, Bitrate, Channels};
+use parking_lot::Mutex;
+use tracing::log;
+
+use rodio::cpal::{
+    self,
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    FromSample, SampleFormat, SizedSample,
+};
+
+pub const EXTENSION: &str = "opus";
+/// Recording stops by itself after this long
+pub const MAX_DURATION: Duration = Duration::from_secs(5 * 60);
+// opus always works at 48kHz, the microphone is resampled to it
+const SAMPLE_RATE: u32 = 48_000;
+// 20ms
+const FRAME_SIZE: usize = 960;
+const BITRATE: i32 = 32_000;
+// the largest packet opus makes is 1275 bytes, a decoded packet is at most 120ms
+const MAX_PACKET_SIZE: usize = 1275;
+const MAX_DECODED_SIZE: usize = 5760;
+
+pub fn is_voice_message(file_name: &str) -> bool {
+    Path::new(file_name)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(EXTENSION))
+}
+
+/// e.g. voice-message-2024-03-01-142501.opus
+pub fn file_name() -> String {
+    format!(
+        "voice-message-{}.{EXTENSION}",
+        chrono::Local::now().format("%Y-%m-%d-%H%M%S")
+    )
+}
+
+/// Records from a microphone until it is finished or dropped. The stream runs on its own
+/// thread, cpal streams can't be sent between threads on every platform.
+pub struct Recorder {
+    samples: Arc<Mutex<Vec<f32>>>,
+    // the loudest sample of the last callback, as f32 bits
+    level: Arc<AtomicU32>,
+    stop: Arc<AtomicBool>,
+    sample_rate: u32,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Recorder {
+    /// Starts recording from the microphone with the given name, or the default one.
+    pub fn start(device_name: Option<&str>) -> anyhow::Result<Self> {
+        let host = cpal::default_host();
+        let device = device_name
+            .and_then(|name| {
+                host.input_devices()
+                    .ok()?
+                    .find(|device| device.name().is_ok_and(|n| n == name))
+            })
+            .or_else(|| host.default_input_device())
+            .context("no microphone found")?;
+
+        let samples = Arc::new(Mutex::new(vec![]));
+        let level = Arc::new(AtomicU32::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let thread = {
+            let samples = samples.clone();
+            let level = level.clone();
+            let stop = stop.clone();
+            thread::spawn(move || {
+                let stream = match open_stream(&device, samples, level) {
+                    Ok((stream, sample_rate)) => {
+                        let _ = ready_tx.send(Ok(sample_rate));
+                        stream
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+                while !stop.load(Ordering::Relaxed) {
+                    thread::sleep(Duration::from_millis(20));
+                }
+                drop(stream);
+            })
+        };
+        let sample_rate = ready_rx.recv().context("the recording thread stopped")??;
+
+        Ok(Self {
+            samples,
+            level,
+            stop,
+            sample_rate,
+            thread: Some(thread),
+        })
+    }
+
+    /// From 0 to 1
+    pub fn level(&self) -> f32 {
+        f32::from_bits(self.level.load(Ordering::Relaxed))
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.samples.lock().len() as f64 / self.sample_rate as f64)
+    }
+
+    /// Stops recording and writes the clip to `dest`.
+    pub fn finish(mut self, dest: &Path) -> anyhow::Result<()> {
+        self.stop();
+        let samples = std::mem::take(&mut *self.samples.lock());
+        if samples.is_empty() {
+            bail!("nothing was recorded");
+        }
+        encode(&resample(&samples, self.sample_rate, SAMPLE_RATE), dest)
+    }
+
+    fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn open_stream(
+    device: &cpal::Device,
+    samples: Arc<Mutex<Vec<f32>>>,
+    level: Arc<AtomicU32>,
+) -> anyhow::Result<(cpal::Stream, u32)> {
+    let config = device.default_input_config()?;
+    let sample_rate = config.sample_rate().0;
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_stream::<f32>(device, &config.into(), samples, level)?,
+        SampleFormat::I16 => build_stream::<i16>(device, &config.into(), samples, level)?,
+        SampleFormat::U16 => build_stream::<u16>(device, &config.into(), samples, level)?,
+        SampleFormat::I32 => build_stream::<i32>(device, &config.into(), samples, level)?,
+        format => bail!("unsupported sample format {format}"),
+    };
+    stream.play()?;
+    Ok((stream, sample_rate))
+}
+
+fn build_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    samples: Arc<Mutex<Vec<f32>>>,
+    level: Arc<AtomicU32>,
+) -> anyhow::Result<cpal::Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = config.channels as usize;
+    let max_samples = (MAX_DURATION.as_secs() * config.sample_rate.0 as u64) as usize;
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _| {
+            // mixed down to mono
+            let mono: Vec<f32> = data
+                .chunks(channels)
+                .map(|frame| {
+                    frame.iter().map(|s| f32::from_sample_(*s)).sum::<f32>() / channels as f32
+                })
+                .collect();
+            let peak = mono.iter().fold(0f32, |peak, s| peak.max(s.abs()));
+            level.store(peak.min(1.0).to_bits(), Ordering::Relaxed);
+            let mut samples = samples.lock();
+            let room = max_samples.saturating_sub(samples.len());
+            samples.extend(mono.into_iter().take(room));
+        },
+        |e| log::error!("voice message recording failed: {e}"),
+        None,
+    )?;
+    Ok(stream)
+}
+
+// linear interpolation is plenty for speech
+fn resample(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let len = (samples.len() as u64 * to as u64 / from as u64) as usize;
+    let step = from as f64 / to as f64;
+    (0..len)
+        .map(|i| {
+            let pos = i as f64 * step;
+            let index = pos as usize;
+            let next = samples.get(index + 1).unwrap_or(&samples[index]);
+            let frac = (pos - index as f64) as f32;
+            samples[index] * (1.0 - frac) + next * frac
+        })
+        .collect()
+}
+
+/// Encodes mono 48kHz samples as Ogg Opus, see RFC 7845.
+fn encode(samples: &[f32], dest: &Path) -> anyhow::Result<()> {
+    let mut encoder = opus::Encoder::new(SAMPLE_RATE, Channels::Mono, Application::Voip)?;
+    encoder.set_bitrate(Bitrate::Bits(BITRATE))?;
+    let pre_skip = encoder.get_lookahead()? as u16;
+
+    let mut writer = PacketWriter::new(BufWriter::new(File::create(dest)?));
+    let serial = rand::random();
+
+    let mut head = b"OpusHead".to_vec();
+    head.push(1); // version
+    head.push(1); // channels
+    head.extend_from_slice(&pre_skip.to_le_bytes());
+    head.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    head.extend_from_slice(&0i16.to_le_bytes()); // gain
+    head.push(0); // mono/stereo channel mapping
+    writer.write_packet(head, serial, PacketWriteEndInfo::EndPage, 0)?;
+
+    let vendor = b"uplink";
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor);
+    tags.extend_from_slice(&0u32.to_le_bytes()); // no comments
+    writer.write_packet(tags, serial, PacketWriteEndInfo::EndPage, 0)?;
+
+    // the encoder lags behind by the pre-skip, silence is added to get the end of the clip out.
+    // the last frame is padded with silence too, the granule position cuts it off again
+    let end = pre_skip as usize + samples.len();
+    let mut padded = samples.to_vec();
+    padded.resize(end.div_ceil(FRAME_SIZE) * FRAME_SIZE, 0.0);
+    let frames = padded.len() / FRAME_SIZE;
+    let mut packet = [0u8; MAX_PACKET_SIZE];
+    for (i, frame) in padded.chunks(FRAME_SIZE).enumerate() {
+        let len = encoder.encode_float(frame, &mut packet)?;
+        let (granule, end_info) = if i + 1 == frames {
+            (end, PacketWriteEndInfo::EndStream)
+        } else {
+            ((i + 1) * FRAME_SIZE, PacketWriteEndInfo::NormalPacket)
+        };
+        writer.write_packet(packet[..len].to_vec(), serial, end_info, granule as u64)?;
+    }
+    Ok(())
+}
+
+/// The samples of a voice message, mono at 48kHz.
+pub fn decode(path: &Path) -> anyhow::Result<Vec<i16>> {
+    let mut reader = PacketReader::new(BufReader::new(File::open(path)?));
+    let head = reader.read_packet()?.context("empty file")?.data;
+    if !head.starts_with(b"OpusHead") || head.len() < 19 {
+        bail!("not an opus file");
+    }
+    let pre_skip = u16::from_le_bytes([head[10], head[11]]) as usize;
+    // the comments
+    reader.read_packet()?;
+
+    // stereo streams are mixed down by the decoder
+    let mut decoder = opus::Decoder::new(SAMPLE_RATE, Channels::Mono)?;
+    let mut samples = vec![];
+    let mut buf = [0i16; MAX_DECODED_SIZE];
+    while let Some(packet) = reader.read_packet()? {
+        let len = decoder.decode(&packet.data, &mut buf, false)?;
+        samples.extend_from_slice(&buf[..len]);
+        if packet.last_in_stream() {
+            let end = packet.absgp_page() as usize;
+            samples.truncate(end.max(pre_skip));
+            break;
+        }
+    }
+    Ok(samples.split_off(pre_skip.min(samples.len())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encodes_and_decodes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(file_name());
+        assert!(is_voice_message(&path.to_string_lossy()));
+        assert!(!is_voice_message("song.ogg"));
+
+        // half a second of a 440Hz tone at 44.1kHz, which isn't a multiple of the frame size
+        let tone: Vec<f32> = (0..22050)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / 44100.0).sin() * 0.5)
+            .collect();
+        let resampled = resample(&tone, 44100, SAMPLE_RATE);
+        assert_eq!(resampled.len(), 24000);
+        encode(&resampled, &path).unwrap();
+
+        let decoded = decode(&path).unwrap();
+        assert_eq!(decoded.len(), 24000);
+        let peak = decoded.iter().map(|s| s.unsigned_abs()).max().unwrap();
+        assert!(peak > i16::MAX as u16 / 4, "peak was {peak}");
+    }
+}
@@ -0,0 +1,324 @@
+//! An account backup is a zip of the Tesseract store, state.json (which includes the Configuration),
+//! login_config.json, custom themes and fonts, encrypted with a passphrase chosen by the user.
+//! Seed words only bring back the identity, a backup brings back everything stored locally as well.
+//! The Tesseract in a backup is unlocked with the backup passphrase, not the pin of the account, so
+//! the backup can be restored with a different pin.
+
+use std::{
+    fs,
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use walkdir::WalkDir;
+use warp::{crypto::cipher::Cipher, error::Error, tesseract::Tesseract};
+use zip::{write::FileOptions, ZipArchive, ZipWriter};
+
+use crate::STATIC_ARGS;
+
+// lets a restore tell a backup apart from any other file before trying to decrypt it
+const MAGIC: &[u8] = b"UPLINK-BACKUP-1\n";
+
+const PROFILE_DIR: &str = "profile";
+const THEMES_DIR: &str = "themes";
+const FONTS_DIR: &str = "fonts";
+
+/// Where the backed up files live.
+pub struct BackupPaths {
+    pub profile: PathBuf,
+    pub tesseract_file: String,
+    pub themes: PathBuf,
+    pub fonts: PathBuf,
+}
+
+impl BackupPaths {
+    pub fn active_profile() -> Self {
+        Self {
+            profile: STATIC_ARGS.uplink_path(),
+            tesseract_file: STATIC_ARGS.tesseract_file.clone(),
+            themes: STATIC_ARGS.themes_path.clone(),
+            fonts: STATIC_ARGS.fonts_path.clone(),
+        }
+    }
+
+    // relative to the profile directory
+    fn profile_files(&self) -> Vec<PathBuf> {
+        vec![
+            Path::new("warp").join(&self.tesseract_file),
+            PathBuf::from("state.json"),
+            PathBuf::from("login_config.json"),
+        ]
+    }
+}
+
+fn other_err<E: ToString>(e: E) -> Error {
+    Error::OtherWithContext(e.to_string())
+}
+
+pub fn create_backup(
+    paths: &BackupPaths,
+    dest: &Path,
+    pin: &str,
+    passphrase: &str,
+) -> Result<(), Error> {
+    let archive = pack(paths, pin, passphrase)?;
+    let mut contents = MAGIC.to_vec();
+    contents.extend(Cipher::direct_encrypt(&archive, passphrase.as_bytes())?);
+    fs::write(dest, contents).map_err(other_err)
+}
+
+/// Returns the archive inside a backup. Fails with [`Error::DecryptionError`] if the passphrase is wrong.
+pub fn decrypt_backup(src: &Path, passphrase: &str) -> Result<Vec<u8>, Error> {
+    let contents = fs::read(src).map_err(other_err)?;
+    let encrypted = match contents.strip_prefix(MAGIC) {
+        Some(encrypted) => encrypted,
+        None => return Err(Error::OtherWithContext("not an Uplink backup".into())),
+    };
+    Cipher::direct_decrypt(encrypted, passphrase.as_bytes()).map_err(|_| Error::DecryptionError)
+}
+
+/// Replaces the profile directory with the one from a decrypted backup, its Tesseract then unlocks
+/// with `pin`. The profile is unpacked next to the current one and only swapped in once its Tesseract
+/// unlocked, the current profile is left alone if anything fails. Themes and fonts are shared by all
+/// profiles, so only the ones which don't exist yet are restored.
+pub fn restore_backup(
+    paths: &BackupPaths,
+    archive: &[u8],
+    passphrase: &str,
+    pin: &str,
+) -> Result<(), Error> {
+    let staged = sibling(&paths.profile, "restoring");
+    if staged.exists() {
+        fs::remove_dir_all(&staged).map_err(other_err)?;
+    }
+    let staged_paths = BackupPaths {
+        profile: staged.clone(),
+        tesseract_file: paths.tesseract_file.clone(),
+        themes: paths.themes.clone(),
+        fonts: paths.fonts.clone(),
+    };
+    let r = unpack(&staged_paths, archive)
+        .map_err(other_err)
+        .and_then(|_| rekey_tesseract(&staged.join("warp"), &paths.tesseract_file, passphrase, pin))
+        .and_then(|_| swap_dir(&staged, &paths.profile).map_err(other_err));
+    if r.is_err() {
+        let _ = fs::remove_dir_all(&staged);
+    }
+    r
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{suffix}"));
+    path.with_file_name(name)
+}
+
+// makes the Tesseract at `dir` unlock with `new` instead of `old`
+fn rekey_tesseract(dir: &Path, file: &str, old: &str, new: &str) -> Result<(), Error> {
+    if !dir.join(file).is_file() {
+        return Err(Error::OtherWithContext("the backup has no account".into()));
+    }
+    let tesseract = Tesseract::open_or_create(dir, file)?;
+    let r = tesseract
+        .unlock(old.as_bytes())
+        .and_then(|_| tesseract.update_unlock(old.as_bytes(), new.as_bytes()))
+        .and_then(|_| tesseract.save());
+    tesseract.lock();
+    r
+}
+
+// moves `src` to `dest`, putting back what was at `dest` if that fails
+fn swap_dir(src: &Path, dest: &Path) -> std::io::Result<()> {
+    let replaced = sibling(dest, "replaced");
+    if replaced.exists() {
+        fs::remove_dir_all(&replaced)?;
+    }
+    let had_dest = dest.exists();
+    if had_dest {
+        fs::rename(dest, &replaced)?;
+    }
+    if let Err(e) = fs::rename(src, dest) {
+        if had_dest {
+            let _ = fs::rename(&replaced, dest);
+        }
+        return Err(e);
+    }
+    if had_dest {
+        let _ = fs::remove_dir_all(&replaced);
+    }
+    Ok(())
+}
+
+fn pack(paths: &BackupPaths, pin: &str, passphrase: &str) -> Result<Vec<u8>, Error> {
+    let warp_dir = paths.profile.join("warp");
+    if !warp_dir.join(&paths.tesseract_file).is_file() {
+        return Err(Error::OtherWithContext(
+            "there is no account to back up".into(),
+        ));
+    }
+    // the copy is rekeyed, the Tesseract in use is left alone
+    let copy = format!("{}.backup", paths.tesseract_file);
+    fs::copy(warp_dir.join(&paths.tesseract_file), warp_dir.join(&copy)).map_err(other_err)?;
+    let r = rekey_tesseract(&warp_dir, &copy, pin, passphrase)
+        .and_then(|_| pack_files(paths, &warp_dir.join(&copy)).map_err(other_err));
+    let _ = fs::remove_file(warp_dir.join(&copy));
+    r
+}
+
+fn pack_files(paths: &BackupPaths, tesseract: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(zip::CompressionMethod::Bzip2);
+
+    let mut add_file = |path: &Path, name: PathBuf| -> anyhow::Result<()> {
+        zip.start_file(name.to_string_lossy().replace('\\', "/"), options)?;
+        zip.write_all(&fs::read(path)?)?;
+        Ok(())
+    };
+
+    for file in paths.profile_files() {
+        let path = if file.ends_with(&paths.tesseract_file) {
+            tesseract.to_path_buf()
+        } else {
+            paths.profile.join(&file)
+        };
+        if path.is_file() {
+            add_file(&path, Path::new(PROFILE_DIR).join(file))?;
+        }
+    }
+
+    for (dir, name) in [(&paths.themes, THEMES_DIR), (&paths.fonts, FONTS_DIR)] {
+        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+            if !entry.path().is_file() {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(dir)?;
+            add_file(entry.path(), Path::new(name).join(relative))?;
+        }
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+fn unpack(paths: &BackupPaths, archive: &[u8]) -> anyhow::Result<()> {
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    for i in 0..zip.len() {
+        let mut file = zip.by_index(i)?;
+        // enclosed_name rejects absolute paths and paths escaping the archive
+        let name = match file.enclosed_name() {
+            Some(name) => name.to_path_buf(),
+            None => continue,
+        };
+        let mut components = name.components();
+        let root = match components.next() {
+            Some(c) => c.as_os_str().to_string_lossy().to_string(),
+            None => continue,
+        };
+        let relative = components.as_path();
+        let dest = match root.as_str() {
+            PROFILE_DIR => paths.profile.join(relative),
+            THEMES_DIR => paths.themes.join(relative),
+            FONTS_DIR => paths.fonts.join(relative),
+            _ => continue,
+        };
+        // themes and fonts of other profiles stay as they are
+        if root != PROFILE_DIR && dest.exists() {
+            continue;
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = vec![];
+        file.read_to_end(&mut contents)?;
+        fs::write(dest, contents)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn paths(root: &Path) -> BackupPaths {
+        BackupPaths {
+            profile: root.join(".user"),
+            tesseract_file: "tesseract.json".into(),
+            themes: root.join("themes"),
+            fonts: root.join("fonts"),
+        }
+    }
+
+    fn create_account(paths: &BackupPaths, pin: &str) {
+        let warp_dir = paths.profile.join("warp");
+        fs::create_dir_all(&warp_dir).unwrap();
+        let tesseract = Tesseract::open_or_create(&warp_dir, &paths.tesseract_file).unwrap();
+        tesseract.unlock(pin.as_bytes()).unwrap();
+        tesseract.set("keypair", "secret").unwrap();
+        tesseract.save().unwrap();
+        tesseract.lock();
+    }
+
+    fn retrieve_keypair(paths: &BackupPaths, pin: &str) -> Result<String, Error> {
+        let tesseract =
+            Tesseract::open_or_create(paths.profile.join("warp"), &paths.tesseract_file)?;
+        tesseract.unlock(pin.as_bytes())?;
+        tesseract.retrieve("keypair")
+    }
+
+    #[test]
+    fn backup_round_trip() {
+        let src = tempfile::tempdir().unwrap();
+        let src_paths = paths(src.path());
+        create_account(&src_paths, "1234");
+        fs::create_dir_all(&src_paths.themes).unwrap();
+        fs::write(src_paths.profile.join("state.json"), "{}").unwrap();
+        fs::write(src_paths.themes.join("dark.scss"), "body {}").unwrap();
+        fs::write(src_paths.themes.join("light.scss"), "body {}").unwrap();
+
+        let backup = src.path().join("account.uplinkbackup");
+        assert!(create_backup(&src_paths, &backup, "4321", "correct horse").is_err());
+        create_backup(&src_paths, &backup, "1234", "correct horse").unwrap();
+        // the account that was backed up still unlocks with its pin
+        assert_eq!(retrieve_keypair(&src_paths, "1234").unwrap(), "secret");
+
+        let dest = tempfile::tempdir().unwrap();
+        let dest_paths = paths(dest.path());
+        fs::create_dir_all(&dest_paths.profile).unwrap();
+        fs::create_dir_all(&dest_paths.themes).unwrap();
+        fs::write(dest_paths.profile.join("old.json"), "{}").unwrap();
+        fs::write(dest_paths.themes.join("dark.scss"), "main {}").unwrap();
+        assert!(matches!(
+            decrypt_backup(&backup, "wrong horse"),
+            Err(Error::DecryptionError)
+        ));
+        let archive = decrypt_backup(&backup, "correct horse").unwrap();
+
+        // nothing is touched if the restored account doesn't unlock
+        assert!(restore_backup(&dest_paths, &archive, "wrong horse", "5678").is_err());
+        assert!(dest_paths.profile.join("old.json").exists());
+        assert!(!sibling(&dest_paths.profile, "restoring").exists());
+
+        restore_backup(&dest_paths, &archive, "correct horse", "5678").unwrap();
+        assert_eq!(retrieve_keypair(&dest_paths, "5678").unwrap(), "secret");
+        assert!(!dest_paths.profile.join("old.json").exists());
+        assert_eq!(
+            fs::read_to_string(dest_paths.profile.join("state.json")).unwrap(),
+            "{}"
+        );
+        // a theme which already exists is kept
+        assert_eq!(
+            fs::read_to_string(dest_paths.themes.join("dark.scss")).unwrap(),
+            "main {}"
+        );
+        assert_eq!(
+            fs::read_to_string(dest_paths.themes.join("light.scss")).unwrap(),
+            "body {}"
+        );
+    }
+
+    #[test]
+    fn backup_requires_an_account() {
+        let src = tempfile::tempdir().unwrap();
+        let dest = src.path().join("account.uplinkbackup");
+        assert!(create_backup(&paths(src.path()), &dest, "1234", "passphrase").is_err());
+    }
+}
@@ -0,0 +1,550 @@
+//! Searches GIFs for the GIF picker. The GIFs come from a [`GifProvider`]: by default the folder of
+//! GIFs the user collected, found by their file names, folders and tags, or one of the GIF services
+//! if it was set up with an API key. The folder is searched whenever the service can't be reached.
+//! GIFs from a service are downloaded before they are sent, so they are sent like any other file.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, Context};
+use derive_more::Display;
+use futures::{future::BoxFuture, FutureExt};
+use once_cell::sync::Lazy;
+use reqwest::header::CONTENT_LENGTH;
+use serde::{Deserialize, Serialize};
+use tracing::log;
+use walkdir::WalkDir;
+
+use crate::{
+    state::configuration::Gifs, utils::local_file_path::get_fixed_path_to_load_local_file,
+    MAX_GIF_SIZE,
+};
+
+/// Tags for the GIFs in the folder, by their path in it: `{"cats/dance.gif": ["happy", "party"]}`
+pub const TAGS_FILE: &str = "tags.json";
+
+// a client without the timeout would wait on a service forever, so failing to build it is an error
+static CLIENT: Lazy<reqwest::Result<reqwest::Client>> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+});
+
+fn client() -> anyhow::Result<&'static reqwest::Client> {
+    CLIENT
+        .as_ref()
+        .map_err(|e| anyhow::anyhow!("failed to build the HTTP client: {e}"))
+}
+
+#[derive(Debug, Default, Display, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+pub enum GifService {
+    /// the folder of GIFs
+    #[default]
+    #[display(fmt = "folder")]
+    Folder,
+    #[display(fmt = "tenor")]
+    Tenor,
+    #[display(fmt = "giphy")]
+    Giphy,
+}
+
+impl GifService {
+    pub const ALL: [GifService; 3] = [GifService::Folder, GifService::Tenor, GifService::Giphy];
+
+    fn base_url(&self) -> &'static str {
+        match self {
+            GifService::Folder => "",
+            GifService::Tenor => "https://tenor.googleapis.com",
+            GifService::Giphy => "https://api.giphy.com",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GifSource {
+    File(PathBuf),
+    Url(String),
+}
+
+impl GifSource {
+    /// For the `src` of an image.
+    pub fn src(&self) -> String {
+        match self {
+            GifSource::File(path) => get_fixed_path_to_load_local_file(path.clone()),
+            GifSource::Url(url) => url.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gif {
+    pub id: String,
+    pub title: String,
+    /// a smaller version, shown in the picker
+    pub preview: GifSource,
+    /// the GIF which is sent
+    pub source: GifSource,
+}
+
+pub trait GifProvider: Send + Sync {
+    /// Up to `limit` GIFs matching the query, the best matches first. Without a query these are
+    /// the trending GIFs, or all of them.
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, anyhow::Result<Vec<Gif>>>;
+}
+
+/// The provider picked in the settings. Without an API key only the folder is searched.
+pub fn provider(config: &Gifs, folder: PathBuf) -> Box<dyn GifProvider> {
+    let folder = Folder::new(folder);
+    if config.service == GifService::Folder || config.api_key.trim().is_empty() {
+        return Box::new(folder);
+    }
+    Box::new(WithFallback {
+        provider: Service::new(config.service, config.api_key.trim()),
+        fallback: folder,
+    })
+}
+
+struct WithFallback<P, F> {
+    provider: P,
+    fallback: F,
+}
+
+impl<P: GifProvider, F: GifProvider> GifProvider for WithFallback<P, F> {
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, anyhow::Result<Vec<Gif>>> {
+        async move {
+            match self.provider.search(query, limit).await {
+                Ok(gifs) => Ok(gifs),
+                Err(e) => {
+                    log::warn!("failed to search GIFs, searching the folder instead: {e}");
+                    self.fallback.search(query, limit).await
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+/// The GIFs in a folder and its subfolders. Each word of the query has to start a word of the file
+/// name, of a folder it is in or one of its tags.
+#[derive(Clone)]
+pub struct Folder {
+    dir: PathBuf,
+}
+
+impl Folder {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn search_blocking(&self, query: &str, limit: usize) -> Vec<Gif> {
+        let tags: HashMap<String, Vec<String>> = std::fs::read(self.dir.join(TAGS_FILE))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+        let query: Vec<String> = words(query).collect();
+        let mut matches: Vec<_> = WalkDir::new(&self.dir)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry.file_type().is_file() && crate::is_gif(&entry.path().to_string_lossy())
+            })
+            .filter_map(|entry| {
+                let relative = entry.path().strip_prefix(&self.dir).ok()?;
+                let key = relative.to_string_lossy().replace('\\', "/");
+                let name = relative.file_stem()?.to_string_lossy().to_string();
+                let folders = relative.parent().map(|p| p.to_string_lossy().to_string());
+                let name_words: Vec<String> = words(&name).collect();
+                let other_words: Vec<String> = folders
+                    .iter()
+                    .chain(tags.get(&key).into_iter().flatten())
+                    .flat_map(|text| words(text))
+                    .collect();
+                let starts = |words: &[String], q: &String| words.iter().any(|w| w.starts_with(q));
+                if !query
+                    .iter()
+                    .all(|q| starts(&name_words, q) || starts(&other_words, q))
+                {
+                    return None;
+                }
+                // the ones found by their names first
+                let in_name = query.iter().filter(|q| starts(&name_words, q)).count();
+                Some((in_name, key, name, entry.into_path()))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, key, name, path)| Gif {
+                id: key,
+                title: name,
+                preview: GifSource::File(path.clone()),
+                source: GifSource::File(path),
+            })
+            .collect()
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl GifProvider for Folder {
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, anyhow::Result<Vec<Gif>>> {
+        async move {
+            let folder = self.clone();
+            let query = query.to_string();
+            let gifs =
+                tokio::task::spawn_blocking(move || folder.search_blocking(&query, limit)).await?;
+            Ok(gifs)
+        }
+        .boxed()
+    }
+}
+
+/// One of the GIF services, Tenor or Giphy.
+pub struct Service {
+    service: GifService,
+    api_key: String,
+    base_url: String,
+}
+
+impl Service {
+    pub fn new(service: GifService, api_key: &str) -> Self {
+        Self::with_base_url(service, api_key, service.base_url())
+    }
+
+    /// A service at another address, such as a proxy.
+    pub fn with_base_url(service: GifService, api_key: &str, base_url: &str) -> Self {
+        Self {
+            service,
+            api_key: api_key.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    async fn tenor(&self, query: &str, limit: usize) -> anyhow::Result<Vec<Gif>> {
+        let endpoint = if query.is_empty() {
+            "featured"
+        } else {
+            "search"
+        };
+        let limit = limit.to_string();
+        let response: TenorResponse = client()?
+            .get(format!("{}/v2/{endpoint}", self.base_url))
+            .query(&[
+                ("q", query),
+                ("key", self.api_key.as_str()),
+                ("client_key", "uplink"),
+                ("limit", limit.as_str()),
+                ("media_filter", "gif,tinygif"),
+                ("contentfilter", "medium"),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let gifs = response
+            .results
+            .into_iter()
+            .filter_map(|mut gif| {
+                let source = gif.media_formats.remove("gif")?.url;
+                let preview = gif
+                    .media_formats
+                    .remove("tinygif")
+                    .map(|media| media.url)
+                    .unwrap_or_else(|| source.clone());
+                Some(Gif {
+                    id: gif.id,
+                    title: gif.content_description,
+                    preview: GifSource::Url(preview),
+                    source: GifSource::Url(source),
+                })
+            })
+            .collect();
+        Ok(gifs)
+    }
+
+    async fn giphy(&self, query: &str, limit: usize) -> anyhow::Result<Vec<Gif>> {
+        let endpoint = if query.is_empty() {
+            "trending"
+        } else {
+            "search"
+        };
+        let limit = limit.to_string();
+        let response: GiphyResponse = client()?
+            .get(format!("{}/v1/gifs/{endpoint}", self.base_url))
+            .query(&[
+                ("q", query),
+                ("api_key", self.api_key.as_str()),
+                ("limit", limit.as_str()),
+                ("rating", "pg-13"),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let gifs = response
+            .data
+            .into_iter()
+            .filter_map(|mut gif| {
+                let mut url = |name: &str| gif.images.remove(name).and_then(|image| image.url);
+                let source = url("original")?;
+                let preview = url("fixed_width_small")
+                    .or_else(|| url("fixed_width"))
+                    .unwrap_or_else(|| source.clone());
+                Some(Gif {
+                    id: gif.id,
+                    title: gif.title,
+                    preview: GifSource::Url(preview),
+                    source: GifSource::Url(source),
+                })
+            })
+            .collect();
+        Ok(gifs)
+    }
+}
+
+impl GifProvider for Service {
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, anyhow::Result<Vec<Gif>>> {
+        let query = query.trim();
+        match self.service {
+            GifService::Folder => async { bail!("the folder isn't a service") }.boxed(),
+            GifService::Tenor => self.tenor(query, limit).boxed(),
+            GifService::Giphy => self.giphy(query, limit).boxed(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TenorResponse {
+    results: Vec<TenorGif>,
+}
+
+#[derive(Deserialize)]
+struct TenorGif {
+    id: String,
+    #[serde(default)]
+    content_description: String,
+    media_formats: HashMap<String, TenorMedia>,
+}
+
+#[derive(Deserialize)]
+struct TenorMedia {
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct GiphyResponse {
+    data: Vec<GiphyGif>,
+}
+
+#[derive(Deserialize)]
+struct GiphyGif {
+    id: String,
+    #[serde(default)]
+    title: String,
+    images: HashMap<String, GiphyImage>,
+}
+
+// some renditions are only videos, without a url
+#[derive(Deserialize)]
+struct GiphyImage {
+    url: Option<String>,
+}
+
+/// Where the GIF is on the disk, to be sent. GIFs from a service are downloaded to `dir` first.
+pub async fn save(gif: &Gif, dir: &Path) -> anyhow::Result<PathBuf> {
+    let url = match &gif.source {
+        GifSource::File(path) => return Ok(path.clone()),
+        GifSource::Url(url) => url,
+    };
+    let mut response = client()?.get(url).send().await?.error_for_status()?;
+    let too_large = response
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|len| len.to_str().ok()?.parse::<usize>().ok())
+        .is_some_and(|len| len > MAX_GIF_SIZE);
+    if too_large {
+        bail!("the GIF is too large");
+    }
+    let mut bytes = vec![];
+    while let Some(chunk) = response.chunk().await? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() > MAX_GIF_SIZE {
+            bail!("the GIF is too large");
+        }
+    }
+    tokio::fs::create_dir_all(dir).await?;
+    let path = dir.join(file_name(gif));
+    tokio::fs::write(&path, bytes)
+        .await
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+// the name the others see, from the title if it has one
+fn file_name(gif: &Gif) -> String {
+    let title: Vec<String> = words(&gif.title).take(8).collect();
+    let name = if title.is_empty() {
+        words(&gif.id).collect::<Vec<_>>().join("-")
+    } else {
+        title.join("-")
+    };
+    if name.is_empty() {
+        "gif.gif".into()
+    } else {
+        format!("{name}.gif")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::http::{response, serve};
+    use std::fs;
+
+    #[test]
+    fn searches_the_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("reactions")).unwrap();
+        for name in [
+            "dancing-cat.gif",
+            "reactions/thumbs_up.GIF",
+            "reactions/no.gif",
+        ] {
+            fs::write(dir.path().join(name), b"GIF89a").unwrap();
+        }
+        fs::write(dir.path().join("cat.png"), b"png").unwrap();
+        fs::write(
+            dir.path().join(TAGS_FILE),
+            r#"{"reactions/thumbs_up.GIF": ["yes", "cat"]}"#,
+        )
+        .unwrap();
+
+        let folder = Folder::new(dir.path().to_path_buf());
+        let titles = |query: &str, limit: usize| -> Vec<String> {
+            folder
+                .search_blocking(query, limit)
+                .into_iter()
+                .map(|gif| gif.title)
+                .collect()
+        };
+        assert_eq!(titles("", 10), ["dancing-cat", "no", "thumbs_up"]);
+        // found by their names before their tags
+        assert_eq!(titles("CAT", 10), ["dancing-cat", "thumbs_up"]);
+        assert_eq!(titles("reac yes", 10), ["thumbs_up"]);
+        assert_eq!(titles("dance", 10), Vec::<String>::new());
+        assert_eq!(titles("", 1), ["dancing-cat"]);
+        let gif = folder.search_blocking("no", 1).remove(0);
+        assert_eq!(
+            gif.source,
+            GifSource::File(dir.path().join("reactions/no.gif"))
+        );
+    }
+
+    #[test]
+    fn searches_the_services() {
+        let (site, requests) = serve(vec![
+            response(
+                "200 OK",
+                "application/json",
+                br#"{"results": [
+                    {"id": "1", "content_description": "Cat Dance", "media_formats": {
+                        "gif": {"url": "https://media.example/1.gif"},
+                        "tinygif": {"url": "https://media.example/1-tiny.gif"}
+                    }},
+                    {"id": "2", "media_formats": {"mp4": {"url": "https://media.example/2.mp4"}}}
+                ], "next": "2"}"#,
+            ),
+            response(
+                "200 OK",
+                "application/json",
+                br#"{"data": [{"id": "abc", "title": "Cat GIF", "images": {
+                    "original": {"url": "https://media.example/abc.gif", "mp4": "https://media.example/abc.mp4"},
+                    "original_mp4": {"mp4": "https://media.example/abc.mp4"}
+                }}], "meta": {"status": 200}}"#,
+            ),
+            response("401 Unauthorized", "application/json", b"{}"),
+        ]);
+        let rt = tokio::runtime::Runtime::new().unwrap();
+
+        let tenor = Service::with_base_url(GifService::Tenor, "key", &format!("{site}/"));
+        let gifs = rt.block_on(tenor.search(" cat dance ", 10)).unwrap();
+        assert_eq!(
+            gifs,
+            [Gif {
+                id: "1".into(),
+                title: "Cat Dance".into(),
+                preview: GifSource::Url("https://media.example/1-tiny.gif".into()),
+                source: GifSource::Url("https://media.example/1.gif".into()),
+            }]
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /v2/search?q=cat+dance&key=key&"));
+        assert!(request.contains("&limit=10&"));
+
+        let giphy = Service::with_base_url(GifService::Giphy, "key", &site);
+        let gifs = rt.block_on(giphy.search("", 5)).unwrap();
+        assert_eq!(gifs[0].preview, gifs[0].source);
+        assert_eq!(file_name(&gifs[0]), "cat-gif.gif");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /v1/gifs/trending?q=&api_key=key&limit=5&"));
+
+        // a wrong key falls back to the folder
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("cat.gif"), b"GIF89a").unwrap();
+        let with_fallback = WithFallback {
+            provider: giphy,
+            fallback: Folder::new(dir.path().to_path_buf()),
+        };
+        let gifs = rt.block_on(with_fallback.search("cat", 5)).unwrap();
+        assert_eq!(gifs[0].source, GifSource::File(dir.path().join("cat.gif")));
+    }
+
+    #[test]
+    fn saves_picked_gifs() {
+        let (site, _requests) = serve(vec![
+            response("200 OK", "image/gif", b"GIF89a"),
+            response("200 OK", "image/gif", &vec![0; MAX_GIF_SIZE + 1]),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let gif = |id: &str| Gif {
+            id: id.into(),
+            title: String::new(),
+            preview: GifSource::Url(format!("{site}/{id}.gif")),
+            source: GifSource::Url(format!("{site}/{id}.gif")),
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let path = rt.block_on(save(&gif("x1"), dir.path())).unwrap();
+        assert_eq!(path, dir.path().join("x1.gif"));
+        assert_eq!(fs::read(path).unwrap(), b"GIF89a");
+        assert!(rt.block_on(save(&gif("big"), dir.path())).is_err());
+    }
+}
@@ -0,0 +1,653 @@
+//! Fetches link previews for the UI, so links are only visited when the user allowed it and never
+//! from the rendering code. A slow or huge page can't hold anything up: requests time out, only the
+//! start of a page is read and images over a size limit are skipped. Previews are kept in a small
+//! cache for each profile, which is saved between sessions. The embeds of pages with an oEmbed
+//! endpoint are discovered here as well. Only public addresses are visited, a link or a redirect
+//! can't reach into the local network.
+
+use std::{
+    collections::VecDeque,
+    io::Cursor,
+    net::{IpAddr, SocketAddr, ToSocketAddrs},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use base64::{engine::general_purpose, Engine};
+use extensions::embeds::Embed;
+use hyper::client::connect::dns::Name;
+use image::{DynamicImage, ImageFormat};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect, Url,
+};
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use tracing::log;
+use warp::error::Error;
+
+use crate::{state::link_preview::LinkPreview, STATIC_ARGS};
+
+// the link previews of the active profile, replaced when another profile is used
+static LINK_PREVIEWS: Lazy<Mutex<Option<Arc<LinkPreviews>>>> = Lazy::new(Default::default);
+
+/// The link previews of the active profile, with its cache.
+pub fn link_previews() -> Result<Arc<LinkPreviews>, Error> {
+    let path = STATIC_ARGS.link_previews_path();
+    let mut previews = LINK_PREVIEWS.lock();
+    match previews.as_ref() {
+        Some(previews) if previews.path.as_ref() == Some(&path) => Ok(previews.clone()),
+        _ => {
+            let new = Arc::new(LinkPreviews::new(Limits::default(), Some(path))?);
+            *previews = Some(new.clone());
+            Ok(new)
+        }
+    }
+}
+
+const CACHE_SIZE: usize = 100;
+const THUMBNAIL_SIZE: u32 = 96;
+// thumbnails are sent along with messages, so they have to stay small
+const MAX_THUMBNAIL_LEN: usize = 8 * 1024;
+const MAX_TITLE_LEN: usize = 100;
+const MAX_DESCRIPTION_LEN: usize = 200;
+const MAX_REDIRECTS: usize = 5;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// for the whole request, including reading the response
+    pub timeout: Duration,
+    /// only this much of a page is read. the preview is in its head
+    pub page_size: usize,
+    /// larger images aren't used as thumbnails
+    pub image_size: usize,
+    /// allows visiting private, loopback and link-local addresses. only for testing with a local
+    /// server
+    pub private_addresses: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            page_size: 256 * 1024,
+            image_size: 2 * 1024 * 1024,
+            private_addresses: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    // None if the link had no preview. these aren't saved, the page may have one next time
+    preview: Option<LinkPreview>,
+}
+
+/// The least recently used entries are dropped first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cache {
+    // most recently used last
+    entries: VecDeque<CacheEntry>,
+}
+
+impl Cache {
+    fn load(path: &Path) -> Self {
+        std::fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    fn to_json(&self) -> Result<Vec<u8>, serde_json::Error> {
+        let saved = Cache {
+            entries: self
+                .entries
+                .iter()
+                .filter(|entry| entry.preview.is_some())
+                .cloned()
+                .collect(),
+        };
+        serde_json::to_vec(&saved)
+    }
+
+    fn get(&mut self, url: &str) -> Option<Option<LinkPreview>> {
+        let pos = self.entries.iter().position(|entry| entry.url == url)?;
+        let entry = self.entries.remove(pos)?;
+        let preview = entry.preview.clone();
+        self.entries.push_back(entry);
+        Some(preview)
+    }
+
+    fn insert(&mut self, url: String, preview: Option<LinkPreview>) {
+        self.entries.retain(|entry| entry.url != url);
+        self.entries.push_back(CacheEntry { url, preview });
+        while self.entries.len() > CACHE_SIZE {
+            self.entries.pop_front();
+        }
+    }
+}
+
+pub struct LinkPreviews {
+    client: reqwest::Client,
+    limits: Limits,
+    cache: Mutex<Cache>,
+    // where the cache is saved
+    path: Option<PathBuf>,
+}
+
+impl LinkPreviews {
+    pub fn new(limits: Limits, path: Option<PathBuf>) -> Result<Self, Error> {
+        let private_addresses = limits.private_addresses;
+        // without the limits a page could hold the request up forever. every host, including the
+        // ones redirected to, is resolved to its public addresses only. the redirect policy
+        // catches the addresses which aren't resolved
+        let client = reqwest::Client::builder()
+            .timeout(limits.timeout)
+            .dns_resolver(Arc::new(PublicResolver { private_addresses }))
+            .redirect(redirect::Policy::custom(move |attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    return attempt.error("too many redirects");
+                }
+                match check_host(attempt.url(), private_addresses) {
+                    Ok(()) => attempt.follow(),
+                    Err(e) => attempt.error(e.to_string()),
+                }
+            }))
+            .build()
+            .map_err(to_error)?;
+        let cache = path.as_deref().map(Cache::load).unwrap_or_default();
+        Ok(Self {
+            client,
+            limits,
+            cache: Mutex::new(cache),
+            path,
+        })
+    }
+
+    /// The preview of `url`, from the cache if it was fetched before.
+    pub async fn get(&self, url: &str) -> Result<LinkPreview, Error> {
+        let cached = self.cache.lock().get(url);
+        let preview = match cached {
+            Some(preview) => preview,
+            None => {
+                let preview = match self.fetch(url).await {
+                    Ok(preview) => Some(preview),
+                    Err(e) => {
+                        log::debug!("no link preview for {url}: {e}");
+                        None
+                    }
+                };
+                let json = {
+                    let mut cache = self.cache.lock();
+                    cache.insert(url.to_string(), preview.clone());
+                    cache.to_json()
+                };
+                if let Some(path) = &self.path {
+                    let saved = match json {
+                        Ok(json) => tokio::fs::write(path, json).await.map_err(to_error),
+                        Err(e) => Err(to_error(e)),
+                    };
+                    if let Err(e) = saved {
+                        log::error!("failed to save link previews: {e}");
+                    }
+                }
+                preview
+            }
+        };
+        preview.ok_or_else(|| Error::OtherWithContext(format!("no link preview for {url}")))
+    }
+
+    /// Asks the oEmbed endpoint the page links to what to embed. Only done once the user clicks an
+    /// embed, so the result isn't cached.
+    pub async fn discover_embed(&self, url: &str) -> Result<Embed, Error> {
+        let base = http_url(url)?;
+        let page = self
+            .download(base.clone(), "text/html", self.limits.page_size, true)
+            .await?;
+        let endpoint = oembed_endpoint(&String::from_utf8_lossy(&page), &base)
+            .ok_or_else(|| Error::OtherWithContext(format!("{url} has no oEmbed endpoint")))?;
+        // also matches application/json+oembed
+        let json = self
+            .download(endpoint, "application/json", self.limits.page_size, false)
+            .await?;
+        let oembed: OEmbed = serde_json::from_slice(&json).map_err(to_error)?;
+        oembed
+            .embed()
+            .ok_or_else(|| Error::OtherWithContext(format!("nothing to embed for {url}")))
+    }
+
+    async fn fetch(&self, url: &str) -> Result<LinkPreview, Error> {
+        let base = http_url(url)?;
+        let page = self
+            .download(base.clone(), "text/html", self.limits.page_size, true)
+            .await?;
+        let html = String::from_utf8_lossy(&page);
+        let oembed = oembed_endpoint(&html, &base).is_some();
+        let page = PageMeta::parse(&html, &base);
+        if page.title.is_empty() {
+            return Err(Error::OtherWithContext(format!("{url} has no title")));
+        }
+        let thumbnail = match page.image {
+            Some(image) => self.thumbnail(image).await.unwrap_or_else(|e| {
+                log::debug!("no thumbnail for {url}: {e}");
+                String::new()
+            }),
+            None => String::new(),
+        };
+        Ok(LinkPreview {
+            url: url.to_string(),
+            title: page.title,
+            description: page.description,
+            thumbnail,
+            oembed,
+        })
+    }
+
+    /// Reads at most `max_size` bytes of the response. Longer ones are cut off if `truncate`,
+    /// otherwise they are an error.
+    async fn download(
+        &self,
+        url: Url,
+        content_type: &str,
+        max_size: usize,
+        truncate: bool,
+    ) -> Result<Vec<u8>, Error> {
+        check_host(&url, self.limits.private_addresses)?;
+        let mut rsp = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|rsp| rsp.error_for_status())
+            .map_err(to_error)?;
+        let has_type = rsp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.starts_with(content_type))
+            .unwrap_or_default();
+        if !has_type {
+            return Err(Error::OtherWithContext(format!("not {content_type}")));
+        }
+        let too_large = || Error::OtherWithContext(format!("over {max_size} bytes"));
+        if !truncate && rsp.content_length().unwrap_or_default() > max_size as u64 {
+            return Err(too_large());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = rsp.chunk().await.map_err(to_error)? {
+            body.extend_from_slice(&chunk);
+            if body.len() > max_size {
+                if !truncate {
+                    return Err(too_large());
+                }
+                body.truncate(max_size);
+                break;
+            }
+        }
+        Ok(body)
+    }
+
+    /// A small jpeg of the image as a data url.
+    async fn thumbnail(&self, url: Url) -> Result<String, Error> {
+        let bytes = self
+            .download(url, "image/", self.limits.image_size, false)
+            .await?;
+        let jpeg = tokio::task::spawn_blocking(move || -> Result<Vec<u8>, image::ImageError> {
+            let image = image::load_from_memory(&bytes)?;
+            // jpeg has no transparency
+            let thumbnail =
+                DynamicImage::ImageRgb8(image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE).to_rgb8());
+            let mut jpeg = Cursor::new(Vec::new());
+            thumbnail.write_to(&mut jpeg, ImageFormat::Jpeg)?;
+            Ok(jpeg.into_inner())
+        })
+        .await
+        .map_err(to_error)?
+        .map_err(to_error)?;
+
+        let data_url = format!(
+            "data:image/jpeg;base64,{}",
+            general_purpose::STANDARD.encode(jpeg)
+        );
+        if data_url.len() > MAX_THUMBNAIL_LEN {
+            return Err(Error::OtherWithContext("thumbnail is too large".into()));
+        }
+        Ok(data_url)
+    }
+}
+
+fn to_error(e: impl ToString) -> Error {
+    Error::OtherWithContext(e.to_string())
+}
+
+fn http_url(url: &str) -> Result<Url, Error> {
+    let parsed = Url::parse(url).map_err(to_error)?;
+    match parsed.scheme() {
+        "http" | "https" => Ok(parsed),
+        _ => Err(Error::OtherWithContext(format!("can't visit {url}"))),
+    }
+}
+
+/// Rejects urls which name a private, loopback or link-local address. Hosts are checked by
+/// `PublicResolver` once they are resolved.
+fn check_host(url: &Url, private_addresses: bool) -> Result<(), Error> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| Error::OtherWithContext(format!("{url} has no host")))?;
+    // ipv6 addresses are in brackets
+    let ip = match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) => ip,
+        Err(_) => return Ok(()),
+    };
+    if private_addresses || is_public(ip) {
+        Ok(())
+    } else {
+        Err(Error::OtherWithContext(format!("can't visit {ip}")))
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast())
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(IpAddr::V4(ip)),
+            None => {
+                let segment = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // unique local
+                    || segment & 0xfe00 == 0xfc00
+                    // link-local
+                    || segment & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+/// Resolves hosts like the system does, leaving out the addresses which aren't public.
+struct PublicResolver {
+    private_addresses: bool,
+}
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let private_addresses = self.private_addresses;
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let resolved = {
+                let host = host.clone();
+                tokio::task::spawn_blocking(move || (host.as_str(), 0).to_socket_addrs()).await??
+            };
+            let addrs: Vec<SocketAddr> = resolved
+                .filter(|addr| private_addresses || is_public(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{host} has no public address").into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+fn oembed_endpoint(html: &str, base: &Url) -> Option<Url> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse(r#"link[type="application/json+oembed"]"#).ok()?;
+    let href = document.select(&selector).next()?.value().attr("href")?;
+    base.join(href)
+        .ok()
+        .filter(|endpoint| matches!(endpoint.scheme(), "http" | "https"))
+}
+
+/// The fields of an oEmbed response which are used.
+#[derive(Debug, Deserialize)]
+struct OEmbed {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    html: String,
+    // some providers send it as a string
+    #[serde(default)]
+    height: Option<serde_json::Value>,
+}
+
+impl OEmbed {
+    /// The html of video and rich embeds isn't used as is, only the frame in it.
+    fn embed(&self) -> Option<Embed> {
+        let https = |src: &str| src.starts_with("https://").then(|| src.to_string());
+        match self.kind.as_str() {
+            "photo" => https(&self.url).map(|src| Embed::Image { src }),
+            "video" | "rich" => {
+                let document = Html::parse_fragment(&self.html);
+                let selector = Selector::parse("iframe").ok()?;
+                let src = https(document.select(&selector).next()?.value().attr("src")?)?;
+                let height = match &self.height {
+                    Some(serde_json::Value::Number(n)) => n.as_u64(),
+                    Some(serde_json::Value::String(s)) => s.parse().ok(),
+                    _ => None,
+                };
+                let height = height.unwrap_or(360).clamp(100, 600) as u32;
+                Some(Embed::Frame { src, height })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct PageMeta {
+    title: String,
+    description: String,
+    image: Option<Url>,
+}
+
+impl PageMeta {
+    /// Prefers the Open Graph tags, which are meant for previews.
+    fn parse(html: &str, base: &Url) -> Self {
+        let document = Html::parse_document(html);
+        let meta = |key: &str| -> Option<String> {
+            let selector = Selector::parse("meta").ok()?;
+            document.select(&selector).find_map(|element| {
+                let element = element.value();
+                let name = element.attr("property").or_else(|| element.attr("name"))?;
+                let content = element.attr("content")?.trim();
+                (name == key && !content.is_empty()).then(|| content.to_string())
+            })
+        };
+        let title_tag = || {
+            let selector = Selector::parse("title").ok()?;
+            let title: String = document.select(&selector).next()?.text().collect();
+            Some(title)
+        };
+
+        let title = meta("og:title").or_else(title_tag).unwrap_or_default();
+        let description = meta("og:description")
+            .or_else(|| meta("description"))
+            .unwrap_or_default();
+        let image = meta("og:image")
+            .or_else(|| meta("twitter:image"))
+            .and_then(|image| base.join(&image).ok())
+            .filter(|image| matches!(image.scheme(), "http" | "https"));
+
+        Self {
+            title: shorten(&title, MAX_TITLE_LEN),
+            description: shorten(&description, MAX_DESCRIPTION_LEN),
+            image,
+        }
+    }
+}
+
+fn shorten(text: &str, max_chars: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > max_chars {
+        text.chars().take(max_chars - 3).collect::<String>() + "..."
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::http::{response, serve};
+    use std::net::TcpListener;
+
+    fn page(head: &str) -> Vec<u8> {
+        format!(
+            "<html><head>{head}</head><body>{}</body></html>",
+            "a".repeat(4096)
+        )
+        .into_bytes()
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut png = Cursor::new(Vec::new());
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut png, ImageFormat::Png)
+            .unwrap();
+        png.into_inner()
+    }
+
+    fn limits() -> Limits {
+        Limits {
+            timeout: Duration::from_millis(500),
+            page_size: 1024,
+            image_size: 64 * 1024,
+            private_addresses: true,
+        }
+    }
+
+    #[test]
+    fn fetches_once_and_caches() {
+        let (site, _requests) = serve(vec![
+            response(
+                "200 OK",
+                "text/html; charset=utf-8",
+                &page(
+                    r#"<title>Fallback</title>
+                        <meta property="og:title" content="An   example">
+                        <meta name="description" content="About &amp; more">
+                        <meta property="og:image" content="/image.png">"#,
+                ),
+            ),
+            response("200 OK", "image/png", &png(400, 200)),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("link_previews.json");
+        let url = format!("{site}/article");
+
+        let previews = LinkPreviews::new(limits(), Some(path.clone())).unwrap();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let preview = rt.block_on(previews.get(&url)).unwrap();
+        assert_eq!(preview.url, url);
+        assert_eq!(preview.title, "An example");
+        assert_eq!(preview.description, "About & more");
+        assert!(preview.thumbnail.starts_with("data:image/jpeg;base64,"));
+
+        // the stand-in is gone, these come from the cache
+        assert_eq!(rt.block_on(previews.get(&url)).unwrap(), preview);
+        let reloaded = LinkPreviews::new(limits(), Some(path)).unwrap();
+        assert_eq!(rt.block_on(reloaded.get(&url)).unwrap(), preview);
+    }
+
+    #[test]
+    fn keeps_to_the_limits() {
+        let (site, _requests) = serve(vec![
+            response("200 OK", "application/json", b"{}"),
+            response(
+                "200 OK",
+                "text/html",
+                &page(r#"<title>Big image</title><meta property="og:image" content="/big.png">"#),
+            ),
+            response("200 OK", "image/png", &vec![0; 128 * 1024]),
+            None,
+        ]);
+
+        let previews = LinkPreviews::new(limits(), None).unwrap();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert!(rt.block_on(previews.get(&format!("{site}/data"))).is_err());
+        let preview = rt.block_on(previews.get(&format!("{site}/big"))).unwrap();
+        assert_eq!(preview.title, "Big image");
+        assert!(preview.thumbnail.is_empty());
+        assert!(rt.block_on(previews.get(&format!("{site}/slow"))).is_err());
+        assert!(rt.block_on(previews.get("file:///etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn stays_out_of_the_local_network() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.0.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "::1",
+            "fe80::1",
+            "fd00::1",
+            "::ffff:192.168.0.1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in ["93.184.216.34", "2606:2800:220:1::"] {
+            assert!(is_public(ip.parse().unwrap()), "{ip}");
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let site = format!("http://{}", listener.local_addr().unwrap());
+        let previews = LinkPreviews::new(Limits::default(), None).unwrap();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert!(rt.block_on(previews.get(&format!("{site}/page"))).is_err());
+        assert!(rt.block_on(previews.get("http://localhost/")).is_err());
+    }
+
+    #[test]
+    fn discovers_embeds() {
+        let (site, _requests) = serve(vec![
+            response(
+                "200 OK",
+                "text/html",
+                &page(r#"<link rel="alternate" type="application/json+oembed" href="/oembed?url=x">"#),
+            ),
+            response(
+                "200 OK",
+                "application/json+oembed",
+                br#"{"type": "rich", "height": "166", "html": "<iframe src=\"https://player.example.com/1\"></iframe><script src=\"https://example.com/x.js\"></script>"}"#,
+            ),
+            response("200 OK", "text/html", &page("<title>No oEmbed</title>")),
+        ]);
+
+        let previews = LinkPreviews::new(limits(), None).unwrap();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            rt.block_on(previews.discover_embed(&format!("{site}/track")))
+                .unwrap(),
+            Embed::Frame {
+                src: "https://player.example.com/1".into(),
+                height: 166
+            }
+        );
+        assert!(rt
+            .block_on(previews.discover_embed(&format!("{site}/page")))
+            .is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, slice, str::FromStr};
+use std::{collections::HashMap, path::PathBuf, slice, str::FromStr};
 
 use base64::{engine::general_purpose, Engine};
 use derive_more::Display;
@@ -30,6 +30,14 @@ pub enum MultiPassCmd {
         seed_words: String,
         rsp: oneshot::Sender<Result<multipass::identity::Identity, warp::error::Error>>,
     },
+    #[display(fmt = "RestoreBackup")]
+    RestoreBackup {
+        backup: PathBuf,
+        backup_passphrase: String,
+        // used to unlock the restored tesseract
+        passphrase: String,
+        rsp: oneshot::Sender<Result<multipass::identity::Identity, warp::error::Error>>,
+    },
     #[display(fmt = "CreateIdentity")]
     CreateIdentity {
         username: String,
@@ -158,10 +166,18 @@ impl std::fmt::Debug for MultiPassCmd {
 pub async fn handle_multipass_cmd(cmd: MultiPassCmd, warp: &mut super::super::Warp) {
     match cmd {
         MultiPassCmd::CreateIdentity { .. }
-        | MultiPassCmd::TryLogIn { .. }
-        | MultiPassCmd::RecoverIdentity { .. } => {
+        | MultiPassCmd::RecoverIdentity { .. }
+        | MultiPassCmd::RestoreBackup { .. } => {
             // do nothing and drop the rsp channel
         }
+        // warp is already running, this comes from the lock screen. only the passphrase needs checking.
+        MultiPassCmd::TryLogIn { passphrase, rsp } => {
+            let r = match super::tesseract_commands::verify_passphrase(&passphrase) {
+                Ok(_) => warp.multipass.get_own_identity().await,
+                Err(e) => Err(e),
+            };
+            let _ = rsp.send(r);
+        }
         MultiPassCmd::RequestFriend {
             id,
             outgoing_requests,
@@ -7,10 +7,25 @@ use walkdir::WalkDir;
 use zip::{result::ZipError, write::FileOptions};
 
 use derive_more::Display;
+use extensions::embeds::Embed;
 use futures::channel::oneshot;
 use warp::error;
 
 use tracing::log;
+use uuid::Uuid;
+
+use crate::{
+    state::{configuration::Gifs, link_preview::LinkPreview},
+    warp_runner::{
+        backup::{self, BackupPaths},
+        gifs::{self, Gif},
+        link_previews::link_previews,
+    },
+    STATIC_ARGS,
+};
+
+// a page of the GIF picker
+const GIFS_TO_TAKE: usize = 30;
 
 #[derive(Display)]
 pub enum OtherCmd {
@@ -20,6 +35,35 @@ pub enum OtherCmd {
         dest: PathBuf,
         rsp: oneshot::Sender<Result<(), error::Error>>,
     },
+    #[display(fmt = "BackupAccount {{ dest: {dest:?} }} ")]
+    BackupAccount {
+        dest: PathBuf,
+        // unlocks the Tesseract of the account
+        pin: String,
+        passphrase: String,
+        rsp: oneshot::Sender<Result<(), error::Error>>,
+    },
+    #[display(fmt = "FetchLinkPreview {{ url: {url} }} ")]
+    FetchLinkPreview {
+        url: String,
+        rsp: oneshot::Sender<Result<LinkPreview, error::Error>>,
+    },
+    #[display(fmt = "DiscoverEmbed {{ url: {url} }} ")]
+    DiscoverEmbed {
+        url: String,
+        rsp: oneshot::Sender<Result<Embed, error::Error>>,
+    },
+    #[display(fmt = "SearchGifs {{ query: {query} }} ")]
+    SearchGifs {
+        config: Gifs,
+        query: String,
+        rsp: oneshot::Sender<Result<Vec<Gif>, error::Error>>,
+    },
+    #[display(fmt = "SaveGif {{ id: {} }} ", "gif.id")]
+    SaveGif {
+        gif: Gif,
+        rsp: oneshot::Sender<Result<PathBuf, error::Error>>,
+    },
 }
 
 pub async fn handle_other_cmd(cmd: OtherCmd) {
@@ -28,9 +72,66 @@ pub async fn handle_other_cmd(cmd: OtherCmd) {
             let r = compress_folder(src, dest).await;
             let _ = rsp.send(r);
         }
+        OtherCmd::BackupAccount {
+            dest,
+            pin,
+            passphrase,
+            rsp,
+        } => {
+            let r = backup_account(dest, pin, passphrase).await;
+            let _ = rsp.send(r);
+        }
+        // fetching can take seconds, don't hold up the other commands
+        OtherCmd::FetchLinkPreview { url, rsp } => {
+            tokio::spawn(async move {
+                let r = match link_previews() {
+                    Ok(previews) => previews.get(&url).await,
+                    Err(e) => Err(e),
+                };
+                let _ = rsp.send(r);
+            });
+        }
+        OtherCmd::DiscoverEmbed { url, rsp } => {
+            tokio::spawn(async move {
+                let r = match link_previews() {
+                    Ok(previews) => previews.discover_embed(&url).await,
+                    Err(e) => Err(e),
+                };
+                let _ = rsp.send(r);
+            });
+        }
+        OtherCmd::SearchGifs { config, query, rsp } => {
+            tokio::spawn(async move {
+                let provider = gifs::provider(&config, STATIC_ARGS.gifs_path.clone());
+                let r = provider.search(&query, GIFS_TO_TAKE).await;
+                let _ = rsp.send(r.map_err(|e| error::Error::OtherWithContext(e.to_string())));
+            });
+        }
+        // GIFs from a service are downloaded to the temp files, they're only needed to be sent
+        OtherCmd::SaveGif { gif, rsp } => {
+            tokio::spawn(async move {
+                let dir = STATIC_ARGS.temp_files.join(Uuid::new_v4().to_string());
+                let r = gifs::save(&gif, &dir).await;
+                let _ = rsp.send(r.map_err(|e| error::Error::OtherWithContext(e.to_string())));
+            });
+        }
     }
 }
 
+async fn backup_account(
+    dest: PathBuf,
+    pin: String,
+    passphrase: String,
+) -> Result<(), error::Error> {
+    let handle = tokio::task::spawn_blocking(move || {
+        backup::create_backup(&BackupPaths::active_profile(), &dest, &pin, &passphrase)
+    });
+    // a backup which didn't finish mustn't be reported as made
+    handle
+        .await
+        .map_err(|e| error::Error::OtherWithContext(format!("backup failed: {e}")))?
+}
+
 async fn compress_folder(src: PathBuf, dest: PathBuf) -> Result<(), error::Error> {
     // I know that warp_runner is basically single threaded but still...put the blocking operation in a separate task and await it
     let handle = tokio::task::spawn_blocking(move || {
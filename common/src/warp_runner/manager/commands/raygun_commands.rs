@@ -1,14 +1,17 @@
 use chrono::{DateTime, Utc};
 use derive_more::Display;
-use futures::channel::oneshot;
+use futures::channel::{mpsc, oneshot};
+use futures::{stream::BoxStream, StreamExt};
 use std::{
     collections::{HashMap, HashSet},
     ops::Range,
-    path::PathBuf,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
+use tempfile::TempDir;
 use uuid::Uuid;
 use warp::{
-    constellation::ConstellationProgressStream,
+    constellation::{ConstellationProgressStream, Progression},
     crypto::DID,
     error::Error,
     raygun::{
@@ -25,13 +28,19 @@ use crate::{
         conv_stream,
         ui_adapter::{
             self, conversation_to_chat, dids_to_identity, fetch_messages2, fetch_messages_between,
-            fetch_messages_from_chat, fetch_pinned_messages_from_chat, get_uninitialized_identity,
+            fetch_messages_from_chat, fetch_pinned_messages_from_chat, fetch_thread, fetch_threads,
+            get_uninitialized_identity,
         },
         Account, FetchMessagesConfig, FetchMessagesResponse, Messaging,
     },
+    STATIC_ARGS,
 };
 
 pub type MultiChatResult = Vec<(Uuid, (Uuid, Option<AttachmentEventStream>))>;
+/// the message sent to each chat, as it is sent. ends with an error if the attachments couldn't be
+/// fetched
+pub type ForwardStream =
+    BoxStream<'static, Result<(Uuid, (Uuid, Option<AttachmentEventStream>)), Error>>;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Display)]
@@ -110,6 +119,18 @@ pub enum RayGunCmd {
         conv_id: Uuid,
         rsp: oneshot::Sender<Result<Vec<ui_adapter::Message>, warp::error::Error>>,
     },
+    #[display(fmt = "FetchThreads")]
+    FetchThreads {
+        conv_id: Uuid,
+        rsp: oneshot::Sender<Result<HashMap<Uuid, chats::Thread>, warp::error::Error>>,
+    },
+    // the message which started the thread followed by the replies, oldest first
+    #[display(fmt = "FetchThread")]
+    FetchThread {
+        conv_id: Uuid,
+        root: Uuid,
+        rsp: oneshot::Sender<Result<Vec<ui_adapter::Message>, warp::error::Error>>,
+    },
     #[display(fmt = "SendMessage")]
     SendMessage {
         conv_id: Uuid,
@@ -124,6 +145,17 @@ pub enum RayGunCmd {
         attachments: Vec<Location>,
         rsp: oneshot::Sender<Result<MultiChatResult, warp::error::Error>>,
     },
+    // re-sends the text and attachments of a message to each chat. attachments are downloaded to
+    // the temp files until they are sent. this happens in the background, the messages are
+    // reported through the stream
+    #[display(fmt = "ForwardMessage")]
+    ForwardMessage {
+        conv_id: Uuid,
+        msg_id: Uuid,
+        msg: Vec<String>,
+        convs_id: Vec<Uuid>,
+        rsp: oneshot::Sender<ForwardStream>,
+    },
     #[display(fmt = "EditMessage")]
     EditMessage {
         conv_id: Uuid,
@@ -290,6 +322,14 @@ pub async fn handle_raygun_cmd(
             let r = fetch_pinned_messages_from_chat(conv_id, messaging).await;
             let _ = rsp.send(r);
         }
+        RayGunCmd::FetchThreads { conv_id, rsp } => {
+            let r = fetch_threads(conv_id, messaging).await;
+            let _ = rsp.send(r);
+        }
+        RayGunCmd::FetchThread { conv_id, root, rsp } => {
+            let r = fetch_thread(conv_id, root, messaging).await;
+            let _ = rsp.send(r);
+        }
         RayGunCmd::SendMessage {
             conv_id,
             msg,
@@ -338,6 +378,25 @@ pub async fn handle_raygun_cmd(
 
             let _ = rsp.send(Ok(results));
         }
+        RayGunCmd::ForwardMessage {
+            conv_id,
+            msg_id,
+            msg,
+            convs_id,
+            rsp,
+        } => {
+            let (tx, rx) = mpsc::unbounded();
+            let mut messaging = messaging.clone();
+            tokio::spawn(async move {
+                if let Err(e) =
+                    raygun_forward_message(conv_id, msg_id, msg, convs_id, &mut messaging, &tx)
+                        .await
+                {
+                    let _ = tx.unbounded_send(Err(e));
+                }
+            });
+            let _ = rsp.send(rx.boxed());
+        }
         RayGunCmd::EditMessage {
             conv_id,
             msg_id,
@@ -574,6 +633,70 @@ async fn raygun_remove_direct_convs(
     }
 }
 
+async fn raygun_forward_message(
+    conv_id: Uuid,
+    msg_id: Uuid,
+    msg: Vec<String>,
+    convs_id: Vec<Uuid>,
+    messaging: &mut Messaging,
+    tx: &mpsc::UnboundedSender<Result<(Uuid, (Uuid, Option<AttachmentEventStream>)), Error>>,
+) -> Result<(), Error> {
+    let message = messaging.get_message(conv_id, msg_id).await?;
+    // attachments can only be sent from disk or Constellation, so they are downloaded to the temp
+    // files once and attached from there to every chat. the directory is removed once every upload
+    // finished
+    std::fs::create_dir_all(&STATIC_ARGS.temp_files)?;
+    let dir = Arc::new(TempDir::new_in(&STATIC_ARGS.temp_files)?);
+    let mut attachments = vec![];
+    for (i, file) in message.attachments().iter().enumerate() {
+        // a directory for each as attachments can share a name. the name comes from the sender and
+        // mustn't lead out of the directory
+        let name = Path::new(&file.name())
+            .file_name()
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| "attachment".into());
+        let path = dir.path().join(i.to_string()).join(name);
+        std::fs::create_dir_all(dir.path().join(i.to_string()))?;
+        let mut progress = messaging
+            .download(conv_id, msg_id, file.name(), path.clone())
+            .await?;
+        while let Some(p) = progress.next().await {
+            if let Progression::ProgressFailed { error, .. } = p {
+                return Err(error);
+            }
+        }
+        attachments.push(Location::Disk { path });
+    }
+
+    for chat_id in convs_id {
+        let r = if attachments.is_empty() {
+            messaging
+                .send(chat_id, msg.clone())
+                .await
+                .map(|id| (id, None))
+        } else {
+            messaging
+                .attach(chat_id, None, attachments.clone(), msg.clone())
+                .await
+                .map(|(id, stream)| {
+                    let dir = dir.clone();
+                    // holds on to the downloaded attachments until the upload is done
+                    let stream = stream.inspect(move |_| {
+                        let _ = &dir;
+                    });
+                    (id, Some(stream.boxed()))
+                })
+        };
+        match r {
+            Ok(r) => {
+                let _ = tx.unbounded_send(Ok((chat_id, r)));
+            }
+            Err(e) => log::error!("Raygun: Forward message: {}", e),
+        }
+    }
+    Ok(())
+}
+
 async fn raygun_create_group_conversation(
     messaging: &mut Messaging,
     recipients: Vec<DID>,
@@ -1,6 +1,9 @@
 use derive_more::Display;
 use futures::channel::oneshot;
-use warp::tesseract::Tesseract;
+use tracing::log;
+use warp::{error::Error, tesseract::Tesseract};
+
+use crate::STATIC_ARGS;
 
 #[derive(Display)]
 pub enum TesseractCmd {
@@ -18,6 +21,13 @@ pub enum TesseractCmd {
     CheckMnemonicExist {
         rsp: oneshot::Sender<Result<bool, warp::error::Error>>,
     },
+    /// Re-encrypts Tesseract with a new passphrase. Fails with `DecryptionError` if `old` is wrong.
+    #[display(fmt = "ChangePassphrase")]
+    ChangePassphrase {
+        old: String,
+        new: String,
+        rsp: oneshot::Sender<Result<(), warp::error::Error>>,
+    },
 }
 
 impl std::fmt::Debug for TesseractCmd {
@@ -39,5 +49,23 @@ pub fn handle_tesseract_cmd(cmd: TesseractCmd, tesseract: &Tesseract) {
             let exists = tesseract.exist("mnemonic");
             let _ = rsp.send(Ok(exists));
         }
+        TesseractCmd::ChangePassphrase { old, new, rsp } => {
+            let r = tesseract
+                .update_unlock(old.as_bytes(), new.as_bytes())
+                .and_then(|_| tesseract.save());
+            if let Err(e) = &r {
+                log::error!("failed to change passphrase: {e}");
+            }
+            let _ = rsp.send(r);
+        }
     }
 }
+
+/// Checks a passphrase against the Tesseract file on disk without touching the running, unlocked Tesseract.
+pub fn verify_passphrase(passphrase: &str) -> Result<(), Error> {
+    let tesseract =
+        Tesseract::open_or_create(STATIC_ARGS.warp_path(), &STATIC_ARGS.tesseract_file)?;
+    let r = tesseract.unlock(passphrase.as_bytes());
+    tesseract.lock();
+    r
+}
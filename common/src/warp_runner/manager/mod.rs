@@ -13,7 +13,7 @@ use warp::{
 
 use tracing::log;
 
-use super::{conv_stream, Account, Calling, Messaging, Storage};
+use super::{conv_stream, ui_adapter, Account, Calling, Messaging, Storage};
 use crate::WARP_CMD_CH;
 
 pub use commands::{BlinkCmd, ConstellationCmd, MultiPassCmd, OtherCmd, RayGunCmd, TesseractCmd};
@@ -28,6 +28,9 @@ pub struct Warp {
 }
 
 pub async fn run(mut warp: Warp, notify: Arc<Notify>) {
+    // the threads of the previous profile
+    ui_adapter::thread_index::clear().await;
+
     // receive command from Uplink
     let warp_cmd_rx = WARP_CMD_CH.rx.clone();
 
@@ -2,10 +2,13 @@
 use derive_more::Display;
 use std::sync::Arc;
 
-use tokio::sync::{
-    broadcast,
-    mpsc::{UnboundedReceiver, UnboundedSender},
-    Mutex, Notify,
+use tokio::{
+    sync::{
+        broadcast,
+        mpsc::{UnboundedReceiver, UnboundedSender},
+        Mutex, Notify,
+    },
+    task::JoinHandle,
 };
 use tracing::log;
 use warp::{
@@ -28,8 +31,11 @@ use crate::{DiscoveryMode, STATIC_ARGS, WARP_CMD_CH};
 
 use self::ui_adapter::{MultiPassEvent, RayGunEvent};
 
+mod backup;
 mod conv_stream;
 mod data;
+pub mod gifs;
+mod link_previews;
 mod manager;
 pub mod ui_adapter;
 
@@ -95,14 +101,18 @@ pub enum WarpCmd {
 
 /// Spawns a task which manages multiple streams, channels, and tasks related to warp
 pub struct WarpRunner {
-    // perhaps collecting a JoinHandle and calling abort() would be better than using Notify.
     notify: Arc<Notify>,
+    // the task may be busy when notified, so it is aborted too
+    task: Option<JoinHandle<()>>,
     ran_once: bool,
 }
 
 impl std::ops::Drop for WarpRunner {
     fn drop(&mut self) {
         self.notify.notify_waiters();
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
     }
 }
 
@@ -116,6 +126,7 @@ impl WarpRunner {
     pub fn new() -> Self {
         Self {
             notify: Arc::new(Notify::new()),
+            task: None,
             ran_once: false,
         }
     }
@@ -126,9 +137,19 @@ impl WarpRunner {
         self.ran_once = true;
 
         let notify = self.notify.clone();
-        tokio::spawn(async move {
+        self.task = Some(tokio::spawn(async move {
             handle_login(notify.clone()).await;
-        });
+        }));
+    }
+
+    /// Stops the task and waits until it has terminated. Until then it holds the receiver of
+    /// WARP_CMD_CH, which another WarpRunner can't run without.
+    pub async fn stop(&mut self) {
+        self.notify.notify_waiters();
+        if let Some(task) = self.task.take() {
+            task.abort();
+            let _ = task.await;
+        }
     }
 }
 
@@ -197,6 +218,64 @@ async fn handle_login(notify: Arc<Notify>) {
                             }
                         }
                     },
+                    Some(WarpCmd::MultiPass(MultiPassCmd::RestoreBackup {
+                        backup,
+                        backup_passphrase,
+                        passphrase,
+                        rsp
+                    })) => {
+                        let archive = match backup::decrypt_backup(&backup, &backup_passphrase) {
+                            Ok(a) => a,
+                            Err(e) => {
+                                let _ = rsp.send(Err(e));
+                                continue;
+                            }
+                        };
+                        // the current profile is only replaced once the restored one unlocks
+                        if let Err(e) = backup::restore_backup(&backup::BackupPaths::active_profile(), &archive, &backup_passphrase, &passphrase) {
+                            log::error!("failed to restore backup: {}", e);
+                            let _ = rsp.send(Err(e));
+                            continue;
+                        }
+                        let tesseract = init_tesseract(false)
+                                .await
+                                .expect("failed to initialize tesseract");
+                        warp = match warp_initialization(tesseract).await {
+                            Ok(w) => w,
+                            Err(e) => {
+                                log::error!("warp init failed: {}", e);
+                                let _ = rsp.send(Err(e));
+                                return;
+                            }
+                        };
+                        if let Err(e) = warp.tesseract.unlock(passphrase.as_bytes()) {
+                            log::info!("unlock failed: {:?}", e);
+                            let _ = rsp.send(Err(e));
+                            continue;
+                        };
+                        // the backup doesn't include the ipfs repo. if the identity isn't there yet, fetch it like a recovery would
+                        let ident = match wait_for_multipass(&mut warp, notify.clone()).await {
+                            Err(Error::IdentityNotCreated) => match warp.tesseract.retrieve("mnemonic") {
+                                Ok(seed_words) => warp.multipass.import_identity(IdentityImportOption::Locate {
+                                    location: multipass::ImportLocation::Remote,
+                                    passphrase: seed_words
+                                }).await,
+                                Err(e) => Err(e),
+                            },
+                            r => r,
+                        };
+                        match ident {
+                            Ok(ident) => {
+                                let _ = rsp.send(Ok(ident));
+                                break Some(warp);
+                            },
+                            Err(e) => {
+                                warp.tesseract.lock();
+                                let _ = rsp.send(Err(e));
+                                continue;
+                            }
+                        }
+                    },
                     Some(WarpCmd::MultiPass(MultiPassCmd::CreateIdentity {
                         username,
                         tesseract_passphrase,
@@ -319,18 +398,18 @@ async fn init_tesseract(overwrite_old_account: bool) -> Result<Tesseract, Error>
 
     if overwrite_old_account {
         // delete old account data
-        if let Err(e) = tokio::fs::remove_dir_all(&STATIC_ARGS.uplink_path).await {
+        if let Err(e) = tokio::fs::remove_dir_all(STATIC_ARGS.uplink_path()).await {
             log::warn!("failed to delete uplink directory: {}", e);
         }
 
         // create directories
-        if let Err(e) = tokio::fs::create_dir_all(&STATIC_ARGS.warp_path).await {
+        if let Err(e) = tokio::fs::create_dir_all(STATIC_ARGS.warp_path()).await {
             log::warn!("failed to create warp directory: {}", e);
         }
     }
 
     // open existing file or create new one
-    Tesseract::open_or_create(&STATIC_ARGS.warp_path, &STATIC_ARGS.tesseract_file)
+    Tesseract::open_or_create(STATIC_ARGS.warp_path(), &STATIC_ARGS.tesseract_file)
 }
 
 impl From<&DiscoveryMode> for Discovery {
@@ -381,7 +460,7 @@ impl From<DiscoveryMode> for Discovery {
 async fn warp_initialization(tesseract: Tesseract) -> Result<manager::Warp, warp::error::Error> {
     log::debug!("warp initialization");
 
-    let path = &STATIC_ARGS.warp_path;
+    let path = STATIC_ARGS.warp_path();
     let mut config = Config::production(path);
 
     *config.save_phrase_mut() = true; // TODO: This should be bound to a setting within Uplink so that the user can choose not to reveal the phrase for increased security.``
@@ -5,6 +5,7 @@
 mod message_event;
 mod multipass_event;
 mod raygun_event;
+pub mod thread_index;
 
 use chrono::{DateTime, Utc};
 pub use message_event::{convert_message_event, MessageEvent};
@@ -14,12 +15,16 @@ use uuid::Uuid;
 
 use crate::{
     profile_update_channel::fetch_identity_data,
-    state::{self, chats, utils::mention_regex_epattern, Identity, MAX_PINNED_MESSAGES},
+    state::{
+        self, chats,
+        utils::{has_group_mention, mention_regex_epattern, typed_lines},
+        Identity, MAX_PINNED_MESSAGES,
+    },
 };
 use futures::{stream::FuturesOrdered, FutureExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     ops::Range,
     slice,
 };
@@ -41,6 +46,10 @@ use super::{FetchMessagesConfig, FetchMessagesResponse};
 pub struct Message {
     pub inner: warp::raygun::Message,
     pub in_reply_to: Option<(String, Vec<File>, DID)>,
+    /// for replies, the message which started the thread. a reply to a reply belongs to the thread
+    /// of the message at the start of the chain.
+    #[serde(default)]
+    pub thread_id: Option<Uuid>,
     is_mention: Option<bool>,
     /// this field exists so that the UI can tell Dioxus when a message has been edited and thus
     /// needs to be re-rendered. Before the addition of this field, the compose view was
@@ -62,14 +71,15 @@ impl Message {
         }
     }
 
-    // Lazily evaluate if the user is mentioned
-    pub fn is_mention_self(&mut self, own: &DID) -> bool {
+    // Lazily evaluate if the user is mentioned.
+    // `group_mentions` is whether @everyone and @here from the sender count, see `Chat::can_mention_group`
+    pub fn is_mention_self(&mut self, own: &DID, group_mentions: bool) -> bool {
         if self.is_mention.is_none() {
+            let text = self.inner.lines().join("\n");
             let reg = mention_regex_epattern(&own.to_string());
             self.is_mention = Some(
-                reg.find(&self.inner.lines().join("\n"))
-                    .map(|c| !c.as_str().starts_with('`'))
-                    .unwrap_or_default(),
+                reg.find_iter(&text).any(|c| !c.as_str().starts_with('`'))
+                    || (group_mentions && has_group_mention(&text)),
             );
         }
         self.is_mention.unwrap()
@@ -91,12 +101,32 @@ pub async fn convert_raygun_message(
         Some(id) => messaging.get_message(msg.conversation_id(), id).await.ok(),
         None => None,
     };
+    // if the message being replied to was deleted, it is still the start of the thread
+    let thread_id = match msg.replied() {
+        Some(parent) => thread_index::with_index(messaging, msg.conversation_id(), |index| {
+            index.insert(msg);
+            index.root(&msg.id())
+        })
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("failed to index threads: {e}");
+            Some(parent)
+        }),
+        None => {
+            thread_index::insert(msg).await;
+            None
+        }
+    };
 
     Message {
         inner: msg.clone(),
+        thread_id,
         in_reply_to: reply.map(|msg: raygun::Message| {
             (
-                msg.lines().first().cloned().unwrap_or_default(),
+                typed_lines(&msg.lines())
+                    .first()
+                    .cloned()
+                    .unwrap_or_default(),
                 msg.attachments(),
                 msg.sender(),
             )
@@ -268,6 +298,34 @@ pub async fn fetch_pinned_messages_from_chat(
     Ok(messages)
 }
 
+/// Finds every thread in a conversation, keyed by the id of the message which started it.
+pub async fn fetch_threads(
+    conv_id: Uuid,
+    messaging: &mut super::Messaging,
+) -> Result<HashMap<Uuid, chats::Thread>, Error> {
+    thread_index::with_index(messaging, conv_id, |index| index.threads()).await
+}
+
+/// Returns the message which started a thread followed by its replies, oldest first.
+pub async fn fetch_thread(
+    conv_id: Uuid,
+    root: Uuid,
+    messaging: &mut super::Messaging,
+) -> Result<Vec<Message>, Error> {
+    let ids = thread_index::with_index(messaging, conv_id, |index| index.thread(&root)).await?;
+    let messaging = &*messaging;
+    let thread: Vec<_> = FuturesOrdered::from_iter(ids.into_iter().map(|id| {
+        async move {
+            let message = messaging.get_message(conv_id, id).await.ok()?;
+            Some(convert_raygun_message(messaging, &message).await)
+        }
+        .boxed()
+    }))
+    .collect()
+    .await;
+    Ok(thread.into_iter().flatten().collect())
+}
+
 pub async fn fetch_messages2(
     conv_id: Uuid,
     messaging: &mut super::Messaging,
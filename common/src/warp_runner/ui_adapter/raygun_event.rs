@@ -1,4 +1,4 @@
-use super::{super::conv_stream, init_conversation, ChatAdapter};
+use super::{super::conv_stream, init_conversation, thread_index, ChatAdapter};
 use derive_more::Display;
 use tracing::log;
 use uuid::Uuid;
@@ -29,6 +29,7 @@ pub async fn convert_raygun_event(
         }
         RayGunEventKind::ConversationDeleted { conversation_id } => {
             stream_manager.remove_stream(conversation_id);
+            thread_index::forget(conversation_id).await;
             RayGunEvent::ConversationDeleted(conversation_id)
         }
     };
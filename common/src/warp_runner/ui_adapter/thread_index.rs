@@ -0,0 +1,271 @@
+//! Which thread each message belongs to. A conversation is indexed from all of its messages the first
+//! time a thread is looked up in it, then kept up to date as messages come in. Finding the start of a
+//! thread doesn't have to fetch every message along a chain of replies that way.
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use warp::{
+    crypto::DID,
+    error::Error,
+    raygun::{self, MessageOptions},
+};
+
+use crate::state::chats;
+
+// how far a chain of replies is followed to find the start of a thread
+const MAX_THREAD_DEPTH: usize = 32;
+
+static INDEXES: Lazy<Mutex<Indexes>> = Lazy::new(Default::default);
+
+#[derive(Default)]
+struct Indexes {
+    // by conversation id
+    conversations: HashMap<Uuid, ThreadIndex>,
+    // the conversations being indexed, with the messages which came in or were deleted meanwhile
+    indexing: HashMap<Uuid, Vec<Change>>,
+    // counts the times warp started, an index built for the previous profile isn't kept
+    generation: usize,
+}
+
+enum Change {
+    Insert(Uuid, IndexedMessage),
+    Remove(Uuid),
+}
+
+struct IndexedMessage {
+    replied: Option<Uuid>,
+    sender: DID,
+    date: DateTime<Utc>,
+    // a deleted message still links its replies to the start of their thread
+    deleted: bool,
+}
+
+#[derive(Default)]
+pub struct ThreadIndex {
+    messages: HashMap<Uuid, IndexedMessage>,
+}
+
+impl ThreadIndex {
+    pub fn insert(&mut self, message: &raygun::Message) {
+        self.insert_entry(
+            message.id(),
+            message.replied(),
+            message.sender(),
+            message.date(),
+        );
+    }
+
+    fn apply(&mut self, change: Change) {
+        match change {
+            Change::Insert(id, entry) => {
+                self.messages.insert(id, entry);
+            }
+            Change::Remove(id) => self.remove(&id),
+        }
+    }
+
+    fn insert_entry(&mut self, id: Uuid, replied: Option<Uuid>, sender: DID, date: DateTime<Utc>) {
+        self.messages.insert(
+            id,
+            IndexedMessage {
+                replied,
+                sender,
+                date,
+                deleted: false,
+            },
+        );
+    }
+
+    pub fn remove(&mut self, id: &Uuid) {
+        if let Some(entry) = self.messages.get_mut(id) {
+            entry.deleted = true;
+        }
+    }
+
+    /// The message which started the thread of a reply. None if `id` isn't a reply.
+    pub fn root(&self, id: &Uuid) -> Option<Uuid> {
+        let mut root = self.messages.get(id)?.replied?;
+        // bounded in case of a reply cycle
+        for _ in 0..MAX_THREAD_DEPTH {
+            match self.messages.get(&root).and_then(|entry| entry.replied) {
+                Some(parent) => root = parent,
+                None => break,
+            }
+        }
+        Some(root)
+    }
+
+    /// Every thread, keyed by the id of the message which started it.
+    pub fn threads(&self) -> HashMap<Uuid, chats::Thread> {
+        let mut threads: HashMap<Uuid, chats::Thread> = HashMap::new();
+        for (id, entry) in self.messages.iter().filter(|(_, entry)| !entry.deleted) {
+            if let Some(root) = self.root(id) {
+                threads
+                    .entry(root)
+                    .or_default()
+                    .add_reply(*id, entry.sender.clone(), entry.date);
+            }
+        }
+        for (root, thread) in threads.iter_mut() {
+            if let Some(entry) = self.messages.get(root) {
+                thread.participants.insert(entry.sender.clone());
+            }
+        }
+        threads
+    }
+
+    /// The message which started a thread followed by its replies, oldest first.
+    pub fn thread(&self, root: &Uuid) -> Vec<Uuid> {
+        let mut thread: Vec<_> = self
+            .messages
+            .iter()
+            .filter(|(id, entry)| {
+                !entry.deleted && (*id == root || self.root(id).as_ref() == Some(root))
+            })
+            .map(|(id, entry)| (entry.date, *id))
+            .collect();
+        thread.sort();
+        thread.into_iter().map(|(_, id)| id).collect()
+    }
+}
+
+/// Runs `f` on the index of a conversation, indexing the conversation first if it hasn't been yet.
+pub async fn with_index<R>(
+    messaging: &super::super::Messaging,
+    conv_id: Uuid,
+    f: impl FnOnce(&mut ThreadIndex) -> R,
+) -> Result<R, Error> {
+    let generation = {
+        let mut indexes = INDEXES.lock().await;
+        if let Some(index) = indexes.conversations.get_mut(&conv_id) {
+            return Ok(f(index));
+        }
+        indexes.indexing.entry(conv_id).or_default();
+        indexes.generation
+    };
+
+    // the whole history is fetched without the lock, so other conversations can be looked up and
+    // kept up to date meanwhile
+    let messages = messaging
+        .get_messages(conv_id, MessageOptions::default())
+        .await
+        .and_then(Vec::<_>::try_from);
+
+    let mut indexes = INDEXES.lock().await;
+    let indexes = &mut *indexes;
+    let is_current = indexes.generation == generation;
+    let messages = match messages {
+        Ok(messages) => messages,
+        Err(e) => {
+            if is_current {
+                indexes.indexing.remove(&conv_id);
+            }
+            return Err(e);
+        }
+    };
+    let mut index = ThreadIndex::default();
+    for message in &messages {
+        index.insert(message);
+    }
+    // warp was started again meanwhile, maybe for another profile
+    if !is_current {
+        return Ok(f(&mut index));
+    }
+    let index = match indexes.conversations.entry(conv_id) {
+        // another lookup indexed it first
+        Entry::Occupied(index) => index.into_mut(),
+        Entry::Vacant(vacant) => {
+            for change in indexes.indexing.remove(&conv_id).unwrap_or_default() {
+                index.apply(change);
+            }
+            vacant.insert(index)
+        }
+    };
+    Ok(f(index))
+}
+
+async fn change(conv_id: Uuid, change: Change) {
+    let mut indexes = INDEXES.lock().await;
+    if let Some(index) = indexes.conversations.get_mut(&conv_id) {
+        index.apply(change);
+    } else if let Some(changes) = indexes.indexing.get_mut(&conv_id) {
+        changes.push(change);
+    }
+}
+
+/// Adds a message to the index of its conversation, if it is indexed.
+pub async fn insert(message: &raygun::Message) {
+    let entry = IndexedMessage {
+        replied: message.replied(),
+        sender: message.sender(),
+        date: message.date(),
+        deleted: false,
+    };
+    change(
+        message.conversation_id(),
+        Change::Insert(message.id(), entry),
+    )
+    .await;
+}
+
+/// Marks a message of a conversation deleted, if the conversation is indexed.
+pub async fn remove(conv_id: Uuid, message_id: Uuid) {
+    change(conv_id, Change::Remove(message_id)).await;
+}
+
+/// Forgets a conversation which was deleted.
+pub async fn forget(conv_id: Uuid) {
+    let mut indexes = INDEXES.lock().await;
+    indexes.conversations.remove(&conv_id);
+    indexes.indexing.remove(&conv_id);
+}
+
+/// Forgets every conversation, for when warp is started again, maybe for another profile.
+pub async fn clear() {
+    let mut indexes = INDEXES.lock().await;
+    indexes.conversations.clear();
+    indexes.indexing.clear();
+    indexes.generation += 1;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn replies_to_replies_share_a_thread() {
+        let [root, a, b, c, other] = [(); 5].map(|_| Uuid::new_v4());
+        let mut index = ThreadIndex::default();
+        // a replies to root, b replies to a, c replies to b, root and other aren't replies
+        for (i, (id, replied)) in [
+            (root, None),
+            (a, Some(root)),
+            (b, Some(a)),
+            (c, Some(b)),
+            (other, None),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let date = DateTime::from_timestamp(i as i64, 0).unwrap();
+            index.insert_entry(id, replied, DID::default(), date);
+        }
+        assert_eq!(index.root(&a), Some(root));
+        assert_eq!(index.root(&b), Some(root));
+        assert_eq!(index.root(&c), Some(root));
+        assert_eq!(index.root(&root), None);
+        assert_eq!(index.root(&other), None);
+        assert_eq!(index.thread(&root), vec![root, a, b, c]);
+        assert_eq!(index.threads()[&root].replies.len(), 3);
+
+        // c still belongs to the thread once the reply it replied to is deleted
+        index.remove(&b);
+        assert_eq!(index.root(&c), Some(root));
+        assert_eq!(index.thread(&root), vec![root, a, c]);
+        assert_eq!(index.threads()[&root].replies.len(), 2);
+    }
+}
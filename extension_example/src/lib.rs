@@ -1,5 +1,7 @@
 use common::icons::outline::Shape as Icon;
 use dioxus::prelude::*;
+use extensions::commands::{Arg, CommandSpec, ExtensionCommand, Output};
+use extensions::embeds::{Embed, ExtensionEmbedProvider, Link};
 use extensions::*;
 use kit::elements::button::Button;
 use once_cell::sync::Lazy;
@@ -33,6 +35,26 @@ impl Extension for ExampleExtension {
         include_str!("./style.css").into()
     }
 
+    // typing `/tableflip` in the chatbar runs `tableflip`
+    fn commands(&self) -> Vec<ExtensionCommand> {
+        vec![ExtensionCommand {
+            spec: CommandSpec::new(
+                "tableflip",
+                "Flips a table".into(),
+                vec![Arg::text("text", false)],
+            ),
+            run: tableflip,
+        }]
+    }
+
+    // links to loom.com/share/<id> get an embedded player
+    fn embed_providers(&self) -> Vec<ExtensionEmbedProvider> {
+        vec![ExtensionEmbedProvider {
+            name: "Loom".into(),
+            embed: loom,
+        }]
+    }
+
     fn render<'a>(&self, cx: &'a ScopeState) -> Element<'a> {
         let styles = self.stylesheet();
 
@@ -44,3 +66,24 @@ impl Extension for ExampleExtension {
         })
     }
 }
+
+fn tableflip(args: &[String]) -> Output {
+    let flip = "(╯°□°)╯︵ ┻━┻";
+    match args.first() {
+        Some(text) => Output::Send(vec![format!("{text} {flip}")]),
+        None => Output::Send(vec![flip.into()]),
+    }
+}
+
+fn loom(url: &str) -> Option<Embed> {
+    let link = Link::parse(url)?;
+    match (link.host.as_str(), link.segments().as_slice()) {
+        ("loom.com", ["share", id]) if id.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            Some(Embed::Frame {
+                src: format!("https://www.loom.com/embed/{id}"),
+                height: 360,
+            })
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,221 @@
+//! Slash commands typed in the chatbar, e.g. `/shrug` or `/me waves`. Uplink has built in commands,
+//! extensions add their own with [`crate::Extension::commands`].
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgKind {
+    // a single word
+    Word,
+    // everything that's left of the input, including whitespace. only valid for the last argument
+    Text,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Arg {
+    pub name: String,
+    pub kind: ArgKind,
+    pub required: bool,
+}
+
+impl Arg {
+    pub fn word(name: &str, required: bool) -> Self {
+        Self {
+            name: name.into(),
+            kind: ArgKind::Word,
+            required,
+        }
+    }
+
+    pub fn text(name: &str, required: bool) -> Self {
+        Self {
+            name: name.into(),
+            kind: ArgKind::Text,
+            required,
+        }
+    }
+}
+
+/// What a command is called and what it expects. Used for parsing, autocomplete and help text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandSpec {
+    // without the leading `/`
+    pub name: String,
+    pub description: String,
+    pub args: Vec<Arg>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingArg(String),
+    TooManyArgs,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingArg(name) => write!(f, "missing <{name}>"),
+            ParseError::TooManyArgs => write!(f, "too many arguments"),
+        }
+    }
+}
+
+impl CommandSpec {
+    pub fn new(name: &str, description: String, args: Vec<Arg>) -> Self {
+        Self {
+            name: name.into(),
+            description,
+            args,
+        }
+    }
+
+    /// e.g. `/me <action>` or `/shrug [text]`
+    pub fn usage(&self) -> String {
+        self.args
+            .iter()
+            .fold(format!("/{}", self.name), |usage, arg| {
+                if arg.required {
+                    format!("{usage} <{}>", arg.name)
+                } else {
+                    format!("{usage} [{}]", arg.name)
+                }
+            })
+    }
+
+    /// Splits the input after the command name into the arguments. Missing optional arguments are
+    /// left out, so the result has between the required and the total amount of arguments.
+    pub fn parse_args(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let mut rest = input.trim();
+        let mut values = vec![];
+        for arg in self.args.iter() {
+            if rest.is_empty() {
+                if arg.required {
+                    return Err(ParseError::MissingArg(arg.name.clone()));
+                }
+                break;
+            }
+            match arg.kind {
+                ArgKind::Text => {
+                    values.push(rest.to_string());
+                    rest = "";
+                }
+                ArgKind::Word => {
+                    let (word, remaining) =
+                        rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                    values.push(word.to_string());
+                    rest = remaining.trim_start();
+                }
+            }
+        }
+        if rest.is_empty() {
+            Ok(values)
+        } else {
+            Err(ParseError::TooManyArgs)
+        }
+    }
+}
+
+/// The result of running a command
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    // send these lines as a message to the active chat
+    Send(Vec<String>),
+    // replace the chatbar text
+    Draft(String),
+    Nothing,
+}
+
+/// A command registered by an extension. `run` gets the parsed arguments.
+#[derive(Clone)]
+pub struct ExtensionCommand {
+    pub spec: CommandSpec,
+    pub run: fn(&[String]) -> Output,
+}
+
+/// Splits `/name args` into the name and the rest of the input. Anything else isn't a command.
+pub fn split(input: &str) -> Option<(&str, &str)> {
+    let input = input.strip_prefix('/')?;
+    let (name, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    valid.then_some((name, rest))
+}
+
+/// Commands by name. `H` is what runs a command, which is up to whoever uses the registry.
+pub struct Registry<H> {
+    commands: Vec<(CommandSpec, H)>,
+}
+
+impl<H> Default for Registry<H> {
+    fn default() -> Self {
+        Self { commands: vec![] }
+    }
+}
+
+impl<H> Registry<H> {
+    /// Commands registered first win if names collide, so built in commands can't be replaced.
+    pub fn register(&mut self, spec: CommandSpec, handler: H) -> bool {
+        if self.find(&spec.name).is_some() {
+            return false;
+        }
+        self.commands.push((spec, handler));
+        true
+    }
+
+    pub fn find(&self, name: &str) -> Option<&(CommandSpec, H)> {
+        self.commands
+            .iter()
+            .find(|(spec, _)| spec.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Commands starting with `prefix`, sorted by name. Used for autocomplete.
+    pub fn matching(&self, prefix: &str) -> Vec<&CommandSpec> {
+        let prefix = prefix.to_lowercase();
+        let mut specs: Vec<_> = self
+            .commands
+            .iter()
+            .map(|(spec, _)| spec)
+            .filter(|spec| spec.name.to_lowercase().starts_with(&prefix))
+            .collect();
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        specs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn splits_commands() {
+        assert_eq!(split("/me waves hello"), Some(("me", "waves hello")));
+        assert_eq!(split("/shrug"), Some(("shrug", "")));
+        assert_eq!(split("/usr/bin/env"), None);
+        assert_eq!(split("hello /me"), None);
+        assert_eq!(split("/"), None);
+    }
+
+    #[test]
+    fn parses_args() {
+        let spec = CommandSpec::new(
+            "remind",
+            String::new(),
+            vec![Arg::word("when", true), Arg::text("what", false)],
+        );
+        assert_eq!(spec.usage(), "/remind <when> [what]");
+        assert_eq!(
+            spec.parse_args(" 5m  get the  laundry "),
+            Ok(vec!["5m".into(), "get the  laundry".into()])
+        );
+        assert_eq!(spec.parse_args("5m"), Ok(vec!["5m".into()]));
+        assert_eq!(
+            spec.parse_args(""),
+            Err(ParseError::MissingArg("when".into()))
+        );
+
+        let spec = CommandSpec::new("call", String::new(), vec![]);
+        assert_eq!(spec.parse_args("now"), Err(ParseError::TooManyArgs));
+    }
+}
@@ -0,0 +1,395 @@
+//! Embeds for links to videos, audio and code snippets. An [`EmbedProvider`] turns a url into an
+//! [`Embed`]. Uplink has built in providers, extensions add their own with
+//! [`crate::Extension::embed_providers`]. An embed is only loaded once the user clicks it, so
+//! receiving a link never visits it.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Embed {
+    /// a page made to be embedded, e.g. a video player. the height is in pixels
+    Frame {
+        src: String,
+        height: u32,
+    },
+    Video {
+        src: String,
+    },
+    Audio {
+        src: String,
+    },
+    Image {
+        src: String,
+    },
+    /// what to embed is only known after asking the oEmbed endpoint the page links to
+    OEmbed {
+        url: String,
+    },
+}
+
+pub trait EmbedProvider {
+    /// shown on the embed before it is loaded, e.g. "YouTube"
+    fn name(&self) -> &str;
+    /// None if the provider can't embed the url
+    fn embed(&self, url: &str) -> Option<Embed>;
+}
+
+/// A provider registered by an extension.
+#[derive(Clone)]
+pub struct ExtensionEmbedProvider {
+    pub name: String,
+    pub embed: fn(&str) -> Option<Embed>,
+}
+
+impl EmbedProvider for ExtensionEmbedProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn embed(&self, url: &str) -> Option<Embed> {
+        (self.embed)(url)
+    }
+}
+
+/// The parts of an http(s) url providers look at.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Link<'a> {
+    /// lowercase, without `www.` and the port
+    pub host: String,
+    /// without the leading and trailing `/`
+    pub path: &'a str,
+    pub query: &'a str,
+}
+
+impl<'a> Link<'a> {
+    pub fn parse(url: &'a str) -> Option<Self> {
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))?;
+        let rest = rest.split('#').next().unwrap_or_default();
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = host.rsplit('@').next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default().to_lowercase();
+        let host = match host.strip_prefix("www.") {
+            Some(host) => host.to_string(),
+            None => host,
+        };
+        (!host.is_empty()).then_some(Self {
+            host,
+            path: path.trim_matches('/'),
+            query,
+        })
+    }
+
+    pub fn segments(&self) -> Vec<&'a str> {
+        self.path.split('/').filter(|s| !s.is_empty()).collect()
+    }
+
+    pub fn query_param(&self, name: &str) -> Option<&'a str> {
+        self.query.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            (key == name).then_some(value)
+        })
+    }
+
+    /// The lowercase file extension of the path, if it has one.
+    pub fn extension(&self) -> Option<String> {
+        let file = self.path.rsplit('/').next()?;
+        let (_, ext) = file.rsplit_once('.')?;
+        Some(ext.to_lowercase())
+    }
+}
+
+// ids are pasted into embed urls, so only allow characters that can't change their meaning
+fn is_id(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Builtin {
+    YouTube,
+    Vimeo,
+    Spotify,
+    SoundCloud,
+    CodePen,
+    JsFiddle,
+    /// links straight to a video or audio file
+    Media,
+    /// a page of a known oEmbed provider, or one the link preview found an oEmbed endpoint on
+    OEmbed,
+}
+
+/// Sites known to link to an oEmbed endpoint from their pages. Links to other sites only get an
+/// oEmbed embed if their link preview found one.
+const OEMBED_HOSTS: [&str; 12] = [
+    "twitter.com",
+    "x.com",
+    "tiktok.com",
+    "reddit.com",
+    "flickr.com",
+    "twitch.tv",
+    "dailymotion.com",
+    "giphy.com",
+    "imgur.com",
+    "bandcamp.com",
+    "mixcloud.com",
+    "ted.com",
+];
+
+const VIDEO_EXTENSIONS: [&str; 4] = ["mp4", "webm", "ogv", "mov"];
+const AUDIO_EXTENSIONS: [&str; 7] = ["mp3", "ogg", "oga", "opus", "wav", "m4a", "flac"];
+
+impl EmbedProvider for Builtin {
+    fn name(&self) -> &str {
+        match self {
+            Builtin::YouTube => "YouTube",
+            Builtin::Vimeo => "Vimeo",
+            Builtin::Spotify => "Spotify",
+            Builtin::SoundCloud => "SoundCloud",
+            Builtin::CodePen => "CodePen",
+            Builtin::JsFiddle => "JSFiddle",
+            Builtin::Media => "Media",
+            Builtin::OEmbed => "oEmbed",
+        }
+    }
+
+    fn embed(&self, url: &str) -> Option<Embed> {
+        let link = Link::parse(url)?;
+        let segments = link.segments();
+        let frame = |src: String, height: u32| Some(Embed::Frame { src, height });
+        match self {
+            Builtin::YouTube => {
+                let id = match (link.host.as_str(), segments.as_slice()) {
+                    ("youtu.be", [id]) => *id,
+                    ("youtube.com" | "m.youtube.com" | "music.youtube.com", ["watch"]) => {
+                        link.query_param("v")?
+                    }
+                    (
+                        "youtube.com" | "m.youtube.com",
+                        ["shorts" | "embed" | "live", id],
+                    ) => *id,
+                    _ => return None,
+                };
+                is_id(id)
+                    .then(|| format!("https://www.youtube.com/embed/{id}"))
+                    .and_then(|src| frame(src, 360))
+            }
+            Builtin::Vimeo => match (link.host.as_str(), segments.as_slice()) {
+                ("vimeo.com", [id]) if id.chars().all(|c| c.is_ascii_digit()) => {
+                    frame(format!("https://player.vimeo.com/video/{id}"), 360)
+                }
+                _ => None,
+            },
+            Builtin::Spotify => {
+                if link.host != "open.spotify.com" {
+                    return None;
+                }
+                // links shared from some countries start with e.g. `intl-de`
+                let segments: Vec<_> = segments
+                    .into_iter()
+                    .filter(|s| !s.starts_with("intl-"))
+                    .collect();
+                match segments.as_slice() {
+                    [kind @ ("track" | "episode"), id] if is_id(id) => {
+                        frame(format!("https://open.spotify.com/embed/{kind}/{id}"), 152)
+                    }
+                    [kind @ ("album" | "playlist" | "show" | "artist"), id] if is_id(id) => {
+                        frame(format!("https://open.spotify.com/embed/{kind}/{id}"), 352)
+                    }
+                    _ => None,
+                }
+            }
+            Builtin::SoundCloud => match (link.host.as_str(), segments.as_slice()) {
+                ("soundcloud.com", [user, track]) if is_id(user) && is_id(track) => frame(
+                    format!(
+                        "https://w.soundcloud.com/player/?url=https%3A%2F%2Fsoundcloud.com%2F{user}%2F{track}"
+                    ),
+                    166,
+                ),
+                _ => None,
+            },
+            Builtin::CodePen => match (link.host.as_str(), segments.as_slice()) {
+                ("codepen.io", [user, "pen" | "embed", id]) if is_id(user) && is_id(id) => frame(
+                    format!("https://codepen.io/{user}/embed/{id}?default-tab=result"),
+                    300,
+                ),
+                _ => None,
+            },
+            Builtin::JsFiddle => {
+                let segments: Vec<_> = segments
+                    .into_iter()
+                    .take_while(|s| *s != "embedded")
+                    .collect();
+                let valid = link.host == "jsfiddle.net"
+                    && (1..=3).contains(&segments.len())
+                    && segments.iter().all(|s| is_id(s));
+                valid
+                    .then(|| format!("https://jsfiddle.net/{}/embedded/", segments.join("/")))
+                    .and_then(|src| frame(src, 300))
+            }
+            Builtin::Media => {
+                let ext = link.extension()?;
+                if VIDEO_EXTENSIONS.contains(&ext.as_str()) {
+                    Some(Embed::Video { src: url.into() })
+                } else if AUDIO_EXTENSIONS.contains(&ext.as_str()) {
+                    Some(Embed::Audio { src: url.into() })
+                } else {
+                    None
+                }
+            }
+            Builtin::OEmbed => Some(Embed::OEmbed { url: url.into() }),
+        }
+    }
+}
+
+/// Asks the providers in the order they were registered. The built in ones come first, except for
+/// the oEmbed discovery, which is only tried if no other provider can embed a link and the site is
+/// known to support it.
+pub struct Registry {
+    providers: Vec<Box<dyn EmbedProvider>>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let builtins = [
+            Builtin::YouTube,
+            Builtin::Vimeo,
+            Builtin::Spotify,
+            Builtin::SoundCloud,
+            Builtin::CodePen,
+            Builtin::JsFiddle,
+            Builtin::Media,
+        ];
+        Self {
+            providers: builtins
+                .into_iter()
+                .map(|builtin| Box::new(builtin) as Box<dyn EmbedProvider>)
+                .collect(),
+        }
+    }
+}
+
+impl Registry {
+    pub fn register(&mut self, provider: impl EmbedProvider + 'static) {
+        self.providers.push(Box::new(provider));
+    }
+
+    /// The name of the provider and what it embeds. Embeds found by the oEmbed discovery are named
+    /// after the site. `discovered` is whether the link preview found an oEmbed endpoint on the
+    /// page, otherwise only the [`OEMBED_HOSTS`] are asked.
+    pub fn find(&self, url: &str, discovered: bool) -> Option<(String, Embed)> {
+        let link = Link::parse(url)?;
+        let found = self
+            .providers
+            .iter()
+            .map(|provider| provider.as_ref())
+            .find_map(|provider| Some((provider.name().to_string(), provider.embed(url)?)));
+        let known = || {
+            OEMBED_HOSTS
+                .iter()
+                .any(|host| link.host == *host || link.host.ends_with(&format!(".{host}")))
+        };
+        found.or_else(|| {
+            (discovered || known())
+                .then(|| Builtin::OEmbed.embed(url))
+                .flatten()
+                .map(|embed| (link.host.clone(), embed))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame(src: &str, height: u32) -> Option<Embed> {
+        Some(Embed::Frame {
+            src: src.into(),
+            height,
+        })
+    }
+
+    #[test]
+    fn parses_links() {
+        let link = Link::parse("https://user@WWW.Example.com:8080/a/b.MP4/?x=1&v=abc#top").unwrap();
+        assert_eq!(link.host, "example.com");
+        assert_eq!(link.path, "a/b.MP4");
+        assert_eq!(link.segments(), vec!["a", "b.MP4"]);
+        assert_eq!(link.query_param("v"), Some("abc"));
+        assert_eq!(link.extension(), Some("mp4".into()));
+        assert_eq!(Link::parse("ftp://example.com"), None);
+        assert_eq!(Link::parse("https://"), None);
+    }
+
+    #[test]
+    fn builtin_providers() {
+        let youtube = frame("https://www.youtube.com/embed/dQw4w9WgXcQ", 360);
+        for url in [
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42",
+            "https://youtu.be/dQw4w9WgXcQ",
+            "https://m.youtube.com/shorts/dQw4w9WgXcQ",
+        ] {
+            assert_eq!(Builtin::YouTube.embed(url), youtube, "{url}");
+        }
+        assert_eq!(
+            Builtin::YouTube.embed("https://youtube.com/watch?v=a\"b"),
+            None
+        );
+        assert_eq!(
+            Builtin::Spotify.embed("https://open.spotify.com/intl-de/track/4uLU6hMCjMI75M1A2tKUQC"),
+            frame(
+                "https://open.spotify.com/embed/track/4uLU6hMCjMI75M1A2tKUQC",
+                152
+            )
+        );
+        assert_eq!(
+            Builtin::JsFiddle.embed("https://jsfiddle.net/user/a1b2c3/4/"),
+            frame("https://jsfiddle.net/user/a1b2c3/4/embedded/", 300)
+        );
+        assert_eq!(
+            Builtin::Media.embed("https://example.com/song.mp3?dl=1"),
+            Some(Embed::Audio {
+                src: "https://example.com/song.mp3?dl=1".into()
+            })
+        );
+        assert_eq!(Builtin::Media.embed("https://example.com/page.html"), None);
+    }
+
+    #[test]
+    fn registry_order() {
+        fn everything(url: &str) -> Option<Embed> {
+            Some(Embed::Image { src: url.into() })
+        }
+        let mut registry = Registry::default();
+        registry.register(ExtensionEmbedProvider {
+            name: "Everything".into(),
+            embed: everything,
+        });
+
+        let (name, _) = registry
+            .find("https://youtu.be/dQw4w9WgXcQ", false)
+            .unwrap();
+        assert_eq!(name, "YouTube");
+        let (name, _) = registry.find("https://example.com/cat", false).unwrap();
+        assert_eq!(name, "Everything");
+
+        let registry = Registry::default();
+        assert_eq!(registry.find("https://www.example.com/cat", false), None);
+        assert_eq!(
+            registry.find("https://www.example.com/cat", true),
+            Some((
+                "example.com".into(),
+                Embed::OEmbed {
+                    url: "https://www.example.com/cat".into()
+                }
+            ))
+        );
+        let (name, _) = registry
+            .find("https://old.reddit.com/r/rust", false)
+            .unwrap();
+        assert_eq!(name, "old.reddit.com");
+        assert_eq!(registry.find("https://notreddit.com/r/rust", false), None);
+        assert_eq!(registry.find("mailto:someone@example.com", true), None);
+    }
+}
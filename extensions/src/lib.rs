@@ -1,6 +1,11 @@
 use dioxus::prelude::*;
 use std::path::PathBuf;
 
+pub mod commands;
+pub mod embeds;
+use commands::ExtensionCommand;
+use embeds::ExtensionEmbedProvider;
+
 // these help filling in Details
 pub static CARGO_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub static RUSTC_VERSION: &str = env!("RUSTC_VERSION");
@@ -17,6 +22,14 @@ pub trait Extension {
     fn details(&self) -> Details;
     fn stylesheet(&self) -> String;
     fn render<'a>(&self, cx: &'a ScopeState, runtime: std::rc::Rc<Runtime>) -> Element<'a>;
+    /// Slash commands the extension adds to the chatbar
+    fn commands(&self) -> Vec<ExtensionCommand> {
+        Vec::new()
+    }
+    /// Embeds for links the built in providers don't handle
+    fn embed_providers(&self) -> Vec<ExtensionEmbedProvider> {
+        Vec::new()
+    }
     fn rustc_version(&self) -> &'static str {
         RUSTC_VERSION
     }
@@ -48,6 +61,18 @@ macro_rules! export_extension {
             $a.render(cx, runtime)
         }
 
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn commands() -> Vec<$crate::commands::ExtensionCommand> {
+            $a.commands()
+        }
+
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn embed_providers() -> Vec<$crate::embeds::ExtensionEmbedProvider> {
+            $a.embed_providers()
+        }
+
         #[doc(hidden)]
         #[no_mangle]
         pub extern "C" fn rustc_version() -> &'static str {
@@ -68,6 +93,8 @@ pub struct UplinkExtension {
     lib: libloading::Library,
     details: Details,
     stylesheet: String,
+    commands: Vec<ExtensionCommand>,
+    embed_providers: Vec<ExtensionEmbedProvider>,
     rustc_version: &'static str,
     cargo_version: &'static str,
 }
@@ -78,6 +105,15 @@ impl UplinkExtension {
             let lib = libloading::Library::new(location)?;
             let details = lib.get::<unsafe extern "C" fn() -> Details>(b"details\0")?();
             let stylesheet = lib.get::<unsafe extern "C" fn() -> String>(b"stylesheet\0")?();
+            // extensions built before commands existed don't export them
+            let commands = lib
+                .get::<unsafe extern "C" fn() -> Vec<ExtensionCommand>>(b"commands\0")
+                .map(|f| f())
+                .unwrap_or_default();
+            let embed_providers = lib
+                .get::<unsafe extern "C" fn() -> Vec<ExtensionEmbedProvider>>(b"embed_providers\0")
+                .map(|f| f())
+                .unwrap_or_default();
             let rustc_version =
                 lib.get::<unsafe extern "C" fn() -> &'static str>(b"rustc_version\0")?();
             let cargo_version =
@@ -86,6 +122,8 @@ impl UplinkExtension {
                 lib,
                 details,
                 stylesheet,
+                commands,
+                embed_providers,
                 rustc_version,
                 cargo_version,
             })
@@ -99,6 +137,14 @@ impl UplinkExtension {
         &self.stylesheet
     }
 
+    pub fn commands(&self) -> &[ExtensionCommand] {
+        &self.commands
+    }
+
+    pub fn embed_providers(&self) -> &[ExtensionEmbedProvider] {
+        &self.embed_providers
+    }
+
     // todo: can an element be converted to an HTML string and have the string be returned instead?
     pub fn render<'a>(&self, cx: &'a ScopeState) -> Element<'a> {
         unsafe {
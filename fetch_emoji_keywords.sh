@@ -0,0 +1,34 @@
+#!/bin/bash
+
+# Downloads the CLDR emoji annotations used by the emoji selector's search into
+# ui/extra/emoji_keywords, one file per shipped locale. Files that are already
+# there are kept, so this can run before every package build.
+
+CLDR_VERSION=44.1.0
+CLDR_URL=https://raw.githubusercontent.com/unicode-org/cldr-json/${CLDR_VERSION}/cldr-json/cldr-annotations-full/annotations
+OUT_DIR=./ui/extra/emoji_keywords
+
+# <uplink locale> <cldr locale>
+LOCALES="
+bs-BA bs
+de    de
+en-US en
+es-MX es-MX
+hr-HR hr
+pl    pl
+pt-BR pt
+pt-PT pt-PT
+sr-RS sr
+"
+
+set -e
+
+mkdir -p ${OUT_DIR}
+
+echo "${LOCALES}" | while read -r LOCALE CLDR_LOCALE; do
+    [ -z "${LOCALE}" ] && continue
+    [ -s "${OUT_DIR}/${LOCALE}.json" ] && continue
+    echo "Fetching emoji keywords for ${LOCALE}"
+    curl -fsSL "${CLDR_URL}/${CLDR_LOCALE}/annotations.json" -o "${OUT_DIR}/${LOCALE}.json.part"
+    mv "${OUT_DIR}/${LOCALE}.json.part" "${OUT_DIR}/${LOCALE}.json"
+done
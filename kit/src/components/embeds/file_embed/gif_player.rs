@@ -0,0 +1,115 @@
+use std::{path::PathBuf, time::Duration};
+
+use common::{
+    language::get_local_text,
+    utils::{local_file_path::get_fixed_path_to_load_local_file, media_compression},
+};
+use dioxus::prelude::*;
+use dioxus_html::input_data::keyboard_types::Modifiers;
+use tracing::log;
+
+// GIFs played on hover are at most 20MB, anything slower most likely failed
+const LOAD_TIMEOUT: Duration = Duration::from_secs(120);
+const LOAD_POLL: Duration = Duration::from_millis(250);
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // the thumbnail of the attachment
+    thumbnail: String,
+    // where the GIF is, or will be once it is downloaded
+    path: PathBuf,
+    big: bool,
+    // called when the GIF has to be downloaded to the path first
+    on_load: EventHandler<'a>,
+    // called when the GIF is clicked, to open it
+    on_press: EventHandler<'a>,
+}
+
+/// A GIF which only plays while the pointer is over it, its first frame is shown otherwise. It is
+/// downloaded the first time it is hovered.
+#[allow(non_snake_case)]
+pub fn GifPlayer<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let available = use_state(cx, || cx.props.path.exists());
+    let loading = use_state(cx, || false);
+    // the download took too long, clicking the GIF tries again
+    let failed = use_state(cx, || false);
+    let hovered = use_state(cx, || false);
+    // the thumbnail may be animated too
+    let still = use_state(cx, || {
+        media_compression::still_data_url(&cx.props.thumbnail)
+            .unwrap_or_else(|_| cx.props.thumbnail.clone())
+    });
+
+    // waits for the download to finish, the file only shows up at the path once it's complete
+    use_future(cx, (loading.get(), &cx.props.path), |(is_loading, path)| {
+        to_owned![available, loading, failed];
+        async move {
+            if !is_loading {
+                return;
+            }
+            let mut waited = Duration::ZERO;
+            while !path.exists() {
+                if waited >= LOAD_TIMEOUT {
+                    log::warn!("GIF wasn't downloaded to {}", path.display());
+                    failed.set(true);
+                    loading.set(false);
+                    return;
+                }
+                tokio::time::sleep(LOAD_POLL).await;
+                waited += LOAD_POLL;
+            }
+            available.set(true);
+            loading.set(false);
+        }
+    });
+
+    let playing = *hovered.get() && *available.get();
+    let src = if playing {
+        get_fixed_path_to_load_local_file(cx.props.path.clone())
+    } else {
+        still.get().clone()
+    };
+
+    let badge = if *failed.get() {
+        get_local_text("messages.gif-load-failed")
+    } else {
+        "GIF".into()
+    };
+
+    cx.render(rsx!(
+        div {
+            class: format_args!("gif-player {}", if playing { "playing" } else { "" }),
+            aria_label: "gif-player",
+            onmouseenter: move |_| {
+                hovered.set(true);
+                if !*available.get() && !*loading.get() && !*failed.get() {
+                    loading.set(true);
+                    cx.props.on_load.call(());
+                }
+            },
+            onmouseleave: move |_| hovered.set(false),
+            img {
+                aria_label: "message-image",
+                class: format_args!("image expandable-image {}", if cx.props.big { "big" } else { "" }),
+                src: "{src}",
+                onclick: move |e: Event<MouseData>| {
+                    if e.modifiers() == Modifiers::CONTROL {
+                        return;
+                    }
+                    // it opens once it's downloaded
+                    if *available.get() {
+                        cx.props.on_press.call(());
+                    } else if *failed.get() {
+                        failed.set(false);
+                        loading.set(true);
+                        cx.props.on_load.call(());
+                    }
+                },
+            },
+            span {
+                class: format_args!("gif-badge {}", if *failed.get() { "failed" } else { "" }),
+                "{badge}"
+            }
+        }
+    ))
+}
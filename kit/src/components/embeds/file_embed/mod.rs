@@ -6,11 +6,14 @@ use crate::elements::Appearance;
 use common::icons::outline::Shape as Icon;
 use common::icons::Icon as IconElement;
 use common::is_file_available_to_preview;
+use common::is_gif;
 use common::is_video;
 use common::return_correct_icon;
 use common::state::get_upload_error_text;
 use common::state::pending_message::FileProgression;
 use common::utils::local_file_path::get_fixed_path_to_load_local_file;
+use common::utils::voice_message::is_voice_message;
+use common::MAX_GIF_SIZE;
 use common::STATIC_ARGS;
 use dioxus::prelude::*;
 use dioxus_html::input_data::keyboard_types::Modifiers;
@@ -21,6 +24,11 @@ use mime::IMAGE_JPEG;
 use mime::IMAGE_PNG;
 use mime::IMAGE_SVG;
 
+mod gif_player;
+mod voice_player;
+use gif_player::GifPlayer;
+use voice_player::VoicePlayer;
+
 #[derive(Props)]
 pub struct Props<'a> {
     // The filename of the file
@@ -100,6 +108,8 @@ pub fn FileEmbed<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     };
 
     let is_pending = cx.props.progress.is_some();
+    // hovering a GIF downloads it, which is only done for the ones the GIF picker would send
+    let plays_on_hover = cx.props.filesize.is_some_and(|size| size <= MAX_GIF_SIZE);
 
     let mut file_size_pending = String::new();
     let mut failed = false;
@@ -173,6 +183,25 @@ pub fn FileEmbed<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let is_file_available_to_preview = is_file_available_to_preview(&file_name_with_extension);
     let is_video = is_video(&file_name_with_extension);
 
+    // sent voice messages are played right in the message, they are downloaded to the temp files
+    if is_voice_message(filename)
+        && cx.props.with_download_button == Some(true)
+        && !is_from_attachments
+        && !is_pending
+    {
+        let path = cx.props.filepath.clone().unwrap_or(temp_dir.clone());
+        return cx.render(rsx!(
+            div {
+                class: format_args!("file-embed voice-message {}", if remote { "remote" } else { "" }),
+                aria_label: "voice-message",
+                VoicePlayer {
+                    path: path,
+                    on_load: move |_| cx.props.on_press.call(Some(temp_dir.clone())),
+                }
+            }
+        ));
+    }
+
     cx.render(rsx! (
         div {
             class: {
@@ -222,7 +251,26 @@ pub fn FileEmbed<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
             div {
                 class: format_args!("{}", if has_thumbnail {""} else {"icon"}),
                 aria_label: "file-icon",
-                if has_thumbnail {
+                // sent GIFs play while they're hovered, the click still opens them. Larger ones are
+                // only downloaded when they're clicked, like any other image
+                if has_thumbnail && is_gif(filename) && plays_on_hover && !is_from_attachments && !is_pending {
+                    let load_path = temp_dir.clone();
+                    let open_path = temp_dir.clone();
+                    rsx!(
+                        div {
+                            class: "image-container",
+                            aria_label: "message-image-container",
+                            GifPlayer {
+                                thumbnail: thumbnail.clone(),
+                                path: cx.props.filepath.clone().unwrap_or(temp_dir.clone()),
+                                big: cx.props.big.unwrap_or_default(),
+                                on_load: move |_| cx.props.on_press.call(Some(load_path.clone())),
+                                on_press: move |_| cx.props.on_press.call(Some(open_path.clone())),
+                            },
+                            show_download_or_minus_button_if_enabled(cx, with_download_button, btn_icon),
+                        }
+                    )
+                } else if has_thumbnail {
                     rsx!(
                         div {
                             class: "image-container",
@@ -340,6 +388,7 @@ fn is_image(filename: String) -> bool {
             "jpg" => IMAGE_JPEG.to_string(),
             "jpeg" => IMAGE_JPEG.to_string(),
             "svg" => IMAGE_SVG.to_string(),
+            "webp" => "image/webp".to_string(),
             &_ => "".to_string(),
         },
         None => "".to_string(),
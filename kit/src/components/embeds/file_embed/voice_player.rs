@@ -0,0 +1,272 @@
+use std::{path::PathBuf, time::Duration};
+
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    utils::{file_preview, local_file_path::get_fixed_path_to_load_local_file},
+};
+use dioxus::prelude::*;
+use tracing::log;
+use uuid::Uuid;
+
+use crate::elements::{button::Button, Appearance};
+
+const WAVEFORM_BARS: usize = 40;
+const SPEEDS: [f64; 3] = [1.0, 1.5, 2.0];
+// a voice message is a couple of MB at most, anything slower most likely failed
+const LOAD_TIMEOUT: Duration = Duration::from_secs(60);
+const LOAD_POLL: Duration = Duration::from_millis(250);
+
+// the audio element may not be rendered yet when this runs
+const PROGRESS_SCRIPT: &str = r#"
+const attach = () => {
+    const audio = document.getElementById("$ID");
+    if (!audio) {
+        setTimeout(attach, 50);
+        return;
+    }
+    const send = () => dioxus.send([audio.currentTime, isFinite(audio.duration) ? audio.duration : 0]);
+    audio.addEventListener("timeupdate", send);
+    audio.addEventListener("durationchange", send);
+    send();
+};
+attach();
+"#;
+
+const TOGGLE_SCRIPT: &str = r#"
+const audio = document.getElementById("$ID");
+if (audio) {
+    audio.playbackRate = $SPEED;
+    if (audio.paused) {
+        audio.play();
+    } else {
+        audio.pause();
+    }
+}
+"#;
+
+const SPEED_SCRIPT: &str = r#"
+const audio = document.getElementById("$ID");
+if (audio) {
+    audio.playbackRate = $SPEED;
+}
+"#;
+
+const SEEK_SCRIPT: &str = r#"
+const audio = document.getElementById("$ID");
+if (audio && isFinite(audio.duration)) {
+    audio.currentTime = audio.duration * $FRACTION;
+}
+"#;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // where the clip is, or will be once it is downloaded
+    path: PathBuf,
+    // called when the clip has to be downloaded to the path first
+    on_load: EventHandler<'a>,
+}
+
+fn format_time(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// A compact player for voice messages, with the waveform of the clip, which can be clicked to
+/// seek, and the playback speed.
+#[allow(non_snake_case)]
+pub fn VoicePlayer<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let id = use_state(cx, || format!("voice-player-{}", Uuid::new_v4()));
+    let available = use_state(cx, || cx.props.path.exists());
+    let loading = use_state(cx, || false);
+    // the download took too long, pressing play tries again
+    let failed = use_state(cx, || false);
+    // it is played once it was downloaded, because play was pressed
+    let play_when_loaded = use_state(cx, || false);
+    let playing = use_state(cx, || false);
+    // the current time and the duration, in seconds
+    let progress = use_state(cx, || (0f64, 0f64));
+    let speed = use_state(cx, || 0usize);
+    let eval = use_eval(cx);
+
+    // waits for the download to finish, the file only shows up at the path once it's complete
+    use_future(cx, (loading.get(), &cx.props.path), |(is_loading, path)| {
+        to_owned![available, loading, failed, play_when_loaded];
+        async move {
+            if !is_loading {
+                return;
+            }
+            let mut waited = Duration::ZERO;
+            while !path.exists() {
+                if waited >= LOAD_TIMEOUT {
+                    log::warn!("voice message wasn't downloaded to {}", path.display());
+                    failed.set(true);
+                    play_when_loaded.set(false);
+                    loading.set(false);
+                    return;
+                }
+                tokio::time::sleep(LOAD_POLL).await;
+                waited += LOAD_POLL;
+            }
+            available.set(true);
+            loading.set(false);
+        }
+    });
+
+    let waveform = use_future(
+        cx,
+        (available.get(), &cx.props.path),
+        |(is_available, path)| async move {
+            if !is_available {
+                return vec![];
+            }
+            tokio::task::spawn_blocking(move || file_preview::waveform(&path, WAVEFORM_BARS))
+                .await
+                .ok()
+                .and_then(|r| {
+                    r.map_err(|e| log::warn!("failed to read a waveform: {e}"))
+                        .ok()
+                })
+                .unwrap_or_default()
+        },
+    );
+
+    use_future(cx, available.get(), |is_available| {
+        to_owned![eval, id, progress];
+        async move {
+            if !is_available {
+                return;
+            }
+            let eval = match eval(&PROGRESS_SCRIPT.replace("$ID", id.get())) {
+                Ok(r) => r,
+                Err(e) => {
+                    log::error!("failed to follow the voice message: {:?}", e);
+                    return;
+                }
+            };
+            while let Ok(value) = eval.recv().await {
+                let times: Vec<f64> = value
+                    .as_array()
+                    .map(|times| times.iter().filter_map(|t| t.as_f64()).collect())
+                    .unwrap_or_default();
+                if let [current, duration] = times[..] {
+                    progress.set((current, duration));
+                }
+            }
+        }
+    });
+
+    let speed_value = SPEEDS[*speed.get()];
+    let set_speed = move |value: f64| {
+        let _ = eval(
+            &SPEED_SCRIPT
+                .replace("$ID", id.get())
+                .replace("$SPEED", &value.to_string()),
+        );
+    };
+
+    let (current, duration) = *progress.get();
+    let played = if duration > 0.0 {
+        current / duration
+    } else {
+        0.0
+    };
+    let time = if *failed.get() {
+        get_local_text("messages.voice-message-load-failed")
+    } else {
+        format_time(if *playing.get() || current > 0.0 {
+            current
+        } else {
+            duration
+        })
+    };
+    let bars = match waveform.value() {
+        Some(bars) if !bars.is_empty() => bars.clone(),
+        _ => vec![0.0; WAVEFORM_BARS],
+    };
+    let bar_count = bars.len();
+    let source = get_fixed_path_to_load_local_file(cx.props.path.clone());
+
+    cx.render(rsx!(
+        div {
+            class: "voice-player",
+            aria_label: "voice-player",
+            Button {
+                icon: match (*failed.get(), *playing.get()) {
+                    (true, _) => Icon::ArrowPath,
+                    (false, true) => Icon::Pause,
+                    (false, false) => Icon::Play,
+                },
+                small: true,
+                appearance: if *failed.get() { Appearance::Danger } else { Appearance::Primary },
+                aria_label: "voice-player-play".into(),
+                loading: *loading.get(),
+                onpress: move |_| {
+                    if *available.get() {
+                        let _ = eval(
+                            &TOGGLE_SCRIPT
+                                .replace("$ID", id.get())
+                                .replace("$SPEED", &speed_value.to_string()),
+                        );
+                    } else if !*loading.get() {
+                        failed.set(false);
+                        loading.set(true);
+                        play_when_loaded.set(true);
+                        cx.props.on_load.call(());
+                    }
+                },
+            },
+            div {
+                class: "voice-player-waveform",
+                aria_label: "voice-player-waveform",
+                bars.into_iter().enumerate().map(|(i, bar)| {
+                    // silent parts still get a short bar
+                    let height = (bar * 100.0).max(8.0);
+                    let is_played = (i as f64 + 0.5) / bar_count as f64 <= played;
+                    rsx!(div {
+                        key: "{i}",
+                        class: format_args!("voice-player-bar {}", if is_played { "played" } else { "" }),
+                        height: "{height}%",
+                        onclick: move |_| {
+                            let fraction = (i as f64 + 0.5) / bar_count as f64;
+                            let _ = eval(
+                                &SEEK_SCRIPT
+                                    .replace("$ID", id.get())
+                                    .replace("$FRACTION", &fraction.to_string()),
+                            );
+                        },
+                    })
+                })
+            },
+            span {
+                class: "voice-player-time",
+                aria_label: "voice-player-time",
+                "{time}"
+            },
+            Button {
+                text: format!("{speed_value}x"),
+                small: true,
+                appearance: Appearance::Secondary,
+                aria_label: "voice-player-speed".into(),
+                onpress: move |_| {
+                    let next = (*speed.get() + 1) % SPEEDS.len();
+                    speed.set(next);
+                    set_speed(SPEEDS[next]);
+                },
+            },
+            available.get().then(|| rsx!(audio {
+                id: "{id}",
+                src: "{source}",
+                preload: "metadata",
+                autoplay: *play_when_loaded.get(),
+                onplay: move |_| {
+                    playing.set(true);
+                    play_when_loaded.set(false);
+                    set_speed(speed_value);
+                },
+                onpause: move |_| playing.set(false),
+                onended: move |_| playing.set(false),
+            }))
+        }
+    ))
+}
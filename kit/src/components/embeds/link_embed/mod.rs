@@ -1,87 +1,52 @@
-use crate::components::embeds::youtube::YouTubePlayer;
+use common::{
+    state::link_preview::LinkPreview,
+    warp_runner::{OtherCmd, WarpCmd},
+    WARP_CMD_CH,
+};
 use dioxus::prelude::*;
 use dioxus::prelude::{rsx, Props};
 use dioxus_core::{Element, Scope};
 use dioxus_hooks::use_future;
-use scraper::{Html, Selector};
-
-use self::get_link_data::*;
-
-mod get_link_data;
-
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
-pub struct SiteMeta {
-    pub title: String,
-    pub description: String,
-    pub icon: String,
-    pub url: String,
-}
-
-pub async fn get_meta(url: &str) -> Result<SiteMeta, reqwest::Error> {
-    let content = reqwest::get(url).await?.text().await?;
-    let document = Html::parse_document(&content);
-    let meta_selector = match Selector::parse("meta") {
-        Ok(data) => data,
-        Err(_) => {
-            return Ok(SiteMeta {
-                title: String::new(),
-                description: String::new(),
-                icon: String::new(),
-                url: String::from(url),
-            });
-        }
-    };
-
-    let icon = if let Ok(Some(icon)) = fetch_icon(url, document.clone()).await {
-        icon
-    } else {
-        get_image_data(document.clone(), meta_selector.clone()).unwrap_or_default()
-    };
-    let title = get_title_data(document.clone(), meta_selector.clone());
-    let description = get_description_data(document.clone(), meta_selector.clone());
-
-    Ok(SiteMeta {
-        title,
-        description,
-        icon,
-        url: String::from(url),
-    })
-}
+use futures::channel::oneshot;
 
 #[derive(Props, PartialEq)]
 pub struct LinkEmbedProps {
     link: String,
     remote: bool,
+    // sent along with the message
+    #[props(!optional)]
+    preview: Option<LinkPreview>,
+    // if the user allowed visiting links in this chat. without a preview nothing is shown otherwise
+    fetch: bool,
 }
 
 #[allow(non_snake_case)]
 pub fn EmbedLinks(cx: Scope<LinkEmbedProps>) -> Element {
-    let fetch_meta = use_future(cx, &cx.props.link, |link| async move {
-        get_meta(link.as_str()).await
-    });
-
-    let meta = match fetch_meta.value() {
-        Some(Ok(val)) => val.clone(),
-        Some(Err(_)) => SiteMeta::default(),
-        None => SiteMeta::default(),
-    };
-    let title = if meta.title.chars().count() > 100 {
-        meta.title[0..97].to_string() + "..."
-    } else {
-        meta.title.clone()
-    };
-
-    let desc = if meta.description.chars().count() > 200 {
-        meta.description[0..197].to_string() + "..."
-    } else {
-        meta.description.clone()
-    };
+    let has_preview = cx.props.preview.is_some();
+    let fetch_meta = use_future(
+        cx,
+        (&cx.props.link, &cx.props.fetch),
+        |(link, fetch)| async move {
+            if !fetch || has_preview {
+                return None;
+            }
+            let (tx, rx) = oneshot::channel();
+            WARP_CMD_CH
+                .tx
+                .send(WarpCmd::Other(OtherCmd::FetchLinkPreview {
+                    url: link,
+                    rsp: tx,
+                }))
+                .ok()?;
+            rx.await.ok()?.ok()
+        },
+    );
 
-    let youtube_video = if cx.props.link.contains("youtube.com/watch?v=") {
-        Some(cx.props.link.replace("watch?v=", "embed/"))
-    } else {
-        None
-    };
+    let meta = match cx.props.preview.as_ref() {
+        Some(preview) => preview.clone(),
+        None => fetch_meta.value().cloned().flatten().unwrap_or_default(),
+    }
+    .without_remote_thumbnail();
 
     cx.render(rsx! {
         if meta.title.is_empty() {
@@ -96,34 +61,27 @@ pub fn EmbedLinks(cx: Scope<LinkEmbedProps>) -> Element {
                         div {
                             class: "embed-icon",
                             aria_label: "embed-icon",
-                            if !meta.icon.is_empty() {
+                            if !meta.thumbnail.is_empty() {
                                 rsx!(  img {
-                                    src: "{meta.icon}",
+                                    src: "{meta.thumbnail}",
                                     alt: "Website Icon",
                                 },)
                             }
-                            if !title.is_empty() {
-                                rsx!(a {
-                                    class: "link-title",
-                                    aria_label: "link-title",
-                                    href: "{cx.props.link}",
-                                    "{title}"
-                                })
+                            a {
+                                class: "link-title",
+                                aria_label: "link-title",
+                                href: "{cx.props.link}",
+                                "{meta.title}"
                             }
                         },
-                        if desc.is_empty() && youtube_video.is_none() {
+                        if meta.description.is_empty() {
                            rsx!(div {})
                         } else {
                             rsx!( div {
                                 class: "embed-details",
                                 aria_label: "embed-details",
-                                youtube_video.is_some().then(|| rsx!(
-                                    YouTubePlayer {
-                                        video_url: youtube_video.unwrap(),
-                                    }
-                                ))
                                 p {
-                                    "{desc}"
+                                    "{meta.description}"
                                 }
                             })
                         }
@@ -0,0 +1,103 @@
+use common::{
+    icons::outline::Shape as Icon,
+    language::{get_local_text, get_local_text_with_args},
+    warp_runner::{OtherCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use extensions::embeds::Embed;
+use futures::channel::oneshot;
+
+use crate::elements::{button::Button, Appearance};
+
+#[derive(Clone, PartialEq)]
+enum LoadState {
+    // nothing has been fetched yet
+    Idle,
+    Loading,
+    Loaded(Embed),
+    Failed,
+}
+
+#[derive(Props, PartialEq)]
+pub struct Props {
+    provider: String,
+    embed: Embed,
+}
+
+/// Shows a button until the user clicks it, only then the embed is loaded.
+#[allow(non_snake_case)]
+pub fn MediaEmbed(cx: Scope<Props>) -> Element {
+    let load_state = use_state(cx, || LoadState::Idle);
+
+    let load = move |_| {
+        let embed = cx.props.embed.clone();
+        let url = match embed {
+            Embed::OEmbed { url } => url,
+            embed => {
+                load_state.set(LoadState::Loaded(embed));
+                return;
+            }
+        };
+        load_state.set(LoadState::Loading);
+        to_owned![load_state];
+        cx.spawn(async move {
+            let (tx, rx) = oneshot::channel();
+            let cmd = WarpCmd::Other(OtherCmd::DiscoverEmbed { url, rsp: tx });
+            let embed = match WARP_CMD_CH.tx.send(cmd) {
+                Ok(_) => rx.await.ok().and_then(|r| r.ok()),
+                Err(_) => None,
+            };
+            load_state.set(match embed {
+                Some(embed) => LoadState::Loaded(embed),
+                None => LoadState::Failed,
+            });
+        });
+    };
+
+    let content = match load_state.get() {
+        LoadState::Idle | LoadState::Loading => rsx!(Button {
+            icon: Icon::Play,
+            small: true,
+            appearance: Appearance::Secondary,
+            aria_label: "media-embed-load".into(),
+            loading: *load_state.get() == LoadState::Loading,
+            text: get_local_text_with_args(
+                "messages.load-embed",
+                vec![("provider", cx.props.provider.clone())]
+            ),
+            onpress: load,
+        }),
+        LoadState::Loaded(Embed::Frame { src, height }) => rsx!(iframe {
+            src: "{src}",
+            height: "{height}",
+            aria_label: "media-embed-frame",
+            allow: "autoplay; clipboard-write; encrypted-media; fullscreen; picture-in-picture",
+            allowfullscreen: true,
+            sandbox: "allow-scripts allow-same-origin allow-popups allow-presentation",
+        }),
+        LoadState::Loaded(Embed::Video { src }) => rsx!(video {
+            src: "{src}",
+            controls: true,
+            preload: "metadata",
+        }),
+        LoadState::Loaded(Embed::Audio { src }) => rsx!(audio {
+            src: "{src}",
+            controls: true,
+            preload: "metadata",
+        }),
+        LoadState::Loaded(Embed::Image { src }) => rsx!(img { src: "{src}" }),
+        LoadState::Loaded(Embed::OEmbed { .. }) | LoadState::Failed => rsx!(
+            p {
+                class: "media-embed-failed",
+                get_local_text("messages.embed-failed")
+            }
+        ),
+    };
+
+    cx.render(rsx!(div {
+        class: "media-embed",
+        aria_label: "media-embed",
+        content
+    }))
+}
@@ -1,3 +1,3 @@
 pub mod file_embed;
 pub mod link_embed;
-pub mod youtube;
+pub mod media_embed;
@@ -0,0 +1,304 @@
+//! Syntax highlighting for code blocks, done here instead of in the webview so it works anywhere
+//! the html is shown. Tokens get `hl-` prefixed scope classes (`hl-keyword`, `hl-string`, ...)
+//! which are colored by the `--code-*` variables, so themes can change them.
+
+use common::language::get_local_text;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+use super::sanitize::{escape_html, push_escaped};
+
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+// highlighting is slow for huge blocks, these are shown as plain text
+const MAX_HIGHLIGHTED_LEN: usize = 20_000;
+
+static SYNTAXES: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+
+// names people use for languages the bundled grammars know under another name
+const ALIASES: &[(&str, &str)] = &[
+    ("ts", "js"),
+    ("tsx", "js"),
+    ("typescript", "js"),
+    ("jsx", "js"),
+    ("shell", "sh"),
+    ("zsh", "sh"),
+    ("console", "sh"),
+    ("json5", "json"),
+    ("jsonc", "json"),
+    ("vue", "html"),
+    ("svelte", "html"),
+    ("kt", "java"),
+    ("kotlin", "java"),
+];
+
+// used when a block has no language hint and its first line doesn't give it away. each language
+// scores a point per pattern found in the code, the best score of at least two wins
+static DETECTORS: Lazy<Vec<(&str, Vec<Regex>)>> = Lazy::new(|| {
+    let detector = |token, patterns: &[&str]| {
+        let patterns = patterns
+            .iter()
+            .map(|p| Regex::new(&format!("(?m){p}")).expect("valid regex"))
+            .collect();
+        (token, patterns)
+    };
+    vec![
+        detector(
+            "rs",
+            &[
+                r"\bfn\s+\w+\s*[<(]",
+                r"\blet\s+(mut\s+)?\w+",
+                r"\b(impl|trait|enum|struct)\s+\w+",
+                r"\w::\w",
+                r"\b(println|vec|format|assert_eq)!",
+                r"&(mut\s+)?self\b",
+            ],
+        ),
+        detector(
+            "py",
+            &[
+                r"^\s*def\s+\w+\(.*\)\s*(->.*)?:\s*$",
+                r"^\s*(from\s+[\w.]+\s+)?import\s+\w+\s*$",
+                r"^\s*(elif|except|class\s+\w+.*:)",
+                r"\bself\.\w+",
+                r"\bprint\(",
+                r":\s*$",
+            ],
+        ),
+        detector(
+            "js",
+            &[
+                r"\b(const|let|var)\s+\w+\s*=",
+                r"=>",
+                r"\bfunction\b",
+                r"\b(console|document|window)\.\w+",
+                r"===|!==",
+                r"^\s*(import .* from|export (default )?\w+)",
+            ],
+        ),
+        detector(
+            "go",
+            &[
+                r"^\s*package\s+\w+\s*$",
+                r"\bfunc\s+(\(\w+\s+\*?\w+\)\s*)?\w+\(",
+                r":=",
+                r"\bfmt\.\w+",
+                r"\bgo\s+func\b",
+            ],
+        ),
+        detector(
+            "cpp",
+            &[
+                r#"^\s*#include\s*[<"]"#,
+                r"\bstd::",
+                r"\bint\s+main\s*\(",
+                r"\b(printf|cout|cin)\b",
+                r"\w->\w",
+            ],
+        ),
+        detector(
+            "java",
+            &[
+                r"\bpublic\s+(static\s+)?(final\s+)?(class|void|interface)\b",
+                r"\bSystem\.out\.",
+                r"^\s*import\s+java\.",
+                r"@Override\b",
+                r"\bnew\s+[A-Z]\w*\(",
+            ],
+        ),
+        detector(
+            "html",
+            &[
+                r"(?i)^\s*<!DOCTYPE",
+                r"(?i)</?(html|head|body|div|span|p|a|ul|li|script)\b[^>]*>",
+                r#"\b(class|href|id)="[^"]*""#,
+            ],
+        ),
+        detector(
+            "css",
+            &[
+                r"^\s*[.#]?[\w-]+(\s*[,>]\s*[.#]?[\w-]+)*\s*\{\s*$",
+                r"^\s*[\w-]+\s*:\s*[^;{]+;\s*$",
+                r"\b\d+(px|rem|em|vh|vw)\b",
+            ],
+        ),
+        detector(
+            "sql",
+            &[
+                r"(?i)\bselect\b.+\bfrom\b",
+                r"(?i)\b(insert\s+into|create\s+table|delete\s+from|alter\s+table)\b",
+                r"(?i)\bwhere\b.+=",
+                r"(?i)\b(inner|left|right)\s+join\b",
+            ],
+        ),
+        detector(
+            "sh",
+            &[
+                r"^\s*\$\s",
+                r"^\s*(sudo|cd|echo|export|apt|apt-get|brew|cargo|npm|git|curl)\s",
+                r"\$\{?\w+\}?",
+                r"^\s*(if|while)\s+\[",
+                r"\|\s*(grep|sed|awk|xargs)\b",
+            ],
+        ),
+    ]
+});
+
+/// The grammar for a code block, from its language hint or from the code itself.
+pub fn find_syntax(code: &str, hint: Option<&str>) -> Option<&'static SyntaxReference> {
+    let syntaxes = &*SYNTAXES;
+    if let Some(hint) = hint.map(str::to_lowercase).filter(|h| !h.is_empty()) {
+        let token = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == hint)
+            .map(|(_, token)| *token)
+            .unwrap_or(&hint);
+        if let Some(syntax) = syntaxes.find_syntax_by_token(token) {
+            return Some(syntax);
+        }
+    }
+    if let Some(syntax) = code
+        .lines()
+        .next()
+        .and_then(|line| syntaxes.find_syntax_by_first_line(line))
+    {
+        return Some(syntax);
+    }
+    detect_language(code).and_then(|token| syntaxes.find_syntax_by_token(token))
+}
+
+/// Guesses the language of `code`, as the token of its grammar (`rs`, `py`, `json`, ...).
+pub fn detect_language(code: &str) -> Option<&'static str> {
+    let trimmed = code.trim();
+    if (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+    {
+        return Some("json");
+    }
+    DETECTORS
+        .iter()
+        .map(|(token, patterns)| {
+            let score = patterns.iter().filter(|p| p.is_match(code)).count();
+            (*token, score)
+        })
+        // max_by_key returns the last of equal elements, this way the first language wins a tie
+        .rev()
+        .max_by_key(|(_, score)| *score)
+        .filter(|(_, score)| *score >= 2)
+        .map(|(token, _)| token)
+}
+
+/// Highlights `code`, returning html for the inside of a `<code>` element. Falls back to escaped
+/// text if there is no grammar for it.
+pub fn highlight(code: &str, syntax: Option<&SyntaxReference>) -> String {
+    let syntax = match syntax {
+        Some(syntax) if code.len() <= MAX_HIGHLIGHTED_LEN => syntax,
+        _ => return escape_html(code),
+    };
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAXES, CLASS_STYLE);
+    for line in LinesWithEndings::from(code) {
+        if generator
+            .parse_html_for_line_which_includes_newline(line)
+            .is_err()
+        {
+            return escape_html(code);
+        }
+    }
+    generator.finalize()
+}
+
+/// Renders a highlighted code block with line numbers, the name of its language and a copy
+/// button. The copy button is handled by the app, it copies the text of the block's `<code>`.
+pub fn code_block(code: &str, hint: Option<&str>) -> String {
+    let syntax = find_syntax(code, hint);
+    let line_count = code.lines().count().max(1);
+    let line_numbers = (1..=line_count)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut out = String::with_capacity(code.len() * 4);
+    out.push_str(r#"<div class="code-block"><div class="code-block-header">"#);
+    if let Some(syntax) = syntax {
+        out.push_str(r#"<span class="code-block-language">"#);
+        push_escaped(&mut out, &syntax.name);
+        out.push_str("</span>");
+    }
+    out.push_str(r#"<button class="copy-code" title=""#);
+    push_escaped(&mut out, &get_local_text("uplink.copy"));
+    out.push_str(r#"">"#);
+    push_escaped(&mut out, &get_local_text("uplink.copy"));
+    out.push_str("</button></div>");
+    out.push_str(&format!(
+        r#"<pre><span class="line-numbers">{line_numbers}</span>"#
+    ));
+    match syntax.and_then(|s| s.file_extensions.first()) {
+        Some(ext) => {
+            out.push_str(r#"<code class="language-"#);
+            push_escaped(&mut out, ext);
+            out.push_str(r#"">"#);
+        }
+        None => out.push_str("<code>"),
+    }
+    out.push_str(&highlight(code, syntax));
+    out.push_str("</code></pre></div>");
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn detected(code: &str) -> Option<String> {
+        find_syntax(code, None).map(|s| s.name.clone())
+    }
+
+    #[test]
+    fn uses_the_hint() {
+        assert_eq!(
+            find_syntax("x", Some("rust")).map(|s| s.name.as_str()),
+            Some("Rust")
+        );
+        assert_eq!(
+            find_syntax("x", Some("TS")).map(|s| s.name.as_str()),
+            Some("JavaScript")
+        );
+    }
+
+    #[test]
+    fn detects_languages() {
+        assert_eq!(
+            detected("fn main() {\n    let mut x = 1;\n}"),
+            Some("Rust".into())
+        );
+        assert_eq!(detected("def main():\n    print(1)"), Some("Python".into()));
+        assert_eq!(detected("{\"a\": [1, 2]}"), Some("JSON".into()));
+        assert_eq!(
+            detected("#!/bin/bash\necho hi"),
+            Some("Bourne Again Shell (bash)".into())
+        );
+        assert_eq!(detected("just some words"), None);
+    }
+
+    #[test]
+    fn highlights_tokens() {
+        let html = highlight("let x = \"<b>\";", find_syntax("", Some("rust")));
+        assert!(html.contains(r#"<span class="hl-storage hl-type hl-rust">let</span>"#));
+        assert!(html.contains("&lt;b&gt;"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn code_block_has_line_numbers() {
+        let html = code_block("a\nb\n", None);
+        assert!(html.contains(
+            r#"<span class="line-numbers">1
+2</span><code>a
+b
+</code>"#
+        ));
+    }
+}
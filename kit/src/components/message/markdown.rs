@@ -0,0 +1,441 @@
+//! Renders message text as CommonMark with a GFM subset (tables, task lists, strikethrough) and
+//! `||spoilers||`. Code blocks are highlighted by [`super::highlight`]. Html typed into a message is
+//! shown as text. The output is meant to be passed through [`super::sanitize::sanitize`] before it
+//! is displayed.
+
+use std::ops::Range;
+
+use pulldown_cmark::{
+    Alignment, CodeBlockKind, Event, LinkType, Options, Parser, Tag, TagEnd, TextMergeStream,
+};
+
+use super::highlight::code_block;
+use super::sanitize::{is_safe_url, push_escaped};
+use super::{replace_emojis, LINK_TAGS_REGEX};
+
+const SPOILER: &str = "||";
+
+/// A url found in plain text.
+pub struct FoundLink {
+    /// Where the link text is in the searched text.
+    pub range: Range<usize>,
+    pub url: String,
+}
+
+/// Finds the urls and email addresses in text which isn't markdown.
+pub fn find_links(text: &str) -> Vec<FoundLink> {
+    LINK_TAGS_REGEX
+        .captures_iter(text)
+        .filter_map(|caps| {
+            if let Some(address) = caps.get(4) {
+                return Some(FoundLink {
+                    range: address.range(),
+                    url: format!("mailto:{}", address.as_str()),
+                });
+            }
+            let found = caps.get(0)?;
+            let mut url = found.as_str();
+            // a closing paren at the end only belongs to the url if it closes one inside of it
+            while url.ends_with(')') && url.matches(')').count() > url.matches('(').count() {
+                url = &url[..url.len() - 1];
+            }
+            Some(FoundLink {
+                range: found.start()..found.start() + url.len(),
+                url: if url.starts_with("www.") {
+                    format!("https://{url}")
+                } else {
+                    url.to_string()
+                },
+            })
+        })
+        .collect()
+}
+
+pub fn render(text: &str, emojis: bool) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let text = keep_indentation(text);
+    let events = TextMergeStream::new(Parser::new_ext(&text, options));
+    let mut renderer = Renderer {
+        out: String::with_capacity(text.len() * 2),
+        emojis,
+        ..Default::default()
+    };
+    for item in split_spoilers(events) {
+        renderer.render(item);
+    }
+    renderer.out
+}
+
+/// Escapes text which isn't rendered as markdown. Its urls still become links.
+pub fn render_plain(text: &str, emojis: bool) -> String {
+    let mut renderer = Renderer {
+        emojis,
+        ..Default::default()
+    };
+    renderer.push_text(text);
+    renderer.out
+}
+
+// A line indented by four spaces would become a code block, which is never what someone typing a
+// chat message means. Code fences are left alone.
+fn keep_indentation(text: &str) -> String {
+    let mut in_fence = false;
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            if !in_fence && line.starts_with("    ") {
+                format!("\u{a0}{}", &line[1..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+enum Item<'a> {
+    Event(Event<'a>),
+    Spoiler,
+}
+
+// spoilers and inline formatting can be mixed, but a spoiler can't span several blocks
+fn is_block_boundary(item: &Item) -> bool {
+    match item {
+        Item::Event(Event::Start(tag)) => !matches!(
+            tag,
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. }
+        ),
+        Item::Event(Event::End(tag)) => !matches!(
+            tag,
+            TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image
+        ),
+        Item::Event(Event::Rule) => true,
+        _ => false,
+    }
+}
+
+fn split_spoilers<'a>(events: impl Iterator<Item = Event<'a>>) -> Vec<Item<'a>> {
+    let mut items = vec![];
+    // markers in the current block. if they don't pair up the last one is just text
+    let mut markers: Vec<usize> = vec![];
+    let mut in_code_block = false;
+
+    let unpair = |items: &mut Vec<Item<'a>>, markers: &mut Vec<usize>| {
+        if markers.len() % 2 == 1 {
+            if let Some(idx) = markers.last() {
+                items[*idx] = Item::Event(Event::Text(SPOILER.into()));
+            }
+        }
+        markers.clear();
+    };
+
+    for event in events {
+        match &event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            _ => {}
+        }
+        let item = match event {
+            Event::Text(text) if !in_code_block && text.contains(SPOILER) => {
+                for (idx, part) in text.split(SPOILER).enumerate() {
+                    if idx > 0 {
+                        markers.push(items.len());
+                        items.push(Item::Spoiler);
+                    }
+                    if !part.is_empty() {
+                        items.push(Item::Event(Event::Text(part.to_string().into())));
+                    }
+                }
+                continue;
+            }
+            event => Item::Event(event),
+        };
+        if is_block_boundary(&item) {
+            unpair(&mut items, &mut markers);
+        }
+        items.push(item);
+    }
+    unpair(&mut items, &mut markers);
+    items
+}
+
+#[derive(Default)]
+struct Renderer {
+    out: String,
+    emojis: bool,
+    in_code_block: bool,
+    // code blocks are highlighted once all of their text is known
+    code: String,
+    code_language: String,
+    // urls in the text of a link aren't turned into links again
+    in_link: bool,
+    // a link with an unsafe url is rendered as its text
+    link_open: bool,
+    in_table_head: bool,
+    table_alignments: Vec<Alignment>,
+    table_cell: usize,
+    in_spoiler: bool,
+}
+
+impl Renderer {
+    fn render(&mut self, item: Item) {
+        if is_block_boundary(&item) && self.in_spoiler {
+            self.in_spoiler = false;
+            self.out.push_str("</span>");
+        }
+        let event = match item {
+            Item::Spoiler => {
+                self.in_spoiler = !self.in_spoiler;
+                self.out.push_str(if self.in_spoiler {
+                    r#"<span class="spoiler" tabindex="0">"#
+                } else {
+                    "</span>"
+                });
+                return;
+            }
+            Item::Event(event) => event,
+        };
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => self.code.push_str(&text),
+            Event::Text(text) => self.push_text(&text),
+            Event::Code(code) => {
+                self.out.push_str("<code>");
+                push_escaped(&mut self.out, &code);
+                self.out.push_str("</code>");
+            }
+            Event::Html(html) | Event::InlineHtml(html) => push_escaped(&mut self.out, &html),
+            Event::SoftBreak | Event::HardBreak => self.out.push_str("<br>"),
+            Event::Rule => self.out.push_str("<hr>"),
+            Event::TaskListMarker(checked) => self.out.push_str(if checked {
+                r#"<input type="checkbox" checked="" disabled="">"#
+            } else {
+                r#"<input type="checkbox" disabled="">"#
+            }),
+            Event::FootnoteReference(name) => push_escaped(&mut self.out, &format!("[^{name}]")),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock => self.out.push_str("<p>"),
+            Tag::Heading { level, .. } => self.out.push_str(&format!("<{level}>")),
+            Tag::BlockQuote => self.out.push_str("<blockquote>"),
+            Tag::CodeBlock(kind) => {
+                self.in_code_block = true;
+                self.code_language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .chars()
+                        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+'))
+                        .collect::<String>(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+            }
+            Tag::List(Some(1)) => self.out.push_str("<ol>"),
+            Tag::List(Some(start)) => self.out.push_str(&format!(r#"<ol start="{start}">"#)),
+            Tag::List(None) => self.out.push_str("<ul>"),
+            Tag::Item => self.out.push_str("<li>"),
+            Tag::Table(alignments) => {
+                self.table_alignments = alignments;
+                self.out.push_str("<table>");
+            }
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.table_cell = 0;
+                self.out.push_str("<thead><tr>");
+            }
+            Tag::TableRow => {
+                self.table_cell = 0;
+                self.out.push_str("<tr>");
+            }
+            Tag::TableCell => {
+                let cell = if self.in_table_head { "th" } else { "td" };
+                match self.table_alignments.get(self.table_cell) {
+                    Some(Alignment::Left) => self
+                        .out
+                        .push_str(&format!(r#"<{cell} class="align-left">"#)),
+                    Some(Alignment::Center) => self
+                        .out
+                        .push_str(&format!(r#"<{cell} class="align-center">"#)),
+                    Some(Alignment::Right) => self
+                        .out
+                        .push_str(&format!(r#"<{cell} class="align-right">"#)),
+                    _ => self.out.push_str(&format!("<{cell}>")),
+                }
+            }
+            Tag::Emphasis => self.out.push_str("<em>"),
+            Tag::Strong => self.out.push_str("<strong>"),
+            Tag::Strikethrough => self.out.push_str("<del>"),
+            // images aren't loaded from wherever they are hosted, they become a link to the image
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            }
+            | Tag::Image {
+                link_type,
+                dest_url,
+                ..
+            } => {
+                self.in_link = true;
+                let url = if link_type == LinkType::Email {
+                    format!("mailto:{dest_url}")
+                } else {
+                    dest_url.to_string()
+                };
+                self.link_open = is_safe_url(&url);
+                if self.link_open {
+                    self.push_link_start(&url);
+                }
+            }
+            Tag::FootnoteDefinition(_) | Tag::MetadataBlock(_) => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.out.push_str("</p>"),
+            TagEnd::HtmlBlock => {
+                // html blocks keep the line break of their last line
+                if self.out.ends_with('\n') {
+                    self.out.pop();
+                }
+                self.out.push_str("</p>");
+            }
+            TagEnd::Heading(level) => self.out.push_str(&format!("</{level}>")),
+            TagEnd::BlockQuote => self.out.push_str("</blockquote>"),
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                let code = std::mem::take(&mut self.code);
+                let hint = Some(self.code_language.as_str()).filter(|l| !l.is_empty());
+                self.out.push_str(&code_block(&code, hint));
+            }
+            TagEnd::List(true) => self.out.push_str("</ol>"),
+            TagEnd::List(false) => self.out.push_str("</ul>"),
+            TagEnd::Item => self.out.push_str("</li>"),
+            TagEnd::Table => self.out.push_str("</tbody></table>"),
+            TagEnd::TableHead => {
+                self.in_table_head = false;
+                self.out.push_str("</tr></thead><tbody>");
+            }
+            TagEnd::TableRow => self.out.push_str("</tr>"),
+            TagEnd::TableCell => {
+                self.out
+                    .push_str(if self.in_table_head { "</th>" } else { "</td>" });
+                self.table_cell += 1;
+            }
+            TagEnd::Emphasis => self.out.push_str("</em>"),
+            TagEnd::Strong => self.out.push_str("</strong>"),
+            TagEnd::Strikethrough => self.out.push_str("</del>"),
+            TagEnd::Link | TagEnd::Image => {
+                if self.link_open {
+                    self.out.push_str("</a>");
+                }
+                self.in_link = false;
+                self.link_open = false;
+            }
+            TagEnd::FootnoteDefinition | TagEnd::MetadataBlock(_) => {}
+        }
+    }
+
+    fn push_link_start(&mut self, url: &str) {
+        self.out.push_str(r#"<a class="external-link" data-href=""#);
+        push_escaped(&mut self.out, url);
+        self.out.push_str(r#"" title=""#);
+        push_escaped(&mut self.out, url);
+        self.out.push_str(r#"">"#);
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.in_link {
+            return self.push_plain(text);
+        }
+        let mut last = 0;
+        for link in find_links(text) {
+            self.push_plain(&text[last..link.range.start]);
+            self.push_link_start(&link.url);
+            push_escaped(&mut self.out, &text[link.range.clone()]);
+            self.out.push_str("</a>");
+            last = link.range.end;
+        }
+        self.push_plain(&text[last..]);
+    }
+
+    fn push_plain(&mut self, text: &str) {
+        if self.emojis {
+            push_escaped(&mut self.out, &replace_emojis(text));
+        } else {
+            push_escaped(&mut self.out, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn md(text: &str) -> String {
+        render(text, false)
+    }
+
+    // a test for each `fixtures/markdown/<name>.md`, which has to render to `<name>.html`
+    macro_rules! fixtures {
+        ($($name:ident),* $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    let markdown =
+                        include_str!(concat!("fixtures/markdown/", stringify!($name), ".md"));
+                    let html =
+                        include_str!(concat!("fixtures/markdown/", stringify!($name), ".html"));
+                    assert_eq!(
+                        md(markdown.trim_end_matches('\n')),
+                        html.trim_end_matches('\n')
+                    );
+                }
+            )*
+        };
+    }
+
+    fixtures!(
+        paragraphs,
+        inline_formatting,
+        headings,
+        block_quotes,
+        code_blocks,
+        lists,
+        task_lists,
+        tables,
+        spoilers,
+        links,
+        images,
+        html,
+        rules,
+    );
+
+    #[test]
+    fn indented_lines_are_not_code() {
+        assert_eq!(md("    indented"), "<p>\u{a0}   indented</p>");
+    }
+
+    #[test]
+    fn emojis() {
+        assert_eq!(render("hi :)", true), "<p>hi 🙂</p>");
+        assert_eq!(render("`:)`", true), "<p><code>:)</code></p>");
+    }
+}
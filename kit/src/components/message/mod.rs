@@ -1,11 +1,24 @@
-use std::borrow::Cow;
+pub mod highlight;
+pub mod markdown;
+pub mod sanitize;
+
+use std::cell::RefCell;
 use std::path::PathBuf;
-use std::{collections::HashSet, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use common::language::{get_local_text, get_local_text_with_args};
+use common::state::emoji_packs::{replace_shortcodes, CustomEmoji};
+use common::state::link_preview::LinkPreview;
 use common::state::pending_message::{FileLocation, FileProgression};
-use common::state::utils::{mention_replacement_pattern, parse_mentions};
+use common::state::utils::{
+    group_mention_replacement_pattern, mention_replacement_pattern, parse_group_mentions,
+    parse_mentions,
+};
 use common::state::{Action, Identity, State, ToastNotification};
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
 use common::warp_runner::{thumbnail_to_base64, MultiPassCmd, WarpCmd};
 use common::{state::pending_message::progress_file, WARP_CMD_CH};
 //use common::icons::outline::Shape as Icon;
@@ -14,11 +27,10 @@ use derive_more::Display;
 use dioxus::prelude::*;
 use futures::StreamExt;
 use once_cell::sync::Lazy;
-use pulldown_cmark::{CodeBlockKind, Options, Tag, TagEnd};
-use regex::{Captures, Regex, Replacer};
+use regex::Regex;
 use uuid::Uuid;
 use warp::error::Error;
-use warp::{constellation::file::File, crypto::DID};
+use warp::{constellation::file::File, crypto::DID, raygun::ConversationType};
 
 use tracing::log;
 
@@ -28,22 +40,15 @@ use crate::components::context_menu::{ContextItem, ContextMenu, IdentityHeader};
 use crate::elements::button::Button;
 use crate::{components::embeds::file_embed::FileEmbed, elements::textarea};
 
-use super::embeds::link_embed::EmbedLinks;
+use super::embeds::{link_embed::EmbedLinks, media_embed::MediaEmbed};
+
+use markdown::find_links;
+use sanitize::{escape_html, sanitize};
 
-pub static MARKDOWN_PROCESSOR_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new("(^|\n)((?:&gt;(?: *&gt;)*)|(?: ))").unwrap());
 pub static LINK_TAGS_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"((?:(?:www\.)|(?:https?:\/\/))[\w-]+(?:\.[\w-]+)+(?:\/[^)\s<]*)*)|((mailto: {0,1})([\w.+-]+@[\w-]+(?:\.[\w.-]+)+))").unwrap()
 });
 
-const HTML_ESCAPES: [(&str, &str); 5] = [
-    ("&", "&amp;"),
-    ("<", "&lt;"),
-    (">", "&gt;"),
-    ("\"", "&quot;"),
-    ("\'", "&#x27;"),
-];
-
 #[derive(Eq, PartialEq, Clone, Copy, Display)]
 pub enum Order {
     #[display(fmt = "message-first")]
@@ -75,6 +80,7 @@ pub struct Props<'a> {
     loading: Option<bool>,
 
     // An optional field that, if set, will be used as the content of a nested div element with a class of "content".
+    // The text isn't shown when there is content, it is used for the edit box.
     with_content: Option<Element<'a>>,
 
     // An optional field that, if set, will be used as the text content of a nested p element with a class of "text".
@@ -115,65 +121,29 @@ pub struct Props<'a> {
     // Progress for attachments which are being uploaded
     #[props(!optional)]
     attachments_pending_uploads: Option<&'a Vec<(FileLocation, FileProgression)>>,
+    // blurred images shown in place of the attachments while they are uploaded
+    attachment_placeholders: Option<&'a HashMap<FileLocation, String>>,
     on_resend: Option<EventHandler<'a, (Option<String>, FileLocation)>>,
     on_delete: Option<EventHandler<'a, FileLocation>>,
 
     pinned: bool,
 
+    // if set, the message was edited and an "(edited)" marker is shown with this as its tooltip
+    edited: Option<String>,
+    // called when the "(edited)" marker is clicked
+    on_edited_click: Option<EventHandler<'a>>,
+
     is_mention: bool,
 
+    // sent along with the message for the first link in it
+    #[props(!optional)]
+    link_preview: Option<LinkPreview>,
+
     state: &'a UseSharedState<State>,
 
     chat: Uuid,
 }
 
-// Struct for replacing links with clickable divs.
-// Also saves the links
-struct LinkReplacer(Vec<String>);
-
-impl Replacer for LinkReplacer {
-    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
-        let mut url = caps.get(0).unwrap().as_str().to_string();
-        if url.starts_with("mailto:") {
-            let s = if url.starts_with("mailto: ") {
-                format!("{}<a href=\"{}\">{}</a>", &caps[3], url, &caps[4])
-            } else {
-                format!("<a href=\"{}\">{}</a>", url, url)
-            };
-            dst.push_str(&s);
-            return;
-        }
-        // Check if it ends with a ) and exclude it if its not part of url
-        while url.ends_with(')') {
-            let count = url.chars().count();
-            let open = url.chars().filter(|c| *c == '(').count();
-            let close = url.chars().filter(|c| *c == ')').count();
-            if close > open {
-                url = url.chars().take(count - 1).collect::<String>();
-            } else {
-                break;
-            }
-        }
-        let s = if url.starts_with("www.") {
-            let html = format!("<a href=\"https://{}\">{}</a>", url, url);
-            url = format!("https://{}", url);
-            html
-        } else {
-            format!("<a href=\"{}\">{}</a>", url, url)
-        };
-        self.0.push(url);
-        dst.push_str(&s);
-    }
-}
-
-fn wrap_links_with_a_tags(text: &str) -> (String, Vec<String>) {
-    let mut links = LinkReplacer(vec![]);
-    let res = LINK_TAGS_REGEX
-        .replace_all(text, links.by_ref())
-        .into_owned();
-    (res, links.0)
-}
-
 #[allow(non_snake_case)]
 pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     //  log::trace!("render Message");
@@ -185,6 +155,24 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     // omitting the class will display the reactions starting from the bottom right corner
     let remote_class = ""; //if is_remote { "remote" } else { "" };
     let reactions_class = format!("message-reactions-container {remote_class}");
+    // reactions with the custom emoji of the chat
+    let custom_reactions: HashMap<&String, String> = cx
+        .props
+        .state
+        .read()
+        .get_chat_by_id(cx.props.chat)
+        .map(|chat| {
+            cx.props
+                .reactions
+                .iter()
+                .filter_map(|reaction| {
+                    let shortcode = reaction.emoji.strip_prefix(':')?.strip_suffix(':')?;
+                    let (emoji, path) = chat.custom_emoji(shortcode)?;
+                    Some((&reaction.emoji, custom_emoji_html(&emoji, path, false)))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
 
     let has_attachments = cx
         .props
@@ -229,9 +217,16 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let pending_attachment_list = cx.props.attachments_pending_uploads.as_ref().map(|vec| {
         vec.iter().map(|(location, prog)| {
             let file = progress_file(prog);
+            let placeholder = cx
+                .props
+                .attachment_placeholders
+                .and_then(|placeholders| placeholders.get(location))
+                .cloned()
+                .unwrap_or_default();
             rsx!(FileEmbed {
                 key: "{file}",
                 filename: file,
+                thumbnail: placeholder,
                 remote: is_remote,
                 download_pending: false,
                 with_download_button: false,
@@ -270,6 +265,12 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         .then_some("message-pending")
         .unwrap_or_default();
     let is_editing = cx.props.with_text.is_some() && cx.props.editing;
+    let has_content = cx
+        .props
+        .with_content
+        .as_ref()
+        .map(|c| c.is_some())
+        .unwrap_or_default();
 
     cx.render(rsx! (
         cx.props.pinned.then(|| {
@@ -314,7 +315,7 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 )
             },
             white_space: "pre-wrap",
-            (cx.props.with_content.is_some()).then(|| rsx! (
+            (has_content && !is_editing).then(|| rsx! (
                     div {
                     class: "content",
                     cx.props.with_content.as_ref(),
@@ -337,7 +338,7 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                     }
                 )
             ),
-            (cx.props.with_text.is_some() && !cx.props.editing).then(|| rsx!(
+            (cx.props.with_text.is_some() && !cx.props.editing && !has_content).then(|| rsx!(
                 ChatText {
                     text: cx.props.with_text.as_ref().cloned().unwrap_or_default(),
                     remote: is_remote,
@@ -346,6 +347,20 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                     state: cx.props.state,
                     chat: cx.props.chat,
                     ascii_emoji: cx.props.transform_ascii_emojis,
+                    link_preview: cx.props.link_preview.clone(),
+                }
+            )),
+            cx.props.edited.as_ref().filter(|_| !is_editing).map(|edited| rsx!(
+                span {
+                    class: format_args!("edited-indicator {}", if cx.props.on_edited_click.is_some() { "pressable" } else { "" }),
+                    aria_label: "edited-indicator",
+                    title: "{edited}",
+                    onclick: move |_| {
+                        if let Some(f) = &cx.props.on_edited_click {
+                            f.call(());
+                        }
+                    },
+                    get_local_text("messages.edited")
                 }
             )),
             has_attachments.then(|| {
@@ -388,7 +403,16 @@ pub fn Message<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                         onclick: move |_| {
                             cx.props.on_click_reaction.call(emoji.clone());
                         },
-                        "{emoji} {reaction_count}"
+                        match custom_reactions.get(emoji) {
+                            Some(html) => rsx!(
+                                span {
+                                    class: "custom-emoji-reaction",
+                                    dangerous_inner_html: "{html}",
+                                },
+                                " {reaction_count}"
+                            ),
+                            None => rsx!("{emoji} {reaction_count}"),
+                        }
                     }
                 )
             })
@@ -432,6 +456,8 @@ pub struct ChatMessageProps<'a> {
     ascii_emoji: bool,
     state: &'a UseSharedState<State>,
     chat: Uuid,
+    #[props(!optional)]
+    link_preview: Option<LinkPreview>,
 }
 
 #[allow(non_snake_case)]
@@ -449,7 +475,27 @@ pub fn ChatText<'a>(cx: Scope<'a, ChatMessageProps<'a>>) -> Element<'a> {
         cx.props.ascii_emoji,
         Some((&cx.props.state.read(), &cx.props.chat, false)),
     );
-    let (formatted_text, links) = wrap_links_with_a_tags(&formatted_text);
+    // mail addresses don't get an embed
+    let links: Vec<String> = find_links(&cx.props.text)
+        .into_iter()
+        .map(|link| link.url)
+        .filter(|url| !url.starts_with("mailto:"))
+        .collect();
+    // keyed by the link, so an edited message doesn't keep showing the old embed
+    let embed = links.first().and_then(|link| {
+        let discovered = cx
+            .props
+            .link_preview
+            .as_ref()
+            .map_or(false, |preview| preview.url == *link && preview.oembed);
+        let (provider, embed) = cx
+            .props
+            .state
+            .read()
+            .embed_registry()
+            .find(link, discovered)?;
+        Some((link.clone(), provider, embed))
+    });
 
     let text_type_class = if cx.props.pending {
         "pending-text"
@@ -468,8 +514,17 @@ pub fn ChatText<'a>(cx: Scope<'a, ChatMessageProps<'a>>) -> Element<'a> {
             links.first().and_then(|l| cx.render(rsx!(
                 EmbedLinks {
                     link: l.to_string(),
-                    remote: cx.props.remote
+                    remote: cx.props.remote,
+                    preview: cx.props.link_preview.clone().filter(|preview| preview.url == *l),
+                    fetch: cx.props.state.read().link_previews_enabled(&cx.props.chat),
                 })
+            )),
+            embed.map(|(link, provider, embed)| rsx!(
+                MediaEmbed {
+                    key: "{link}",
+                    provider: provider,
+                    embed: embed,
+                }
             ))
         }
     ))
@@ -481,50 +536,75 @@ pub fn format_text(
     emojis: bool,
     data: Option<(&State, &Uuid, bool)>,
 ) -> String {
-    // warning: this will probably break markdown regarding block quotes. still seems like an improvement.
-    let safe_text = HTML_ESCAPES
-        .iter()
-        .fold(Cow::from(text), |s, (from, to)| s.replace(*from, to).into())
-        .replace('\n', "&nbsp;&nbsp;\n");
-    let mut text = safe_text;
-    // We want to do this after we escape html tags
+    // mentions and custom emoji are html. they are swapped for placeholders until the text has been
+    // rendered so that everything the user typed can be escaped.
+    let mentions = RefCell::new(vec![]);
+    let mut text = text.to_string();
     if let Some((state, chat, visual)) = data {
-        if let Some(participants) = state
-            .get_chat_by_id(*chat)
-            .map(|c| state.chat_participants(&c))
-        {
+        if let Some(chat) = state.get_chat_by_id(*chat) {
+            // a custom emoji on its own is shown bigger, a sticker at its full size
+            let alone = text.trim().to_string();
+            text = replace_shortcodes(&text, |shortcode| {
+                let (emoji, path) = chat.custom_emoji(shortcode)?;
+                let mut mentions = mentions.borrow_mut();
+                mentions.push(custom_emoji_html(&emoji, path, alone == emoji.code()));
+                Some(mention_placeholder(mentions.len() - 1))
+            });
+            let participants = state.chat_participants(&chat);
             let (line, _) = parse_mentions(&text, &participants, &state.did_key(), false, |id| {
-                mention_replacement_pattern(id, visual)
+                let mut mentions = mentions.borrow_mut();
+                mentions.push(mention_replacement_pattern(id, visual));
+                mention_placeholder(mentions.len() - 1)
             });
             text = line;
+            if chat.conversation_type == ConversationType::Group {
+                text = parse_group_mentions(&text, |name| {
+                    let mut mentions = mentions.borrow_mut();
+                    mentions.push(group_mention_replacement_pattern(name));
+                    mention_placeholder(mentions.len() - 1)
+                });
+            }
         }
     }
-    if should_markdown {
-        markdown(&text, emojis)
-    } else if emojis {
-        let s = replace_emojis(text.trim());
-        if is_only_emojis(&s) {
-            format!("<span class=\"big-emoji\">{s}</span>")
+    let html = if should_markdown {
+        render_markdown(&text, emojis)
+    } else {
+        let text = text.trim();
+        if emojis && is_only_emojis(&replace_emojis(text)) {
+            format!("<span class=\"big-emoji\">{}</span>", replace_emojis(text))
         } else {
-            format!("<p>{s}</p>")
+            format!("<p>{}</p>", markdown::render_plain(text, emojis))
         }
-    } else {
-        format!("<p>{}</p>", text.trim())
-    }
+    };
+    let html = mentions
+        .into_inner()
+        .iter()
+        .enumerate()
+        .fold(html, |html, (idx, mention)| {
+            html.replace(&mention_placeholder(idx), mention)
+        });
+    sanitize(&html)
 }
 
-fn stack_processor(stack: &str, unescape_html: bool, emojis: bool) -> &str {
-    if unescape_html {
-        if let Some((esc, _)) = HTML_ESCAPES.iter().find(|(_, s)| stack.eq(*s)) {
-            return esc;
-        }
-        if "&nbsp;".eq(stack) {
-            return " ";
-        }
-    }
-    if !emojis {
-        return stack;
-    }
+pub fn custom_emoji_html(emoji: &CustomEmoji, path: PathBuf, alone: bool) -> String {
+    let class = match (emoji.sticker, alone) {
+        (true, true) => "custom-emoji custom-sticker",
+        (false, true) => "custom-emoji custom-emoji-big",
+        _ => "custom-emoji",
+    };
+    let code = escape_html(&emoji.code());
+    format!(
+        r#"<img class="{class}" src="{}" alt="{code}" title="{code}">"#,
+        escape_html(&get_fixed_path_to_load_local_file(path))
+    )
+}
+
+// private use characters, they can't come from markdown syntax
+fn mention_placeholder(idx: usize) -> String {
+    format!("\u{e000}{idx}\u{e001}")
+}
+
+fn stack_processor(stack: &str) -> &str {
     match stack {
         "<3" => "❤️",
         ">:)" => "😈",
@@ -567,23 +647,10 @@ where
 }
 
 pub fn replace_emojis(input: &str) -> String {
-    process_string(input, |s| stack_processor(s, false, true))
+    process_string(input, stack_processor)
 }
 
-struct RegexReplacer;
-
-impl Replacer for RegexReplacer {
-    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
-        dst.push_str(&caps[1]);
-        if caps[2].eq(" ") {
-            dst.push_str("&nbsp;");
-        } else {
-            dst.push_str(&caps[2].replace("&gt;", ">"));
-        }
-    }
-}
-
-fn markdown(text: &str, emojis: bool) -> String {
+fn render_markdown(text: &str, emojis: bool) -> String {
     let txt = text.trim();
     if emojis {
         let r = replace_emojis(txt);
@@ -603,98 +670,7 @@ fn markdown(text: &str, emojis: bool) -> String {
         }
     }
 
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-
-    let text = MARKDOWN_PROCESSOR_REGEX.replace_all(txt, RegexReplacer);
-
-    let mut html_output = String::new();
-    let mut in_paragraph = false;
-    let mut in_code_block = false;
-    let (mut skipping, mut in_link) = (false, false);
-
-    let parser = pulldown_cmark::Parser::new_ext(&text, options);
-    for (event, range) in parser.into_offset_iter() {
-        if skipping {
-            skipping = if in_link {
-                matches!(event, pulldown_cmark::Event::End(TagEnd::Link))
-            } else {
-                matches!(event, pulldown_cmark::Event::End(TagEnd::Image))
-            };
-            continue;
-        }
-        match event {
-            pulldown_cmark::Event::Start(pulldown_cmark::Tag::CodeBlock(
-                CodeBlockKind::Indented,
-            )) => {
-                html_output.push_str("</p>\n<p> </p><p>");
-            }
-            pulldown_cmark::Event::Code(mut txt) => {
-                txt = HTML_ESCAPES
-                    .iter()
-                    .fold(txt, |s, (to, from)| s.replace(*from, to).into());
-                pulldown_cmark::html::push_html(
-                    &mut html_output,
-                    std::iter::once(pulldown_cmark::Event::Code(txt)),
-                )
-            }
-            pulldown_cmark::Event::End(TagEnd::CodeBlock) => {}
-            pulldown_cmark::Event::SoftBreak => {
-                if in_paragraph {
-                    html_output.push_str("</p>\n<p>");
-                }
-            }
-            pulldown_cmark::Event::Start(Tag::Paragraph) => {
-                in_paragraph = true;
-                html_output.push_str("<p>");
-            }
-            pulldown_cmark::Event::End(TagEnd::Paragraph) => {
-                in_paragraph = false;
-            }
-            pulldown_cmark::Event::Start(Tag::Image { .. })
-            | pulldown_cmark::Event::Start(Tag::Link { .. }) => {
-                // Ignore links and image parsing
-                // We only want Autolink but that doesn't work (or needs <> which we also dont weed)
-                skipping = true;
-                in_link = matches!(event, pulldown_cmark::Event::End(TagEnd::Link));
-                html_output.push_str(&text[range]);
-            }
-            pulldown_cmark::Event::Text(t) => {
-                let text = if emojis || in_code_block {
-                    process_string(&t, |s| stack_processor(s, in_code_block, emojis))
-                } else {
-                    t.to_string()
-                };
-                let txt: pulldown_cmark::CowStr<'_> = if in_paragraph {
-                    text.replace("\n\n", "<br/>").into()
-                } else {
-                    text.into()
-                };
-                if in_code_block {
-                    html_output.push_str(&txt);
-                } else {
-                    pulldown_cmark::html::push_html(
-                        &mut html_output,
-                        std::iter::once(pulldown_cmark::Event::Text(txt)),
-                    );
-                }
-            }
-            event => {
-                match event {
-                    pulldown_cmark::Event::Start(Tag::CodeBlock(_)) => {
-                        in_code_block = true;
-                    }
-                    pulldown_cmark::Event::End(TagEnd::CodeBlock) => {
-                        in_code_block = false;
-                    }
-                    _ => {}
-                }
-                pulldown_cmark::html::push_html(&mut html_output, std::iter::once(event))
-            }
-        }
-    }
-    html_output.push('\n');
-    html_output
+    markdown::render(txt, emojis)
 }
 
 #[derive(Display)]
@@ -991,6 +967,14 @@ mod tests2 {
         assert_eq!(&format_text(input, true, true, None), expected);
         assert_eq!(&format_text(input, false, true, None), expected);
     }
+
+    #[test]
+    fn test_format_text_escapes_html() {
+        let input = "<img src=x onerror=alert(1)>";
+        let expected = "<p>&lt;img src=x onerror=alert(1)&gt;</p>";
+        assert_eq!(&format_text(input, true, false, None), expected);
+        assert_eq!(&format_text(input, false, false, None), expected);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,184 @@
+//! Allow-list HTML sanitizer for message text. Everything which ends up in `dangerous_inner_html`
+//! goes through [`sanitize`]: the html is parsed and written out again, keeping only the elements
+//! and attributes listed here. Unknown elements are unwrapped, their text is kept.
+
+use common::state::emoji_packs::is_pack_image;
+use scraper::{ElementRef, Html, Node};
+
+// elements whose content is dropped along with them
+const DROPPED: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "template", "head",
+];
+
+const VOID: &[&str] = &["br", "hr", "input", "img"];
+
+fn allowed_attributes(tag: &str) -> Option<&'static [&'static str]> {
+    let attributes: &[&str] = match tag {
+        "p" | "br" | "hr" | "strong" | "em" | "del" | "blockquote" | "ul" | "li" | "table"
+        | "thead" | "tbody" | "tr" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => &[],
+        "ol" => &["start"],
+        "code" | "pre" | "th" | "td" | "div" => &["class"],
+        "span" => &["class", "tabindex"],
+        // links don't get an href. the webview would open it right away, `data-href` is opened by
+        // the app after asking the user
+        "a" => &["class", "value", "data-href", "title"],
+        "input" => &["type", "checked", "disabled"],
+        // the copy button of code blocks, the app listens for clicks on it
+        "button" => &["class", "title"],
+        // custom emoji, see `keep_image`
+        "img" => &["class", "src", "alt", "title"],
+        _ => return None,
+    };
+    Some(attributes)
+}
+
+/// Links are only kept for these schemes.
+pub fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_ascii_lowercase();
+    ["https://", "http://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    push_escaped(&mut escaped, text);
+    escaped
+}
+
+pub(crate) fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            c => out.push(c),
+        }
+    }
+}
+
+// images are only shown for the custom emoji of the chat, which are local files. anything else
+// would be loaded by the webview as soon as the message is shown
+fn keep_image(element: ElementRef) -> bool {
+    let is_custom_emoji = element
+        .value()
+        .attr("class")
+        .is_some_and(|class| class.split_whitespace().all(|c| c.starts_with("custom-")));
+    is_custom_emoji && element.value().attr("src").is_some_and(is_pack_image)
+}
+
+pub fn sanitize(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut out = String::with_capacity(html.len());
+    write_children(fragment.root_element(), &mut out);
+    out
+}
+
+fn write_children(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => push_escaped(out, text),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    write_element(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn write_element(element: ElementRef, out: &mut String) {
+    let tag = element.value().name();
+    if DROPPED.contains(&tag) {
+        return;
+    }
+    let attributes = match allowed_attributes(tag) {
+        Some(attributes) => attributes,
+        None => return write_children(element, out),
+    };
+    if tag == "input" && element.value().attr("type") != Some("checkbox") {
+        return;
+    }
+    if tag == "img" && !keep_image(element) {
+        return;
+    }
+
+    out.push('<');
+    out.push_str(tag);
+    // in allow-list order, the parsed attributes aren't ordered
+    for name in attributes {
+        let value = match element.value().attr(name) {
+            Some(value) => value,
+            None => continue,
+        };
+        if *name == "data-href" && !is_safe_url(value) {
+            continue;
+        }
+        out.push(' ');
+        out.push_str(name);
+        out.push_str("=\"");
+        push_escaped(out, value);
+        out.push('"');
+    }
+    out.push('>');
+
+    if VOID.contains(&tag) {
+        return;
+    }
+    write_children(element, out);
+    out.push_str("</");
+    out.push_str(tag);
+    out.push('>');
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_allowed_markup() {
+        assert_eq!(
+            sanitize("<p><strong>bold</strong> <em>it</em></p>"),
+            "<p><strong>bold</strong> <em>it</em></p>"
+        );
+    }
+
+    #[test]
+    fn drops_scripts_and_handlers() {
+        assert_eq!(
+            sanitize("<p onclick=\"alert(1)\">hi<script>alert(1)</script></p>"),
+            "<p>hi</p>"
+        );
+    }
+
+    #[test]
+    fn unwraps_unknown_elements() {
+        assert_eq!(
+            sanitize("<section><img src=\"x\" onerror=\"alert(1)\">text</section>"),
+            "text"
+        );
+    }
+
+    #[test]
+    fn drops_unsafe_links() {
+        assert_eq!(
+            sanitize("<a data-href=\"javascript:alert(1)\" href=\"https://a.b\">x</a>"),
+            "<a>x</a>"
+        );
+        assert_eq!(
+            sanitize("<a data-href=\"https://satellite.im\">x</a>"),
+            "<a data-href=\"https://satellite.im\">x</a>"
+        );
+    }
+
+    #[test]
+    fn only_checkbox_inputs() {
+        assert_eq!(
+            sanitize("<input type=\"checkbox\" checked=\"\" disabled=\"\"><input type=\"text\">"),
+            "<input type=\"checkbox\" checked=\"\" disabled=\"\">"
+        );
+    }
+}
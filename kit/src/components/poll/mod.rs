@@ -0,0 +1,121 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use dioxus::prelude::*;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PollOption {
+    pub text: String,
+    pub votes: usize,
+    // names of the voters, empty for anonymous polls
+    pub voters: Vec<String>,
+    // the user voted for this option
+    pub selected: bool,
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    question: String,
+    options: Vec<PollOption>,
+    multiple_choice: bool,
+    anonymous: bool,
+    closed: bool,
+    // when the poll closes, already formatted
+    #[props(!optional)]
+    closes: Option<String>,
+    remote: Option<bool>,
+    on_vote: EventHandler<'a, usize>,
+    // the creator can close an open poll
+    can_close: bool,
+    on_close: EventHandler<'a>,
+}
+
+#[allow(non_snake_case)]
+pub fn Poll<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let total: usize = cx.props.options.iter().map(|o| o.votes).sum();
+    let closed = cx.props.closed;
+
+    let mut details = vec![if cx.props.multiple_choice {
+        get_local_text("polls.multiple-choice")
+    } else {
+        get_local_text("polls.single-choice")
+    }];
+    if cx.props.anonymous {
+        details.push(get_local_text("polls.anonymous"));
+    }
+    if closed {
+        details.push(get_local_text("polls.closed"));
+    } else if let Some(closes) = &cx.props.closes {
+        details.push(closes.clone());
+    }
+    let details = details.join(" · ");
+
+    cx.render(rsx!(
+        div {
+            class: format_args!(
+                "poll {} {}",
+                if cx.props.remote.unwrap_or_default() { "remote" } else { "" },
+                if closed { "poll-closed" } else { "" }
+            ),
+            aria_label: "poll",
+            div {
+                class: "poll-question",
+                IconElement {
+                    icon: Icon::ChartBar,
+                },
+                p { "{cx.props.question}" }
+            },
+            div {
+                class: "poll-options",
+                cx.props.options.iter().enumerate().map(|(idx, option)| {
+                    let percent = if total == 0 { 0 } else { option.votes * 100 / total };
+                    let voters = option.voters.join(", ");
+                    rsx!(
+                        button {
+                            key: "{idx}",
+                            class: format_args!("poll-option {}", if option.selected { "selected" } else { "" }),
+                            aria_label: "poll-option",
+                            disabled: closed,
+                            title: "{voters}",
+                            onclick: move |_| cx.props.on_vote.call(idx),
+                            div {
+                                class: "poll-option-bar",
+                                width: "{percent}%",
+                            },
+                            span {
+                                class: "poll-option-check",
+                                option.selected.then(|| rsx!(IconElement {
+                                    icon: Icon::Check,
+                                }))
+                            },
+                            span {
+                                class: "poll-option-text",
+                                "{option.text}"
+                            },
+                            span {
+                                class: "poll-option-votes",
+                                "{option.votes}"
+                            }
+                        }
+                    )
+                })
+            },
+            div {
+                class: "poll-footer",
+                span {
+                    class: "poll-details",
+                    get_local_text_with_args("polls.votes", vec![("amount", total)]),
+                    " · {details}"
+                },
+                (cx.props.can_close && !closed).then(|| rsx!(
+                    button {
+                        class: "poll-close",
+                        aria_label: "poll-close",
+                        onclick: move |_| cx.props.on_close.call(()),
+                        get_local_text("polls.close")
+                    }
+                ))
+            }
+        }
+    ))
+}
@@ -28,6 +28,36 @@ pub enum JSTextData {
     Cursor(i64),
     KeyPress(Code),
     Submit,
+    Paste(PastedData),
+}
+
+/// What was pasted into the rich editor, instead of it being inserted. `from` and `to` are the
+/// selection it replaces, in UTF-16 code units.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PastedData {
+    pub html: Option<String>,
+    pub text: String,
+    pub from: i64,
+    pub to: i64,
+}
+
+impl PastedData {
+    /// `value` with the pasted over selection replaced by `text`.
+    pub fn insert_into(&self, value: &str, text: &str) -> String {
+        let byte_index = |utf16_index: i64| {
+            let mut count = 0;
+            for (i, c) in value.char_indices() {
+                if count >= utf16_index {
+                    return i;
+                }
+                count += c.len_utf16() as i64;
+            }
+            value.len()
+        };
+        let from = byte_index(self.from.min(self.to));
+        let to = byte_index(self.from.max(self.to));
+        format!("{}{text}{}", &value[..from], &value[to..])
+    }
 }
 
 impl Size {
@@ -60,6 +90,8 @@ pub struct Props<'a> {
     oncursor_update: Option<EventHandler<'a, (String, i64)>>,
     onkeyup: Option<EventHandler<'a, Code>>,
     on_paste_keydown: Option<EventHandler<'a, Event<KeyboardData>>>,
+    // only used by the rich editor, rich text, code and long pastes are handed over to it
+    onpaste: Option<EventHandler<'a, PastedData>>,
     value: String,
     #[props(default = false)]
     is_disabled: bool,
@@ -93,6 +125,7 @@ pub fn Input<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         oncursor_update,
         onkeyup,
         on_paste_keydown,
+        onpaste: _,
         value,
         is_disabled,
         show_char_counter,
@@ -316,6 +349,7 @@ pub fn InputRich<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         oncursor_update,
         onkeyup,
         on_paste_keydown,
+        onpaste,
         value,
         is_disabled,
         show_char_counter,
@@ -369,6 +403,7 @@ pub fn InputRich<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         let rich_editor: String = include_str!("./rich_editor_handler.js")
             .replace("$EDITOR_ID", &id2)
             .replace("$AUTOFOCUS", &(!cx.props.ignore_focus).to_string())
+            .replace("$INTERCEPT_PASTE", &cx.props.onpaste.is_some().to_string())
             .replace("$INIT", &value.replace('"', "\\\"").replace('\n', "\\n"));
         async move {
             if let Ok(eval) = eval(&rich_editor) {
@@ -436,6 +471,11 @@ pub fn InputRich<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                     };
                 }
             }
+            JSTextData::Paste(data) => {
+                if let Some(e) = onpaste {
+                    e.call(data);
+                }
+            }
             JSTextData::Init => {
                 let focus_script = include_str!("./focus.js").replace("$UUID", &id);
                 let _ = eval(&focus_script);
@@ -502,3 +542,20 @@ pub fn InputRich<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         script { script },
     ))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inserts_paste_over_selection() {
+        let pasted = PastedData {
+            html: None,
+            text: String::new(),
+            from: 12,
+            to: 4,
+        };
+        // the emoji is two UTF-16 code units
+        assert_eq!(pasted.insert_into("a😀 selected b", "x"), "a😀 x b");
+    }
+}
@@ -75,6 +75,26 @@ text.focus = _opt => {
     editor.codemirror.focus();
 }
 
+if ($INTERCEPT_PASTE) {
+    // Rich text, code and long pastes are processed by uplink. Short plain text and files are
+    // left to the editor and the paste shortcut
+    editor.codemirror.dom.addEventListener("paste", (e) => {
+        let data = e.clipboardData
+        if (!data) {
+            return
+        }
+        let html = data.getData("text/html")
+        let plain = data.getData("text/plain")
+        if (!html && (data.files.length > 0 || (plain.length < 200 && !plain.includes("\n")))) {
+            return
+        }
+        e.preventDefault()
+        e.stopPropagation()
+        let selection = editor.codemirror.state.selection.main
+        dioxus.send(JSON.stringify({ Paste: { html: html || null, text: plain, from: selection.from, to: selection.to } }))
+    }, true)
+}
+
 editor.registerListener("input", ({ _element, _codemirror, value }) => {
     // Sync value to uplink
     dioxus.send(`{\"Input\":\"${value}\"}`)
@@ -13,14 +13,43 @@ use crate::{
 };
 
 use common::{icons, language::get_local_text, warp_runner::thumbnail_to_base64};
+
+mod voice_recorder;
+pub use voice_recorder::Recording;
+use voice_recorder::VoiceRecorder;
+
 pub type To = &'static str;
 
 pub enum SuggestionType {
     None,
     // Emoji suggestions. First is the string that was matched. Second is the emojis matched
     Emoji(String, Vec<(String, String)>),
-    // Username tag suggestions. First is the string that was matched. Second is the users that matched
-    Tag(String, Vec<Identity>),
+    // Username tag suggestions. First is the string that was matched. Second is the tags that matched
+    Tag(String, Vec<TagSuggestion>),
+    // Slash command suggestions. First is the string that was matched. Second is the commands that matched
+    Command(String, Vec<CommandSuggestion>),
+}
+
+pub struct CommandSuggestion {
+    // without the leading `/`
+    pub name: String,
+    pub usage: String,
+    pub description: String,
+}
+
+pub enum TagSuggestion {
+    // @everyone or @here, without the @
+    Group(String),
+    User(Identity),
+}
+
+impl TagSuggestion {
+    pub fn replacement(&self) -> String {
+        match self {
+            TagSuggestion::Group(name) => name.clone(),
+            TagSuggestion::User(id) => format!("{}#{}", id.username(), id.short_id()),
+        }
+    }
 }
 
 impl SuggestionType {
@@ -28,10 +57,10 @@ impl SuggestionType {
         match self {
             SuggestionType::None => (String::new(), String::new()),
             SuggestionType::Emoji(pattern, v) => (pattern.clone(), v[index].0.clone()),
-            SuggestionType::Tag(pattern, v) => (
-                pattern.clone(),
-                format!("{}#{}", v[index].username(), v[index].short_id()),
-            ),
+            SuggestionType::Tag(pattern, v) => (pattern.clone(), v[index].replacement()),
+            SuggestionType::Command(pattern, v) => {
+                (pattern.clone(), format!("/{} ", v[index].name))
+            }
         }
     }
 
@@ -40,6 +69,7 @@ impl SuggestionType {
             SuggestionType::None => true,
             SuggestionType::Emoji(_, v) => v.is_empty(),
             SuggestionType::Tag(_, v) => v.is_empty(),
+            SuggestionType::Command(_, v) => v.is_empty(),
         }
     }
 }
@@ -69,6 +99,8 @@ pub struct Props<'a> {
     loading: Option<bool>,
     onchange: EventHandler<'a, String>,
     on_paste_keydown: Option<EventHandler<'a, Event<KeyboardData>>>,
+    // rich text, code and long pastes, which are inserted as plain text without it
+    on_paste: Option<EventHandler<'a, textarea::PastedData>>,
     onreturn: EventHandler<'a, String>,
     #[props(default = false)]
     is_disabled: bool,
@@ -77,6 +109,10 @@ pub struct Props<'a> {
     oncursor_update: Option<EventHandler<'a, (String, i64)>>,
     on_suggestion_click: Option<EventHandler<'a, (String, String, i64)>>,
     onup_down_arrow: Option<EventHandler<'a, Code>>,
+    // the microphone voice messages are recorded with
+    microphone: Option<String>,
+    // shows the button to record voice messages
+    on_voice_message: Option<EventHandler<'a, Recording>>,
 }
 
 #[derive(Props)]
@@ -218,6 +254,13 @@ pub fn Chatbar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                             e.call(keyboard_event);
                         }
                     },
+                    onpaste: move |data: textarea::PastedData| match cx.props.on_paste.as_ref() {
+                        Some(e) => e.call(data),
+                        None => {
+                            let value = cx.props.value.clone().unwrap_or_default();
+                            cx.props.onchange.call(data.insert_into(&value, &data.text));
+                        }
+                    },
                     onchange: move |(v, _)| {
                         cx.props.onchange.call(v);
                         *is_suggestion_modal_closed.write_silent() = false;
@@ -250,6 +293,7 @@ pub fn Chatbar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                                 SuggestionType::None => 0,
                                 SuggestionType::Emoji(_, v) => v.len(),
                                 SuggestionType::Tag(_, v) => v.len(),
+                                SuggestionType::Command(_, v) => v.len(),
                             };
                             if amount == 0 {
                                 *selected_suggestion.write_silent() = None;
@@ -286,6 +330,11 @@ pub fn Chatbar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
             cx.props.extensions.as_ref(),
             div {
                 class: "controls",
+                cx.props.on_voice_message.as_ref().map(|on_voice_message| rsx!(VoiceRecorder {
+                    microphone: cx.props.microphone.clone(),
+                    disabled: cx.props.is_disabled || cx.props.loading.unwrap_or_default(),
+                    on_record: move |recorded| on_voice_message.call(recorded),
+                })),
                 cx.props.controls.as_ref()
             },
             (!cx.props.suggestions.is_empty() && !*is_suggestion_modal_closed.read()).then(||
@@ -349,14 +398,19 @@ fn SuggestionsMenu<'a>(cx: Scope<'a, SuggestionProps<'a>>) -> Element<'a> {
                             *arrow = false
                         });
                     },
-                    format_args!("{emoji}  :{alias}:"),
+                    // custom emoji are their shortcode
+                    if emoji.starts_with(':') {
+                        rsx!("{emoji}")
+                    } else {
+                        rsx!("{emoji}  :{alias}:")
+                    }
                 })
             }).collect();
             (get_local_text("messages.emoji-suggestion"), component)
         }
-        SuggestionType::Tag(pattern, identities) => {
-            let component = identities.iter().enumerate().map(|(num, id)| {
-                let username = format!("{}#{}", id.username(), id.short_id());
+        SuggestionType::Tag(pattern, tags) => {
+            let component = tags.iter().enumerate().map(|(num, tag)| {
+                let username = tag.replacement();
                 rsx!(div {
                     class: format_args!("{} {}", "chatbar-suggestion", match cx.props.selected.read().as_ref() {
                         Some(v) => if *v == num {"chatbar-selected"} else {""},
@@ -378,19 +432,68 @@ fn SuggestionsMenu<'a>(cx: Scope<'a, SuggestionProps<'a>>) -> Element<'a> {
                             *arrow = false
                         });
                     },
-                    div {
-                        class: "user-suggestion-profile",
-                        UserImage {
-                            platform: id.platform().into(),
-                            status: id.identity_status().into(),
-                            image: id.profile_picture()
-                        }
+                    match tag {
+                        TagSuggestion::Group(name) => rsx!(
+                            div {
+                                class: "user-suggestion-profile group-suggestion",
+                                icons::Icon {
+                                    icon: icons::outline::Shape::Megaphone,
+                                }
+                            }
+                            format_args!("@{name}"),
+                            span {
+                                class: "group-suggestion-hint",
+                                get_local_text(&format!("messages.mention-{name}"))
+                            }
+                        ),
+                        TagSuggestion::User(id) => rsx!(
+                            div {
+                                class: "user-suggestion-profile",
+                                UserImage {
+                                    platform: id.platform().into(),
+                                    status: id.identity_status().into(),
+                                    image: id.profile_picture()
+                                }
+                            }
+                            format_args!("{username}"),
+                        ),
                     }
-                    format_args!("{username}"),
                 })
             }).collect();
             (get_local_text("messages.username-suggestion"), component)
         }
+        SuggestionType::Command(pattern, commands) => {
+            let component = commands.iter().enumerate().map(|(num, command)| {
+                let replacement = format!("/{} ", command.name);
+                rsx!(div {
+                    class: format_args!("{} {}", "chatbar-suggestion command-suggestion", match cx.props.selected.read().as_ref() {
+                        Some(v) => if *v == num {"chatbar-selected"} else {""},
+                        None => ""
+                    }),
+                    aria_label: "command-suggested-{command.name}",
+                    onclick: move |_| {
+                        cx.props.on_click.call((replacement.clone(), pattern.clone()))
+                    },
+                    onmouseover: move |_| {
+                        cx.props.arrow_selected.with_mut(|arrow|{
+                            if !*arrow {
+                                *cx.props.selected.write() = Some(num);
+                            }
+                            *arrow = false
+                        });
+                    },
+                    span {
+                        class: "command-usage",
+                        "{command.usage}"
+                    },
+                    span {
+                        class: "command-description",
+                        "{command.description}"
+                    }
+                })
+            }).collect();
+            (get_local_text("messages.command-suggestion"), component)
+        }
     };
     cx.render(rsx!(div {
         id: "chatbar-suggestions",
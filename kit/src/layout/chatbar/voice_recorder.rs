@@ -0,0 +1,206 @@
+use std::{path::PathBuf, time::Duration};
+
+use common::{
+    icons::outline::Shape as Icon,
+    icons::Icon as IconElement,
+    language::get_local_text,
+    utils::voice_message::{self, Recorder},
+    STATIC_ARGS,
+};
+use dioxus::prelude::*;
+use futures::future::{self, BoxFuture, FutureExt};
+use tracing::log;
+
+use crate::elements::{
+    button::Button,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+
+// how far the pointer has to be moved to the left to throw the recording away
+const CANCEL_DISTANCE: f64 = 120.0;
+// anything shorter was most likely a click on the button
+const MIN_DURATION: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, PartialEq)]
+struct Gesture {
+    start_x: f64,
+    // how far the pointer was moved to the left, never positive
+    offset: f64,
+}
+
+/// A clip which is still being written to the disk. Resolves to its path, or why it couldn't be
+/// recorded.
+pub type Recording = BoxFuture<'static, Result<PathBuf, String>>;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // the name of the microphone, the default one is used if it can't be found
+    microphone: Option<String>,
+    disabled: bool,
+    on_record: EventHandler<'a, Recording>,
+}
+
+/// Records while the button is held down and hands the clip over once it is released. Sliding to
+/// the left cancels the recording. Nothing is recorded after [`voice_message::MAX_DURATION`], the
+/// clip is still only handed over once the button is released.
+#[allow(non_snake_case)]
+pub fn VoiceRecorder<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let recorder: &UseRef<Option<Recorder>> = use_ref(cx, || None);
+    let gesture: &UseState<Option<Gesture>> = use_state(cx, || None);
+    let level = use_state(cx, || 0f32);
+    let elapsed = use_state(cx, || Duration::ZERO);
+    // why the microphone couldn't be opened, handed over once the button is released
+    let failed: &UseRef<Option<String>> = use_ref(cx, || None);
+
+    let start = move |e: Event<MouseData>| {
+        if cx.props.disabled || gesture.get().is_some() {
+            return;
+        }
+        gesture.set(Some(Gesture {
+            start_x: e.client_coordinates().x,
+            offset: 0.0,
+        }));
+        level.set(0.0);
+        elapsed.set(Duration::ZERO);
+        *failed.write_silent() = None;
+        let microphone = cx.props.microphone.clone();
+        to_owned![recorder, gesture, failed];
+        cx.spawn(async move {
+            let started =
+                tokio::task::spawn_blocking(move || Recorder::start(microphone.as_deref())).await;
+            match started {
+                // the button may have been released already, which drops the recorder
+                Ok(Ok(started)) if gesture.current().is_some() => {
+                    *recorder.write() = Some(started);
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => {
+                    log::error!("failed to start recording: {e}");
+                    if gesture.current().is_some() {
+                        *failed.write_silent() = Some(e.to_string());
+                    }
+                }
+                Err(e) => log::error!("failed to start recording: {e}"),
+            }
+        });
+    };
+
+    let stop = move |cancel: bool| {
+        gesture.set(None);
+        if let Some(e) = failed.write_silent().take() {
+            cx.props.on_record.call(future::ready(Err(e)).boxed());
+            return;
+        }
+        let Some(stopped) = recorder.write_silent().take() else {
+            return;
+        };
+        if cancel || stopped.duration() < MIN_DURATION {
+            return;
+        }
+        // encoding a long clip takes a moment
+        let finished = tokio::task::spawn_blocking(move || {
+            std::fs::create_dir_all(&STATIC_ARGS.temp_files)?;
+            // kept there, so the sent message doesn't have to download it again
+            let dest = STATIC_ARGS.temp_files.join(voice_message::file_name());
+            stopped.finish(&dest).map(|_| dest)
+        });
+        cx.props.on_record.call(
+            async move {
+                match finished.await {
+                    Ok(finished) => finished.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+            .boxed(),
+        );
+    };
+
+    let is_recording = gesture.get().is_some();
+    use_future(cx, &is_recording, |is_recording| {
+        to_owned![recorder, level, elapsed];
+        async move {
+            while is_recording {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let Some((current_level, duration)) = recorder
+                    .read()
+                    .as_ref()
+                    .map(|recorder| (recorder.level(), recorder.duration()))
+                else {
+                    continue;
+                };
+                level.set(current_level);
+                elapsed.set(duration.min(voice_message::MAX_DURATION));
+            }
+        }
+    });
+
+    let seconds = elapsed.get().as_secs();
+    let level_width = (*level.get() * 100.0).round();
+
+    cx.render(rsx!(
+        div {
+            class: "voice-recorder",
+            aria_label: "voice-recorder",
+            onmousedown: start,
+            Button {
+                icon: Icon::Microphone,
+                disabled: cx.props.disabled,
+                appearance: if is_recording { Appearance::Danger } else { Appearance::Secondary },
+                aria_label: "voice-message-button".into(),
+                tooltip: cx.render(rsx!(Tooltip {
+                    arrow_position: ArrowPosition::Bottom,
+                    text: get_local_text("messages.voice-message-hold"),
+                })),
+            },
+        },
+        (*gesture.get()).map(|current| {
+            let offset = current.offset;
+            let cancelling = offset < -CANCEL_DISTANCE / 2.0;
+            rsx!(
+                // catches the pointer everywhere until the button is released
+                div {
+                    class: "voice-recorder-overlay",
+                    onmousemove: move |e| {
+                        let offset = (e.client_coordinates().x - current.start_x).min(0.0);
+                        if offset <= -CANCEL_DISTANCE {
+                            stop(true);
+                        } else {
+                            gesture.set(Some(Gesture { offset, ..current }));
+                        }
+                    },
+                    onmouseup: move |_| stop(false),
+                    // the release can't be seen outside of the window
+                    onmouseleave: move |_| stop(true),
+                },
+                div {
+                    class: format_args!("voice-recorder-panel {}", if cancelling { "cancelling" } else { "" }),
+                    aria_label: "voice-recorder-panel",
+                    div {
+                        class: "voice-recorder-dot",
+                    },
+                    span {
+                        class: "voice-recorder-time",
+                        format!("{}:{:02}", seconds / 60, seconds % 60)
+                    },
+                    div {
+                        class: "voice-recorder-level",
+                        aria_label: "voice-recorder-level",
+                        div {
+                            class: "voice-recorder-level-fill",
+                            style: "width: {level_width}%",
+                        }
+                    },
+                    span {
+                        class: "voice-recorder-hint",
+                        style: "transform: translateX({offset}px)",
+                        IconElement {
+                            icon: Icon::ChevronLeft,
+                        },
+                        get_local_text("messages.voice-message-cancel")
+                    },
+                }
+            )
+        })
+    ))
+}
@@ -10,4 +10,4 @@ xcode-select --install
 curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh
 
 # Install CMake, FFmpeg and Opus
-brew install cmake ffmpeg opus
+brew install cmake ffmpeg opus poppler
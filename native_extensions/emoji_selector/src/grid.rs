@@ -0,0 +1,87 @@
+//! Moving through the emoji with the arrow keys. The selector is made of sections, each a grid of
+//! emoji in rows of [`COLUMNS`].
+
+// the same as `grid-template-columns` in style.css
+pub const COLUMNS: usize = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub section: usize,
+    pub index: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Where the selection moves from `from`, in sections of `sizes` emoji. It starts at the first
+/// emoji and stays where it is at the edges.
+pub fn step(sizes: &[usize], from: Option<Position>, direction: Direction) -> Option<Position> {
+    let in_grid = |p: &Position| sizes.get(p.section).is_some_and(|size| p.index < *size);
+    let Some(from) = from.filter(in_grid) else {
+        let section = sizes.iter().position(|size| *size > 0)?;
+        return Some(Position { section, index: 0 });
+    };
+    let size = sizes[from.section];
+    let column = from.index % COLUMNS;
+    let next = sizes
+        .iter()
+        .enumerate()
+        .skip(from.section + 1)
+        .find(|(_, size)| **size > 0);
+    let previous = sizes
+        .iter()
+        .enumerate()
+        .take(from.section)
+        .rev()
+        .find(|(_, size)| **size > 0);
+    let to = match direction {
+        Direction::Right if from.index + 1 < size => Some((from.section, from.index + 1)),
+        Direction::Right => next.map(|(section, _)| (section, 0)),
+        Direction::Left if from.index > 0 => Some((from.section, from.index - 1)),
+        Direction::Left => previous.map(|(section, size)| (section, size - 1)),
+        // the last row may be shorter, the last emoji is the closest then
+        Direction::Down if from.index / COLUMNS < (size - 1) / COLUMNS => {
+            Some((from.section, (from.index + COLUMNS).min(size - 1)))
+        }
+        Direction::Down => next.map(|(section, size)| (section, column.min(size - 1))),
+        Direction::Up if from.index >= COLUMNS => Some((from.section, from.index - COLUMNS)),
+        Direction::Up => previous.map(|(section, size)| {
+            let last_row = (size - 1) / COLUMNS;
+            (section, (last_row * COLUMNS + column).min(size - 1))
+        }),
+    };
+    Some(
+        to.map(|(section, index)| Position { section, index })
+            .unwrap_or(from),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn at(section: usize, index: usize) -> Option<Position> {
+        Some(Position { section, index })
+    }
+
+    #[test]
+    fn moves_across_sections() {
+        // a section of 9 emoji is a full row and a row of 2, the empty one is skipped
+        let sizes = [9, 0, 3];
+        assert_eq!(step(&sizes, None, Direction::Down), at(0, 0));
+        assert_eq!(step(&sizes, at(0, 5), Direction::Down), at(0, 8));
+        assert_eq!(step(&sizes, at(0, 8), Direction::Down), at(2, 1));
+        assert_eq!(step(&sizes, at(2, 2), Direction::Up), at(0, 8));
+        assert_eq!(step(&sizes, at(2, 0), Direction::Left), at(0, 8));
+        assert_eq!(step(&sizes, at(0, 8), Direction::Right), at(2, 0));
+        // nowhere to go
+        assert_eq!(step(&sizes, at(0, 0), Direction::Up), at(0, 0));
+        assert_eq!(step(&sizes, at(2, 2), Direction::Right), at(2, 2));
+        assert_eq!(step(&[0], None, Direction::Down), None);
+    }
+}
@@ -1,29 +1,51 @@
+use std::{cell::Cell, rc::Rc};
+
 use common::language::get_local_text;
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
 use common::warp_runner::{RayGunCmd, WarpCmd};
 use common::{
     icons::outline::Shape as Icon,
-    state::{scope_ids::ScopeIds, ui::EmojiDestination, Action, State},
+    state::{
+        scope_ids::ScopeIds,
+        ui::{EmojiDestination, SkinTone},
+        Action, State,
+    },
 };
 use dioxus::prelude::*;
-use emojis::{Group, UnicodeVersion};
+use dioxus_elements::input_data::keyboard_types::Code;
+use emojis::{Emoji, Group, UnicodeVersion};
 use extensions::{export_extension, Details, Extension, Location, Meta, Type};
 use futures::StreamExt;
 use kit::components::invisible_closer::InvisibleCloser;
 use kit::elements::textarea;
 use kit::{
     components::nav::{Nav, Route},
-    elements::{button::Button, label::Label},
+    elements::{
+        button::Button,
+        label::Label,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
 };
 use once_cell::sync::Lazy;
-use regex::Regex;
 use tracing::log;
 use uuid::Uuid;
 use warp::raygun::ReactionState;
+
+use grid::{Direction, Position};
+use search::Keywords;
+
+mod grid;
+mod search;
+
 // These two lines are all you need to use your Extension implementation as a shared library
 static EXTENSION: Lazy<EmojiSelector> = Lazy::new(|| EmojiSelector {});
-static EMOJI_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(":[^:]{2,}:?$").unwrap());
 export_extension!(EXTENSION);
 
+const SCROLL_TO_SELECTED: &str = r#"
+    document.querySelector('#emoji_selector .emoji.selected')?.scrollIntoView({ block: 'nearest' });
+"#;
+
 pub struct EmojiSelector;
 
 fn group_to_str(group: emojis::Group) -> String {
@@ -40,7 +62,7 @@ fn group_to_str(group: emojis::Group) -> String {
     }
 }
 
-fn is_supported(unicode_version: UnicodeVersion) -> bool {
+pub(crate) fn is_supported(unicode_version: UnicodeVersion) -> bool {
     let (major, minor, _) = std::char::UNICODE_VERSION;
     unicode_version.major() <= major as u32 && unicode_version.minor() <= minor as u32
 }
@@ -139,6 +161,67 @@ fn build_nav(cx: Scope<'_>) -> Element<'_> {
     }))
 }
 
+/// An emoji of the selector.
+#[derive(Clone, PartialEq)]
+struct Item {
+    /// What is sent, with the skin tone for unicode emoji and the shortcode for custom ones
+    emoji: String,
+    name: String,
+    // the image of custom emoji
+    image: Option<String>,
+    // unicode emoji are favorites without their skin tone, custom ones can't be favorites
+    favorite: Option<String>,
+}
+
+impl Item {
+    fn new(emoji: &'static Emoji, skin_tone: SkinTone) -> Self {
+        Item {
+            emoji: with_skin_tone(emoji, skin_tone).to_string(),
+            name: emoji.name().to_string(),
+            image: None,
+            favorite: Some(emoji.to_string()),
+        }
+    }
+}
+
+struct Section {
+    // what the nav scrolls to
+    id: String,
+    title: String,
+    items: Vec<Item>,
+}
+
+fn with_skin_tone(emoji: &'static Emoji, skin_tone: SkinTone) -> &'static Emoji {
+    let skin_tone = match skin_tone {
+        SkinTone::Default => emojis::SkinTone::Default,
+        SkinTone::Light => emojis::SkinTone::Light,
+        SkinTone::MediumLight => emojis::SkinTone::MediumLight,
+        SkinTone::Medium => emojis::SkinTone::Medium,
+        SkinTone::MediumDark => emojis::SkinTone::MediumDark,
+        SkinTone::Dark => emojis::SkinTone::Dark,
+    };
+    emoji.with_skin_tone(skin_tone).unwrap_or(emoji)
+}
+
+// the custom emoji of the active chat which were downloaded
+fn custom_emojis(state: &State, pattern: &str) -> Vec<Item> {
+    let Some(chat) = state.get_active_chat() else {
+        return vec![];
+    };
+    chat.matching_custom_emoji(pattern)
+        .into_iter()
+        .filter_map(|(code, shortcode)| {
+            let (_, path) = chat.custom_emoji(&shortcode)?;
+            Some(Item {
+                name: code.clone(),
+                emoji: code,
+                image: Some(get_fixed_path_to_load_local_file(path)),
+                favorite: None,
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 enum Command {
     React(Uuid, Uuid, String),
@@ -152,7 +235,11 @@ fn render_selector<'a>(
 ) -> Element<'a> {
     let state = use_shared_state::<State>(cx)?;
     let mouse_over_emoji_selector = use_ref(cx, || false);
-    let emoji_suggestions = use_state(cx, Vec::new);
+    let query = use_state(cx, String::new);
+    let selected: &UseState<Option<Position>> = use_state(cx, || None);
+    let show_skin_tones = use_state(cx, || false);
+    // the emoji shown in each section, for the keyboard handlers
+    let grid_emojis: &UseRef<Vec<Vec<String>>> = use_ref(cx, Vec::new);
 
     let eval = use_eval(cx);
 
@@ -190,6 +277,115 @@ fn render_selector<'a>(
         }
     });
 
+    let skin_tone = state.read().ui.emojis.skin_tone();
+    let sections: Vec<Section> = if query.get().trim().is_empty() {
+        let favorites = state
+            .read()
+            .ui
+            .emojis
+            .favorites()
+            .iter()
+            .filter_map(|favorite| emojis::get(favorite))
+            .map(|emoji| Item::new(emoji, skin_tone))
+            .collect();
+        [
+            Section {
+                id: "Favorites".into(),
+                title: get_local_text("favorites.favorites"),
+                items: favorites,
+            },
+            Section {
+                id: "Custom".into(),
+                title: get_local_text("messages.custom-emoji"),
+                items: custom_emojis(&state.read(), ""),
+            },
+        ]
+        .into_iter()
+        .chain(Group::iter().map(|group| {
+            Section {
+                id: group_to_str(group),
+                title: group_to_str(group),
+                items: group
+                    .emojis()
+                    .filter(|emoji| is_supported(emoji.unicode_version()))
+                    .map(|emoji| Item::new(emoji, skin_tone))
+                    .collect(),
+            }
+        }))
+        .filter(|section| !section.items.is_empty())
+        .collect()
+    } else {
+        let query = query.get().trim();
+        let keywords = Keywords::load(&state.read().settings.language_id());
+        let mut items = custom_emojis(&state.read(), query.trim_matches(':'));
+        let found = search::search(query, &keywords, |emoji| {
+            state
+                .read()
+                .ui
+                .emojis
+                .frecency(with_skin_tone(emoji, skin_tone).as_str())
+        });
+        items.extend(found.into_iter().map(|emoji| Item::new(emoji, skin_tone)));
+        vec![Section {
+            id: "Results".into(),
+            title: String::new(),
+            items,
+        }]
+    };
+    *grid_emojis.write_silent() = sections
+        .iter()
+        .map(|section| {
+            section
+                .items
+                .iter()
+                .map(|item| item.emoji.clone())
+                .collect()
+        })
+        .collect();
+    let selected_item = selected
+        .get()
+        .and_then(|p| sections.get(p.section)?.items.get(p.index).cloned());
+
+    let move_selection = move |direction: Direction| {
+        let sizes: Vec<usize> = grid_emojis.read().iter().map(Vec::len).collect();
+        selected.set(grid::step(&sizes, *selected.get(), direction));
+    };
+    // the selected emoji, or the best match when searching
+    let pick_selected = move || {
+        let emoji = {
+            let grid_emojis = grid_emojis.read();
+            match selected.get() {
+                Some(p) => grid_emojis
+                    .get(p.section)
+                    .and_then(|section| section.get(p.index))
+                    .cloned(),
+                None if !query.get().trim().is_empty() => grid_emojis
+                    .first()
+                    .and_then(|section| section.first())
+                    .cloned(),
+                None => None,
+            }
+        };
+        if let Some(emoji) = emoji {
+            select_emoji_to_send(cx.scope, state, emoji, ch);
+        }
+    };
+
+    use_effect(cx, &*selected.get(), |_| {
+        to_owned![eval];
+        async move {
+            let _ = eval(SCROLL_TO_SELECTED);
+        }
+    });
+
+    let hand = |tone: SkinTone| {
+        emojis::get("✋")
+            .map(|emoji| with_skin_tone(emoji, tone).to_string())
+            .unwrap_or_default()
+    };
+    let current_hand = hand(skin_tone);
+    let skin_tone_title = get_local_text("messages.skin-tone");
+
     cx.render(rsx! (
         InvisibleCloser {
             onclose: |_|{
@@ -209,6 +405,14 @@ fn render_selector<'a>(
                 *mouse_over_emoji_selector.write_silent() = false;
                 let _ = eval(focus_script);
             },
+            // up and down come from the search input, left and right only move through the
+            // grid once something is selected so they still move the cursor while typing
+            onkeydown: move |evt| match evt.code() {
+                Code::ArrowLeft if selected.is_some() => move_selection(Direction::Left),
+                Code::ArrowRight if selected.is_some() => move_selection(Direction::Right),
+                Code::Escape => state.write().mutate(Action::SetEmojiPickerVisible(false)),
+                _ => {}
+            },
             id: "emoji_selector",
             aria_label: "emoji-selector",
             tabindex: "0",
@@ -223,76 +427,139 @@ fn render_selector<'a>(
                     show_char_counter: false,
                     aria_label: "emoji-search-input".into(),
                     value: String::new(),
-                    onreturn:  |_| {},
-                    onchange: |_| {},
+                    onreturn: move |_| pick_selected(),
+                    onchange: move |(v, _): (String, bool)| {
+                        query.set(v);
+                        selected.set(None);
+                    },
                     onkeyup: |_| {},
-                    prevent_up_down_arrows: !emoji_suggestions.is_empty(),
-                    oncursor_update: move |(v, p): (String, i64)| {
-                        let mut sub: String = v.chars().take(p as usize).collect();
-                        sub = if !sub.starts_with(':') {
-                            format!(":{}", sub)
+                    prevent_up_down_arrows: true,
+                    onup_down_arrow: move |code| {
+                        if code == Code::ArrowUp {
+                            move_selection(Direction::Up);
                         } else {
-                            sub
-                        };
-                        let capture = EMOJI_REGEX.captures(&sub);
-                        match capture {
-                            Some(emoji) => {
-                                let emoji = &emoji[0];
-                                if emoji.contains(char::is_whitespace) {
-                                    emoji_suggestions.set(vec![]);
-                                    return;
-                                }
-                                let alias = emoji.replace(':', "");
-                                    emoji_suggestions
-                                        .set(state.read().ui.emojis.get_matching_emoji(&alias, false));
-                            }
-                            None => emoji_suggestions.set(vec![]),
+                            move_selection(Direction::Down);
                         }
                     },
-                    }
                 },
-            div {
-                id: "scrolling",
-                padding_top: if !emoji_suggestions.is_empty() {"4px"} else {""},
-                if !emoji_suggestions.is_empty() {
-                    rsx!(emoji_suggestions.iter().map(|(emoji, _)| {
+                div {
+                    class: "skin-tones",
+                    aria_label: "skin-tones",
+                    if *show_skin_tones.get() {
+                        rsx!(SkinTone::ALL.into_iter().map(|tone| {
+                            let tone_hand = hand(tone);
+                            rsx!(
+                                div {
+                                    key: "{tone_hand}",
+                                    class: format_args!("emoji skin-tone {}", if tone == skin_tone { "selected" } else { "" }),
+                                    aria_label: "skin-tone",
+                                    onclick: move |_| {
+                                        state.write().mutate(Action::SetEmojiSkinTone(tone));
+                                        show_skin_tones.set(false);
+                                    },
+                                    "{tone_hand}"
+                                }
+                            )
+                        }))
+                    } else {
                         rsx!(
                             div {
-                                aria_label: emoji.as_str(),
-                                class: "emoji",
-                                onclick: move |_| select_emoji_to_send(cx.scope, state, emoji.to_string(), ch),
-                                emoji.as_str()
+                                class: "emoji skin-tone",
+                                aria_label: "skin-tone-button",
+                                title: "{skin_tone_title}",
+                                onclick: move |_| show_skin_tones.set(true),
+                                "{current_hand}"
                             }
                         )
-                    }))
-                } else {
-                    rsx! (emojis::Group::iter().map(|group| {
-                        let name: String = group_to_str(group);
-                        rsx!(
+                    }
+                }
+            },
+            div {
+                id: "scrolling",
+                sections.is_empty().then(|| rsx!(
+                    p {
+                        class: "emoji-empty",
+                        get_local_text("messages.no-emoji-found")
+                    }
+                )),
+                sections.iter().enumerate().map(|(section_index, section)| {
+                    rsx!(
+                        (!section.title.is_empty()).then(|| rsx!(
                             div {
-                                id: "{group_to_str(group)}",
+                                id: "{section.id}",
                                 Label {
-                                    text: name
+                                    text: section.title.clone()
                                 },
                             }
-                            div {
-                                class: "emojis-container",
-                                aria_label: "emojis-container",
-                                group.emojis().filter(|emoji|is_supported(emoji.unicode_version())).map(|emoji| {
-                                    rsx!(
-                                        div {
-                                            aria_label: emoji.as_str(),
-                                            class: "emoji",
-                                            onclick: move |_| select_emoji_to_send(cx.scope, state, emoji.to_string(), ch),
-                                            emoji.as_str()
+                        )),
+                        div {
+                            class: "emojis-container",
+                            aria_label: "emojis-container",
+                            section.items.iter().enumerate().map(|(index, item)| {
+                                let position = Position { section: section_index, index };
+                                let is_selected = *selected.get() == Some(position);
+                                let emoji = item.emoji.clone();
+                                rsx!(
+                                    div {
+                                        aria_label: "{item.emoji}",
+                                        class: format_args!("emoji {}", if is_selected { "selected" } else { "" }),
+                                        onclick: move |_| select_emoji_to_send(cx.scope, state, emoji.clone(), ch),
+                                        onmouseenter: move |_| {
+                                            if !is_selected {
+                                                selected.set(Some(position));
+                                            }
+                                        },
+                                        match &item.image {
+                                            Some(image) => rsx!(img { class: "custom-emoji", src: "{image}", alt: "{item.emoji}" }),
+                                            None => rsx!("{item.emoji}"),
                                         }
-                                    )
-                                })
-                            }
-                        )
-                    }))
-                }
+                                    }
+                                )
+                            })
+                        }
+                    )
+                })
             }
+            selected_item.map(|item| {
+                let favorite = item.favorite.clone();
+                let is_favorite = favorite
+                    .as_ref()
+                    .map(|favorite| state.read().ui.emojis.is_favorite(favorite));
+                rsx!(
+                    div {
+                        class: "emoji-preview",
+                        aria_label: "emoji-preview",
+                        span {
+                            class: "emoji-preview-emoji",
+                            match &item.image {
+                                Some(image) => rsx!(img { class: "custom-emoji", src: "{image}", alt: "{item.emoji}" }),
+                                None => rsx!("{item.emoji}"),
+                            }
+                        },
+                        span {
+                            class: "emoji-preview-name",
+                            "{item.name}"
+                        },
+                        is_favorite.map(|is_favorite| rsx!(
+                            Button {
+                                icon: Icon::Star,
+                                small: true,
+                                appearance: if is_favorite { Appearance::Primary } else { Appearance::Secondary },
+                                aria_label: "favorite-emoji".into(),
+                                tooltip: cx.render(rsx!(Tooltip {
+                                    arrow_position: ArrowPosition::Right,
+                                    text: get_local_text(if is_favorite { "favorites.remove" } else { "favorites.add" }),
+                                })),
+                                onpress: move |_| {
+                                    if let Some(favorite) = favorite.clone() {
+                                        state.write().mutate(Action::ToggleFavoriteEmoji(favorite));
+                                    }
+                                }
+                            }
+                        ))
+                    }
+                )
+            }),
             nav
         },
         script { focus_script },
@@ -306,13 +573,16 @@ fn render_1(cx: Scope, _unused: bool) -> Element {
     let mouse_over_emoji_button = use_ref(cx, || false);
     let visible = state.read().ui.emoji_picker_visible;
     log::debug!("vis {}", visible);
+    // the filter is kept in the state, it can't read the skin tone from there
+    let skin_tone = cx.use_hook(|| Rc::new(Cell::new(SkinTone::default())));
+    skin_tone.set(state.read().ui.emojis.skin_tone());
 
     use_effect(cx, (), |_| {
-        to_owned![state];
+        to_owned![state, skin_tone];
         async move {
             state.write_silent().ui.emojis.register_emoji_filter(
                 String::from("emoji_picker"),
-                |pattern, exact| {
+                move |pattern, exact| {
                     emojis::Group::iter()
                         .flat_map(|group| group.emojis())
                         .filter_map(|emoji| {
@@ -322,7 +592,10 @@ fn render_1(cx: Scope, _unused: bool) -> Element {
                                     (exact && (*short).eq(pattern))
                                         || (!exact && (*short).starts_with(pattern))
                                 })
-                                .map(|short| (emoji.to_string(), short.to_string()))
+                                .map(|short| {
+                                    let emoji = with_skin_tone(emoji, skin_tone.get());
+                                    (emoji.to_string(), short.to_string())
+                                })
                         })
                         .collect()
                 },
@@ -410,6 +683,9 @@ fn select_emoji_to_send(
             state
                 .write_silent()
                 .mutate(Action::SetChatDraft(c.id, new_draft));
+            state
+                .write_silent()
+                .mutate(Action::TrackEmojiUsage(emoji.clone()));
             if let Some(scope_id_usize) = state.read().scope_ids.chatbar {
                 cx.needs_update_any(ScopeIds::scope_id_from_usize(scope_id_usize));
             };
@@ -0,0 +1,155 @@
+//! Finding emoji by shortcode, name or keyword. Shortcodes and names are in English. The keywords
+//! of other languages are the CLDR annotations of the language, read from
+//! `emoji_keywords/<language id>.json` in the extras folder, which `fetch_emoji_keywords.sh`
+//! downloads from cldr-json's `annotations.json`.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use emojis::Emoji;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+static KEYWORDS: Lazy<Mutex<HashMap<String, Arc<Keywords>>>> = Lazy::new(Default::default);
+
+/// The keywords of each emoji in one language, lowercase.
+#[derive(Default)]
+pub struct Keywords(HashMap<String, Vec<String>>);
+
+impl Keywords {
+    /// The keywords for the language, read once. There are none if it has no annotations file.
+    pub fn load(language_id: &str) -> Arc<Keywords> {
+        let mut loaded = KEYWORDS.lock().unwrap_or_else(|e| e.into_inner());
+        loaded
+            .entry(language_id.to_string())
+            .or_insert_with(|| {
+                let keywords = common::get_extras_dir()
+                    .map(|dir| {
+                        dir.join("emoji_keywords")
+                            .join(format!("{language_id}.json"))
+                    })
+                    .and_then(|path| Ok(std::fs::read_to_string(path)?))
+                    .map(|json| Keywords::parse(&json))
+                    .unwrap_or_default();
+                Arc::new(keywords)
+            })
+            .clone()
+    }
+
+    fn parse(json: &str) -> Keywords {
+        let Ok(json) = serde_json::from_str::<Value>(json) else {
+            return Keywords::default();
+        };
+        let Some(annotations) = json
+            .get("annotations")
+            .and_then(|json| json.get("annotations")?.as_object())
+        else {
+            return Keywords::default();
+        };
+        let keywords = annotations
+            .iter()
+            .map(|(emoji, annotation)| {
+                let keywords = ["default", "tts"]
+                    .iter()
+                    .filter_map(|key| annotation.get(key)?.as_array())
+                    .flatten()
+                    .filter_map(|keyword| Some(keyword.as_str()?.to_lowercase()))
+                    .collect();
+                (without_variation(emoji), keywords)
+            })
+            .collect();
+        Keywords(keywords)
+    }
+
+    fn get(&self, emoji: &str) -> &[String] {
+        self.0
+            .get(&without_variation(emoji))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+// CLDR leaves out the variation selector which makes a character an emoji
+fn without_variation(emoji: &str) -> String {
+    emoji.replace('\u{fe0f}', "")
+}
+
+/// The emoji matching the query, the best matches first and among those the most used.
+pub fn search(
+    query: &str,
+    keywords: &Keywords,
+    frecency: impl Fn(&Emoji) -> f64,
+) -> Vec<&'static Emoji> {
+    let query = query.trim().trim_matches(':').to_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+    let mut matches: Vec<_> = emojis::iter()
+        .filter(|emoji| crate::is_supported(emoji.unicode_version()))
+        .filter_map(|emoji| {
+            let shortcodes: Vec<&str> = emoji.shortcodes().collect();
+            let rank = rank(
+                &query,
+                &shortcodes,
+                emoji.name(),
+                keywords.get(emoji.as_str()),
+            )?;
+            Some((rank, frecency(emoji), emoji))
+        })
+        .collect();
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.total_cmp(&a.1)));
+    matches.into_iter().map(|(_, _, emoji)| emoji).collect()
+}
+
+// how well an emoji matches the query, lower is better
+fn rank(query: &str, shortcodes: &[&str], name: &str, keywords: &[String]) -> Option<u8> {
+    let shortcode = query.replace(' ', "_");
+    if shortcodes.iter().any(|s| *s == shortcode) {
+        return Some(0);
+    }
+    if shortcodes.iter().any(|s| s.starts_with(&shortcode)) {
+        return Some(1);
+    }
+    // every word of the query starts a word of the name or the keywords, "thu up" finds 👍
+    let name = name.to_lowercase();
+    let words: Vec<&str> = std::iter::once(name.as_str())
+        .chain(keywords.iter().map(String::as_str))
+        .flat_map(|text| text.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .collect();
+    if query
+        .split_whitespace()
+        .all(|q| words.iter().any(|word| word.starts_with(q)))
+    {
+        return Some(2);
+    }
+    if shortcodes.iter().any(|s| s.contains(&shortcode)) || name.contains(query) {
+        return Some(3);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ranks_shortcodes_before_keywords() {
+        let keywords = Keywords::parse(
+            r#"{"annotations": {"identity": {}, "annotations": {
+                "👍": {"default": ["Daumen", "Daumen hoch", "gut"], "tts": ["Daumen hoch"]},
+                "❤": {"default": ["Herz"], "tts": ["rotes Herz"]}
+            }}}"#,
+        );
+        let thumbs = (["+1", "thumbsup"], "thumbs up", keywords.get("👍"));
+        assert_eq!(rank("thumbsup", &thumbs.0, thumbs.1, thumbs.2), Some(0));
+        assert_eq!(rank("thumb", &thumbs.0, thumbs.1, thumbs.2), Some(1));
+        assert_eq!(rank("daumen ho", &thumbs.0, thumbs.1, thumbs.2), Some(2));
+        assert_eq!(rank("umbs", &thumbs.0, thumbs.1, thumbs.2), Some(3));
+        assert_eq!(rank("herz", &thumbs.0, thumbs.1, thumbs.2), None);
+        // the keywords are found with the variation selector too
+        assert_eq!(keywords.get("❤\u{fe0f}"), ["herz", "rotes herz"]);
+    }
+}
@@ -1 +0,0 @@
-Prism.languages.csv={value:/[^\r\n,"]+|"(?:[^"]|"")*"(?!")/,punctuation:/,/};
\ No newline at end of file
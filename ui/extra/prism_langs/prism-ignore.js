@@ -1,23 +0,0 @@
-(function (Prism) {
-	Prism.languages.ignore = {
-		// https://git-scm.com/docs/gitignore
-		'comment': /^#.*/m,
-		'entry': {
-			pattern: /\S(?:.*(?:(?:\\ )|\S))?/,
-			alias: 'string',
-			inside: {
-				'operator': /^!|\*\*?|\?/,
-				'regex': {
-					pattern: /(^|[^\\])\[[^\[\]]*\]/,
-					lookbehind: true
-				},
-				'punctuation': /\//
-			}
-		}
-	};
-
-	Prism.languages.gitignore = Prism.languages.ignore;
-	Prism.languages.hgignore = Prism.languages.ignore;
-	Prism.languages.npmignore = Prism.languages.ignore;
-
-}(Prism));
@@ -4,6 +4,7 @@ use super::*;
 
 use crate::utils::auto_updater::DownloadState;
 use chrono::Local;
+use common::profiles::{active_profile, Profiles};
 use common::state::data_transfer::TransferTracker;
 use common::state::ui::WindowMeta;
 use common::state::State;
@@ -14,14 +15,24 @@ use overlay::OverlayDom;
 use warp::multipass;
 
 pub fn use_warp_runner(cx: &ScopeState) {
-    cx.use_hook(|| {
-        // Now turn on the warp runner and save it to the hook so it doesn't get dropped
+    // Now turn on the warp runner and keep it in a shared state so it doesn't get dropped.
+    // The log in pages replace it when switching profiles
+    use_shared_state_provider(cx, || {
         let mut runner = warp_runner::WarpRunner::new();
         runner.run();
         runner
     });
 }
 
+// the old runner's task has to be gone before the new one starts, it holds the warp command channel
+pub async fn restart_warp_runner(runner: UseSharedState<warp_runner::WarpRunner>) {
+    let mut old_runner = std::mem::take(&mut *runner.write());
+    old_runner.stop().await;
+    let mut new_runner = warp_runner::WarpRunner::new();
+    new_runner.run();
+    *runner.write() = new_runner;
+}
+
 pub(crate) fn use_bootstrap<'a>(
     cx: &'a ScopeState,
     identity: &multipass::identity::Identity,
@@ -37,6 +48,10 @@ pub(crate) fn use_bootstrap<'a>(
             assert!(state.initialized);
         } else {
             state.set_own_identity(identity.clone().into());
+            let mut profiles = Profiles::load();
+            if let Err(e) = profiles.set_name(&active_profile(), identity.username()) {
+                log::error!("failed to save profile name: {e}");
+            }
         }
 
         // TODO: This overlay needs to be fixed in windows
@@ -107,11 +122,12 @@ pub fn create_uplink_dirs() {
             .expect("Error removing temp files directory");
     }
     // Initializes the cache dir if needed
-    std::fs::create_dir_all(&STATIC_ARGS.uplink_path).expect("Error creating Uplink directory");
-    std::fs::create_dir_all(&STATIC_ARGS.warp_path).expect("Error creating Warp directory");
+    std::fs::create_dir_all(STATIC_ARGS.uplink_path()).expect("Error creating Uplink directory");
+    std::fs::create_dir_all(STATIC_ARGS.warp_path()).expect("Error creating Warp directory");
     std::fs::create_dir_all(&STATIC_ARGS.themes_path).expect("error creating themes directory");
     std::fs::create_dir_all(&STATIC_ARGS.fonts_path)
         .expect("error creating fonts themes directory");
+    std::fs::create_dir_all(&STATIC_ARGS.gifs_path).expect("error creating gifs directory");
     std::fs::create_dir_all(&STATIC_ARGS.temp_files)
         .expect("error creatings temporary files directory");
 }
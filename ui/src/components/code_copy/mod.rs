@@ -0,0 +1,59 @@
+use arboard::Clipboard;
+use common::language::get_local_text;
+use common::state::{Action, State, ToastNotification};
+use dioxus::prelude::*;
+use tracing::log;
+
+// code blocks come with a copy button, see kit::components::message::highlight
+const COPY_CLICK_SCRIPT: &str = r#"
+document.addEventListener("click", (event) => {
+    const button = event.target.closest("button.copy-code");
+    const code = button && button.closest(".code-block")?.querySelector("code");
+    if (code) {
+        event.preventDefault();
+        event.stopPropagation();
+        dioxus.send(code.textContent);
+    }
+}, true);
+"#;
+
+/// Copies the text of a code block when its copy button is clicked.
+#[allow(non_snake_case)]
+pub fn CodeCopyListener(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let eval = use_eval(cx);
+
+    use_future(cx, (), |_| {
+        to_owned![eval, state];
+        async move {
+            let eval = match eval(COPY_CLICK_SCRIPT) {
+                Ok(r) => r,
+                Err(e) => {
+                    log::error!("failed to listen for code copy clicks: {:?}", e);
+                    return;
+                }
+            };
+            while let Ok(value) = eval.recv().await {
+                let code = match value.as_str() {
+                    Some(code) => code.to_string(),
+                    None => continue,
+                };
+                let result = Clipboard::new().and_then(|mut c| c.set_text(code));
+                if let Err(e) = result {
+                    log::warn!("Unable to set text to clipboard: {e}");
+                    continue;
+                }
+                state
+                    .write()
+                    .mutate(Action::AddToastNotification(ToastNotification::init(
+                        "".into(),
+                        get_local_text("uplink.copied-code"),
+                        None,
+                        2,
+                    )));
+            }
+        }
+    });
+
+    None
+}
@@ -4,6 +4,7 @@ use common::{
     icons::outline::Shape as Icon,
     state::{utils::get_available_themes, Action, State},
 };
+use kit::components::message::highlight::code_block;
 use kit::elements::{
     button::Button,
     label::Label,
@@ -228,24 +229,14 @@ pub fn DebugLogger(cx: Scope) -> Element {
                         })
                     }
                 }),
-                Tab::State => rsx!(div {
-                    aria_label: "debug-logger-body",
-                    class: "body",
-                    pre {
-                        class: "language-js",
-
-                        code {
-                            "{state_json}"
-                        }
-                    }
-                    script {
-                        r#"
-                        (() => {{
-                            Prism.highlightAll();
-                        }})();
-                        "#
-                    }
-                })
+                Tab::State => {
+                    let state_html = code_block(&state_json, Some("json"));
+                    rsx!(div {
+                        aria_label: "debug-logger-body",
+                        class: "body",
+                        dangerous_inner_html: "{state_html}"
+                    })
+                }
             }
         },
     ))
@@ -41,11 +41,11 @@ pub fn EmojiGroup<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
             for emoji in sorted_list {
                 Button {
                     aria_label: "frequent-emoji".into(),
-                    key: "{emoji.0}",
-                    text: emoji.0.clone(),
+                    key: "{emoji}",
+                    text: emoji.clone(),
                     appearance: Appearance::Secondary,
                     onpress: move |_| {
-                        cx.props.onselect.call(emoji.0.clone());
+                        cx.props.onselect.call(emoji.clone());
                     }
                 }
             }
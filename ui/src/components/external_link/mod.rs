@@ -0,0 +1,89 @@
+use common::icons::outline::Shape as Icon;
+use common::language::get_local_text;
+use dioxus::prelude::*;
+use kit::components::message::sanitize::is_safe_url;
+use kit::elements::{button::Button, Appearance};
+use kit::layout::modal::Modal;
+use tracing::log;
+
+// links in messages only have a `data-href`, see kit::components::message::sanitize
+const LINK_CLICK_SCRIPT: &str = r#"
+document.addEventListener("click", (event) => {
+    const link = event.target.closest("a[data-href]");
+    if (link) {
+        event.preventDefault();
+        event.stopPropagation();
+        dioxus.send(link.getAttribute("data-href"));
+    }
+}, true);
+"#;
+
+/// Asks before a link from a message is opened in the browser.
+#[allow(non_snake_case)]
+pub fn ExternalLinkConfirmation(cx: Scope) -> Element {
+    let eval = use_eval(cx);
+    let pending_link: &UseState<Option<String>> = use_state(cx, || None);
+
+    use_future(cx, (), |_| {
+        to_owned![eval, pending_link];
+        async move {
+            let eval = match eval(LINK_CLICK_SCRIPT) {
+                Ok(r) => r,
+                Err(e) => {
+                    log::error!("failed to listen for link clicks: {:?}", e);
+                    return;
+                }
+            };
+            while let Ok(value) = eval.recv().await {
+                if let Some(url) = value.as_str().filter(|url| is_safe_url(url)) {
+                    pending_link.set(Some(url.to_string()));
+                }
+            }
+        }
+    });
+
+    let url = match pending_link.get() {
+        Some(url) => url.clone(),
+        None => return None,
+    };
+
+    cx.render(rsx!(Modal {
+        open: true,
+        onclose: move |_| pending_link.set(None),
+        transparent: false,
+        close_on_click_inside_modal: false,
+        with_title: get_local_text("external-link.title"),
+        div {
+            class: "external-link-confirmation",
+            aria_label: "external-link-confirmation",
+            p {
+                get_local_text("external-link.description")
+            },
+            p {
+                class: "external-link-url",
+                aria_label: "external-link-url",
+                "{url}"
+            },
+            div {
+                class: "button-container",
+                Button {
+                    text: get_local_text("uplink.cancel"),
+                    aria_label: "external-link-cancel".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| pending_link.set(None),
+                },
+                Button {
+                    text: get_local_text("external-link.open"),
+                    aria_label: "external-link-open".into(),
+                    icon: Icon::ArrowTopRightOnSquare,
+                    onpress: move |_| {
+                        if let Err(e) = open::that(&url) {
+                            log::error!("failed to open link: {e}");
+                        }
+                        pending_link.set(None);
+                    },
+                },
+            }
+        }
+    }))
+}
@@ -1,24 +1,44 @@
 use std::path::PathBuf;
 
 use common::{
-    icons, language::get_local_text_args_builder, state::State, warp_runner::thumbnail_to_base64,
+    icons,
+    language::{get_local_text, get_local_text_args_builder},
+    state::State,
+    utils::{image_edit, media_compression::Compression},
+    warp_runner::thumbnail_to_base64,
     MAX_FILES_PER_MESSAGE,
 };
 use dioxus::prelude::*;
-use kit::components::embeds::file_embed::FileEmbed;
+use kit::{
+    components::embeds::file_embed::FileEmbed,
+    elements::{
+        button::Button,
+        select::Select,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+};
 use uuid::Uuid;
 use warp::raygun::Location;
 
+use super::image_editor::ImageEditor;
+use crate::components::settings::sub_pages::files::compression_label;
+
 #[derive(Props)]
 pub struct AttachmentProps<'a> {
     pub chat_id: Uuid,
     pub files_to_attach: Vec<Location>,
     pub on_remove: EventHandler<'a, Vec<Location>>,
+    /// called with the attachments after an image was replaced with an edited copy
+    pub on_edit: EventHandler<'a, Vec<Location>>,
+    pub compression: Compression,
+    pub on_compression: EventHandler<'a, Compression>,
 }
 
 #[allow(non_snake_case)]
 pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
     let state = use_shared_state::<State>(cx)?;
+    let editing = use_state(cx, || None::<PathBuf>);
     let files_attached_to_send = cx.props.files_to_attach.clone();
     let files_attached_to_send3 = files_attached_to_send;
 
@@ -57,20 +77,42 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
             ),
         };
 
-        rsx!(FileEmbed {
-            filename: filename,
-            filepath: filepath,
-            remote: false,
-            is_from_attachments: true,
-            thumbnail: thumbnail,
-            button_icon: icons::outline::Shape::Minus,
-            on_press: move |pathbuf: Option<PathBuf>| {
-                if pathbuf.is_none() {
-                    let mut attachments = cx.props.files_to_attach.clone();
-                    attachments.retain(|location2| location2 != location);
-                    cx.props.on_remove.call(attachments);
-                }
+        // only images on disk can be edited, files from storage were already uploaded
+        let editable =
+            matches!(location, Location::Disk { .. }) && image_edit::is_editable(&filepath);
+        let edit_path = filepath.clone();
+
+        rsx!(div {
+            class: "attachment",
+            FileEmbed {
+                filename: filename,
+                filepath: filepath,
+                remote: false,
+                is_from_attachments: true,
+                thumbnail: thumbnail,
+                button_icon: icons::outline::Shape::Minus,
+                on_press: move |pathbuf: Option<PathBuf>| {
+                    if pathbuf.is_none() {
+                        let mut attachments = cx.props.files_to_attach.clone();
+                        attachments.retain(|location2| location2 != location);
+                        cx.props.on_remove.call(attachments);
+                    }
+                },
             },
+            editable.then(|| rsx!(div {
+                class: "attachment-edit",
+                Button {
+                    icon: icons::outline::Shape::PencilSquare,
+                    aria_label: "attachment-edit-image".into(),
+                    appearance: Appearance::Secondary,
+                    small: true,
+                    onpress: move |_| editing.set(Some(edit_path.clone())),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Bottom,
+                        text: get_local_text("image-editor.edit"),
+                    })),
+                }
+            }))
         })
     })));
 
@@ -79,10 +121,26 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
     if attachments_vec.is_empty() {
         return None;
     }
+    // files from storage were uploaded already
+    let can_compress = attachments_vec
+        .iter()
+        .any(|location| matches!(location, Location::Disk { .. }));
 
     cx.render(rsx!(div {
         id: "compose-attachments",
         aria_label: "compose-attachments",
+            editing.get().clone().map(|path| rsx!(ImageEditor {
+                path: path.clone(),
+                on_cancel: move |_| editing.set(None),
+                on_save: move |edited: PathBuf| {
+                    let attachments = cx.props.files_to_attach.iter().map(|location| match location {
+                        Location::Disk { path: p } if *p == path => Location::Disk { path: edited.clone() },
+                        location => location.clone(),
+                    }).collect();
+                    cx.props.on_edit.call(attachments);
+                    editing.set(None);
+                },
+            })),
             div {
                 id: "attachments-error",
                 if attachments_vec.len() >= MAX_FILES_PER_MESSAGE {
@@ -99,6 +157,20 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
                     })
                 }
             attachments
-            }
+            },
+            can_compress.then(|| rsx!(div {
+                class: "attachments-compression",
+                aria_label: "attachments-compression",
+                label { get_local_text("settings-files.compression") },
+                Select {
+                    initial_value: compression_label(cx.props.compression),
+                    options: Compression::ALL.into_iter().map(compression_label).collect(),
+                    onselect: move |label: String| {
+                        if let Some(compression) = Compression::ALL.into_iter().find(|c| compression_label(*c) == label) {
+                            cx.props.on_compression.call(compression);
+                        }
+                    }
+                }
+            }))
     }))
 }
@@ -0,0 +1,413 @@
+use std::path::PathBuf;
+
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    utils::image_edit::{self, Edit, Rect, Rotation},
+    STATIC_ARGS,
+};
+use dioxus::prelude::*;
+use kit::{
+    elements::{
+        button::Button,
+        input::{Input, Options},
+        loader::Loader,
+        range::Range,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+use uuid::Uuid;
+
+use tracing::log;
+
+// the preview is scaled down to fit into the modal
+const PREVIEW_WIDTH: u32 = 640;
+const PREVIEW_HEIGHT: u32 = 420;
+const COLORS: [[u8; 3]; 4] = [[239, 68, 68], [250, 204, 21], [255, 255, 255], [0, 0, 0]];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tool {
+    Crop,
+    Blur,
+    Redact,
+    Arrow,
+    Text,
+}
+
+impl Tool {
+    fn icon(&self) -> Icon {
+        match self {
+            Tool::Crop => Icon::Scissors,
+            Tool::Blur => Icon::EyeSlash,
+            Tool::Redact => Icon::Backspace,
+            Tool::Arrow => Icon::ArrowLongRight,
+            Tool::Text => Icon::Language,
+        }
+    }
+
+    fn id(&self) -> &'static str {
+        match self {
+            Tool::Crop => "crop",
+            Tool::Blur => "blur",
+            Tool::Redact => "redact",
+            Tool::Arrow => "arrow",
+            Tool::Text => "text",
+        }
+    }
+
+    fn label(&self) -> String {
+        get_local_text(&format!("image-editor.{}", self.id()))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Preview {
+    src: String,
+    width: u32,
+    height: u32,
+    // the size of the edited image, which the preview is a scaled down copy of
+    image_width: u32,
+    image_height: u32,
+}
+
+impl Preview {
+    // from a point on the preview to a pixel of the image
+    fn to_image(&self, (x, y): (f64, f64)) -> (u32, u32) {
+        let x = x.clamp(0.0, self.width as f64) * self.image_width as f64 / self.width as f64;
+        let y = y.clamp(0.0, self.height as f64) * self.image_height as f64 / self.height as f64;
+        (x.round() as u32, y.round() as u32)
+    }
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    path: PathBuf,
+    on_cancel: EventHandler<'a>,
+    /// called with the path of the edited copy, the original isn't changed
+    on_save: EventHandler<'a, PathBuf>,
+}
+
+#[allow(non_snake_case)]
+pub fn ImageEditor<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let edits = use_state(cx, Vec::<Edit>::new);
+    let tool = use_state(cx, || Tool::Crop);
+    let color = use_state(cx, || COLORS[0]);
+    let text = use_state(cx, String::new);
+    // in percent of the edited image, applied when saving
+    let size = use_state(cx, || 100.0_f32);
+    // the corners of the selection on the preview, while the mouse is held down
+    let drag = use_state(cx, || None::<((f64, f64), (f64, f64))>);
+    let saving = use_state(cx, || false);
+    let failed = use_state(cx, || false);
+
+    // set once the edited copy is written, the handler can't be called from the task
+    let saved = use_state(cx, || None::<PathBuf>);
+
+    if let Some(path) = saved.get() {
+        cx.props.on_save.call(path.clone());
+        saved.set(None);
+    }
+
+    // the whole image is edited again after each change, so undoing is just dropping an edit
+    let preview = use_future(
+        cx,
+        (&cx.props.path, edits.get()),
+        |(path, edits)| async move {
+            let r = tokio::task::spawn_blocking(move || {
+                let image = edits
+                    .iter()
+                    .fold(image_edit::open(&path)?, image_edit::apply);
+                let (src, width, height) =
+                    image_edit::preview(&image, PREVIEW_WIDTH, PREVIEW_HEIGHT)?;
+                anyhow::Ok(Preview {
+                    src,
+                    width,
+                    height,
+                    image_width: image.width(),
+                    image_height: image.height(),
+                })
+            })
+            .await;
+            match r {
+                Ok(Ok(preview)) => Some(preview),
+                Ok(Err(e)) => {
+                    log::error!("failed to edit image: {e}");
+                    None
+                }
+                Err(e) => {
+                    log::error!("failed to edit image: {e}");
+                    None
+                }
+            }
+        },
+    );
+    let current = preview.value().cloned().flatten();
+
+    let push = move |edit: Edit| {
+        let mut new_edits = edits.get().clone();
+        new_edits.push(edit);
+        edits.set(new_edits);
+    };
+
+    let finish_drag = {
+        let current = current.clone();
+        move |end: (f64, f64)| {
+            let (Some((start, _)), Some(current)) = (*drag.get(), current.as_ref()) else {
+                return;
+            };
+            drag.set(None);
+            let (from, to) = (current.to_image(start), current.to_image(end));
+            let rect = Rect::from_corners(from, to);
+            // a click, rather than a drag, only places text
+            let is_click = (start.0 - end.0).abs() < 3.0 && (start.1 - end.1).abs() < 3.0;
+            let edit = match *tool.get() {
+                Tool::Text if !text.trim().is_empty() => Edit::Text {
+                    at: from,
+                    text: text.get().clone(),
+                    color: *color.get(),
+                },
+                _ if is_click => return,
+                Tool::Crop => Edit::Crop(rect),
+                Tool::Blur => Edit::Blur(rect),
+                Tool::Redact => Edit::Redact(rect),
+                Tool::Arrow => Edit::Arrow {
+                    from,
+                    to,
+                    color: *color.get(),
+                },
+                Tool::Text => return,
+            };
+            push(edit);
+        }
+    };
+
+    let selection = drag.get().map(|((x1, y1), (x2, y2))| {
+        if *tool.get() == Tool::Arrow {
+            rsx!(svg {
+                class: "image-editor-selection",
+                line {
+                    x1: "{x1}",
+                    y1: "{y1}",
+                    x2: "{x2}",
+                    y2: "{y2}",
+                    stroke: "var(--primary)",
+                    stroke_width: "3",
+                }
+            })
+        } else {
+            rsx!(div {
+                class: "image-editor-selection image-editor-rect",
+                left: "{x1.min(x2)}px",
+                top: "{y1.min(y2)}px",
+                width: "{(x1 - x2).abs()}px",
+                height: "{(y1 - y2).abs()}px",
+            })
+        }
+    });
+
+    let resized = current.as_ref().map(|current| {
+        let scale = *size.get() / 100.0;
+        (
+            ((current.image_width as f32 * scale).round() as u32).max(1),
+            ((current.image_height as f32 * scale).round() as u32).max(1),
+        )
+    });
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        with_title: get_local_text("image-editor"),
+        onclose: move |_| cx.props.on_cancel.call(()),
+        div {
+            id: "image-editor",
+            aria_label: "image-editor",
+            div {
+                class: "image-editor-toolbar",
+                [Tool::Crop, Tool::Blur, Tool::Redact, Tool::Arrow, Tool::Text].into_iter().map(|t| rsx!(Button {
+                    key: "{t.id()}",
+                    icon: t.icon(),
+                    aria_label: format!("image-editor-{}", t.id()),
+                    appearance: if *tool.get() == t { Appearance::Primary } else { Appearance::Secondary },
+                    onpress: move |_| tool.set(t),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: t.label(),
+                    })),
+                })),
+                div { class: "image-editor-divider" },
+                Button {
+                    icon: Icon::ArrowUturnLeft,
+                    aria_label: "image-editor-rotate-left".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| push(Edit::Rotate(Rotation::ThreeQuarters)),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: get_local_text("image-editor.rotate-left"),
+                    })),
+                },
+                Button {
+                    icon: Icon::ArrowUturnRight,
+                    aria_label: "image-editor-rotate-right".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| push(Edit::Rotate(Rotation::Quarter)),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: get_local_text("image-editor.rotate-right"),
+                    })),
+                },
+                Button {
+                    icon: Icon::ArrowPath,
+                    aria_label: "image-editor-undo".into(),
+                    appearance: Appearance::Secondary,
+                    disabled: edits.is_empty(),
+                    onpress: move |_| {
+                        let mut new_edits = edits.get().clone();
+                        new_edits.pop();
+                        edits.set(new_edits);
+                    },
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: get_local_text("image-editor.undo"),
+                    })),
+                },
+            },
+            matches!(*tool.get(), Tool::Arrow | Tool::Text).then(|| rsx!(div {
+                class: "image-editor-toolbar",
+                COLORS.into_iter().map(|c| {
+                    let [r, g, b] = c;
+                    rsx!(div {
+                        key: "{r}-{g}-{b}",
+                        class: format_args!("image-editor-color {}", if *color.get() == c { "selected" } else { "" }),
+                        aria_label: "image-editor-color",
+                        background: "rgb({r}, {g}, {b})",
+                        onclick: move |_| color.set(c),
+                    })
+                }),
+                (*tool.get() == Tool::Text).then(|| rsx!(Input {
+                    id: "image-editor-text".to_owned(),
+                    aria_label: "image-editor-text".into(),
+                    placeholder: get_local_text("image-editor.text-placeholder"),
+                    max_length: 200,
+                    options: Options {
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, _): (String, bool)| text.set(val),
+                }))
+            })),
+            match current.as_ref() {
+                Some(current) => rsx!(div {
+                    class: "image-editor-canvas",
+                    width: "{current.width}px",
+                    height: "{current.height}px",
+                    img {
+                        src: "{current.src}",
+                        draggable: "false",
+                    },
+                    div {
+                        class: "image-editor-overlay",
+                        aria_label: "image-editor-overlay",
+                        onmousedown: move |e| {
+                            let point = e.element_coordinates();
+                            drag.set(Some(((point.x, point.y), (point.x, point.y))));
+                        },
+                        onmousemove: move |e| {
+                            if let Some((start, _)) = *drag.get() {
+                                let point = e.element_coordinates();
+                                drag.set(Some((start, (point.x, point.y))));
+                            }
+                        },
+                        onmouseup: move |e| {
+                            let point = e.element_coordinates();
+                            finish_drag((point.x, point.y));
+                        },
+                        onmouseleave: move |_| drag.set(None),
+                    },
+                    selection
+                }),
+                None if preview.value().is_some() => rsx!(p {
+                    class: "error",
+                    get_local_text("image-editor.failed"),
+                }),
+                None => rsx!(div {
+                    class: "image-editor-canvas image-editor-loading",
+                    Loader { spinning: true },
+                }),
+            },
+            div {
+                class: "image-editor-size",
+                label {
+                    get_local_text("image-editor.size"),
+                    resized.map(|(width, height)| rsx!(span { " {width} × {height}" }))
+                },
+                Range {
+                    aria_label: "image-editor-size".into(),
+                    initial_value: 100.0,
+                    min: 10.0,
+                    max: 100.0,
+                    step: 5.0,
+                    onchange: move |value: f32| size.set(value),
+                }
+            },
+            failed.then(|| rsx!(p {
+                class: "error",
+                get_local_text("image-editor.failed"),
+            })),
+            div {
+                class: "image-editor-actions",
+                Button {
+                    text: get_local_text("uplink.cancel"),
+                    aria_label: "image-editor-cancel".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| cx.props.on_cancel.call(()),
+                },
+                Button {
+                    text: get_local_text("image-editor.save"),
+                    aria_label: "image-editor-save".into(),
+                    icon: Icon::Check,
+                    loading: *saving.get(),
+                    disabled: *saving.get() || current.is_none(),
+                    onpress: move |_| {
+                        let mut all_edits = edits.get().clone();
+                        if let Some((width, height)) = resized {
+                            all_edits.push(Edit::Resize { width, height });
+                        }
+                        saving.set(true);
+                        failed.set(false);
+                        cx.spawn({
+                            to_owned![saving, failed, saved];
+                            let path = cx.props.path.clone();
+                            async move {
+                                // a new directory keeps the file name, which is what the attachment is shown as
+                                let dir = STATIC_ARGS.temp_files.join(Uuid::new_v4().to_string());
+                                let dest = dir.join(path.file_name().unwrap_or_default());
+                                let r = tokio::task::spawn_blocking({
+                                    let dest = dest.clone();
+                                    move || {
+                                        std::fs::create_dir_all(&dir)?;
+                                        image_edit::edit_file(&path, &all_edits, &dest)
+                                    }
+                                })
+                                .await;
+                                saving.set(false);
+                                match r {
+                                    Ok(Ok(())) => saved.set(Some(dest)),
+                                    Ok(Err(e)) => {
+                                        log::error!("failed to save edited image: {e}");
+                                        failed.set(true);
+                                    }
+                                    Err(e) => {
+                                        log::error!("failed to save edited image: {e}");
+                                        failed.set(true);
+                                    }
+                                }
+                            }
+                        });
+                    },
+                }
+            }
+        }
+    }))
+}
@@ -1,2 +1,3 @@
 pub mod attachments;
+pub mod image_editor;
 pub mod upload_progress_bar;
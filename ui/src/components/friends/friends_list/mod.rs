@@ -24,7 +24,7 @@ use kit::{
 use common::{get_images_dir, icons::outline::Shape as Icon, language::get_local_text_with_args};
 use common::{language::get_local_text, state::Identity};
 use common::{
-    state::{Action, Chat, State},
+    state::{utils::typed_lines, Action, Chat, State},
     warp_runner::{MultiPassCmd, RayGunCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
@@ -494,7 +494,7 @@ pub fn ShareFriendsModal(cx: Scope<FriendProps>) -> Element {
                     None => State::join_usernames(&other_participants)
                 };
                 let unwrapped_message = match chat.messages.iter().last() {Some(m) => m.inner.clone(),None => raygun::Message::default()};
-                let subtext_val = match unwrapped_message.lines().iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
+                let subtext_val = match typed_lines(&unwrapped_message.lines()).iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
                     Some(v) => format_text(v, state.read().ui.should_transform_markdown_text(), state.read().ui.should_transform_ascii_emojis(), Some((&state.read(), &chat.id, true))),
                     _ => match &unwrapped_message.attachments()[..] {
                         [] => get_local_text("sidebar.chat-new"),
@@ -1,11 +1,14 @@
+pub mod code_copy;
 pub mod community;
 pub mod crop_image_tool;
 pub mod debug_logger;
 pub mod emoji_group;
+pub mod external_link;
 pub mod file_transfer;
 pub mod files;
 pub mod friends;
 pub mod media;
+pub mod notification_center;
 pub mod settings;
 pub mod shortcuts;
 pub mod toast;
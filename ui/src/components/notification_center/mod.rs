@@ -0,0 +1,159 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::get_local_text;
+use common::notifications::NOTIFICATION_LISTENER;
+use common::state::notifications::{NotificationEntry, NotificationEntryKind};
+use common::state::{Action, State};
+use dioxus::prelude::*;
+use kit::elements::{button::Button, Appearance};
+use tracing::log;
+use uuid::Uuid;
+
+use crate::utils::format_timestamp::format_timestamp_timeago;
+
+fn get_entry_icon(kind: NotificationEntryKind) -> Icon {
+    match kind {
+        NotificationEntryKind::Mention => Icon::AtSymbol,
+        NotificationEntryKind::ThreadReply => Icon::ChatBubbleLeftRight,
+        NotificationEntryKind::FriendRequest => Icon::UserPlus,
+        NotificationEntryKind::MissedCall => Icon::PhoneXMark,
+        NotificationEntryKind::Update => Icon::ArrowDownCircle,
+    }
+}
+
+fn get_group_name(state: &State, conversation_id: Option<Uuid>) -> String {
+    let chat = match conversation_id.and_then(|id| state.get_chat_by_id(id)) {
+        Some(chat) => chat,
+        None => return get_local_text("notification-center.general"),
+    };
+    chat.conversation_name.clone().unwrap_or_else(|| {
+        State::join_usernames(&state.remove_self(&state.chat_participants(&chat)))
+    })
+}
+
+/// A bell in the titlebar which opens a panel listing past notifications, grouped by conversation.
+#[allow(non_snake_case)]
+pub fn NotificationCenter(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let show_panel = use_state(cx, || false);
+
+    let unread = state.read().ui.notification_history.unread_count();
+    let has_entries = !state.read().ui.notification_history.is_empty();
+    let active_language = state.read().settings.language_id();
+    let groups: Vec<(String, Vec<NotificationEntry>)> = {
+        let state = state.read();
+        state
+            .ui
+            .notification_history
+            .grouped()
+            .into_iter()
+            .map(|(id, entries)| {
+                (
+                    get_group_name(&state, id),
+                    entries.into_iter().cloned().collect(),
+                )
+            })
+            .collect()
+    };
+
+    cx.render(rsx!(
+        div {
+            id: "notification-center",
+            aria_label: "notification-center",
+            Button {
+                aria_label: "notification-center-button".into(),
+                icon: if unread > 0 { Icon::BellAlert } else { Icon::Bell },
+                appearance: Appearance::Transparent,
+                with_badge: if unread > 0 {
+                    unread.to_string()
+                } else {
+                    "".into()
+                },
+                onpress: move |_| show_panel.set(!show_panel.get()),
+            },
+            show_panel.get().then(|| rsx!(
+                div {
+                    class: "notification-center-panel",
+                    aria_label: "notification-center-panel",
+                    div {
+                        class: "notification-center-header",
+                        p {
+                            get_local_text("notification-center.title")
+                        },
+                        Button {
+                            aria_label: "notification-center-mark-all-read".into(),
+                            icon: Icon::Check,
+                            appearance: Appearance::Transparent,
+                            small: true,
+                            disabled: unread == 0,
+                            text: get_local_text("notification-center.mark-all-read"),
+                            onpress: move |_| {
+                                state.write().mutate(Action::MarkAllNotificationsRead);
+                            }
+                        },
+                        Button {
+                            aria_label: "notification-center-clear".into(),
+                            icon: Icon::Trash,
+                            appearance: Appearance::Transparent,
+                            small: true,
+                            disabled: !has_entries,
+                            onpress: move |_| {
+                                state.write().mutate(Action::ClearNotificationHistory);
+                            }
+                        },
+                    },
+                    if !has_entries {
+                        rsx!(p {
+                            class: "notification-center-empty",
+                            get_local_text("notification-center.empty")
+                        })
+                    }
+                    groups.into_iter().map(|(name, entries)| rsx!(
+                        div {
+                            class: "notification-center-group",
+                            label {
+                                "{name}"
+                            },
+                            entries.into_iter().map(|entry| {
+                                let timestamp = format_timestamp_timeago(entry.date, &active_language);
+                                let NotificationEntry { id, kind, title, content, action, read, .. } = entry;
+                                rsx!(
+                                    div {
+                                        key: "{id}",
+                                        class: format_args!("notification-center-entry {}", if read { "" } else { "unread" }),
+                                        aria_label: "notification-center-entry",
+                                        onclick: move |_| {
+                                            state.write().mutate(Action::MarkNotificationRead(id));
+                                            if let Err(e) = NOTIFICATION_LISTENER.tx.send(action.clone()) {
+                                                log::error!("failed to send notification action {}", e);
+                                            }
+                                            show_panel.set(false);
+                                        },
+                                        IconElement {
+                                            icon: get_entry_icon(kind),
+                                        },
+                                        div {
+                                            class: "notification-center-entry-content",
+                                            p {
+                                                class: "notification-center-entry-title",
+                                                "{title}"
+                                            },
+                                            p {
+                                                class: "notification-center-entry-text",
+                                                "{content}"
+                                            },
+                                            p {
+                                                class: "notification-center-entry-time",
+                                                "{timestamp}"
+                                            }
+                                        }
+                                    }
+                                )
+                            })
+                        }
+                    ))
+                }
+            ))
+        }
+    ))
+}
@@ -22,8 +22,8 @@ pub enum Page {
     Extensions,
     General,
     Messages,
-    //Files,
-    //Privacy,
+    Files,
+    Privacy,
     Keybinds,
     Profile,
     Notifications,
@@ -54,11 +54,11 @@ impl FromStr for Page {
             "audio" => Ok(Page::Audio),
             "developer" => Ok(Page::Developer),
             "extensions" => Ok(Page::Extensions),
-            //"files" => Ok(Page::Files),
+            "files" => Ok(Page::Files),
             "general" => Ok(Page::General),
             "messages" => Ok(Page::Messages),
             "keybinds" => Ok(Page::Keybinds),
-            //"privacy" => Ok(Page::Privacy),
+            "privacy" => Ok(Page::Privacy),
             "profile" => Ok(Page::Profile),
             "notifications" => Ok(Page::Notifications),
             "accessibility" => Ok(Page::Accessibility),
@@ -116,18 +116,18 @@ pub fn Sidebar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         icon: Icon::MusicalNote,
         ..UIRoute::default()
     };
-    /*let privacy = UIRoute {
+    let privacy = UIRoute {
         to: "privacy",
         name: get_local_text("settings.privacy"),
         icon: Icon::LockClosed,
         ..UIRoute::default()
-    };*/
-    /*let files = UIRoute {
+    };
+    let files = UIRoute {
         to: "files",
         name: get_local_text("settings.files"),
         icon: Icon::Folder,
         ..UIRoute::default()
-    };*/
+    };
     let extensions = UIRoute {
         to: "extensions",
         name: get_local_text("settings.extensions"),
@@ -173,9 +173,9 @@ pub fn Sidebar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
 
     let mut routes = vec![profile, general, messages];
     // To control order of routes, add them here.
-    // routes.push(privacy);
+    routes.push(privacy);
     routes.push(audio);
-    // routes.push(files);
+    routes.push(files);
     routes.push(extensions);
     routes.push(keybinds);
     routes.push(accessibility);
@@ -33,7 +33,7 @@ pub fn DeveloperSettings(cx: Scope) -> Element {
                 let warp_cmd_tx = WARP_CMD_CH.tx.clone();
                 let (tx, rx) = oneshot::channel::<Result<(), warp::error::Error>>();
                 let compress_cmd = OtherCmd::CompressFolder {
-                    src: STATIC_ARGS.uplink_path.clone(),
+                    src: STATIC_ARGS.uplink_path(),
                     dest,
                     rsp: tx,
                 };
@@ -119,7 +119,7 @@ pub fn DeveloperSettings(cx: Scope) -> Element {
                     appearance: Appearance::Secondary,
                     icon: Icon::FolderOpen,
                     onpress: |_| {
-                        let _ = opener::open(&STATIC_ARGS.uplink_path);
+                        let _ = opener::open(STATIC_ARGS.uplink_path());
                     }
                 }
             },
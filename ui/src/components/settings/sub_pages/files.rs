@@ -1,20 +1,42 @@
 use common::language::get_local_text;
+use common::state::{action::ConfigAction, Action, State};
+use common::utils::media_compression::Compression;
 use dioxus::prelude::*;
 #[allow(unused_imports)]
-use kit::elements::{button::Button, switch::Switch};
+use kit::elements::{button::Button, select::Select, switch::Switch};
 use tracing::log;
 
 use crate::components::settings::SettingSection;
 
-#[allow(dead_code)]
+pub fn compression_label(compression: Compression) -> String {
+    get_local_text(&format!("settings-files.compression-{compression}"))
+}
+
 #[allow(non_snake_case)]
 pub fn FilesSettings(cx: Scope) -> Element {
     log::trace!("Files settings page rendered.");
+    let state = use_shared_state::<State>(cx)?;
+    let compression = state.read().configuration.files.compression;
+
     cx.render(rsx!(
         div {
             id: "settings-files",
             aria_label: "settings-files",
             SettingSection {
+                aria_label: "compression-section".into(),
+                section_label: get_local_text("settings-files.compression"),
+                section_description: get_local_text("settings-files.compression-description"),
+                Select {
+                    initial_value: compression_label(compression),
+                    options: Compression::ALL.into_iter().map(compression_label).collect(),
+                    onselect: move |label: String| {
+                        if let Some(compression) = Compression::ALL.into_iter().find(|c| compression_label(*c) == label) {
+                            state.write().mutate(Action::Config(ConfigAction::SetCompression(compression)));
+                        }
+                    }
+                }
+            },
+            /*SettingSection {
                 aria_label: "local-sync-section".into(),
                 section_label: get_local_text("settings-files.local-sync"),
                 section_description: get_local_text("settings-files.local-sync-description"),
@@ -22,7 +44,7 @@ pub fn FilesSettings(cx: Scope) -> Element {
 
                 }
             },
-            /*SettingSection {
+            SettingSection {
                 section_label: get_local_text("settings-files.open-sync-folder"),
                 section_description: get_local_text("settings-files.open-sync-folder-description"),
                 Button {
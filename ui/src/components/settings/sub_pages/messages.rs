@@ -1,17 +1,31 @@
 use common::{
+    icons::outline::Shape as Icon,
     language::get_local_text,
-    state::{Action, State},
+    state::{action::ConfigAction, Action, State},
+    warp_runner::gifs::GifService,
+    STATIC_ARGS,
 };
 use dioxus::prelude::*;
-use kit::elements::switch::Switch;
+use kit::elements::{
+    button::Button,
+    input::{Input, Options},
+    select::Select,
+    switch::Switch,
+    tooltip::{ArrowPosition, Tooltip},
+};
 use tracing::log;
 
 use crate::components::settings::SettingSection;
 
+fn gif_service_label(service: GifService) -> String {
+    get_local_text(&format!("settings-messages.gif-service-{service}"))
+}
+
 #[allow(non_snake_case)]
 pub fn Messages(cx: Scope) -> Element {
     log::trace!("Messages settings page rendered.");
     let state = use_shared_state::<State>(cx)?;
+    let gifs = state.read().configuration.gifs.clone();
     cx.render(rsx!(
         div {
             id: "settings-messages",
@@ -37,6 +51,45 @@ pub fn Messages(cx: Scope) -> Element {
                         state.write().mutate(Action::SetTransformMarkdownText(flag));
                     }
                 }
+            },
+            SettingSection {
+                aria_label: "gif-search-section".into(),
+                section_label: get_local_text("settings-messages.gifs"),
+                section_description: get_local_text("settings-messages.gifs-description"),
+                (gifs.service != GifService::Folder).then(|| rsx!(Input {
+                    id: "gif-api-key-input".to_owned(),
+                    aria_label: "gif-api-key-input".into(),
+                    placeholder: get_local_text("settings-messages.gif-api-key"),
+                    is_password: true,
+                    default_text: gifs.api_key.clone(),
+                    options: Options {
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(key, _): (String, bool)| {
+                        state.write().mutate(Action::Config(ConfigAction::SetGifApiKey(key)));
+                    },
+                })),
+                Select {
+                    initial_value: gif_service_label(gifs.service),
+                    options: GifService::ALL.into_iter().map(gif_service_label).collect(),
+                    onselect: move |label: String| {
+                        if let Some(service) = GifService::ALL.into_iter().find(|s| gif_service_label(*s) == label) {
+                            state.write().mutate(Action::Config(ConfigAction::SetGifService(service)));
+                        }
+                    }
+                },
+                Button {
+                    icon: Icon::FolderOpen,
+                    aria_label: "open-gif-folder-button".into(),
+                    onpress: move |_| {
+                        let _ = opener::open(&STATIC_ARGS.gifs_path);
+                    },
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Right,
+                        text: get_local_text("messages.open-gif-folder"),
+                    }))
+                },
             }
         }
     ))
@@ -1,8 +1,7 @@
-/*
-use common::icons::outline::Shape as Icon;
 use common::language::get_local_text;
+use common::state::{action::ConfigAction, Action, State};
 use dioxus::prelude::*;
-use kit::elements::{button::Button, Appearance};
+use kit::elements::switch::Switch;
 use tracing::log;
 
 use crate::components::settings::SettingSection;
@@ -10,21 +9,35 @@ use crate::components::settings::SettingSection;
 #[allow(non_snake_case)]
 pub fn PrivacySettings(cx: Scope) -> Element {
     log::trace!("Privacy settings page rendered.");
+    let state = use_shared_state::<State>(cx)?;
+    let privacy = state.read().configuration.privacy;
+
     cx.render(rsx!(
         div {
             id: "settings-privacy",
             aria_label: "settings-privacy",
             SettingSection {
-                section_label: get_local_text("settings-privacy.backup-recovery-phrase"),
-                section_description: get_local_text("settings-privacy.backup-phrase-description"),
-                Button {
-                    text: get_local_text("settings-privacy.backup-phrase"),
-                    aria_label: "backup-phrase-button".into(),
-                    appearance: Appearance::Secondary,
-                    icon: Icon::DocumentText,
+                aria_label: "read-receipts-section".into(),
+                section_label: get_local_text("settings-privacy.read-receipts"),
+                section_description: get_local_text("settings-privacy.read-receipts-description"),
+                Switch {
+                    active: privacy.send_read_receipts,
+                    onflipped: move |flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetSendReadReceipts(flag)));
+                    }
+                }
+            },
+            SettingSection {
+                aria_label: "link-previews-section".into(),
+                section_label: get_local_text("settings-privacy.link-previews"),
+                section_description: get_local_text("settings-privacy.link-previews-description"),
+                Switch {
+                    active: privacy.link_previews,
+                    onflipped: move |flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetLinkPreviews(flag)));
+                    }
                 }
             },
         }
     ))
 }
-*/
@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+
+use common::icons::outline::Shape as Icon;
+use common::language::get_local_text;
+use common::state::{Action, State, ToastNotification};
+use common::warp_runner::{OtherCmd, WarpCmd};
+use common::WARP_CMD_CH;
+use dioxus::prelude::*;
+use futures::channel::oneshot;
+use futures::StreamExt;
+use kit::elements::{
+    button::Button,
+    input::{Input, Options, Validation},
+    Appearance,
+};
+use rfd::FileDialog;
+use tracing::log;
+
+use crate::components::settings::SettingSection;
+
+struct Cmd {
+    dest: PathBuf,
+    pin: String,
+    passphrase: String,
+}
+
+/// Saves the account and local data to a passphrase protected file which can be restored from the log in pages.
+#[allow(non_snake_case)]
+pub fn AccountBackup(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let pin = use_ref(cx, String::new);
+    let passphrase = use_ref(cx, String::new);
+    let valid_pin = use_state(cx, || false);
+    let valid = use_state(cx, || false);
+    let in_progress = use_state(cx, || false);
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<Cmd>| {
+        to_owned![state, in_progress];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            while let Some(Cmd {
+                dest,
+                pin,
+                passphrase,
+            }) = rx.next().await
+            {
+                let (tx, rx) = oneshot::channel::<Result<(), warp::error::Error>>();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::Other(OtherCmd::BackupAccount {
+                    dest,
+                    pin,
+                    passphrase,
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                    in_progress.set(false);
+                    continue;
+                }
+
+                let text = match rx.await.expect("command canceled") {
+                    Ok(_) => get_local_text("settings-profile.backup-created"),
+                    Err(e) => {
+                        log::error!("failed to create backup: {e}");
+                        get_local_text("settings-profile.backup-failed")
+                    }
+                };
+                state
+                    .write()
+                    .mutate(Action::AddToastNotification(ToastNotification::init(
+                        "".into(),
+                        text,
+                        None,
+                        2,
+                    )));
+                in_progress.set(false);
+            }
+        }
+    });
+
+    cx.render(rsx!(
+        SettingSection {
+            aria_label: "backup-account-section".into(),
+            section_label: get_local_text("settings-profile.backup"),
+            section_description: get_local_text("settings-profile.backup-description"),
+            div {
+                class: "backup-account",
+                Input {
+                    id: "backup-pin-input".to_owned(),
+                    is_password: true,
+                    icon: Icon::LockClosed,
+                    aria_label: "backup-pin-input".into(),
+                    disabled: *in_progress.get(),
+                    placeholder: get_local_text("settings-profile.backup-pin"),
+                    options: Options {
+                        with_validation: Some(Validation {
+                            min_length: Some(4),
+                            ..Default::default()
+                        }),
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, is_valid): (String, bool)| {
+                        *pin.write_silent() = val;
+                        valid_pin.set(is_valid);
+                    },
+                },
+                Input {
+                    id: "backup-passphrase-input".to_owned(),
+                    is_password: true,
+                    icon: Icon::Key,
+                    aria_label: "backup-passphrase-input".into(),
+                    disabled: *in_progress.get(),
+                    placeholder: get_local_text("settings-profile.backup-passphrase"),
+                    options: Options {
+                        with_validation: Some(Validation {
+                            min_length: Some(4),
+                            ..Default::default()
+                        }),
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, is_valid): (String, bool)| {
+                        *passphrase.write_silent() = val;
+                        valid.set(is_valid);
+                    },
+                },
+                Button {
+                    text: get_local_text("settings-profile.backup"),
+                    aria_label: "backup-account-button".into(),
+                    appearance: Appearance::Secondary,
+                    icon: Icon::ArrowDownTray,
+                    loading: *in_progress.get(),
+                    disabled: !*valid_pin.get() || !*valid.get() || *in_progress.get(),
+                    onpress: move |_| {
+                        let dest = match FileDialog::new()
+                            .set_file_name("uplink.uplinkbackup")
+                            .add_filter("Uplink Backup", &["uplinkbackup"])
+                            .save_file()
+                        {
+                            Some(d) => d,
+                            None => return,
+                        };
+                        in_progress.set(true);
+                        ch.send(Cmd {
+                            dest,
+                            pin: pin.read().clone(),
+                            passphrase: passphrase.read().clone(),
+                        });
+                    }
+                }
+            }
+        }
+    ))
+}
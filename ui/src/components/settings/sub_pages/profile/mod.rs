@@ -1,3 +1,6 @@
+mod backup;
+mod security;
+
 use std::path::PathBuf;
 
 use arboard::Clipboard;
@@ -753,6 +756,8 @@ pub fn ProfileSettings(cx: Scope) -> Element {
                         }
                     )),
                 )}
+                backup::AccountBackup {},
+                security::Security {},
                 if open_crop_image_modal_for_banner_picture.get().0 {
                     rsx!(CropRectImageModal {
                         large_thumbnail: open_crop_image_modal_for_banner_picture.1.clone(),
@@ -0,0 +1,194 @@
+use common::icons::outline::Shape as Icon;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::{action::ConfigAction, Action, State, ToastNotification};
+use common::warp_runner::{TesseractCmd, WarpCmd};
+use common::WARP_CMD_CH;
+use dioxus::prelude::*;
+use futures::channel::oneshot;
+use futures::StreamExt;
+use kit::elements::{
+    button::Button,
+    input::{Input, Options, Validation},
+    select::Select,
+    switch::Switch,
+    Appearance,
+};
+use tracing::log;
+
+use crate::components::settings::SettingSection;
+
+const AUTO_LOCK_OPTIONS: [u32; 6] = [0, 1, 5, 15, 30, 60];
+
+fn auto_lock_label(minutes: u32) -> String {
+    if minutes == 0 {
+        get_local_text("settings-profile.auto-lock-never")
+    } else {
+        get_local_text_with_args(
+            "settings-profile.auto-lock-minutes",
+            vec![("minutes", minutes.to_string())],
+        )
+    }
+}
+
+// same rules as the pin on the unlock page
+fn pin_validation() -> Validation {
+    Validation {
+        max_length: Some(32),
+        min_length: Some(4),
+        no_whitespace: true,
+        ..Default::default()
+    }
+}
+
+/// Changing the passphrase and locking the app while the user is away.
+#[allow(non_snake_case)]
+pub fn Security(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let old_passphrase = use_ref(cx, String::new);
+    let new_passphrase = use_ref(cx, String::new);
+    let valid = use_state(cx, || (false, false));
+    let in_progress = use_state(cx, || false);
+    // each Input clears its own reset flag, so they can't share one
+    let reset_old = use_state(cx, || false);
+    let reset_new = use_state(cx, || false);
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<(String, String)>| {
+        to_owned![state, in_progress, valid, reset_old, reset_new];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            while let Some((old, new)) = rx.next().await {
+                let (tx, rx) = oneshot::channel::<Result<(), warp::error::Error>>();
+                if let Err(e) =
+                    warp_cmd_tx.send(WarpCmd::Tesseract(TesseractCmd::ChangePassphrase {
+                        old,
+                        new,
+                        rsp: tx,
+                    }))
+                {
+                    log::error!("failed to send warp command: {}", e);
+                    in_progress.set(false);
+                    continue;
+                }
+
+                let text = match rx.await.expect("command canceled") {
+                    Ok(_) => {
+                        reset_old.set(true);
+                        reset_new.set(true);
+                        valid.set((false, false));
+                        get_local_text("settings-profile.passphrase-changed")
+                    }
+                    Err(warp::error::Error::DecryptionError) => {
+                        get_local_text("settings-profile.wrong-passphrase")
+                    }
+                    Err(_) => get_local_text("settings-profile.passphrase-change-failed"),
+                };
+                state
+                    .write()
+                    .mutate(Action::AddToastNotification(ToastNotification::init(
+                        "".into(),
+                        text,
+                        None,
+                        2,
+                    )));
+                in_progress.set(false);
+            }
+        }
+    });
+
+    let security = state.read().configuration.security;
+
+    cx.render(rsx!(
+        SettingSection {
+            aria_label: "change-passphrase-section".into(),
+            section_label: get_local_text("settings-profile.change-passphrase"),
+            section_description: get_local_text("settings-profile.change-passphrase-description"),
+            div {
+                class: "change-passphrase",
+                Input {
+                    id: "current-passphrase-input".to_owned(),
+                    is_password: true,
+                    icon: Icon::Key,
+                    aria_label: "current-passphrase-input".into(),
+                    disabled: *in_progress.get(),
+                    reset: reset_old.clone(),
+                    placeholder: get_local_text("settings-profile.current-passphrase"),
+                    options: Options {
+                        with_validation: Some(pin_validation()),
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, is_valid): (String, bool)| {
+                        *old_passphrase.write_silent() = val;
+                        valid.set((is_valid, valid.get().1));
+                    },
+                },
+                Input {
+                    id: "new-passphrase-input".to_owned(),
+                    is_password: true,
+                    icon: Icon::Key,
+                    aria_label: "new-passphrase-input".into(),
+                    disabled: *in_progress.get(),
+                    reset: reset_new.clone(),
+                    placeholder: get_local_text("settings-profile.new-passphrase"),
+                    options: Options {
+                        with_validation: Some(pin_validation()),
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, is_valid): (String, bool)| {
+                        *new_passphrase.write_silent() = val;
+                        valid.set((valid.get().0, is_valid));
+                    },
+                },
+                Button {
+                    text: get_local_text("settings-profile.change-passphrase"),
+                    aria_label: "change-passphrase-button".into(),
+                    appearance: Appearance::Secondary,
+                    loading: *in_progress.get(),
+                    disabled: !(valid.get().0 && valid.get().1) || *in_progress.get(),
+                    onpress: move |_| {
+                        in_progress.set(true);
+                        ch.send((old_passphrase.read().clone(), new_passphrase.read().clone()));
+                    }
+                }
+            }
+        },
+        SettingSection {
+            aria_label: "auto-lock-section".into(),
+            section_label: get_local_text("settings-profile.auto-lock"),
+            section_description: get_local_text("settings-profile.auto-lock-description"),
+            Select {
+                initial_value: auto_lock_label(security.auto_lock_minutes),
+                options: AUTO_LOCK_OPTIONS.iter().map(|m| auto_lock_label(*m)).collect(),
+                onselect: move |value: String| {
+                    if let Some(minutes) = AUTO_LOCK_OPTIONS.iter().find(|m| auto_lock_label(**m) == value) {
+                        state.write().mutate(Action::Config(ConfigAction::SetAutoLockMinutes(*minutes)));
+                    }
+                }
+            }
+        },
+        SettingSection {
+            aria_label: "lock-on-suspend-section".into(),
+            section_label: get_local_text("settings-profile.lock-on-suspend"),
+            section_description: get_local_text("settings-profile.lock-on-suspend-description"),
+            Switch {
+                active: security.lock_on_suspend,
+                onflipped: move |e| {
+                    state.write().mutate(Action::Config(ConfigAction::SetLockOnSuspend(e)));
+                }
+            }
+        },
+        SettingSection {
+            aria_label: "lock-now-section".into(),
+            section_label: get_local_text("settings-profile.lock-now"),
+            section_description: get_local_text("settings-profile.lock-now-description"),
+            Button {
+                text: get_local_text("settings-profile.lock-now"),
+                aria_label: "lock-now-button".into(),
+                appearance: Appearance::Secondary,
+                icon: Icon::LockClosed,
+                onpress: move |_| state.write().mutate(Action::LockApp),
+            }
+        }
+    ))
+}
@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use common::warp_runner::ui_adapter;
+use common::{state::chats::READ_RECEIPT_REACTION, warp_runner::ui_adapter};
 use std::collections::{HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 use warp::crypto::DID;
@@ -43,6 +43,25 @@ impl Messages {
         }
     }
 
+    /// When `own` last interacted with each user in the loaded messages: a message from them,
+    /// or a reply from `own` to them. Used to rank mention suggestions.
+    pub fn recent_interactions(&self, own: &DID) -> HashMap<DID, DateTime<Utc>> {
+        let mut interactions = HashMap::new();
+        for msg in self.all.iter() {
+            let sender = msg.inner.sender();
+            let other = if sender == *own {
+                msg.in_reply_to.as_ref().map(|(_, _, did)| did.clone())
+            } else {
+                Some(sender)
+            };
+            // messages are sorted earliest first, so later ones overwrite older interactions
+            if let Some(did) = other.filter(|did| did != own) {
+                interactions.insert(did, msg.inner.date());
+            }
+        }
+        interactions
+    }
+
     pub fn reset(&mut self) {
         let len = self.all.len();
         for msg in self
@@ -187,6 +206,41 @@ impl Messages {
         self.loaded.len() != self.all.len()
     }
 
+    /// When a message someone else sent was sent, if it is loaded.
+    pub fn received_date(&self, own: &DID, id: Uuid) -> Option<DateTime<Utc>> {
+        self.all
+            .iter()
+            .find(|msg| msg.inner.id() == id && msg.inner.sender() != *own)
+            .map(|msg| msg.inner.date())
+    }
+
+    /// The participants who read up to each message, going by the newest message each one put
+    /// their read receipt on. Nobody is listed under a message they sent.
+    pub fn seen_by(&self) -> HashMap<Uuid, Vec<DID>> {
+        let mut newest_read: HashMap<DID, Uuid> = HashMap::new();
+        // messages are sorted earliest first, so later receipts overwrite older ones
+        for msg in self.all.iter() {
+            let sender = msg.inner.sender();
+            let receipts = msg
+                .inner
+                .reactions()
+                .into_iter()
+                .filter(|(emoji, _)| emoji == READ_RECEIPT_REACTION)
+                .flat_map(|(_, users)| users);
+            for did in receipts.filter(|did| *did != sender) {
+                newest_read.insert(did, msg.inner.id());
+            }
+        }
+        let mut seen_by: HashMap<Uuid, Vec<DID>> = HashMap::new();
+        for (did, id) in newest_read {
+            seen_by.entry(id).or_default().push(did);
+        }
+        for dids in seen_by.values_mut() {
+            dids.sort_by_key(|did| did.to_string());
+        }
+        seen_by
+    }
+
     pub fn top(&self) -> Option<Uuid> {
         self.all.front().map(|x| x.inner.id())
     }
@@ -1,4 +1,4 @@
-use common::state::{self, Identity, State};
+use common::state::{self, mentions::GroupMentionPermission, Identity, State};
 use kit::components::indicator::Platform;
 use uuid::Uuid;
 use warp::{
@@ -24,6 +24,7 @@ pub struct Metadata {
     pub replying_to: Option<raygun::Message>,
     pub unreads: usize,
     pub pinned_messages: Vec<raygun::Message>,
+    pub group_mention_permission: GroupMentionPermission,
 }
 
 impl Metadata {
@@ -65,6 +66,7 @@ impl Metadata {
             replying_to: chat.replying_to.clone(),
             unreads: chat.unreads() as _,
             pinned_messages: chat.pinned_messages.clone(),
+            group_mention_permission: chat.group_mention_permission(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use common::{
-    state::{self, Identity, State},
+    state::{self, mentions::GroupMentionPermission, Identity, State},
     warp_runner::ui_adapter,
 };
 use kit::components::indicator::Platform;
@@ -58,6 +58,14 @@ impl ActiveChat {
         self.messages.times.contains_key(&id)
     }
 
+    pub fn get_message(&self, id: Uuid) -> Option<raygun::Message> {
+        self.messages
+            .all
+            .iter()
+            .find(|msg| msg.inner.id() == id)
+            .map(|msg| msg.inner.clone())
+    }
+
     pub fn metadata_changed(&self, metadata: &Metadata) -> bool {
         &self.metadata != metadata
     }
@@ -132,6 +140,25 @@ impl ActiveChat {
         self.metadata.pinned_messages.clone()
     }
 
+    pub fn group_mention_permission(&self) -> GroupMentionPermission {
+        self.metadata.group_mention_permission
+    }
+
+    // see `Chat::can_mention_group`
+    pub fn can_mention_group(&self, sender: &DID) -> bool {
+        self.conversation_type() == ConversationType::Group
+            && self
+                .metadata
+                .group_mention_permission
+                .allows(sender, self.metadata.creator.as_ref())
+    }
+
+    // only the creator may unpin the group mention setting, unpinning it would bring back the default
+    pub fn can_unpin(&self, message: &raygun::Message, user: &DID) -> bool {
+        GroupMentionPermission::from_lines(&message.lines()).is_none()
+            || self.metadata.creator.as_ref() == Some(user)
+    }
+
     pub fn unreads(&self) -> usize {
         self.metadata.unreads
     }
@@ -4,9 +4,12 @@ mod typing_info;
 pub use typing_indicator::*;
 pub use typing_info::*;
 use uuid::Uuid;
+use warp::raygun::Location;
 
 pub struct MsgChInput {
     pub msg: Vec<String>,
     pub conv_id: Uuid,
     pub replying_to: Option<Uuid>,
+    // sent instead of the attachments of the chat, which are kept
+    pub attachments: Option<Vec<Location>>,
 }
@@ -1,7 +1,7 @@
 // todo: move the MessageGroup from State to this file.
 // todo: consider building a VecDeque of MessageGroup inside of ChatData, as messages are added/removed.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use common::{
     state::{
@@ -10,6 +10,7 @@ use common::{
     },
     warp_runner::ui_adapter,
 };
+use uuid::Uuid;
 use warp::crypto::DID;
 
 // Define a struct to represent a group of messages from the same sender.
@@ -39,6 +40,9 @@ pub struct MessageGroupMsg {
     pub is_first: bool,
     pub is_last: bool,
     pub file_progress: Option<Vec<(FileLocation, FileProgression)>>,
+    pub placeholders: HashMap<FileLocation, String>,
+    // the participants who read up to this message
+    pub seen_by: Vec<DID>,
 }
 
 impl MessageGroupMsg {
@@ -56,6 +60,7 @@ pub fn create_message_groups(
     my_id: Identity,
     other_ids: Vec<Identity>,
     mut input: VecDeque<ui_adapter::Message>,
+    mut seen_by: HashMap<Uuid, Vec<DID>>,
 ) -> Vec<MessageGroup> {
     let mut messages: Vec<MessageGroup> = vec![];
     let mut other_ids = other_ids.clone();
@@ -69,11 +74,13 @@ pub fn create_message_groups(
                     && msg.in_reply_to.is_none()
                 {
                     let g = MessageGroupMsg {
+                        seen_by: seen_by.remove(&msg.inner.id()).unwrap_or_default(),
                         message: msg.clone(),
                         is_pending: false,
                         is_first: false,
                         is_last: true,
                         file_progress: None,
+                        placeholders: HashMap::new(),
                     };
                     // I really hope last() is O(1) time
                     if let Some(g) = group.messages.iter_mut().last() {
@@ -89,11 +96,13 @@ pub fn create_message_groups(
         // new group
         let mut grp = MessageGroup::new(msg.inner.sender(), &my_id.did_key());
         let g = MessageGroupMsg {
+            seen_by: seen_by.remove(&msg.inner.id()).unwrap_or_default(),
             message: msg.clone(),
             is_pending: false,
             is_first: true,
             is_last: true,
             file_progress: None,
+            placeholders: HashMap::new(),
         };
         grp.messages.push(g);
         messages.push(grp);
@@ -123,6 +132,8 @@ pub fn pending_group_messages(
                 is_first: false,
                 is_last: true,
                 file_progress: Some(msg.attachments_progress.clone().into_iter().collect()),
+                placeholders: msg.placeholders.clone(),
+                seen_by: vec![],
             };
             messages.push(g);
             continue;
@@ -133,6 +144,8 @@ pub fn pending_group_messages(
             is_first: true,
             is_last: true,
             file_progress: Some(msg.attachments_progress.clone().into_iter().collect()),
+            placeholders: msg.placeholders.clone(),
+            seen_by: vec![],
         };
         messages.push(g);
     }
@@ -0,0 +1,98 @@
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    state::{
+        bookmarks::{parse_tags, Bookmark},
+        Action, State,
+    },
+};
+use dioxus::prelude::*;
+use kit::{
+    elements::{
+        button::Button,
+        input::{Input, Options},
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // a new bookmark or one which was saved before
+    bookmark: Bookmark,
+    onclose: EventHandler<'a>,
+}
+
+/// Saves a message with an optional note and tags.
+#[allow(non_snake_case)]
+pub fn EditBookmark<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let note = use_state(cx, || cx.props.bookmark.note.clone());
+    let tags = use_state(cx, || cx.props.bookmark.tags.join(", "));
+    let id = cx.props.bookmark.message_id;
+    let is_saved = state.read().chats().bookmarks.get(&id).is_some();
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        with_title: get_local_text(if is_saved { "bookmarks.edit" } else { "bookmarks.save" }),
+        onclose: move |_| cx.props.onclose.call(()),
+        div {
+            id: "edit-bookmark",
+            aria_label: "edit-bookmark",
+            Input {
+                id: format!("bookmark-note-{id}"),
+                aria_label: "bookmark-note-input".into(),
+                focus: true,
+                placeholder: get_local_text("bookmarks.note"),
+                default_text: note.get().clone(),
+                max_length: 300,
+                options: Options {
+                    clear_on_submit: false,
+                    ..Default::default()
+                },
+                onchange: move |(val, _): (String, bool)| note.set(val),
+            },
+            Input {
+                id: format!("bookmark-tags-{id}"),
+                aria_label: "bookmark-tags-input".into(),
+                placeholder: get_local_text("bookmarks.tags"),
+                default_text: tags.get().clone(),
+                icon: Icon::Tag,
+                max_length: 200,
+                options: Options {
+                    clear_on_submit: false,
+                    ..Default::default()
+                },
+                onchange: move |(val, _): (String, bool)| tags.set(val),
+            },
+            div {
+                class: "edit-bookmark-buttons",
+                is_saved.then(|| rsx!(Button {
+                    text: get_local_text("bookmarks.remove"),
+                    aria_label: "bookmark-remove".into(),
+                    icon: Icon::BookmarkSlash,
+                    appearance: Appearance::Danger,
+                    onpress: move |_| {
+                        state.write().mutate(Action::RemoveBookmark(id));
+                        cx.props.onclose.call(());
+                    },
+                })),
+                Button {
+                    text: get_local_text("bookmarks.save-button"),
+                    aria_label: "bookmark-save".into(),
+                    icon: Icon::Bookmark,
+                    onpress: move |_| {
+                        let bookmark = Bookmark {
+                            note: note.trim().to_string(),
+                            tags: parse_tags(tags.get()),
+                            ..cx.props.bookmark.clone()
+                        };
+                        state.write().mutate(Action::SaveBookmark(bookmark));
+                        cx.props.onclose.call(());
+                    },
+                }
+            }
+        }
+    }))
+}
@@ -0,0 +1,215 @@
+mod edit;
+
+pub use edit::EditBookmark;
+
+use common::{
+    icons::outline::Shape as Icon,
+    icons::Icon as IconElement,
+    language::get_local_text,
+    state::{bookmarks::Bookmark, Action, State},
+};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use kit::{
+    components::message::ChatText,
+    elements::{
+        button::Button,
+        input::{Input, Options},
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+
+use crate::UplinkRoute;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    onclose: EventHandler<'a>,
+}
+
+/// Every saved message, across all chats.
+#[allow(non_snake_case)]
+pub fn SavedMessages<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let router = use_navigator(cx);
+    let query = use_state(cx, String::new);
+    let tag = use_state(cx, || None::<String>);
+    let editing = use_state(cx, || None::<Bookmark>);
+
+    let date_format = get_local_text("uplink.date-time-format");
+    let tags = state.read().chats().bookmarks.tags();
+    let has_bookmarks = !state.read().chats().bookmarks.is_empty();
+    let bookmarks: Vec<Bookmark> = state
+        .read()
+        .chats()
+        .bookmarks
+        .filtered(query.trim(), tag.get().as_deref())
+        .into_iter()
+        .cloned()
+        .collect();
+    let no_results = bookmarks.is_empty();
+
+    let entries = bookmarks.into_iter().map(|bookmark| {
+        let sender = state
+            .read()
+            .get_identity(&bookmark.sender)
+            .map(|id| id.username())
+            .unwrap_or_else(|| get_local_text("uplink.unknown"));
+        let chat = state.read().get_chat_by_id(bookmark.conversation_id);
+        let chat_name = match chat.as_ref() {
+            Some(chat) => match &chat.conversation_name {
+                Some(name) => name.clone(),
+                None => {
+                    let participants = state.read().chat_participants(chat);
+                    State::join_usernames(&state.read().remove_self(&participants))
+                }
+            },
+            None => get_local_text("bookmarks.unknown-chat"),
+        };
+        let can_go_to = chat.is_some() && !bookmark.deleted;
+        let date = bookmark.date.format(&date_format).to_string();
+        let id = bookmark.message_id;
+        let text = bookmark.lines.join("\n");
+        let bookmark2 = bookmark.clone();
+        rsx!(div {
+            key: "{id}",
+            class: "bookmark",
+            aria_label: "bookmark",
+            div {
+                class: "bookmark-header",
+                span { class: "bookmark-sender", "{sender}" },
+                span { class: "bookmark-chat", "{chat_name}" },
+                span { class: "bookmark-date", "{date}" },
+            },
+            bookmark.deleted.then(|| rsx!(p {
+                class: "bookmark-deleted",
+                get_local_text("bookmarks.deleted")
+            })),
+            (!text.is_empty()).then(|| rsx!(ChatText {
+                text: text,
+                remote: true,
+                pending: false,
+                state: state,
+                chat: bookmark.conversation_id,
+                markdown: state.read().ui.should_transform_markdown_text(),
+                ascii_emoji: state.read().ui.should_transform_ascii_emojis(),
+                link_preview: None,
+            })),
+            bookmark.attachments.iter().map(|name| rsx!(div {
+                class: "bookmark-attachment",
+                IconElement { icon: Icon::PaperClip },
+                span { "{name}" }
+            })),
+            (!bookmark.note.is_empty()).then(|| rsx!(p {
+                class: "bookmark-note",
+                "{bookmark.note}"
+            })),
+            div {
+                class: "bookmark-footer",
+                div {
+                    class: "bookmark-tags",
+                    bookmark.tags.iter().map(|t| {
+                        let t2 = t.clone();
+                        rsx!(span {
+                            class: "bookmark-tag",
+                            onclick: move |_| tag.set(Some(t2.clone())),
+                            "#{t}"
+                        })
+                    })
+                },
+                Button {
+                    text: get_local_text("bookmarks.go-to"),
+                    aria_label: "bookmark-go-to".into(),
+                    appearance: Appearance::Secondary,
+                    disabled: !can_go_to,
+                    onpress: move |_| {
+                        state.write().mutate(Action::GoToMessage(
+                            bookmark.conversation_id,
+                            bookmark.message_id,
+                            bookmark.date,
+                        ));
+                        if state.read().ui.is_minimal_view() {
+                            state.write().mutate(Action::SidebarHidden(true));
+                        }
+                        router.replace(UplinkRoute::ChatLayout {});
+                        cx.props.onclose.call(());
+                    },
+                },
+                Button {
+                    icon: Icon::Pencil,
+                    aria_label: "bookmark-edit".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| editing.set(Some(bookmark2.clone())),
+                },
+                Button {
+                    icon: Icon::Trash,
+                    aria_label: "bookmark-remove".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| state.write().mutate(Action::RemoveBookmark(id)),
+                },
+            }
+        })
+    });
+
+    cx.render(rsx!(
+        Modal {
+            open: true,
+            transparent: false,
+            with_title: get_local_text("bookmarks.title"),
+            onclose: move |_| cx.props.onclose.call(()),
+            div {
+                id: "saved-messages",
+                aria_label: "saved-messages",
+                has_bookmarks.then(|| rsx!(
+                    Input {
+                        id: "bookmark-search".to_owned(),
+                        aria_label: "bookmark-search".into(),
+                        placeholder: get_local_text("bookmarks.search"),
+                        icon: Icon::MagnifyingGlass,
+                        options: Options {
+                            clear_on_submit: false,
+                            with_clear_btn: true,
+                            ..Default::default()
+                        },
+                        onchange: move |(val, _): (String, bool)| query.set(val),
+                    },
+                    (!tags.is_empty()).then(|| rsx!(div {
+                        class: "bookmark-tag-filter",
+                        span {
+                            class: format_args!("bookmark-tag {}", if tag.is_none() { "selected" } else { "" }),
+                            onclick: move |_| tag.set(None),
+                            get_local_text("bookmarks.all-tags")
+                        },
+                        tags.iter().map(|t| {
+                            let selected = tag.get().as_deref() == Some(t.as_str());
+                            let t2 = t.clone();
+                            rsx!(span {
+                                key: "{t}",
+                                class: format_args!("bookmark-tag {}", if selected { "selected" } else { "" }),
+                                onclick: move |_| tag.set(Some(t2.clone())),
+                                "#{t}"
+                            })
+                        })
+                    }))
+                )),
+                if !has_bookmarks {
+                    rsx!(p {
+                        class: "bookmarks-empty",
+                        get_local_text("bookmarks.empty")
+                    })
+                } else if no_results {
+                    rsx!(p {
+                        class: "bookmarks-empty",
+                        get_local_text("bookmarks.no-results")
+                    })
+                } else {
+                    rsx!(entries)
+                }
+            }
+        },
+        editing.get().as_ref().map(|bookmark| rsx!(EditBookmark {
+            bookmark: bookmark.clone(),
+            onclose: move |_| editing.set(None),
+        }))
+    ))
+}
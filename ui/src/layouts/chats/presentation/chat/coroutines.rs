@@ -1,7 +1,7 @@
 use anyhow::bail;
 use chrono::{DateTime, Utc};
 use common::{
-    state::State,
+    state::{emoji_packs::EmojiPack, Action, State},
     warp_runner::{
         ui_adapter::{self, MessageEvent},
         FetchMessagesConfig, FetchMessagesResponse, RayGunCmd, WarpCmd, WarpEvent,
@@ -12,7 +12,30 @@ use dioxus::prelude::*;
 use futures::channel::oneshot;
 use uuid::Uuid;
 
-use crate::layouts::chats::data::{self, ChatBehavior, ChatData};
+use crate::{
+    layouts::chats::data::{self, ChatBehavior, ChatData},
+    utils::emoji_packs,
+};
+
+/// Downloads the images of the emoji packs of the active chat which aren't on the disk yet.
+pub fn sync_emoji_packs(cx: Scope, state: &UseSharedState<State>) {
+    let missing: Option<(Uuid, Vec<(Uuid, EmojiPack)>)> = state
+        .read()
+        .get_active_chat()
+        .map(|chat| (chat.id, chat.incomplete_emoji_packs()));
+    use_future(cx, &missing, |missing| {
+        to_owned![state];
+        async move {
+            let Some((conv_id, packs)) = missing.filter(|(_, packs)| !packs.is_empty()) else {
+                return;
+            };
+            for (message_id, pack) in packs {
+                emoji_packs::download_missing(conv_id, message_id, &pack).await;
+            }
+            state.write().emoji_downloaded(conv_id);
+        }
+    });
+}
 
 pub fn handle_warp_events(
     cx: Scope,
@@ -21,7 +44,7 @@ pub fn handle_warp_events(
 ) {
     let active_chat_id = state.read().get_active_chat().map(|x| x.id);
     use_future(cx, &active_chat_id, |chat_id| {
-        to_owned![chat_data];
+        to_owned![state, chat_data];
         async move {
             let mut ch = WARP_EVENT_CH.tx.subscribe();
             while let Ok(evt) = ch.recv().await {
@@ -61,6 +84,14 @@ pub fn handle_warp_events(
                         if chat_data.read().active_chat.id() != conversation_id {
                             continue;
                         }
+                        // the version which was loaded in the chat is the one the edit replaced
+                        if let Some(previous) =
+                            chat_data.read().active_chat.get_message(message.inner.id())
+                        {
+                            state
+                                .write()
+                                .add_message_revision(&previous, &message.inner);
+                        }
                         chat_data.write().update_message(message.inner);
                     }
                     MessageEvent::Deleted {
@@ -113,6 +144,15 @@ pub fn init_chat_data<'a>(
                 None => return,
                 Some(x) => x,
             };
+            // go_to_message loads the messages around the one to show instead
+            if state
+                .read()
+                .get_chat_by_id(conv_id)
+                .and_then(|chat| chat.go_to_message)
+                .is_some()
+            {
+                return;
+            }
 
             let behavior = chat_data.read().get_chat_behavior(conv_id);
             let config = behavior.messages_config();
@@ -142,6 +182,88 @@ pub fn init_chat_data<'a>(
     })
 }
 
+// shows the messages around a message which was opened from outside the chat, e.g. a bookmark
+pub fn go_to_message<'a>(
+    cx: &Scoped<'a>,
+    state: &'a UseSharedState<State>,
+    chat_data: &'a UseSharedState<ChatData>,
+) {
+    let target = state
+        .read()
+        .get_active_chat()
+        .and_then(|chat| chat.go_to_message.map(|(id, date)| (chat.id, id, date)));
+    use_future(cx, &target, |target| {
+        to_owned![state, chat_data];
+        async move {
+            let (conv_id, message_id, date) = match target {
+                None => return,
+                Some(x) => x,
+            };
+            while !state.read().initialized {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+
+            let behavior = data::ChatBehavior {
+                view_init: data::ViewInit {
+                    scroll_to: data::ScrollTo::ScrollUp {
+                        view_top: message_id,
+                    },
+                    msg_time: Some(date),
+                    limit: data::DEFAULT_MESSAGES_TO_TAKE,
+                },
+                // these fields will be overwritten by fetch_window
+                on_scroll_end: data::ScrollBehavior::FetchMore,
+                on_scroll_top: data::ScrollBehavior::FetchMore,
+                ..Default::default()
+            };
+            match fetch_window(conv_id, behavior, date, data::DEFAULT_MESSAGES_TO_TAKE / 2).await {
+                Ok((messages, behavior)) => {
+                    chat_data
+                        .write()
+                        .set_active_chat(&state.read(), &conv_id, behavior, messages);
+                }
+                Err(e) => log::error!("{e}"),
+            }
+            state.write().mutate(Action::ClearGoToMessage(conv_id));
+        }
+    });
+}
+
+// threads aren't loaded with the messages, a thread can start before the oldest message shown
+pub fn fetch_threads<'a>(cx: &Scoped<'a>, state: &'a UseSharedState<State>) {
+    let active_chat_id = state.read().get_active_chat().map(|x| x.id);
+    use_future(cx, &active_chat_id, |conv_id| {
+        to_owned![state];
+        async move {
+            while !state.read().initialized {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+
+            let conv_id = match conv_id {
+                None => return,
+                Some(x) => x,
+            };
+
+            let (tx, rx) = oneshot::channel();
+            if let Err(e) = WARP_CMD_CH
+                .tx
+                .send(WarpCmd::RayGun(RayGunCmd::FetchThreads {
+                    conv_id,
+                    rsp: tx,
+                }))
+            {
+                log::error!("failed to send warp command: {e}");
+                return;
+            }
+
+            match rx.await.expect("command canceled") {
+                Ok(threads) => state.write().mutate(Action::SetThreads(conv_id, threads)),
+                Err(e) => log::error!("failed to fetch threads: {e}"),
+            }
+        }
+    });
+}
+
 pub async fn fetch_window<'a>(
     conv_id: Uuid,
     chat_behavior: ChatBehavior,
@@ -1,26 +1,36 @@
 #[allow(unused_imports)]
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+use common::icons::outline::Shape as Icon;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::mentions::GroupMentionPermission;
+use common::state::{Action, State, ToastNotification};
 use common::warp_runner::{RayGunCmd, WarpCmd};
 use common::WARP_CMD_CH;
 use dioxus::prelude::*;
 use futures::channel::oneshot;
 use futures::StreamExt;
-use kit::elements::switch::Switch;
-use warp::raygun::{ConversationSettings, GroupSettings};
+use kit::elements::{button::Button, switch::Switch, tooltip::Tooltip, Appearance};
+use rfd::FileDialog;
+use uuid::Uuid;
+use warp::raygun::{self, ConversationSettings, GroupSettings, PinState};
 
 use crate::components::settings::SettingSectionSimple;
 use crate::layouts::chats::data::ChatData;
+use crate::utils::emoji_packs;
 
 #[allow(non_snake_case)]
 pub fn GroupSettings(cx: Scope) -> Element {
     log::trace!("rendering edit_group");
     let chat_data = use_shared_state::<ChatData>(cx)?;
+    let state = use_shared_state::<State>(cx)?;
+    let importing_pack = use_state(cx, || false);
 
     #[derive(Debug)]
     enum GroupSettingsChange {
         MembersCanAddParticipants(bool),
         MembersCanChangeName(bool),
+        MembersCanMentionEveryone(bool),
     }
 
     let get_group_settings = || match chat_data.read().active_chat.conversation_settings() {
@@ -32,10 +42,13 @@ pub fn GroupSettings(cx: Scope) -> Element {
     };
 
     let group_settings_state = use_ref(cx, get_group_settings);
+    let group_mention_permission = use_state(cx, || {
+        chat_data.read().active_chat.group_mention_permission()
+    });
 
     let group_settings_changed_channel =
         use_coroutine(cx, |mut rx: UnboundedReceiver<GroupSettingsChange>| {
-            to_owned![chat_data, group_settings_state];
+            to_owned![chat_data, group_settings_state, group_mention_permission];
             async move {
                 let warp_cmd_tx = WARP_CMD_CH.tx.clone();
                 while let Some(change) = rx.next().await {
@@ -60,6 +73,23 @@ pub fn GroupSettings(cx: Scope) -> Element {
                                 .write_silent()
                                 .set_members_can_change_name(switch_state);
                         }
+                        GroupSettingsChange::MembersCanMentionEveryone(switch_state) => {
+                            let permission = if switch_state {
+                                GroupMentionPermission::Everyone
+                            } else {
+                                GroupMentionPermission::CreatorOnly
+                            };
+                            group_mention_permission.set(permission);
+                            let conv_id = chat_data.read().active_chat.id();
+                            let pinned = chat_data.read().active_chat.pinned_messages();
+                            if let Err(e) =
+                                set_group_mention_permission(conv_id, permission, pinned).await
+                            {
+                                log::error!("failed to set group mention permission: {e}");
+                            }
+                            // not part of warp's group settings
+                            continue;
+                        }
                     }
 
                     let (tx, rx) = oneshot::channel();
@@ -77,6 +107,43 @@ pub fn GroupSettings(cx: Scope) -> Element {
             }
         });
 
+    let emoji_packs = state
+        .read()
+        .get_active_chat()
+        .map(|chat| chat.emoji_packs().to_vec())
+        .unwrap_or_default();
+    // a pack is either a folder or a zip of images
+    let share_emoji_pack = move |zip: bool| {
+        let dialog = FileDialog::new().set_directory(dirs::home_dir().unwrap_or(".".into()));
+        let path = if zip {
+            dialog.add_filter("zip", &["zip"]).pick_file()
+        } else {
+            dialog.pick_folder()
+        };
+        let Some(path) = path else {
+            return;
+        };
+        let conv_id = chat_data.read().active_chat.id();
+        importing_pack.set(true);
+        cx.spawn({
+            to_owned![state, importing_pack];
+            async move {
+                if let Err(e) = emoji_packs::share(conv_id, path).await {
+                    log::error!("failed to share an emoji pack: {e}");
+                    state
+                        .write()
+                        .mutate(Action::AddToastNotification(ToastNotification::init(
+                            "".into(),
+                            get_local_text("messages.emoji-pack-failed"),
+                            None,
+                            2,
+                        )));
+                }
+                importing_pack.set(false);
+            }
+        });
+    };
+
     cx.render(rsx!(
         div {
             id: "group-settings",
@@ -107,7 +174,140 @@ pub fn GroupSettings(cx: Scope) -> Element {
                         }
                     }
                 },
+                SettingSectionSimple {
+                    aria_label: "allow-members-to-mention-everyone".into(),
+                    p {
+                        get_local_text("messages.allow-mention-everyone")
+                    }
+                    Switch {
+                        active: *group_mention_permission.get() == GroupMentionPermission::Everyone,
+                        onflipped: |switch_state| {
+                            group_settings_changed_channel.send(GroupSettingsChange::MembersCanMentionEveryone(switch_state))
+                        }
+                    }
+                },
+            }
+            div {
+                class: "emoji-packs",
+                aria_label: "emoji-packs",
+                p {
+                    get_local_text("messages.emoji-packs")
+                },
+                emoji_packs.into_iter().map(|(message_id, pack)| {
+                    let count = get_local_text_with_args(
+                        "messages.emoji-pack-count",
+                        vec![("num", pack.emojis.len())],
+                    );
+                    rsx!(
+                        div {
+                            key: "{message_id}",
+                            class: "emoji-pack",
+                            aria_label: "emoji-pack",
+                            span {
+                                class: "emoji-pack-name",
+                                "{pack.name}"
+                            },
+                            span {
+                                class: "emoji-pack-count",
+                                "{count}"
+                            },
+                            Button {
+                                icon: Icon::Trash,
+                                small: true,
+                                appearance: Appearance::Secondary,
+                                aria_label: "remove-emoji-pack".into(),
+                                tooltip: cx.render(rsx!(Tooltip {
+                                    text: get_local_text("messages.emoji-pack-remove"),
+                                })),
+                                onpress: move |_| {
+                                    let conv_id = chat_data.read().active_chat.id();
+                                    cx.spawn(async move {
+                                        if let Err(e) = emoji_packs::remove(conv_id, message_id).await {
+                                            log::error!("failed to remove an emoji pack: {e}");
+                                        }
+                                    });
+                                }
+                            }
+                        }
+                    )
+                }),
+                div {
+                    class: "emoji-pack-controls",
+                    Button {
+                        icon: Icon::FolderPlus,
+                        small: true,
+                        appearance: Appearance::Secondary,
+                        aria_label: "add-emoji-pack-folder".into(),
+                        text: get_local_text("messages.emoji-pack-folder"),
+                        loading: *importing_pack.get(),
+                        disabled: *importing_pack.get(),
+                        onpress: move |_| share_emoji_pack(false),
+                    },
+                    Button {
+                        icon: Icon::ArchiveBox,
+                        small: true,
+                        appearance: Appearance::Secondary,
+                        aria_label: "add-emoji-pack-zip".into(),
+                        text: get_local_text("messages.emoji-pack-zip"),
+                        loading: *importing_pack.get(),
+                        disabled: *importing_pack.get(),
+                        onpress: move |_| share_emoji_pack(true),
+                    },
+                }
             }
         }
     ))
 }
+
+// the setting is a message pinned by the creator, see `common::state::mentions`. the previous
+// setting is unpinned so it doesn't take up room for pinned messages.
+async fn set_group_mention_permission(
+    conv_id: Uuid,
+    permission: GroupMentionPermission,
+    pinned: Vec<raygun::Message>,
+) -> Result<(), warp::error::Error> {
+    let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+    let pin = |message_id: Uuid, pinstate: PinState| {
+        let (tx, rx) = oneshot::channel();
+        let cmd = RayGunCmd::Pin {
+            conversation_id: conv_id,
+            message_id,
+            pinstate,
+            rsp: tx,
+        };
+        if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(cmd)) {
+            log::error!("failed to send warp command: {}", e);
+        }
+        rx
+    };
+
+    for message in pinned
+        .iter()
+        .filter(|m| GroupMentionPermission::from_lines(&m.lines()).is_some())
+    {
+        pin(message.id(), PinState::Unpin)
+            .await
+            .expect("command canceled")?;
+    }
+
+    let text = match permission {
+        GroupMentionPermission::Everyone => get_local_text("messages.mention-everyone-allowed"),
+        GroupMentionPermission::CreatorOnly => {
+            get_local_text("messages.mention-everyone-creator-only")
+        }
+    };
+    let (tx, rx) = oneshot::channel();
+    let cmd = RayGunCmd::SendMessage {
+        conv_id,
+        msg: vec![text, permission.to_line()],
+        attachments: vec![],
+        rsp: tx,
+    };
+    if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(cmd)) {
+        log::error!("failed to send warp command: {}", e);
+    }
+    let (message_id, _) = rx.await.expect("command canceled")?;
+    pin(message_id, PinState::Pin)
+        .await
+        .expect("command canceled")
+}
@@ -4,6 +4,7 @@ mod edit_group;
 mod group_settings;
 mod group_users;
 mod pinned_messages;
+mod thread;
 mod topbar;
 
 use dioxus::prelude::*;
@@ -18,7 +19,10 @@ use crate::{
     layouts::chats::{
         data::{self, ChatData, MessagesToEdit, MessagesToSend, ScrollBtn},
         presentation::{
-            chat::{edit_group::EditGroup, group_settings::GroupSettings, group_users::GroupUsers},
+            chat::{
+                edit_group::EditGroup, group_settings::GroupSettings, group_users::GroupUsers,
+                thread::ThreadModal,
+            },
             chatbar::get_chatbar,
             messages::get_messages,
         },
@@ -45,7 +49,9 @@ pub fn Compose(cx: Scope) -> Element {
     let chat_data = use_shared_state::<ChatData>(cx)?;
 
     let init = coroutines::init_chat_data(cx, state, chat_data);
+    coroutines::go_to_message(cx, state, chat_data);
     coroutines::handle_warp_events(cx, state, chat_data);
+    coroutines::sync_emoji_packs(cx, state);
 
     state.write_silent().ui.current_layout = ui::Layout::Compose;
 
@@ -109,6 +115,14 @@ pub fn Compose(cx: Scope) -> Element {
     let chat_id = chat_data.read().active_chat.id();
     let user_did: DID = state.read().did_key();
     let is_owner = creator.map(|id| id == user_did).unwrap_or_default();
+    let open_thread = state
+        .read()
+        .chats()
+        .all
+        .get(&chat_id)
+        .and_then(|chat| chat.open_thread);
+
+    coroutines::fetch_threads(cx, state);
 
     if init.value().is_some() {
         if let Some(chat) = state.read().get_active_chat() {
@@ -204,6 +218,13 @@ pub fn Compose(cx: Scope) -> Element {
                     }
                 }
         )),
+        open_thread.map(|root| rsx!(
+            ThreadModal {
+                key: "{root}",
+                conv_id: chat_id,
+                root: root,
+            }
+        )),
         CallControl {
             in_chat: true
         },
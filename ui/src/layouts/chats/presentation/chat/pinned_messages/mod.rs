@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use common::{
     language::get_local_text,
-    state::{Identity, State},
+    state::{utils::typed_lines, Identity, State},
     warp_runner::{thumbnail_to_base64, RayGunCmd, WarpCmd},
     WARP_CMD_CH,
 };
@@ -19,12 +19,6 @@ use crate::layouts::chats::{
     presentation::chat::coroutines::fetch_window,
 };
 
-const MARKDOWN_FOR_CODE_BLOCK: &str = r#"
-(() => {{
-    Prism.highlightAll();
-}})();
-"#;
-
 pub enum ChannelCommand {
     RemovePinnedMessage {
         conversation_id: Uuid,
@@ -127,13 +121,8 @@ pub fn PinnedMessages(cx: Scope<'_, Props>) -> Element<'_> {
         }
     });
     let pinned_messages = chat_data.read().active_chat.pinned_messages();
-    let eval = use_eval(cx);
-    let _ = eval(MARKDOWN_FOR_CODE_BLOCK);
 
     cx.render(rsx!(
-        script {
-            MARKDOWN_FOR_CODE_BLOCK
-        }
         div {
             id: "pinned-messages-container",
             class: format_args!("{}", if minimal {"pinned-minimal"} else {""}),
@@ -156,6 +145,7 @@ pub fn PinnedMessages(cx: Scope<'_, Props>) -> Element<'_> {
                     let conversation_id = message.conversation_id();
                     let message_id = message.id();
                     let message_date = message.date();
+                    let can_unpin = chat_data.read().active_chat.can_unpin(message, &state.read().did_key());
                     rsx!(PinnedMessage {
                         message: message.clone(),
                         chat: chat_data.read().active_chat.id(),
@@ -164,6 +154,7 @@ pub fn PinnedMessages(cx: Scope<'_, Props>) -> Element<'_> {
                             let conv = &msg.conversation_id();
                             ch.send(ChannelCommand::RemovePinnedMessage{ conversation_id: *conv, message_id: msg.id(), show_pinned: cx.props.show_pinned.clone() })
                         },
+                        can_unpin: can_unpin,
                         time: time,
                         onclick: move |_| {
                             ch.send(ChannelCommand::GoToPinnedMessage{conversation_id, message_id, message_date, show_pinned: cx.props.show_pinned.clone()});
@@ -183,6 +174,7 @@ pub struct PinnedMessageProp<'a> {
     #[props(!optional)]
     sender: Option<Identity>,
     onremove: EventHandler<'a, (Event<MouseData>, warp::raygun::Message)>,
+    can_unpin: bool,
     time: String,
     onclick: EventHandler<'a, ()>,
 }
@@ -208,6 +200,7 @@ pub fn PinnedMessage<'a>(cx: Scope<'a, PinnedMessageProp<'a>>) -> Element<'a> {
         })
     });
     let has_attachments = !attachments.is_empty();
+    let text = typed_lines(&message.lines()).join("\n");
 
     cx.render(rsx!(div {
             class: "pinned-message-wrap",
@@ -254,24 +247,25 @@ pub fn PinnedMessage<'a>(cx: Scope<'a, PinnedMessageProp<'a>>) -> Element<'a> {
                                 },
                                 get_local_text("messages.pin-button-goto")
                             },
-                            button {
+                            cx.props.can_unpin.then(|| rsx!(button {
                                 class: "pinned-buttons",
                                 aria_label: "pin-button-unpin",
                                 onclick: move |e| {
                                     cx.props.onremove.call((e, cx.props.message.clone()));
                                 },
                                 get_local_text("messages.pin-button-unpin"),
-                            }
+                            }))
                         }
                     }
                     ChatText {
-                        text: message.lines().join("\n"),
+                        text: text,
                         remote: true,
                         pending: false,
                         state: &state,
                         chat: cx.props.chat,
                         markdown: state.read().ui.should_transform_markdown_text(),
                         ascii_emoji: state.read().ui.should_transform_ascii_emojis(),
+                        link_preview: None,
                     }
                 },
                 has_attachments.then(|| {
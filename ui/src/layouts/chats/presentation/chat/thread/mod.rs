@@ -0,0 +1,205 @@
+use common::{
+    language::get_local_text,
+    state::{link_preview::LinkPreview, utils::typed_lines, Action, State},
+    warp_runner::{ui_adapter, RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+
+use futures::{channel::oneshot, StreamExt};
+use kit::{
+    components::{message::ChatText, user_image::UserImage},
+    elements::loader::Loader,
+    layout::chatbar::{Chatbar, SuggestionType},
+};
+use uuid::Uuid;
+
+use tracing::log;
+
+struct Reply {
+    conv_id: Uuid,
+    root: Uuid,
+    msg: Vec<String>,
+}
+
+#[derive(Props, PartialEq)]
+pub struct Props {
+    conv_id: Uuid,
+    // the message which started the thread
+    root: Uuid,
+}
+
+/// The message which started a thread and its replies, with a chatbar to reply in the thread.
+#[allow(non_snake_case)]
+pub fn ThreadPanel(cx: Scope<Props>) -> Element {
+    log::trace!("rendering thread");
+    let state = use_shared_state::<State>(cx)?;
+    let draft = use_ref(cx, String::new);
+    let conv_id = cx.props.conv_id;
+    let root = cx.props.root;
+
+    let reply_count = state
+        .read()
+        .chats()
+        .all
+        .get(&conv_id)
+        .and_then(|chat| chat.threads.get(&root))
+        .map(|thread| thread.replies.len())
+        .unwrap_or_default();
+
+    // fetched again whenever a reply comes in or is deleted
+    let messages = use_future(
+        cx,
+        (&conv_id, &root, &reply_count),
+        |(conv_id, root, _)| async move {
+            let (tx, rx) = oneshot::channel();
+            WARP_CMD_CH
+                .tx
+                .send(WarpCmd::RayGun(RayGunCmd::FetchThread {
+                    conv_id,
+                    root,
+                    rsp: tx,
+                }))
+                .map_err(|e| log::error!("failed to send warp command: {e}"))
+                .ok()?;
+            match rx.await.expect("command canceled") {
+                Ok(messages) => Some(messages),
+                Err(e) => {
+                    log::error!("failed to fetch thread: {e}");
+                    None
+                }
+            }
+        },
+    );
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<Reply>| async move {
+        let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+        while let Some(Reply { conv_id, root, msg }) = rx.next().await {
+            let (tx, rx) = oneshot::channel();
+            if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::Reply {
+                conv_id,
+                reply_to: root,
+                msg,
+                attachments: vec![],
+                rsp: tx,
+            })) {
+                log::error!("failed to send warp command: {e}");
+                continue;
+            }
+            if let Err(e) = rx.await.expect("command canceled") {
+                log::error!("failed to reply in thread: {e}");
+            }
+        }
+    });
+
+    let submit = move || {
+        let msg: Vec<String> = draft.read().lines().map(|x| x.to_string()).collect();
+        if !msg.iter().any(|line| !line.trim().is_empty()) {
+            return;
+        }
+        ch.send(Reply { conv_id, root, msg });
+        draft.set(String::new());
+    };
+
+    let own = state.read().did_key();
+    let render_markdown = state.read().ui.should_transform_markdown_text();
+    let should_transform_ascii_emojis = state.read().ui.should_transform_ascii_emojis();
+
+    let thread_messages = match messages.value() {
+        None => rsx!(Loader { spinning: true }),
+        Some(None) => rsx!(div {
+            class: "thread-empty",
+            get_local_text("messages.thread-loading-failed")
+        }),
+        Some(Some(messages)) => rsx!(messages.iter().map(|message: &ui_adapter::Message| {
+            let sender = state
+                .read()
+                .get_identity(&message.inner.sender())
+                .unwrap_or_default();
+            let time = message
+                .inner
+                .date()
+                .format(&get_local_text("uplink.date-time-format"))
+                .to_string();
+            let is_root = message.inner.id() == root;
+            let remote = message.inner.sender() != own;
+            rsx!(div {
+                key: "{message.key}",
+                class: format_args!("thread-message {}", if is_root { "thread-root" } else { "" }),
+                aria_label: "thread-message",
+                UserImage {
+                    image: sender.profile_picture(),
+                    platform: sender.platform().into(),
+                },
+                div {
+                    class: "thread-message-content",
+                    div {
+                        class: "thread-message-sender",
+                        p {
+                            class: "ellipsis-overflow",
+                            sender.username()
+                        },
+                        p {
+                            class: "thread-message-time",
+                            "{time}"
+                        }
+                    },
+                    ChatText {
+                        text: typed_lines(&message.inner.lines()).join("\n"),
+                        remote: remote,
+                        pending: false,
+                        markdown: render_markdown,
+                        ascii_emoji: should_transform_ascii_emojis,
+                        link_preview: LinkPreview::split(&message.inner.lines()).0,
+                        state: state,
+                        chat: conv_id,
+                    }
+                }
+            })
+        })),
+    };
+
+    cx.render(rsx!(
+        div {
+            id: "thread",
+            aria_label: "thread",
+            div {
+                class: "thread-messages",
+                thread_messages
+            },
+            Chatbar {
+                key: "{conv_id}-{root}",
+                id: format!("{root}-thread-chatbar"),
+                placeholder: get_local_text("messages.thread-placeholder"),
+                typing_users: vec![],
+                ignore_focus: true,
+                suggestions: &SuggestionType::None,
+                value: draft.read().clone(),
+                onchange: move |v: String| {
+                    *draft.write_silent() = v;
+                },
+                onreturn: move |_| submit(),
+            }
+        }
+    ))
+}
+
+#[allow(non_snake_case)]
+pub fn ThreadModal(cx: Scope<Props>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let conv_id = cx.props.conv_id;
+    cx.render(rsx!(kit::layout::modal::Modal {
+        open: true,
+        right: "8px",
+        transparent: true,
+        change_horizontal_position: true,
+        with_title: get_local_text("messages.thread"),
+        onclose: move |_| {
+            state.write().mutate(Action::CloseThread(conv_id));
+        },
+        ThreadPanel {
+            conv_id: conv_id,
+            root: cx.props.root,
+        }
+    }))
+}
@@ -0,0 +1,196 @@
+//! Slash commands typed in the chatbar. Built in commands are registered first, then the
+//! commands of enabled extensions. Text which isn't a known command is sent as a message.
+
+use common::{
+    language::{get_local_text, get_local_text_with_args},
+    state::{Action, State, ToastNotification},
+};
+use dioxus::prelude::*;
+use extensions::commands::{Arg, CommandSpec, Output, ParseError, Registry};
+use kit::layout::chatbar::CommandSuggestion;
+use uuid::Uuid;
+use warp::crypto::DID;
+
+use tracing::log;
+
+use crate::layouts::chats::data::ChatData;
+
+#[derive(Clone, Copy)]
+pub enum Builtin {
+    Shrug,
+    Me,
+    Poll,
+    Call,
+    Mute,
+}
+
+pub enum Handler {
+    Builtin(Builtin),
+    Extension(fn(&[String]) -> Output),
+}
+
+pub enum CallCmd {
+    Offer {
+        conversation_id: Uuid,
+        participants: Vec<DID>,
+    },
+    // true to mute, false to unmute
+    Mute(bool),
+}
+
+pub fn registry(state: &State) -> Registry<Handler> {
+    let mut registry = Registry::default();
+    let builtins = [
+        (
+            "shrug",
+            "commands.shrug",
+            vec![Arg::text("text", false)],
+            Builtin::Shrug,
+        ),
+        (
+            "me",
+            "commands.me",
+            vec![Arg::text("action", true)],
+            Builtin::Me,
+        ),
+        ("poll", "commands.poll", vec![], Builtin::Poll),
+        ("call", "commands.call", vec![], Builtin::Call),
+        ("mute", "commands.mute", vec![], Builtin::Mute),
+    ];
+    for (name, description, args, builtin) in builtins {
+        let spec = CommandSpec::new(name, get_local_text(description), args);
+        registry.register(spec, Handler::Builtin(builtin));
+    }
+    for (_, ext) in state.ui.extensions.values().filter(|(enabled, _)| *enabled) {
+        for command in ext.commands() {
+            if !registry.register(command.spec.clone(), Handler::Extension(command.run)) {
+                log::warn!(
+                    "extension {} tried to replace the /{} command",
+                    ext.details().meta.name,
+                    command.spec.name
+                );
+            }
+        }
+    }
+    registry
+}
+
+/// The commands to suggest while the name of one is typed, e.g. `/sh`.
+pub fn suggestions(state: &State, typed: &str) -> Vec<CommandSuggestion> {
+    let prefix = match typed.strip_prefix('/') {
+        Some(prefix) if !prefix.contains(char::is_whitespace) => prefix,
+        _ => return vec![],
+    };
+    registry(state)
+        .matching(prefix)
+        .into_iter()
+        .map(|spec| CommandSuggestion {
+            name: spec.name.clone(),
+            usage: spec.usage(),
+            description: spec.description.clone(),
+        })
+        .collect()
+}
+
+pub struct Context<'a> {
+    pub state: &'a UseSharedState<State>,
+    pub chat_data: &'a UseSharedState<ChatData>,
+    pub show_create_poll: &'a UseState<bool>,
+    pub call_ch: &'a Coroutine<CallCmd>,
+}
+
+/// Runs `draft` if it is a command. Returns None if it isn't one and should be sent as it is.
+pub fn run(draft: &str, cx: Context) -> Option<Output> {
+    let (name, input) = extensions::commands::split(draft.trim())?;
+    let registry = registry(&cx.state.read());
+    let (spec, handler) = registry.find(name)?;
+    let args = match spec.parse_args(input) {
+        Ok(args) => args,
+        Err(e) => {
+            let reason = match e {
+                ParseError::MissingArg(arg) => {
+                    get_local_text_with_args("commands.missing-arg", vec![("arg", arg)])
+                }
+                ParseError::TooManyArgs => get_local_text("commands.too-many-args"),
+            };
+            toast(cx.state, reason, spec.usage());
+            // keep the draft so it can be fixed
+            return Some(Output::Draft(draft.to_string()));
+        }
+    };
+
+    let builtin = match handler {
+        Handler::Extension(run) => return Some(run(&args)),
+        Handler::Builtin(builtin) => *builtin,
+    };
+    let output = match builtin {
+        Builtin::Shrug => {
+            // escaped so markdown doesn't eat the arm
+            let shrug = r"¯\\\_(ツ)\_/¯";
+            let line = match args.first() {
+                Some(text) => format!("{text} {shrug}"),
+                None => shrug.to_string(),
+            };
+            Output::Send(vec![line])
+        }
+        Builtin::Me => {
+            let username = cx.state.read().get_own_identity().username();
+            Output::Send(vec![format!("_{username} {}_", args.join(" "))])
+        }
+        Builtin::Poll => {
+            cx.show_create_poll.set(true);
+            Output::Nothing
+        }
+        Builtin::Call => {
+            let experimental = cx
+                .state
+                .read()
+                .configuration
+                .developer
+                .experimental_features;
+            let in_call = cx.state.read().ui.call_info.active_call().is_some();
+            if !experimental {
+                toast(cx.state, get_local_text("uplink.coming-soon"), spec.usage());
+            } else if in_call {
+                toast(
+                    cx.state,
+                    get_local_text("commands.already-in-call"),
+                    spec.usage(),
+                );
+            } else {
+                let chat_data = cx.chat_data.read();
+                let active_chat = &chat_data.active_chat;
+                cx.call_ch.send(CallCmd::Offer {
+                    conversation_id: active_chat.id(),
+                    participants: active_chat
+                        .other_participants()
+                        .iter()
+                        .map(|x| x.did_key())
+                        .collect(),
+                });
+            }
+            Output::Nothing
+        }
+        Builtin::Mute => {
+            let active_call = cx.state.read().ui.call_info.active_call();
+            match active_call {
+                Some(active) => cx.call_ch.send(CallCmd::Mute(!active.call.self_muted)),
+                None => toast(
+                    cx.state,
+                    get_local_text("commands.not-in-call"),
+                    spec.usage(),
+                ),
+            }
+            Output::Nothing
+        }
+    };
+    Some(output)
+}
+
+fn toast(state: &UseSharedState<State>, title: String, content: String) {
+    state
+        .write()
+        .mutate(Action::AddToastNotification(ToastNotification::init(
+            title, content, None, 2,
+        )));
+}
@@ -1,12 +1,29 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
+use anyhow::Context;
 use common::{
-    state::{Action, State},
-    warp_runner::{RayGunCmd, WarpCmd},
+    language::get_local_text,
+    state::{
+        call,
+        link_preview::LinkPreview,
+        pending_message::{FileLocation, FileProgression},
+        poll::Poll,
+        Action, State, ToastNotification,
+    },
+    utils::{
+        image_edit,
+        media_compression::{self, Compression},
+    },
+    warp_runner::{BlinkCmd, OtherCmd, RayGunCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
 use dioxus::prelude::*;
-use futures::{channel::oneshot, StreamExt};
+use futures::{channel::oneshot, stream::FuturesOrdered, Future, StreamExt};
+use kit::components::message::markdown::find_links;
 use uuid::Uuid;
 use warp::raygun;
 
@@ -15,26 +32,59 @@ use crate::{
     utils::async_task_queue::chat_upload_stream_handler,
 };
 
-use super::TypingIndicator;
+use super::{commands::CallCmd, TypingIndicator};
 
 pub fn get_msg_ch(cx: &ScopeState, state: &UseSharedState<State>) -> Coroutine<MsgChInput> {
     let upload_streams = chat_upload_stream_handler(cx);
+    let link_previews = get_link_preview_ch(cx, state);
     use_coroutine(cx, |mut rx: UnboundedReceiver<MsgChInput>| {
-        to_owned![state, upload_streams];
+        to_owned![state, upload_streams, link_previews];
         async move {
             let warp_cmd_tx = WARP_CMD_CH.tx.clone();
-            while let Some(MsgChInput {
-                msg,
-                conv_id,
-                replying_to,
-            }) = rx.next().await
-            {
+            // compressing a video takes a while. the attachments are prepared on another thread
+            // while the message is shown as pending, but messages are still sent in order
+            let mut preparing = FuturesOrdered::new();
+            loop {
+                let (sending, prepared) = tokio::select! {
+                    input = rx.next() => match input {
+                        Some(input) => {
+                            preparing.push_back(start_sending(&state, input));
+                            continue;
+                        }
+                        None => break,
+                    },
+                    Some(prepared) = preparing.next() => prepared,
+                };
+                let Sending {
+                    msg,
+                    conv_id,
+                    replying_to,
+                    attachments,
+                    from_chat,
+                    pending_id,
+                } = sending;
+                let (attachments, copies, placeholders) = match prepared {
+                    Ok(prepared) => prepared,
+                    Err(e) => {
+                        // rather not send it than send where a photo was taken
+                        log::error!("failed to prepare attachments: {e}");
+                        let mut state = state.write();
+                        state.decrement_outgoing_messages(conv_id, pending_id);
+                        if from_chat {
+                            state.mutate(Action::SetChatDraft(conv_id, msg.join("\n")));
+                            state.mutate(Action::SetChatAttachments(conv_id, attachments));
+                        }
+                        state.mutate(Action::AddToastNotification(ToastNotification::init(
+                            "".into(),
+                            get_local_text("messages.attachments-not-prepared"),
+                            None,
+                            3,
+                        )));
+                        continue;
+                    }
+                };
+                let fetch_preview = state.read().link_previews_enabled(&conv_id);
                 let (tx, rx) = oneshot::channel();
-                let attachments = state
-                    .read()
-                    .get_active_chat()
-                    .map(|f| f.files_attached_to_send)
-                    .unwrap_or_default();
                 let cmd = match replying_to {
                     Some(reply_to) => RayGunCmd::Reply {
                         conv_id,
@@ -50,24 +100,37 @@ pub fn get_msg_ch(cx: &ScopeState, state: &UseSharedState<State>) -> Coroutine<M
                         rsp: tx,
                     },
                 };
-                state
-                    .write_silent()
-                    .mutate(Action::ClearChatAttachments(conv_id));
                 if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(cmd)) {
                     log::error!("failed to send warp command: {}", e);
+                    state
+                        .write()
+                        .decrement_outgoing_messages(conv_id, pending_id);
                     continue;
                 }
 
                 let rsp = rx.await.expect("command canceled");
                 match rsp {
                     Ok((id, stream)) => {
-                        state.write().increment_outgoing_messages(id, msg.clone());
+                        if fetch_preview {
+                            link_previews.send((conv_id, id, msg.clone()));
+                        }
+                        let mut state = state.write();
+                        state.decrement_outgoing_messages(conv_id, pending_id);
+                        state.increment_outgoing_messages_for(conv_id, id, msg.clone());
+                        state.set_outgoing_placeholders(conv_id, id, placeholders);
                         if let Some(stream) = stream {
-                            upload_streams.write().append((conv_id, id, stream));
+                            // the copies are removed once they were uploaded
+                            let stream = stream.inspect(move |_| {
+                                let _ = &copies;
+                            });
+                            upload_streams.write().append((conv_id, id, stream.boxed()));
                         }
                     }
                     Err(e) => {
                         log::error!("failed to send message: {}", e);
+                        state
+                            .write()
+                            .decrement_outgoing_messages(conv_id, pending_id);
                     }
                 }
             }
@@ -76,6 +139,256 @@ pub fn get_msg_ch(cx: &ScopeState, state: &UseSharedState<State>) -> Coroutine<M
     .clone()
 }
 
+/// A message which is shown as pending while its attachments are prepared.
+struct Sending {
+    msg: Vec<String>,
+    conv_id: Uuid,
+    replying_to: Option<Uuid>,
+    // as they were picked, put back if they can't be prepared
+    attachments: Vec<raygun::Location>,
+    from_chat: bool,
+    pending_id: Uuid,
+}
+
+// the attachments to send, their copies and the placeholders shown until they are uploaded
+type Prepared = (Vec<raygun::Location>, Copies, HashMap<FileLocation, String>);
+
+fn start_sending(
+    state: &UseSharedState<State>,
+    input: MsgChInput,
+) -> impl Future<Output = (Sending, anyhow::Result<Prepared>)> {
+    let MsgChInput {
+        msg,
+        conv_id,
+        replying_to,
+        attachments,
+    } = input;
+    let compression = state
+        .read()
+        .get_active_chat()
+        .and_then(|c| c.compression_to_send)
+        .unwrap_or(state.read().configuration.files.compression);
+    let from_chat = attachments.is_none();
+    let attachments = match attachments {
+        Some(attachments) => attachments,
+        None => {
+            let attachments = state
+                .read()
+                .get_active_chat()
+                .map(|f| f.files_attached_to_send)
+                .unwrap_or_default();
+            // the attachments shouldn't be sent twice while they are prepared
+            state
+                .write_silent()
+                .mutate(Action::ClearChatAttachments(conv_id));
+            attachments
+        }
+    };
+
+    // replaced by the pending message with the id warp gives it once it was sent
+    let pending_id = Uuid::new_v4();
+    {
+        let mut state = state.write();
+        state.increment_outgoing_messages_for(conv_id, pending_id, msg.clone());
+        for location in &attachments {
+            let name = match location {
+                raygun::Location::Disk { path } => path.file_name(),
+                raygun::Location::Constellation { path } => Path::new(path).file_name(),
+            };
+            let progress = FileProgression::CurrentProgress {
+                name: name.unwrap_or_default().to_string_lossy().to_string(),
+                current: 0,
+                total: None,
+            };
+            state.update_outgoing_messages(conv_id, pending_id, location.clone(), progress);
+        }
+    }
+
+    let sending = Sending {
+        msg,
+        conv_id,
+        replying_to,
+        attachments: attachments.clone(),
+        from_chat,
+        pending_id,
+    };
+    async move {
+        let prepared = tokio::spawn(async move {
+            let (attachments, copies) = prepare_attachments(attachments, compression).await?;
+            let placeholders = placeholders(&attachments).await;
+            anyhow::Ok((attachments, copies, placeholders))
+        })
+        .await;
+        match prepared {
+            Ok(prepared) => (sending, prepared),
+            Err(e) => (sending, Err(e.into())),
+        }
+    }
+}
+
+// (conversation id, message id, text) of a message which was sent
+type SentMessage = (Uuid, Uuid, Vec<String>);
+
+/// Adds the preview of the first link to messages once they were sent. The sender fetches it, so
+/// the receivers never have to visit the link, and a slow page doesn't hold up sending.
+fn get_link_preview_ch(cx: &ScopeState, state: &UseSharedState<State>) -> Coroutine<SentMessage> {
+    use_coroutine(cx, |mut rx: UnboundedReceiver<SentMessage>| {
+        to_owned![state];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            while let Some((conv_id, msg_id, msg)) = rx.next().await {
+                let preview = match link_preview(&msg).await {
+                    Some(preview) => preview,
+                    None => continue,
+                };
+                // the message may have been edited meanwhile
+                let mut msg = state
+                    .read()
+                    .chats()
+                    .all
+                    .get(&conv_id)
+                    .and_then(|chat| {
+                        chat.messages
+                            .iter()
+                            .find(|m| m.inner.id() == msg_id)
+                            .map(|m| m.inner.lines())
+                    })
+                    .unwrap_or(msg);
+                if LinkPreview::split(&msg).0.is_some() {
+                    continue;
+                }
+                msg.push(preview.to_line());
+                let (tx, rx) = oneshot::channel();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::EditMessage {
+                    conv_id,
+                    msg_id,
+                    msg,
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                    continue;
+                }
+                if let Err(e) = rx.await.expect("command canceled") {
+                    log::error!("failed to add link preview: {}", e);
+                }
+            }
+        }
+    })
+    .clone()
+}
+
+async fn link_preview(msg: &[String]) -> Option<LinkPreview> {
+    if Poll::from_lines(msg).is_some() {
+        return None;
+    }
+    let url = find_links(&msg.join("\n"))
+        .into_iter()
+        .map(|link| link.url)
+        .find(|url| url.starts_with("http"))?;
+    let (tx, rx) = oneshot::channel();
+    WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Other(OtherCmd::FetchLinkPreview { url, rsp: tx }))
+        .ok()?;
+    rx.await.ok()?.ok()
+}
+
+/// Directories in the temp files with copies of attachments, removed when dropped.
+struct Copies(Vec<PathBuf>);
+
+impl Drop for Copies {
+    fn drop(&mut self) {
+        for dir in self.0.iter().filter(|dir| dir.exists()) {
+            if let Err(e) = std::fs::remove_dir_all(dir) {
+                log::warn!("failed to remove {}: {e}", dir.display());
+            }
+        }
+    }
+}
+
+// attachments are made smaller, unless the original was picked, and photos are sent without their
+// EXIF data, which often includes where they were taken. the copies keep the file names, only
+// compressed files may get another extension. fails if a photo couldn't be stripped, instead of
+// sending it as is
+async fn prepare_attachments(
+    attachments: Vec<raygun::Location>,
+    compression: Compression,
+) -> anyhow::Result<(Vec<raygun::Location>, Copies)> {
+    let mut prepared = vec![];
+    let mut copies = Copies(vec![]);
+    // kept if this fails, the attachments are put back then
+    let mut sent_copies = vec![];
+    for location in attachments {
+        let path = match location {
+            raygun::Location::Disk { path } => path,
+            location => {
+                prepared.push(location);
+                continue;
+            }
+        };
+        // edited or pasted images and gifs are already copies, only made to be sent
+        if let Some(dir) = path.parent().filter(|dir| {
+            dir.parent() == Some(STATIC_ARGS.temp_files.as_path())
+                && !sent_copies.contains(&dir.to_path_buf())
+        }) {
+            sent_copies.push(dir.to_path_buf());
+        }
+        let dir = STATIC_ARGS.temp_files.join(Uuid::new_v4().to_string());
+        copies.0.push(dir.clone());
+        let src = path.clone();
+        let copy = tokio::task::spawn_blocking(move || prepare_attachment(&src, compression, &dir))
+            .await?
+            .with_context(|| format!("failed to prepare {} for sending", path.display()))?;
+        prepared.push(raygun::Location::Disk {
+            path: copy.unwrap_or(path),
+        });
+    }
+    copies.0.extend(sent_copies);
+    Ok((prepared, copies))
+}
+
+fn prepare_attachment(
+    path: &Path,
+    compression: Compression,
+    dir: &Path,
+) -> anyhow::Result<Option<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    match media_compression::compress(path, compression, dir) {
+        Ok(Some(compressed)) => return Ok(Some(compressed)),
+        Ok(None) => {}
+        // e.g. ffmpeg isn't installed, the original is sent instead
+        Err(e) => log::warn!("failed to compress {}: {e}", path.display()),
+    }
+    if !image_edit::is_editable(path) {
+        return Ok(None);
+    }
+    let dest = dir.join(path.file_name().unwrap_or_default());
+    Ok(image_edit::strip_metadata(path, &dest)?.then_some(dest))
+}
+
+// blurred images are shown until the attachments are uploaded
+async fn placeholders(attachments: &[raygun::Location]) -> HashMap<FileLocation, String> {
+    let paths: Vec<PathBuf> = attachments
+        .iter()
+        .filter_map(|location| match location {
+            raygun::Location::Disk { path } if image_edit::is_editable(path) => Some(path.clone()),
+            _ => None,
+        })
+        .collect();
+    tokio::task::spawn_blocking(move || {
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let (hash, width, height) = media_compression::blurhash(&path).ok()?;
+                let url = media_compression::blurhash_data_url(&hash, width, height).ok()?;
+                Some((FileLocation::Disk { path }, url))
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
 pub fn get_scroll_ch(
     cx: &Scoped<'_, ChatProps>,
     chat_data: &UseSharedState<data::ChatData>,
@@ -185,3 +498,59 @@ pub fn get_typing_ch(cx: &Scoped<'_, ChatProps>) -> Coroutine<TypingIndicator> {
     })
     .clone()
 }
+
+// starts a call or mutes/unmutes in response to the /call and /mute commands
+pub fn get_call_ch(cx: &ScopeState, state: &UseSharedState<State>) -> Coroutine<CallCmd> {
+    use_coroutine(cx, |mut rx: UnboundedReceiver<CallCmd>| {
+        to_owned![state];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            while let Some(cmd) = rx.next().await {
+                match cmd {
+                    CallCmd::Offer {
+                        conversation_id,
+                        participants,
+                    } => {
+                        let (tx, rx) = oneshot::channel();
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Blink(BlinkCmd::OfferCall {
+                            conversation_id,
+                            participants: participants.clone(),
+                            rsp: tx,
+                        })) {
+                            log::error!("failed to send command to warp_runner: {e}");
+                            continue;
+                        }
+                        match rx.await.expect("warp runner failed") {
+                            Ok(call_id) => {
+                                state.write().mutate(Action::OfferCall(call::Call::new(
+                                    call_id,
+                                    conversation_id,
+                                    participants,
+                                )));
+                            }
+                            Err(e) => {
+                                log::error!("BlinkCmd::OfferCall failed: {e}");
+                            }
+                        }
+                    }
+                    CallCmd::Mute(mute) => {
+                        let (tx, rx) = oneshot::channel();
+                        let cmd = if mute {
+                            BlinkCmd::MuteSelf { rsp: tx }
+                        } else {
+                            BlinkCmd::UnmuteSelf { rsp: tx }
+                        };
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Blink(cmd)) {
+                            log::error!("failed to send blink command: {e}");
+                            continue;
+                        }
+                        match rx.await.expect("command canceled") {
+                            Ok(_) => state.write().mutate(Action::ToggleMute),
+                            Err(e) => log::error!("warp_runner failed to toggle mute: {e}"),
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
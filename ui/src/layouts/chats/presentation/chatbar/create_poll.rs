@@ -0,0 +1,195 @@
+use chrono::{Duration, Utc};
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    state::poll::{Poll, MAX_POLL_OPTIONS},
+    warp_runner::{RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use futures::{channel::oneshot, StreamExt};
+use kit::{
+    elements::{
+        button::Button,
+        input::{Input, Options},
+        select::Select,
+        switch::Switch,
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+use uuid::Uuid;
+
+use tracing::log;
+
+const MIN_POLL_OPTIONS: usize = 2;
+
+// (label, how long the poll stays open)
+fn durations() -> Vec<(String, Option<Duration>)> {
+    vec![
+        (get_local_text("polls.never"), None),
+        (get_local_text("polls.one-hour"), Some(Duration::hours(1))),
+        (get_local_text("polls.one-day"), Some(Duration::days(1))),
+        (get_local_text("polls.one-week"), Some(Duration::weeks(1))),
+    ]
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    conv_id: Uuid,
+    onclose: EventHandler<'a>,
+}
+
+#[allow(non_snake_case)]
+pub fn CreatePoll<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let question = use_state(cx, String::new);
+    // the ids key the inputs, so removing an option doesn't move text between them
+    let options = use_ref(cx, || {
+        (0..MIN_POLL_OPTIONS)
+            .map(|_| (Uuid::new_v4(), String::new()))
+            .collect::<Vec<_>>()
+    });
+    let multiple_choice = use_state(cx, || false);
+    let anonymous = use_state(cx, || false);
+    let duration = use_state(cx, || None::<Duration>);
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<(Uuid, Poll)>| async move {
+        let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+        while let Some((conv_id, poll)) = rx.next().await {
+            let (tx, rx) = oneshot::channel();
+            if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::SendMessage {
+                conv_id,
+                msg: poll.to_lines(),
+                attachments: vec![],
+                rsp: tx,
+            })) {
+                log::error!("failed to send warp command: {}", e);
+                continue;
+            }
+            if let Err(e) = rx.await.expect("command canceled") {
+                log::error!("failed to send poll: {}", e);
+            }
+        }
+    });
+
+    let filled_options: Vec<String> = options
+        .read()
+        .iter()
+        .map(|(_, text)| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .collect();
+    let can_send = !question.trim().is_empty() && filled_options.len() >= MIN_POLL_OPTIONS;
+    let can_add = options.read().len() < MAX_POLL_OPTIONS;
+    let can_remove = options.read().len() > MIN_POLL_OPTIONS;
+    let duration_labels: Vec<String> = durations().into_iter().map(|(label, _)| label).collect();
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        with_title: get_local_text("polls.create"),
+        onclose: move |_| cx.props.onclose.call(()),
+        div {
+            id: "create-poll",
+            aria_label: "create-poll",
+            Input {
+                id: "poll-question-input".to_owned(),
+                aria_label: "poll-question-input".into(),
+                focus: true,
+                placeholder: get_local_text("polls.question"),
+                max_length: 300,
+                options: Options {
+                    clear_on_submit: false,
+                    ..Default::default()
+                },
+                onchange: move |(val, _): (String, bool)| question.set(val),
+            },
+            div {
+                class: "poll-option-inputs",
+                options.read().iter().enumerate().map(|(idx, (id, _))| {
+                    let id = *id;
+                    rsx!(div {
+                        key: "{id}",
+                        class: "poll-option-input",
+                        Input {
+                            id: format!("poll-option-{id}"),
+                            aria_label: "poll-option-input".into(),
+                            placeholder: format!("{} {}", get_local_text("polls.option"), idx + 1),
+                            max_length: 100,
+                            options: Options {
+                                clear_on_submit: false,
+                                ..Default::default()
+                            },
+                            onchange: move |(val, _): (String, bool)| {
+                                if let Some(option) = options.write().iter_mut().find(|(option, _)| *option == id) {
+                                    option.1 = val;
+                                }
+                            },
+                        },
+                        can_remove.then(|| rsx!(Button {
+                            icon: Icon::XMark,
+                            aria_label: "poll-remove-option".into(),
+                            appearance: Appearance::Secondary,
+                            onpress: move |_| options.write().retain(|(option, _)| *option != id),
+                        }))
+                    })
+                }),
+                can_add.then(|| rsx!(Button {
+                    icon: Icon::Plus,
+                    text: get_local_text("polls.add-option"),
+                    aria_label: "poll-add-option".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| options.write().push((Uuid::new_v4(), String::new())),
+                }))
+            },
+            div {
+                class: "poll-setting",
+                label { get_local_text("polls.multiple-choice") },
+                Switch {
+                    active: *multiple_choice.get(),
+                    onflipped: move |e| multiple_choice.set(e),
+                }
+            },
+            div {
+                class: "poll-setting",
+                label { get_local_text("polls.anonymous") },
+                Switch {
+                    active: *anonymous.get(),
+                    onflipped: move |e| anonymous.set(e),
+                }
+            },
+            div {
+                class: "poll-setting",
+                label { get_local_text("polls.close-after") },
+                Select {
+                    initial_value: duration_labels[0].clone(),
+                    options: duration_labels.clone(),
+                    onselect: move |label: String| {
+                        let selected = durations()
+                            .into_iter()
+                            .find(|(l, _)| *l == label)
+                            .and_then(|(_, d)| d);
+                        duration.set(selected);
+                    }
+                }
+            },
+            Button {
+                text: get_local_text("polls.send"),
+                aria_label: "poll-send".into(),
+                icon: Icon::ChartBar,
+                disabled: !can_send,
+                onpress: move |_| {
+                    let poll = Poll {
+                        question: question.trim().to_string(),
+                        options: filled_options.clone(),
+                        multiple_choice: *multiple_choice.get(),
+                        anonymous: *anonymous.get(),
+                        closes_at: duration.get().map(|d| Utc::now() + d),
+                        results: None,
+                    };
+                    ch.send((cx.props.conv_id, poll));
+                    cx.props.onclose.call(());
+                },
+            }
+        }
+    }))
+}
@@ -0,0 +1,161 @@
+use std::{path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, Context};
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    state::{configuration::Gifs, State},
+    warp_runner::{
+        gifs::{Gif, GifService},
+        OtherCmd, WarpCmd,
+    },
+    STATIC_ARGS, WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use dioxus_html::input_data::keyboard_types::Code;
+use futures::channel::oneshot;
+use kit::{
+    components::invisible_closer::InvisibleCloser,
+    elements::{
+        button::Button,
+        input::{Input, Options},
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+};
+
+// the search waits for the typing to stop
+const SEARCH_DELAY: Duration = Duration::from_millis(300);
+
+#[derive(Props)]
+pub struct Props<'a> {
+    onpick: EventHandler<'a, Gif>,
+    onclose: EventHandler<'a>,
+}
+
+#[allow(non_snake_case)]
+pub fn GifPicker<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let query = use_state(cx, String::new);
+    let config = state.read().configuration.gifs.clone();
+    let uses_folder = config.service == GifService::Folder || config.api_key.trim().is_empty();
+
+    let results = use_future(cx, (query.get(), &config), |(query, config)| async move {
+        if !query.is_empty() {
+            tokio::time::sleep(SEARCH_DELAY).await;
+        }
+        search(config, query).await
+    });
+    let gifs = match results.value() {
+        Some(Ok(gifs)) => gifs.clone(),
+        _ => vec![],
+    };
+    let first = gifs.first().cloned();
+
+    cx.render(rsx!(
+        InvisibleCloser {
+            onclose: move |_| cx.props.onclose.call(()),
+        },
+        div {
+            id: "gif-picker",
+            aria_label: "gif-picker",
+            onkeydown: move |evt| {
+                if evt.code() == Code::Escape {
+                    cx.props.onclose.call(());
+                }
+            },
+            div {
+                class: "gif-picker-search",
+                Input {
+                    id: "gif-search-input".to_owned(),
+                    aria_label: "gif-search-input".into(),
+                    focus: true,
+                    placeholder: get_local_text("messages.search-gifs"),
+                    options: Options {
+                        clear_on_submit: false,
+                        ..Default::default()
+                    },
+                    onchange: move |(val, _): (String, bool)| query.set(val.trim().to_string()),
+                    // the best match is sent
+                    onreturn: move |_| {
+                        if let Some(gif) = first.clone() {
+                            cx.props.onpick.call(gif);
+                        }
+                    },
+                },
+                Button {
+                    icon: Icon::FolderOpen,
+                    appearance: Appearance::Secondary,
+                    aria_label: "open-gif-folder".into(),
+                    onpress: move |_| {
+                        let _ = opener::open(&STATIC_ARGS.gifs_path);
+                    },
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Right,
+                        text: get_local_text("messages.open-gif-folder"),
+                    })),
+                },
+            },
+            div {
+                class: "gif-picker-results",
+                match results.value() {
+                    None => rsx!(p {
+                        class: "gif-picker-note",
+                        get_local_text("messages.loading")
+                    }),
+                    Some(Err(_)) => rsx!(p {
+                        class: "gif-picker-note",
+                        aria_label: "gif-search-failed",
+                        get_local_text("messages.gif-search-failed")
+                    }),
+                    Some(Ok(found)) if found.is_empty() => rsx!(p {
+                        class: "gif-picker-note",
+                        aria_label: "no-gifs-found",
+                        get_local_text(if uses_folder { "messages.gif-folder-hint" } else { "messages.no-gifs-found" })
+                    }),
+                    Some(Ok(_)) => rsx!(div {
+                        class: "gif-grid",
+                        gifs.into_iter().map(|gif| {
+                            let src = gif.preview.src();
+                            rsx!(img {
+                                key: "{gif.id}",
+                                class: "gif",
+                                aria_label: "gif",
+                                src: "{src}",
+                                title: "{gif.title}",
+                                alt: "{gif.title}",
+                                onclick: move |_| cx.props.onpick.call(gif.clone()),
+                            })
+                        })
+                    }),
+                }
+            }
+        }
+    ))
+}
+
+async fn search(config: Gifs, query: String) -> Result<Vec<Gif>, String> {
+    let (tx, rx) = oneshot::channel();
+    WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Other(OtherCmd::SearchGifs {
+            config,
+            query,
+            rsp: tx,
+        }))
+        .map_err(|e| e.to_string())?;
+    match rx.await {
+        Ok(r) => r.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Where the picked GIF is on the disk. GIFs from a service are downloaded first.
+pub async fn save(gif: Gif) -> anyhow::Result<PathBuf> {
+    let (tx, rx) = oneshot::channel();
+    WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Other(OtherCmd::SaveGif { gif, rsp: tx }))
+        .map_err(|e| anyhow!("failed to send warp command: {e}"))?;
+    Ok(rx.await.context("command canceled")??)
+}
@@ -1,4 +1,7 @@
+mod commands;
 pub mod coroutines;
+mod create_poll;
+mod gif_picker;
 
 use std::{path::PathBuf, time::Duration};
 
@@ -6,14 +9,17 @@ use common::{
     icons::{self},
     language::{get_local_text, get_local_text_with_args},
     state::{
-        utils::{mention_to_did_key, parse_mentions},
-        Action, Identity, State,
+        mentions::GROUP_MENTIONS,
+        utils::{mention_to_did_key, parse_mentions, typed_lines},
+        Action, Identity, State, ToastNotification,
     },
+    warp_runner::gifs::Gif,
     MAX_FILES_PER_MESSAGE, STATIC_ARGS,
 };
 use dioxus::prelude::*;
 use dioxus_html::input_data::keyboard_types::Code;
 use dioxus_html::input_data::keyboard_types::Modifiers;
+use extensions::commands::Output;
 use kit::{
     components::{
         indicator::{Platform, Status},
@@ -21,10 +27,11 @@ use kit::{
     },
     elements::{
         button::Button,
+        textarea::PastedData,
         tooltip::{ArrowPosition, Tooltip},
         Appearance,
     },
-    layout::chatbar::{Chatbar, Reply, SuggestionType},
+    layout::chatbar::{Chatbar, Recording, Reply, SuggestionType, TagSuggestion},
 };
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -52,9 +59,12 @@ use crate::{
     },
     utils::{
         build_user_from_identity,
-        clipboard::clipboard_data::{
-            check_if_there_is_file_or_string_in_clipboard, get_files_path_from_clipboard,
-            ClipboardDataType,
+        clipboard::{
+            clipboard_data::{
+                check_if_there_is_file_or_string_in_clipboard, get_files_path_from_clipboard,
+                ClipboardDataType,
+            },
+            paste::{self, Paste},
         },
     },
 };
@@ -82,6 +92,8 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
     let update_script = use_state(cx, String::new);
     let upload_button_menu_uuid = &*cx.use_hook(|| Uuid::new_v4().to_string());
     let show_storage_modal = use_state(cx, || false);
+    let show_create_poll = use_state(cx, || false);
+    let show_gif_picker = use_state(cx, || false);
 
     let suggestions = use_state(cx, || SuggestionType::None);
     let mentions = use_ref(cx, Vec::new);
@@ -162,16 +174,20 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                     .unwrap_or_default(),
                 conv_id: active_chat_id,
                 replying_to: None,
+                attachments: None,
             });
         }
         to_send.with_mut(|s| s.messages_to_send.clear())
     }
+    let call_ch = coroutines::get_call_ch(cx, state);
     let local_typing_ch = coroutines::get_typing_ch(cx);
     let local_typing_ch2 = local_typing_ch.clone();
 
     // drives the sending of TypingIndicator
     let local_typing_ch1 = local_typing_ch.clone();
     let enable_paste_shortcut = use_ref(cx, || true);
+    // a long paste until it's decided what to do with it
+    let long_paste: &UseRef<Option<(Paste, PastedData)>> = use_ref(cx, || None);
 
     use_future(cx, (), |_| {
         to_owned![enable_paste_shortcut];
@@ -231,25 +247,59 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
         .unwrap_or_default();
     let chat_participants_2 = chat_participants.clone();
     let chat_participants_3 = chat_participants.clone();
+    let can_mention_group = chat_data
+        .read()
+        .active_chat
+        .can_mention_group(&state.read().did_key());
 
     let submit_fn = move || {
         local_typing_ch.send(TypingIndicator::NotTyping);
         let active_chat_id = chat_data.read().active_chat.id();
 
-        let msg = state
+        let draft = state
             .read()
             .get_active_chat()
             .as_ref()
             .and_then(|d| d.draft.clone())
-            .map(|msg| {
-                let (txt, _) =
-                    parse_mentions(&msg, &chat_participants_3, &my_id, true, mention_to_did_key);
-                txt
-            })
-            .unwrap_or_default()
-            .lines()
-            .map(|x| x.trim_end().to_string())
-            .collect::<Vec<String>>();
+            .unwrap_or_default();
+        let command = commands::run(
+            &draft,
+            commands::Context {
+                state,
+                chat_data,
+                show_create_poll,
+                call_ch: &call_ch,
+            },
+        );
+        let msg = match command {
+            Some(Output::Send(lines)) => lines,
+            Some(Output::Draft(text)) => {
+                state
+                    .write()
+                    .mutate(Action::SetChatDraft(active_chat_id, text));
+                suggestions.set(SuggestionType::None);
+                return;
+            }
+            Some(Output::Nothing) => {
+                state
+                    .write()
+                    .mutate(Action::SetChatDraft(active_chat_id, String::new()));
+                suggestions.set(SuggestionType::None);
+                return;
+            }
+            None => {
+                let (txt, _) = parse_mentions(
+                    &draft,
+                    &chat_participants_3,
+                    &my_id,
+                    true,
+                    mention_to_did_key,
+                );
+                txt.lines()
+                    .map(|x| x.trim_end().to_string())
+                    .collect::<Vec<String>>()
+            }
+        };
 
         if !active_chat_id.is_nil() {
             state
@@ -276,12 +326,84 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                 msg,
                 conv_id: active_chat_id,
                 replying_to,
+                attachments: None,
             });
         }
     };
 
     let submit_fn2 = submit_fn.clone();
 
+    let voice_msg_ch = msg_ch.clone();
+    let send_voice_message = move |recording: Recording| {
+        let active_chat_id = chat_data.read().active_chat.id();
+        if active_chat_id.is_nil() {
+            return;
+        }
+        let replying_to = state.read().chats().get_replying_to();
+        if replying_to.is_some() {
+            state.write().mutate(Action::CancelReply(active_chat_id));
+        }
+        to_owned![state, voice_msg_ch];
+        // sent on its own, whatever is attached stays for the next message
+        cx.spawn(async move {
+            match recording.await {
+                Ok(path) => voice_msg_ch.send(MsgChInput {
+                    msg: vec![],
+                    conv_id: active_chat_id,
+                    replying_to,
+                    attachments: Some(vec![Location::Disk { path }]),
+                }),
+                Err(e) => {
+                    log::error!("failed to record a voice message: {e}");
+                    state
+                        .write()
+                        .mutate(Action::AddToastNotification(ToastNotification::init(
+                            "".into(),
+                            get_local_text("messages.voice-message-failed"),
+                            None,
+                            2,
+                        )));
+                }
+            }
+        });
+    };
+
+    let gif_msg_ch = msg_ch.clone();
+    let send_gif = move |gif: Gif| {
+        show_gif_picker.set(false);
+        let active_chat_id = chat_data.read().active_chat.id();
+        if active_chat_id.is_nil() {
+            return;
+        }
+        let replying_to = state.read().chats().get_replying_to();
+        if replying_to.is_some() {
+            state.write().mutate(Action::CancelReply(active_chat_id));
+        }
+        to_owned![state, gif_msg_ch];
+        // sent on its own like a voice message, once a GIF from a service was downloaded
+        cx.spawn(async move {
+            match gif_picker::save(gif).await {
+                Ok(path) => gif_msg_ch.send(MsgChInput {
+                    msg: vec![],
+                    conv_id: active_chat_id,
+                    replying_to,
+                    attachments: Some(vec![Location::Disk { path }]),
+                }),
+                Err(e) => {
+                    log::error!("failed to send a GIF: {e}");
+                    state
+                        .write()
+                        .mutate(Action::AddToastNotification(ToastNotification::init(
+                            "".into(),
+                            get_local_text("messages.gif-failed"),
+                            None,
+                            2,
+                        )));
+                }
+            }
+        });
+    };
+
     let extensions = &state.read().ui.extensions;
     let ext_renders = extensions
         .values()
@@ -320,6 +442,81 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
             error.set((false, active_chat_id));
         }
     };
+    let insert_paste = move |text: &str, data: &PastedData| {
+        insert_into_draft(state, active_chat_id, text, data);
+        validate_max();
+        update_send();
+    };
+
+    // the editor leaves the paste to uplink, so it has to end up somewhere
+    let on_paste = move |data: PastedData| {
+        if active_chat_id.is_nil() {
+            return;
+        }
+        // files are attached by the paste shortcut, only the text is left
+        if *enable_paste_shortcut.read() {
+            insert_paste(&data.text, &data);
+            return;
+        }
+        to_owned![state, long_paste];
+        cx.spawn(async move {
+            let (html, text) = (data.html.clone(), data.text.clone());
+            let paste =
+                match tokio::task::spawn_blocking(move || paste::process(html.as_deref(), &text))
+                    .await
+                {
+                    Ok(paste) => paste,
+                    Err(e) => {
+                        log::error!("failed to process a paste: {e}");
+                        insert_into_draft(&state, active_chat_id, &data.text, &data);
+                        return;
+                    }
+                };
+            // all of them go with the next message
+            if !paste.images.is_empty() {
+                state.write().mutate(Action::AppendChatAttachments(
+                    active_chat_id,
+                    paste.images.clone(),
+                ));
+            }
+            if paste.is_long() {
+                *long_paste.write() = Some((paste, data));
+            } else if !paste.text.is_empty() {
+                insert_into_draft(&state, active_chat_id, &paste.text, &data);
+            }
+        });
+    };
+
+    let attach_long_paste = move |_: Event<MouseData>| {
+        let Some((paste, _)) = long_paste.write().take() else {
+            return;
+        };
+        match paste.save_as_file() {
+            Ok(path) => {
+                state
+                    .write()
+                    .mutate(Action::AppendChatAttachments(active_chat_id, vec![path]));
+                update_send();
+            }
+            Err(e) => {
+                log::error!("failed to save a paste as a file: {e}");
+                state
+                    .write()
+                    .mutate(Action::AddToastNotification(ToastNotification::init(
+                        "".into(),
+                        get_local_text("messages.paste-as-file-failed"),
+                        None,
+                        2,
+                    )));
+            }
+        }
+    };
+
+    let long_paste_len = long_paste
+        .read()
+        .as_ref()
+        .map(|(paste, _)| paste.text.chars().count());
+
     let placeholder_text = if !state.read().ui.is_minimal_view() {
         get_local_text("messages.say-something-placeholder")
     } else {
@@ -361,11 +558,19 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
             },
             value: state.read().get_active_chat().as_ref().and_then(|d| d.draft.clone()).unwrap_or_default(),
             onreturn: move |_| submit_fn(),
+            on_paste: on_paste,
+            microphone: state.read().settings.input_device.clone(),
+            on_voice_message: move |recorded| send_voice_message(recorded),
             extensions: cx.render(rsx!(for node in ext_renders { rsx!(node) })),
             suggestions: suggestions,
             oncursor_update: move |(mut v, p): (String, i64)| {
                 if !active_chat_id.is_nil() {
                     let sub: String = v.chars().take(p as usize).collect();
+                    let command_suggestions = commands::suggestions(&state.read(), &sub);
+                    if !command_suggestions.is_empty() {
+                        suggestions.set(SuggestionType::Command(sub, command_suggestions));
+                        return;
+                    }
                     let emoji_capture = EMOJI_REGEX.captures(&sub);
                     if let Some(emoji) = emoji_capture {
                             let emoji = &emoji[0];
@@ -376,7 +581,9 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                             if emoji.ends_with(':') {
                                 // Replace emoji alias
                                 let alias = emoji.replace(':', "");
-                                let s = state.read().ui.emojis.get_matching_emoji(&alias, true);
+                                // custom emoji stay as they are, they're shown when the message is
+                                let is_custom = state.read().get_active_chat().is_some_and(|chat| chat.custom_emoji(&alias).is_some());
+                                let s = if is_custom { vec![] } else { state.read().ui.emojis.get_matching_emoji(&alias, true) };
                                 let replacement = s.first();
                                 if let Some((emoji, _)) = replacement {
                                     v = v.replace(&sub, &sub.replace(&format!(":{alias}:"), emoji));
@@ -386,7 +593,9 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                             } else {
                                 //Suggest emojis
                                 let alias = emoji.replace(':', "");
-                                suggestions.set(SuggestionType::Emoji(emoji.to_string(), state.read().ui.emojis.get_matching_emoji(&alias, false)));
+                                let mut matching = state.read().get_active_chat().map(|chat| chat.matching_custom_emoji(&alias)).unwrap_or_default();
+                                matching.extend(state.read().ui.emojis.get_matching_emoji(&alias, false));
+                                suggestions.set(SuggestionType::Emoji(emoji.to_string(), matching));
                             }
                             return;
                     }
@@ -408,9 +617,19 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                                 return;
                             }
                             let lower = tag.to_lowercase();
-                            let users: Vec<_> = chat_participants.iter().filter(|id|id.username().to_lowercase().starts_with(&lower))
+                            // the people talked to most recently come first
+                            let interactions = chat_data.read().active_chat.messages.recent_interactions(&state.read().did_key());
+                            let mut users: Vec<_> = chat_participants.iter().filter(|id|id.username().to_lowercase().starts_with(&lower))
                                 .cloned().collect();
-                            suggestions.set(SuggestionType::Tag(tag, users));
+                            users.sort_by(|a, b| {
+                                interactions.get(&b.did_key()).cmp(&interactions.get(&a.did_key()))
+                                    .then_with(|| a.username().to_lowercase().cmp(&b.username().to_lowercase()))
+                            });
+                            let mut tags: Vec<_> = users.into_iter().map(TagSuggestion::User).collect();
+                            if can_mention_group {
+                                tags.extend(GROUP_MENTIONS.iter().filter(|name| name.starts_with(&lower)).map(|name| TagSuggestion::Group(name.to_string())));
+                            }
+                            suggestions.set(SuggestionType::Tag(tag, tags));
                         }
                         None => {
                             suggestions.set(SuggestionType::None);
@@ -432,10 +651,11 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                         .write()
                         .mutate(Action::SetChatDraft(active_chat_id, draft));
                     if let SuggestionType::Tag(_, _) = suggestions.get() {
-                        let amount = replacement.chars().count() - 9;
-                        let name: String = replacement.chars().take(amount).collect(); // remove short did
-                        if let Some(participant) = chat_participants_2.iter().find(|id|id.username().eq(&name)) {
-                            mentions.write_silent().push((participant.did_key(), replacement));
+                        // remove short did. group mentions don't have one and stay as they are
+                        if let Some((name, _)) = replacement.rsplit_once('#') {
+                            if let Some(participant) = chat_participants_2.iter().find(|id|id.username().eq(name)) {
+                                mentions.write_silent().push((participant.did_key(), replacement.clone()));
+                            }
                         }
                     }
                     suggestions.set(SuggestionType::None);
@@ -450,6 +670,32 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
             },
             controls: cx.render(
                 rsx!(
+                    show_gif_picker.then(|| rsx!(gif_picker::GifPicker {
+                        onpick: send_gif,
+                        onclose: move |_| show_gif_picker.set(false),
+                    })),
+                    Button {
+                        icon: icons::outline::Shape::Gif,
+                        disabled: is_loading || disabled,
+                        appearance: if *show_gif_picker.get() { Appearance::Primary } else { Appearance::Secondary },
+                        aria_label: "gif-button".into(),
+                        onpress: move |_| show_gif_picker.set(!show_gif_picker.get()),
+                        tooltip: cx.render(rsx!(Tooltip {
+                            arrow_position: ArrowPosition::Bottom,
+                            text: get_local_text("messages.gifs"),
+                        })),
+                    },
+                    Button {
+                        icon: icons::outline::Shape::ChartBar,
+                        disabled: is_loading || disabled,
+                        appearance: Appearance::Secondary,
+                        aria_label: "create-poll-button".into(),
+                        onpress: move |_| show_create_poll.set(true),
+                        tooltip: cx.render(rsx!(Tooltip {
+                            arrow_position: ArrowPosition::Bottom,
+                            text: get_local_text("polls.create"),
+                        })),
+                    },
                     Button {
                         icon: icons::outline::Shape::ChevronDoubleRight,
                         disabled: is_loading || disabled,
@@ -484,7 +730,7 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                                         state.write().mutate(Action::CancelReply(active_chat_id))
                                     },
                                     attachments: msg.attachments(),
-                                    message: msg.lines().join("\n"), 
+                                    message: typed_lines(&msg.lines()).join("\n"),
                                     markdown: state.read().ui.should_transform_markdown_text(),
                                     transform_ascii_emojis: state.read().ui.should_transform_ascii_emojis(),
                                     state: state,
@@ -553,6 +799,42 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                 aria_label: "chatbar-input-error",
                 get_local_text_with_args("warning-messages.maximum-of", vec![("num", MAX_CHARS_LIMIT)])
             }
+        )),
+        long_paste_len.map(|len| rsx!(
+            div {
+                class: "paste-prompt",
+                aria_label: "paste-prompt",
+                p {
+                    get_local_text_with_args("messages.paste-long", vec![("num", len)])
+                },
+                Button {
+                    text: get_local_text("messages.paste-as-file"),
+                    small: true,
+                    appearance: Appearance::Primary,
+                    aria_label: "paste-as-file".into(),
+                    onpress: attach_long_paste,
+                },
+                Button {
+                    text: get_local_text("messages.paste-as-text"),
+                    small: true,
+                    appearance: Appearance::Secondary,
+                    aria_label: "paste-as-text".into(),
+                    onpress: move |_| {
+                        if let Some((paste, data)) = long_paste.write().take() {
+                            insert_paste(&paste.text, &data);
+                        }
+                    },
+                },
+                Button {
+                    icon: icons::outline::Shape::XMark,
+                    small: true,
+                    appearance: Appearance::Secondary,
+                    aria_label: "paste-cancel".into(),
+                    onpress: move |_| {
+                        long_paste.write().take();
+                    },
+                },
+            }
         ))
     ));
 
@@ -565,6 +847,10 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                             .mutate(Action::AppendChatAttachments(active_chat_id, files_local_path));
                     }})
             }
+                show_create_poll.then(|| rsx!(create_poll::CreatePoll {
+                    conv_id: active_chat_id,
+                    onclose: move |_| show_create_poll.set(false),
+                })),
                 SendFilesLayoutModal {
                     send_files_from_storage: show_storage_modal,
                     send_files_start_location: SendFilesStartLocation::Chats,
@@ -605,6 +891,13 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
             on_remove: move |files_attached| {
                 state.write().mutate(Action::SetChatAttachments(active_chat_id, files_attached));
                 update_send();
+            },
+            on_edit: move |files_attached| {
+                state.write().mutate(Action::SetChatAttachments(active_chat_id, files_attached));
+            },
+            compression: state.read().get_active_chat().and_then(|c| c.compression_to_send).unwrap_or(state.read().configuration.files.compression),
+            on_compression: move |compression| {
+                state.write().mutate(Action::SetChatCompression(active_chat_id, compression));
             }
         },
         chatbar
@@ -619,3 +912,18 @@ fn get_platform_and_status(msg_sender: Option<&Identity>) -> (Platform, Status,
     let user_sender = build_user_from_identity(sender);
     (user_sender.platform, user_sender.status, user_sender.photo)
 }
+
+// puts the paste where it was pasted into the draft of the chat
+fn insert_into_draft(state: &UseSharedState<State>, chat_id: Uuid, text: &str, data: &PastedData) {
+    let draft = state
+        .read()
+        .chats()
+        .all
+        .get(&chat_id)
+        .and_then(|chat| chat.draft.clone())
+        .unwrap_or_default();
+    state.write().mutate(Action::SetChatDraft(
+        chat_id,
+        data.insert_into(&draft, text),
+    ));
+}
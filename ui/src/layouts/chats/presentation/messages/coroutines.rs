@@ -1,9 +1,11 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use common::{
     language::get_local_text_with_args,
     state::{
+        chats::READ_RECEIPT_REACTION,
         data_transfer::{TrackerType, TransferState, TransferTracker},
+        poll::OPTION_EMOJIS,
         Action, State, ToastNotification,
     },
     warp_runner::{FetchMessagesConfig, FetchMessagesResponse, RayGunCmd, WarpCmd},
@@ -16,6 +18,7 @@ use dioxus_hooks::{
 };
 use futures::{channel::oneshot, pin_mut, StreamExt};
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use warp::raygun::{PinState, ReactionState};
 
@@ -32,14 +35,93 @@ use crate::{
 
 use super::{DownloadTracker, MessagesCommand};
 
+/// (conversation id, message id, date sent) of a message from someone else which came into view
+pub type ReadMessage = (Uuid, Uuid, DateTime<Utc>);
+
+/// Sends a read receipt for the newest message seen in each chat, see `READ_RECEIPT_REACTION`.
+/// Messages come into view in bursts, so they are collected for a moment before anything is sent.
+pub fn handle_read_receipts(
+    cx: &ScopeState,
+    state: &UseSharedState<State>,
+) -> Coroutine<ReadMessage> {
+    use_coroutine(cx, |mut rx: UnboundedReceiver<ReadMessage>| {
+        to_owned![state];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            let react = |conversation_id: Uuid, message_id: Uuid, reaction_state: ReactionState| {
+                let (tx, rx) = oneshot::channel();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::React {
+                    conversation_id,
+                    message_id,
+                    reaction_state,
+                    emoji: READ_RECEIPT_REACTION.into(),
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                }
+                rx
+            };
+            while let Some(first) = rx.next().await {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                let mut newest: HashMap<Uuid, (Uuid, DateTime<Utc>)> = HashMap::new();
+                let mut read = vec![first];
+                while let Ok(Some(next)) = rx.try_next() {
+                    read.push(next);
+                }
+                for (conv_id, msg_id, date) in read {
+                    let is_newer = newest
+                        .get(&conv_id)
+                        .map_or(true, |(_, newest)| *newest < date);
+                    if is_newer {
+                        newest.insert(conv_id, (msg_id, date));
+                    }
+                }
+
+                if !state.read().configuration.privacy.send_read_receipts {
+                    continue;
+                }
+                for (conv_id, (msg_id, date)) in newest {
+                    let previous = state
+                        .read()
+                        .chats()
+                        .all
+                        .get(&conv_id)
+                        .and_then(|chat| chat.read_receipt);
+                    if previous.map_or(false, |(_, read)| read >= date) {
+                        continue;
+                    }
+                    match react(conv_id, msg_id, ReactionState::Add).await {
+                        Ok(Ok(_)) => state
+                            .write_silent()
+                            .mutate(Action::SetReadReceipt(conv_id, msg_id, date)),
+                        Ok(Err(e)) => {
+                            log::error!("failed to send read receipt: {}", e);
+                            continue;
+                        }
+                        Err(_) => continue,
+                    }
+                    // the receipt moves along, it would mark older messages as read otherwise
+                    if let Some((id, _)) = previous.filter(|(id, _)| *id != msg_id) {
+                        if let Ok(Err(e)) = react(conv_id, id, ReactionState::Remove).await {
+                            log::error!("failed to remove read receipt: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+    })
+    .clone()
+}
+
 pub fn handle_msg_scroll(
     cx: &ScopeState,
     eval_provider: &crate::utils::EvalProvider,
     chat_data: &UseSharedState<ChatData>,
     scroll_btn: &UseSharedState<ScrollBtn>,
+    read_receipts: Coroutine<ReadMessage>,
 ) -> Coroutine<()> {
     let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<()>| {
-        to_owned![eval_provider, chat_data, scroll_btn];
+        to_owned![eval_provider, chat_data, scroll_btn, read_receipts];
         async move {
             let warp_cmd_tx = WARP_CMD_CH.tx.clone();
 
@@ -197,6 +279,11 @@ pub fn handle_msg_scroll(
                                         if !loaded1 && loaded2 {
                                             chat_data.write().active_chat.is_initialized = true;
                                         }
+
+                                        let own = chat_data.read().active_chat.my_id().did_key();
+                                        if let Some(date) = chat_data.read().active_chat.messages.received_date(&own, msg_id) {
+                                            read_receipts.send((conv_id, msg_id, date));
+                                        }
                                     },
                                     JsMsg::Remove { msg_id, .. } => {
                                         let loaded1 = chat_data.read().is_loaded(conv_id);
@@ -491,6 +578,90 @@ pub fn handle_warp_commands(
                             }
                         }
                     }
+                    MessagesCommand::Vote {
+                        user,
+                        message,
+                        poll,
+                        option,
+                    } => {
+                        let emoji = match OPTION_EMOJIS.get(option) {
+                            Some(emoji) => emoji.to_string(),
+                            None => continue,
+                        };
+                        let own_votes: Vec<String> = message
+                            .reactions()
+                            .iter()
+                            .filter(|(emoji, users)| {
+                                users.contains(&user)
+                                    && poll.option_for_emoji(&emoji.to_string()).is_some()
+                            })
+                            .map(|(emoji, _)| emoji.to_string())
+                            .collect();
+                        // a vote for a selected option takes it back. single choice polls replace the previous vote
+                        let mut changes = vec![];
+                        if own_votes.contains(&emoji) {
+                            changes.push((ReactionState::Remove, emoji));
+                        } else {
+                            if !poll.multiple_choice {
+                                changes.extend(
+                                    own_votes.into_iter().map(|e| (ReactionState::Remove, e)),
+                                );
+                            }
+                            changes.push((ReactionState::Add, emoji));
+                        }
+                        for (reaction_state, emoji) in changes {
+                            let (tx, rx) = futures::channel::oneshot::channel();
+                            if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::React {
+                                conversation_id: message.conversation_id(),
+                                message_id: message.id(),
+                                reaction_state,
+                                emoji,
+                                rsp: tx,
+                            })) {
+                                log::error!("failed to send warp command: {}", e);
+                                break;
+                            }
+                            if let Err(e) = rx.await.expect("command canceled") {
+                                log::error!("failed to vote: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                    MessagesCommand::ClosePoll { message, poll } => {
+                        if poll.results.is_some() {
+                            continue;
+                        }
+                        // the votes as they are now, not when the poll was rendered
+                        let message = state
+                            .read()
+                            .chats()
+                            .all
+                            .get(&message.conversation_id())
+                            .and_then(|chat| {
+                                chat.messages.iter().find(|m| m.inner.id() == message.id())
+                            })
+                            .map(|m| m.inner.clone())
+                            .unwrap_or(message);
+                        let closed = poll.closed(
+                            message
+                                .reactions()
+                                .iter()
+                                .map(|(emoji, users)| (emoji.to_string(), users)),
+                        );
+                        let (tx, rx) = futures::channel::oneshot::channel();
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::EditMessage {
+                            conv_id: message.conversation_id(),
+                            msg_id: message.id(),
+                            msg: closed.to_lines(),
+                            rsp: tx,
+                        })) {
+                            log::error!("failed to send warp command: {}", e);
+                            continue;
+                        }
+                        if let Err(e) = rx.await.expect("command canceled") {
+                            log::error!("failed to close poll: {}", e);
+                        }
+                    }
                     MessagesCommand::DeleteMessage { conv_id, msg_id } => {
                         let (tx, rx) = futures::channel::oneshot::channel();
                         if let Err(e) =
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use common::{
+    language::get_local_text,
+    state::{MessageRevision, State},
+    utils::diff::{diff_words, DiffSegment},
+};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+#[derive(Props, PartialEq)]
+pub struct Props {
+    conversation_id: Uuid,
+    message_id: Uuid,
+    // the message as it is now
+    lines: Vec<String>,
+    edited: DateTime<Utc>,
+}
+
+/// Every known version of an edited message, newest first, each compared to the one before it.
+#[allow(non_snake_case)]
+pub fn EditHistory(cx: Scope<Props>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let date_format = get_local_text("uplink.date-time-format");
+
+    let mut versions: Vec<MessageRevision> = state
+        .read()
+        .chats()
+        .revisions(cx.props.conversation_id, cx.props.message_id)
+        .to_vec();
+    versions.push(MessageRevision {
+        lines: cx.props.lines.clone(),
+        date: cx.props.edited,
+    });
+    // revisions from before this client saw the message are unknown
+    let complete = versions.len() > 1;
+
+    let entries = versions.iter().enumerate().rev().map(|(idx, version)| {
+        let text = version.lines.join("\n");
+        let segments = match idx.checked_sub(1).map(|prev| &versions[prev]) {
+            Some(prev) => diff_words(&prev.lines.join("\n"), &text),
+            None => vec![DiffSegment::Same(text)],
+        };
+        let date = version.date.format(&date_format).to_string();
+        let label = if idx + 1 == versions.len() {
+            get_local_text("messages.edit-history-current")
+        } else if idx == 0 && complete {
+            get_local_text("messages.edit-history-original")
+        } else {
+            String::new()
+        };
+        rsx!(div {
+            key: "{idx}",
+            class: "edit-revision",
+            aria_label: "edit-revision",
+            div {
+                class: "edit-revision-header",
+                span { class: "edit-revision-label", "{label}" },
+                span { class: "edit-revision-date", "{date}" },
+            },
+            p {
+                class: "edit-revision-text",
+                segments.into_iter().map(|segment| match segment {
+                    DiffSegment::Same(text) => rsx!(span { "{text}" }),
+                    DiffSegment::Added(text) => rsx!(span { class: "diff-added", "{text}" }),
+                    DiffSegment::Removed(text) => rsx!(span { class: "diff-removed", "{text}" }),
+                })
+            }
+        })
+    });
+
+    cx.render(rsx!(
+        div {
+            id: "edit-history",
+            aria_label: "edit-history",
+            (!complete).then(|| rsx!(
+                div {
+                    class: "edit-history-unknown",
+                    get_local_text("messages.edit-history-unknown")
+                }
+            )),
+            entries
+        }
+    ))
+}
@@ -0,0 +1,207 @@
+use common::{
+    icons::outline::Shape as Icon,
+    language::{get_local_text, get_local_text_with_args},
+    state::{forwarded::Forwarded, Action, State, ToastNotification},
+    warp_runner::{RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use futures::{channel::oneshot, StreamExt};
+use kit::{
+    components::{user::User, user_image::UserImage, user_image_group::UserImageGroup},
+    elements::{button::Button, checkbox::Checkbox, label::Label, Appearance},
+    layout::modal::Modal,
+};
+use uuid::Uuid;
+use warp::raygun::{self, ConversationType};
+
+use tracing::log;
+
+use crate::utils::{async_task_queue::chat_upload_stream_handler, build_participants};
+
+#[derive(PartialEq, Props)]
+pub struct Props {
+    message: UseState<Option<raygun::Message>>,
+}
+
+/// Picks the chats to forward a message to. The text is re-sent with where it came from, along
+/// with the attachments.
+#[allow(non_snake_case)]
+pub fn ForwardModal(cx: Scope<Props>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let chats_selected = use_ref(cx, Vec::new);
+    let upload_streams = chat_upload_stream_handler(cx);
+    let ch = use_coroutine(
+        cx,
+        |mut rx: UnboundedReceiver<(raygun::Message, Vec<String>, Vec<Uuid>)>| {
+            to_owned![state, upload_streams];
+            async move {
+                let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+                while let Some((message, msg, convs_id)) = rx.next().await {
+                    let (tx, rx) = oneshot::channel();
+                    let cmd = RayGunCmd::ForwardMessage {
+                        conv_id: message.conversation_id(),
+                        msg_id: message.id(),
+                        msg: msg.clone(),
+                        convs_id,
+                        rsp: tx,
+                    };
+                    if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(cmd)) {
+                        log::error!("failed to send warp command: {}", e);
+                        continue;
+                    }
+
+                    let mut stream = rx.await.expect("command canceled");
+                    while let Some(sent) = stream.next().await {
+                        match sent {
+                            Ok((chat, (id, stream))) => {
+                                state.write().increment_outgoing_messages_for(
+                                    chat,
+                                    id,
+                                    msg.clone(),
+                                );
+                                if let Some(stream) = stream {
+                                    upload_streams.write().append((chat, id, stream))
+                                }
+                            }
+                            Err(e) => {
+                                log::error!("failed to forward message: {}", e);
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        "".into(),
+                                        get_local_text("messages.forward-failed"),
+                                        None,
+                                        2,
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    );
+
+    let message = match cx.props.message.get() {
+        Some(message) => message.clone(),
+        None => return cx.render(rsx!(())),
+    };
+    let sender_name = state
+        .read()
+        .get_identity(&message.sender())
+        .map(|id| id.username())
+        .unwrap_or_else(|| get_local_text("uplink.unknown"));
+    let conversation_name = state
+        .read()
+        .get_chat_by_id(message.conversation_id())
+        .filter(|chat| chat.conversation_type == ConversationType::Group)
+        .map(|chat| match &chat.conversation_name {
+            Some(name) => name.clone(),
+            None => State::join_usernames(&state.read().chat_participants(&chat)),
+        });
+    let forwarded = Forwarded {
+        sender: message.sender(),
+        sender_name,
+        conversation_name,
+        date: message.date(),
+    };
+    let lines = forwarded.to_lines(&message.lines());
+
+    let chats = state.read().chats_sidebar();
+    cx.render(rsx!(Modal {
+        open: true,
+        onclose: move |_| cx.props.message.set(None),
+        show_close_button: false,
+        transparent: false,
+        close_on_click_inside_modal: false,
+        dont_pad: true,
+        div {
+            aria_label: "forward-modal",
+            class: "modal-share-friends",
+            div {
+                class: "modal-share-friends-header",
+                Label {
+                    aria_label: "forward-header".into(),
+                    text: get_local_text("messages.forward-to"),
+                },
+                div {
+                    class: "send-chat-button",
+                    Button {
+                        text: get_local_text("messages.forward"),
+                        icon: Icon::ArrowUturnRight,
+                        aria_label: "forward-button".into(),
+                        appearance: Appearance::Secondary,
+                        disabled: chats_selected.read().is_empty(),
+                        onpress: move |_| {
+                            ch.send((message.clone(), lines.clone(), chats_selected.read().clone()));
+                            cx.props.message.set(None);
+                        },
+                    },
+                }
+            }
+            chats.is_empty().then(|| rsx!(div {
+                class: "modal-share-friend-empty",
+                aria_label: "modal-share-friend-empty",
+                get_local_text("messages.no-chats")
+            })),
+            chats.iter().map(|chat| {
+                let id = chat.id;
+                let participants = state.read().chat_participants(chat);
+                let other_participants = state.read().remove_self(&participants);
+                let user = other_participants.first().cloned().unwrap_or_default();
+                let name = match &chat.conversation_name {
+                    Some(name) => name.clone(),
+                    None => State::join_usernames(&other_participants),
+                };
+                let subtext = match chat.conversation_type {
+                    ConversationType::Direct => String::new(),
+                    _ => get_local_text_with_args("uplink.members-count", vec![("num", participants.len())]),
+                };
+                let selected = chats_selected.read().contains(&id);
+                let toggle = move || chats_selected.with_mut(|v| {
+                    if selected {
+                        v.retain(|c| *c != id);
+                    } else {
+                        v.push(id);
+                    }
+                });
+                rsx!(div {
+                    key: "{id}",
+                    class: format_args!("modal-share-friend {}", if selected { "share-friend-selected" } else { "" }),
+                    User {
+                        aria_label: name.clone(),
+                        username: name,
+                        subtext: subtext,
+                        active: false,
+                        user_image: cx.render(rsx!(
+                            div {
+                                class: "modal-share-friend-image-group",
+                                Checkbox {
+                                    aria_label: "chat-to-forward-checkbox".into(),
+                                    disabled: false,
+                                    width: "1em".into(),
+                                    height: "1em".into(),
+                                    is_checked: selected,
+                                    on_click: move |_| toggle(),
+                                },
+                                match chat.conversation_type {
+                                    ConversationType::Direct => rsx!(UserImage {
+                                        platform: user.platform().into(),
+                                        status: user.identity_status().into(),
+                                        image: user.profile_picture(),
+                                        typing: false,
+                                    }),
+                                    _ => rsx!(UserImageGroup {
+                                        participants: build_participants(&participants),
+                                        typing: false,
+                                    })
+                                }
+                            }
+                        )),
+                        onpress: move |_| toggle(),
+                    }
+                })
+            })
+        }
+    }))
+}
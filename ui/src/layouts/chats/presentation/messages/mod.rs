@@ -8,17 +8,32 @@ use arboard::Clipboard;
 use dioxus::prelude::{EventHandler, *};
 
 mod coroutines;
+mod edit_history;
 mod effects;
+mod forward;
 
+use edit_history::EditHistory;
+use forward::ForwardModal;
+
+use chrono::Utc;
 use common::state::{
+    bookmarks::Bookmark,
+    chats::READ_RECEIPT_REACTION,
+    forwarded::Forwarded,
+    link_preview::LinkPreview,
     pending_message::{FileLocation, PendingMessage},
+    poll::Poll,
+    utils::typed_lines,
     Action, Identity, State,
 };
 use common::{
     icons::outline::Shape as Icon,
     icons::Icon as IconElement,
+    is_gif,
     language::get_local_text_with_args,
     state::{ui::EmojiDestination, ToastNotification},
+    utils::voice_message,
+    MAX_GIF_SIZE,
 };
 use kit::{
     components::{
@@ -27,12 +42,15 @@ use kit::{
         message::{Message, Order, ReactionAdapter},
         message_group::MessageGroup,
         message_reply::MessageReply,
+        poll::{Poll as PollView, PollOption},
         user_image::UserImage,
+        user_image_group::UserImageGroup,
     },
     elements::{
         loader::Loader,
         tooltip::{ArrowPosition, Tooltip},
     },
+    layout::modal::Modal,
 };
 
 use common::language::get_local_text;
@@ -43,7 +61,7 @@ use warp::{
     constellation::file::File,
     crypto::DID,
     multipass::identity::IdentityStatus,
-    raygun::{self},
+    raygun::{self, ConversationType},
 };
 
 use tracing::log;
@@ -53,11 +71,12 @@ use crate::{
     layouts::{
         chats::{
             data::{self, ChatData, MessagesToEdit, MessagesToSend, ScrollBtn},
+            presentation::bookmarks::EditBookmark,
             scripts,
         },
         storage::files_layout::file_preview::open_file_preview_modal,
     },
-    utils::format_timestamp::format_timestamp_timeago,
+    utils::{build_participants, format_timestamp::format_timestamp_timeago},
 };
 
 #[allow(clippy::large_enum_variant)]
@@ -79,6 +98,16 @@ pub enum MessagesCommand {
         msg: Vec<String>,
     },
     Pin(raygun::Message),
+    Vote {
+        user: DID,
+        message: raygun::Message,
+        poll: Poll,
+        option: usize,
+    },
+    ClosePoll {
+        message: raygun::Message,
+        poll: Poll,
+    },
 }
 
 pub type DownloadTracker = HashMap<Uuid, HashSet<warp::constellation::file::File>>;
@@ -96,7 +125,8 @@ pub fn get_messages(
     let pending_downloads = use_shared_state::<DownloadTracker>(cx)?;
 
     let eval = use_eval(cx);
-    let ch = coroutines::handle_msg_scroll(cx, eval, chat_data, scroll_btn);
+    let read_receipts = coroutines::handle_read_receipts(cx, state);
+    let ch = coroutines::handle_msg_scroll(cx, eval, chat_data, scroll_btn, read_receipts);
     let fetch_later_ch = coroutines::fetch_later_ch(cx, chat_data, scroll_btn);
     effects::init_msg_scroll(cx, chat_data, eval, ch);
 
@@ -175,7 +205,7 @@ pub fn get_messages(
                 rsx!(
                     msg_container_end,
                     loop_over_message_groups {
-                        groups: data::create_message_groups(chat_data.read().active_chat.my_id(), chat_data.read().active_chat.other_participants(), chat_data.read().active_chat.messages()),
+                        groups: data::create_message_groups(chat_data.read().active_chat.my_id(), chat_data.read().active_chat.other_participants(), chat_data.read().active_chat.messages(), chat_data.read().active_chat.messages.seen_by()),
                         active_chat_id: chat_data.read().active_chat.id(),
                         on_context_menu_action: move |(e, mut id): (Event<MouseData>, Identity)| {
                             let own = state.read().get_own_identity().did_key().eq(&id.did_key());
@@ -343,9 +373,12 @@ struct MessagesProps<'a> {
 }
 fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Element<'a> {
     let state = use_shared_state::<State>(cx)?;
+    let chat_data = use_shared_state::<ChatData>(cx)?;
     let edit_msg = use_shared_state::<MessagesToEdit>(cx)?;
     // see comment in ContextMenu about this variable.
     let reacting_to: &UseState<Option<Uuid>> = use_state(cx, || None);
+    let forwarding: &UseState<Option<raygun::Message>> = use_state(cx, || None);
+    let saving: &UseState<Option<Bookmark>> = use_state(cx, || None);
 
     let emoji_selector_extension = "emoji_selector";
 
@@ -370,6 +403,13 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
         let context_key = format!("message-{}", &message_id);
         let msg_uuid = message.inner.id();
         let conversation_id = message.inner.conversation_id();
+        let is_poll = Poll::from_lines(&message.inner.lines()).is_some();
+        let can_pin = !message.inner.pinned()
+            || chat_data
+                .read()
+                .active_chat
+                .can_unpin(&message.inner, &state.read().did_key());
+        let bookmark = state.read().chats().bookmarks.get(&msg_uuid).cloned();
 
         if cx.props.pending {
             return rsx!(render_message {
@@ -407,6 +447,7 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
                 ContextItem {
                     icon: Icon::Pin,
                     aria_label: "messages-pin".into(),
+                    should_render: can_pin,
                     text: if message.inner.pinned() {get_local_text("messages.unpin")} else {get_local_text("messages.pin")},
                     onpress: move |_| {
                         log::trace!("pinning message: {}", message.inner.id());
@@ -432,6 +473,37 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
                             .mutate(Action::StartReplying(&cx.props.active_chat_id, message));
                     }
                 },
+                ContextItem {
+                    icon: Icon::ChatBubbleLeftRight,
+                    aria_label: "messages-reply-in-thread".into(),
+                    text: get_local_text("messages.reply-in-thread"),
+                    onpress: move |_| {
+                        let root = message.thread_id.unwrap_or(msg_uuid);
+                        state
+                            .write()
+                            .mutate(Action::OpenThread(cx.props.active_chat_id, root));
+                    }
+                },
+                ContextItem {
+                    icon: Icon::ArrowUturnRight,
+                    aria_label: "messages-forward".into(),
+                    text: get_local_text("messages.forward"),
+                    should_render: !is_poll,
+                    onpress: move |_| {
+                        forwarding.set(Some(message.inner.clone()));
+                    }
+                },
+                ContextItem {
+                    icon: Icon::Bookmark,
+                    aria_label: "messages-save".into(),
+                    text: if bookmark.is_some() {get_local_text("bookmarks.edit")} else {get_local_text("bookmarks.save")},
+                    onpress: move |_| {
+                        let bookmark = bookmark
+                            .clone()
+                            .unwrap_or_else(|| Bookmark::new(&message.inner, String::new(), vec![]));
+                        saving.set(Some(bookmark));
+                    }
+                },
                 ContextItem {
                     icon: Icon::FaceSmile,
                     aria_label: "messages-react".into(),
@@ -464,7 +536,8 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
                     aria_label: "messages-copy".into(),
                     text: get_local_text("uplink.copy-text"),
                     onpress: move |_| {
-                        let text = message.inner.lines().join("\n");
+                        let lines = message.inner.lines();
+                        let text = typed_lines(&lines).join("\n");
                         match Clipboard::new() {
                             Ok(mut c) => {
                                 if let Err(e) = c.set_text(text) {
@@ -482,6 +555,7 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
                     aria_label: "messages-edit".into(),
                     text: get_local_text("messages.edit"),
                     should_render: !cx.props.is_remote
+                        && !is_poll
                         && edit_msg.read().edit.map(|id| id != msg_uuid).unwrap_or(true),
                     onpress: move |_| {
                         edit_msg.write().edit = Some(msg_uuid);
@@ -514,7 +588,14 @@ fn wrap_messages_in_context_menu<'a>(cx: Scope<'a, MessagesProps<'a>>) -> Elemen
                 },
             )) // end of context menu items
         }) // end context menu
-    }))) // end outer cx.render
+    }),
+    ForwardModal {
+        message: forwarding.clone(),
+    },
+    saving.get().as_ref().map(|bookmark| rsx!(EditBookmark {
+        bookmark: bookmark.clone(),
+        onclose: move |_| saving.set(None),
+    })))) // end outer cx.render
 }
 
 #[derive(Props)]
@@ -553,10 +634,19 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
         .map(|id| !cx.props.is_remote && (id == message.inner.id()))
         .unwrap_or(false);
 
-    let reactions_list: Vec<ReactionAdapter> = message
-        .inner
-        .reactions()
+    let poll = Poll::from_lines(&message.inner.lines());
+    let reactions = message.inner.reactions();
+
+    let reactions_list: Vec<ReactionAdapter> = reactions
         .iter()
+        // votes are shown in the poll, read receipts below the message
+        .filter(|(emoji, _)| {
+            *emoji != READ_RECEIPT_REACTION
+                && poll
+                    .as_ref()
+                    .and_then(|poll| poll.option_for_emoji(&emoji.to_string()))
+                    .is_none()
+        })
         .map(|(emoji, users)| {
             let user_names: Vec<String> = users
                 .iter()
@@ -576,9 +666,27 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
     let pending_uploads = grouped_message.file_progress.as_ref();
     let render_markdown = state.read().ui.should_transform_markdown_text();
     let should_transform_ascii_emojis = state.read().ui.should_transform_ascii_emojis();
-    let msg_lines = message.inner.lines().join("\n");
+    let all_lines = message.inner.lines();
+    let (forwarded, lines) = Forwarded::split(&all_lines);
+    let (link_preview, _) = LinkPreview::split(lines);
+    let msg_lines = typed_lines(&all_lines).join("\n");
+    let forwarded_unverified = get_local_text("messages.forwarded-unverified");
+    let forwarded_from = forwarded.as_ref().map(|forwarded| {
+        let user = ("user", forwarded.sender_name.clone());
+        match &forwarded.conversation_name {
+            Some(chat) => get_local_text_with_args(
+                "messages.forwarded-from-chat",
+                vec![user, ("chat", chat.clone())],
+            ),
+            None => get_local_text_with_args("messages.forwarded-from", vec![user]),
+        }
+    });
 
-    let is_mention = message.clone().is_mention_self(&user_did);
+    let group_mentions = chat_data
+        .read()
+        .active_chat
+        .can_mention_group(&message.inner.sender());
+    let is_mention = message.clone().is_mention_self(&user_did, group_mentions);
     let preview_file_in_the_message: &UseState<(bool, Option<File>)> =
         use_state(cx, || (false, None));
 
@@ -588,6 +696,143 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
     }
     let to_send = use_shared_state::<MessagesToSend>(cx)?;
 
+    let is_poll = poll.is_some();
+    // the creator closes a timed poll once it runs out, so votes added later don't count
+    let closes_at = poll
+        .as_ref()
+        .filter(|poll| poll.results.is_none() && message.inner.sender() == user_did)
+        .and_then(|poll| poll.closes_at);
+    use_future(cx, &closes_at, |closes_at| {
+        to_owned![ch, poll, message];
+        async move {
+            let (Some(closes_at), Some(poll)) = (closes_at, poll) else {
+                return;
+            };
+            if let Ok(wait) = (closes_at - Utc::now()).to_std() {
+                tokio::time::sleep(wait).await;
+            }
+            ch.send(MessagesCommand::ClosePoll {
+                message: message.inner,
+                poll,
+            });
+        }
+    });
+
+    let poll_content = poll.map(|poll| {
+        let voters = poll.voters(
+            reactions
+                .iter()
+                .map(|(emoji, users)| (emoji.to_string(), users)),
+        );
+        let tally = poll.tally(
+            reactions
+                .iter()
+                .map(|(emoji, users)| (emoji.to_string(), users)),
+        );
+        let options: Vec<PollOption> = poll
+            .options
+            .iter()
+            .zip(voters.iter().zip(tally.iter()))
+            .map(|(text, (voters, votes))| PollOption {
+                text: text.clone(),
+                votes: *votes,
+                voters: if poll.anonymous {
+                    vec![]
+                } else {
+                    voters
+                        .iter()
+                        .filter_map(|did| state.read().get_identity(did).map(|id| id.username()))
+                        .collect()
+                },
+                selected: voters.contains(&user_did),
+            })
+            .collect();
+        let closes = poll.closes_at.map(|date| {
+            get_local_text_with_args(
+                "polls.closes",
+                vec![(
+                    "date",
+                    date.format(&get_local_text("uplink.date-time-format"))
+                        .to_string(),
+                )],
+            )
+        });
+        let question = poll.question.clone();
+        let multiple_choice = poll.multiple_choice;
+        let anonymous = poll.anonymous;
+        let closed = poll.is_closed(Utc::now());
+        let can_close = message.inner.sender() == user_did;
+        let closing_poll = poll.clone();
+        let user_did = user_did.clone();
+        rsx!(PollView {
+            question: question,
+            options: options,
+            multiple_choice: multiple_choice,
+            anonymous: anonymous,
+            closed: closed,
+            closes: closes,
+            remote: cx.props.is_remote,
+            can_close: can_close,
+            on_vote: move |option| {
+                ch.send(MessagesCommand::Vote {
+                    user: user_did.clone(),
+                    message: message.inner.clone(),
+                    poll: poll.clone(),
+                    option,
+                });
+            },
+            on_close: move |_| {
+                ch.send(MessagesCommand::ClosePoll {
+                    message: message.inner.clone(),
+                    poll: closing_poll.clone(),
+                });
+            },
+        })
+    });
+
+    let show_edit_history = use_state(cx, || false);
+    // closing a poll or adding a link preview edits the message, which isn't worth pointing out
+    let automatic_edit = is_poll
+        || (link_preview.is_some()
+            && message.inner.modified().map_or(false, |modified| {
+                LinkPreview::is_preview_edit(message.inner.date(), modified)
+            }));
+    let edited = message
+        .inner
+        .modified()
+        .filter(|_| !automatic_edit)
+        .map(|date| {
+            (
+                date,
+                get_local_text_with_args(
+                    "messages.edited-at",
+                    vec![(
+                        "date",
+                        date.format(&get_local_text("uplink.date-time-format"))
+                            .to_string(),
+                    )],
+                ),
+            )
+        });
+
+    // (replies, unread replies) if this message started a thread
+    let thread = state
+        .read()
+        .chats()
+        .all
+        .get(&message.inner.conversation_id())
+        .and_then(|chat| chat.threads.get(&message.inner.id()))
+        .filter(|thread| !thread.replies.is_empty())
+        .map(|thread| (thread.replies.len(), thread.unreads()));
+
+    // in group chats, the participants who read up to this message
+    let seen_by = if chat_data.read().active_chat.conversation_type() == ConversationType::Group {
+        state.read().get_identities(&grouped_message.seen_by)
+    } else {
+        vec![]
+    };
+    let seen_by_names = State::join_usernames(&seen_by);
+
     cx.render(rsx!(
         div {
             class: "msg-wrapper",
@@ -643,13 +888,27 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                     }))
                 }
             )),
+            forwarded_from.map(|text| rsx!(
+                div {
+                    class: format_args!("message-forwarded {}", if cx.props.is_remote { "remote" } else { "" }),
+                    aria_label: "message-forwarded",
+                    // the header is written by whoever forwarded the message, it isn't proof of the original sender
+                    title: "{forwarded_unverified}",
+                    IconElement {
+                        icon: Icon::ArrowUturnRight,
+                    },
+                    span { "{text}" }
+                }
+            )),
             Message {
                 id: message_key.clone(),
                 key: "{message_key}",
                 editing: is_editing,
                 remote: cx.props.is_remote,
                 with_text: msg_lines,
+                with_content: poll_content.and_then(|poll| cx.render(poll)),
                 is_mention: is_mention,
+                link_preview: link_preview.clone(),
                 reactions: reactions_list,
                 state: state,
                 chat: chat_data.read().active_chat.id(),
@@ -663,7 +922,10 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                 },
                 pending: cx.props.pending,
                 pinned: message.inner.pinned(),
+                edited: edited.as_ref().map(|(_, tooltip)| tooltip.clone()),
+                on_edited_click: move |_| show_edit_history.set(true),
                 attachments_pending_uploads: pending_uploads,
+                attachment_placeholders: &grouped_message.placeholders,
                 on_resend: move |(txt, file): (Option<String>, FileLocation)|{
                     match txt.clone() {
                         Some(_) => {
@@ -687,29 +949,104 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                 parse_markdown: render_markdown,
                 transform_ascii_emojis: should_transform_ascii_emojis,
                 on_download: move |(file, temp_dir): (warp::constellation::file::File, Option<PathBuf>)| {
-                    if temp_dir.is_some() {
-                        preview_file_in_the_message.set((true, Some(file.clone())));
-                    } else {
-                        download_file(&file, message.inner.conversation_id(), message.inner.id(), pending_downloads, ch);
+                    match temp_dir {
+                        // played right in the message
+                        Some(path) if voice_message::is_voice_message(&file.name()) => {
+                            ch.send(MessagesCommand::DownloadAttachment {
+                                conv_id: message.inner.conversation_id(),
+                                msg_id: message.inner.id(),
+                                file,
+                                file_path_to_download: path,
+                            });
+                        }
+                        // played on hover, it's opened once it was downloaded. Larger GIFs are
+                        // previewed like other images
+                        Some(path) if is_gif(&file.name()) && file.size() <= MAX_GIF_SIZE && !path.exists() => {
+                            ch.send(MessagesCommand::DownloadAttachment {
+                                conv_id: message.inner.conversation_id(),
+                                msg_id: message.inner.id(),
+                                file,
+                                file_path_to_download: path,
+                            });
+                        }
+                        Some(_) => preview_file_in_the_message.set((true, Some(file.clone()))),
+                        None => download_file(&file, message.inner.conversation_id(), message.inner.id(), pending_downloads, ch),
                     }
                 },
                 on_edit: move |update: String| {
                     edit_msg.write().edit = None;
                     state.write().ui.ignore_focus = false;
-                    let msg = update.split('\n').map(|x| x.to_string()).collect::<Vec<String>>();
-                    if  message.inner.lines() == msg || !msg.iter().any(|x| !x.trim().is_empty()) {
+                    let mut msg = update.split('\n').map(|x| x.to_string()).collect::<Vec<String>>();
+                    let is_empty = !msg.iter().any(|x| !x.trim().is_empty());
+                    // keep the preview while the link is still there
+                    if let Some(preview) = link_preview.as_ref().filter(|preview| update.contains(&preview.url)) {
+                        msg.push(preview.to_line());
+                    }
+                    // keep where a forwarded message came from
+                    if let Some(forwarded) = &forwarded {
+                        msg = forwarded.to_lines(&msg);
+                    }
+                    if  message.inner.lines() == msg || is_empty {
                         return;
                     }
                     ch.send(MessagesCommand::EditMessage { conv_id: message.inner.conversation_id(), msg_id: message.inner.id(), msg})
                 }
             },
-            script {
-                r#"
-                (() => {{
-                    Prism.highlightAll();
-                }})();
-                "#
-            } // Highlights Pre blocks
+            edited.as_ref().filter(|_| *show_edit_history.get()).map(|(date, _)| rsx!(
+                Modal {
+                    open: true,
+                    transparent: true,
+                    with_title: get_local_text("messages.edit-history"),
+                    onclose: move |_| show_edit_history.set(false),
+                    EditHistory {
+                        conversation_id: message.inner.conversation_id(),
+                        message_id: message.inner.id(),
+                        lines: message.inner.lines(),
+                        edited: *date,
+                    }
+                }
+            )),
+            thread.map(|(replies, unreads)| rsx!(
+                button {
+                    class: format_args!("thread-summary {}", if cx.props.is_remote { "remote" } else { "" }),
+                    aria_label: "thread-summary",
+                    onclick: move |_| {
+                        state
+                            .write()
+                            .mutate(Action::OpenThread(message.inner.conversation_id(), message.inner.id()));
+                    },
+                    IconElement {
+                        icon: Icon::ChatBubbleLeftRight,
+                    },
+                    span {
+                        class: "thread-replies",
+                        get_local_text_with_args("messages.thread-replies", vec![("amount", replies)])
+                    },
+                    (unreads > 0).then(|| rsx!(
+                        span {
+                            class: "thread-unreads",
+                            aria_label: "thread-unreads",
+                            "{unreads}"
+                        }
+                    )),
+                    span {
+                        class: "thread-view",
+                        get_local_text("messages.view-thread")
+                    }
+                }
+            )),
+            (!seen_by.is_empty()).then(|| rsx!(
+                div {
+                    class: format_args!("message-seen-by {}", if cx.props.is_remote { "remote" } else { "" }),
+                    aria_label: "message-seen-by",
+                    title: "{seen_by_names}",
+                    UserImageGroup {
+                        participants: build_participants(&seen_by),
+                        aria_label: get_local_text_with_args("messages.seen-by", vec![("names", seen_by_names.clone())]),
+                        typing: false,
+                    }
+                }
+            )),
         }
     ))
 }
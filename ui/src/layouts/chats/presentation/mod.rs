@@ -1,3 +1,4 @@
+pub mod bookmarks;
 pub mod chat;
 pub mod chatbar;
 pub mod context_menus;
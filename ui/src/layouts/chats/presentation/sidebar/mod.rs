@@ -3,7 +3,9 @@ mod search;
 
 use common::language::{get_local_text, get_local_text_with_args};
 use common::state::ui::Layout;
-use common::state::{self, identity_search_result, Action, Chat, Identity, State};
+use common::state::{
+    self, identity_search_result, utils::typed_lines, Action, Chat, Identity, State,
+};
 use common::warp_runner::{RayGunCmd, WarpCmd};
 use common::{icons::outline::Shape as Icon, WARP_CMD_CH};
 use dioxus::html::input_data::keyboard_types::Code;
@@ -42,6 +44,7 @@ use tracing::log;
 use crate::components::file_transfer::FileTransferModal;
 use crate::components::media::calling::CallControl;
 
+use crate::layouts::chats::presentation::bookmarks::SavedMessages;
 use crate::layouts::chats::presentation::sidebar::create_group::CreateGroup;
 use crate::utils::build_participants;
 use crate::UplinkRoute;
@@ -107,6 +110,14 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
     };
 
     let show_create_group = use_state(cx, || false);
+    let show_saved = use_state(cx, || false);
+    // only show chats with unread mentions
+    let mentions_only = use_state(cx, || false);
+    let has_chats = !sidebar_chats.is_empty();
+    let sidebar_chats: Vec<Chat> = sidebar_chats
+        .into_iter()
+        .filter(|chat| !mentions_only.get() || chat.unread_mentions() > 0)
+        .collect();
 
     let extensions = &state.read().ui.extensions;
     let ext_renders = extensions
@@ -221,13 +232,41 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
             div {
                 id: "chats",
                 aria_label: "Chats",
-                (!sidebar_chats.is_empty()).then(|| rsx!(
+                has_chats.then(|| rsx!(
                     div {
                         class: "sidebar-chats-header",
                         Label {
                             text: get_local_text("uplink.chats"),
                             aria_label: "chats-label".into(),
                         },
+                        Button {
+                            appearance: if *mentions_only.get() { Appearance::Primary } else { Appearance::Secondary },
+                            aria_label: "mentions-filter".into(),
+                            icon: Icon::AtSymbol,
+                            tooltip: cx.render(rsx!(
+                                Tooltip {
+                                    arrow_position: ArrowPosition::Right,
+                                    text: get_local_text("messages.mentions-filter")
+                                }
+                            )),
+                            onpress: move |_| {
+                                mentions_only.set(!mentions_only.get());
+                            }
+                        }
+                        Button {
+                            appearance: if *show_saved.get() { Appearance::Primary } else { Appearance::Secondary },
+                            aria_label: "saved-messages".into(),
+                            icon: Icon::Bookmark,
+                            tooltip: cx.render(rsx!(
+                                Tooltip {
+                                    arrow_position: ArrowPosition::Right,
+                                    text: get_local_text("bookmarks.title")
+                                }
+                            )),
+                            onpress: move |_| {
+                                show_saved.set(!show_saved.get());
+                            }
+                        }
                         Button {
                             appearance: if *show_create_group.get() { Appearance::Primary } else { Appearance::Secondary },
                             aria_label: "create-group-chat".into(),
@@ -243,6 +282,11 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                             }
                         }
                     }
+                    show_saved.then(|| rsx!(
+                        SavedMessages {
+                            onclose: move |_| show_saved.set(false),
+                        }
+                    )),
                     show_create_group.then(|| {
                         let clss = format!(
                             "create-group-modal {}",
@@ -269,6 +313,13 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                         }
                     )}),
                 )),
+                (has_chats && sidebar_chats.is_empty()).then(|| rsx!(
+                    p {
+                        class: "sidebar-no-mentions",
+                        aria_label: "no-mentions",
+                        get_local_text("messages.no-mentions")
+                    }
+                )),
                 sidebar_chats.iter().cloned().map(|chat| {
                     let users_typing = chat.typing_indicator.iter().any(|(k, _)| *k != state.read().did_key());
                     let participants = state.read().chat_participants(&chat);
@@ -296,6 +347,7 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                     let is_active = state.read().get_active_chat().map(|c| c.id) == Some(chat.id);
                     let chat_with = chat.clone();
                     let clear_unreads = chat.clone();
+                    let link_previews_global = state.read().configuration.privacy.link_previews;
                     let markdown = false;
                     let should_transform_ascii_emojis = state.read().ui.should_transform_ascii_emojis();
 
@@ -305,7 +357,7 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                         None => State::join_usernames(&other_participants)
                     };
 
-                    let subtext_val = match unwrapped_message.lines().iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
+                    let subtext_val = match typed_lines(&unwrapped_message.lines()).iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
                         Some(v) => {
                             format_text(v, markdown, should_transform_ascii_emojis, Some((&state.read(), &chat.id, true)))
                         }
@@ -351,6 +403,16 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                                         state.write().mutate(Action::RemoveFromSidebar(chat.id));
                                     }
                                 },
+                                ContextItem {
+                                    icon: Icon::Link,
+                                    aria_label: "chats-link-previews".into(),
+                                    text: if chat.link_previews {get_local_text("uplink.disable-link-previews")}
+                                    else {get_local_text("uplink.enable-link-previews")},
+                                    should_render: !link_previews_global,
+                                    onpress: move |_| {
+                                        state.write().mutate(Action::SetChatLinkPreviews(chat.id, !chat.link_previews));
+                                    }
+                                },
                                 show_delete_conversation.read().then(||
                                     rsx!(
                                         ContextItem {
@@ -20,18 +20,13 @@ pub fn LoadingWash(cx: Scope) -> Element {
     }
 }
 
-pub fn use_loaded_assets(cx: &ScopeState) -> &UseFuture<Result<(), tokio::task::JoinError>> {
+pub fn use_loaded_assets(cx: &ScopeState) -> &UseFuture<()> {
     let desktop = dioxus_desktop::use_window(cx);
     let state = use_shared_state::<State>(cx).unwrap();
 
     use_future(cx, (), |_| {
         to_owned![desktop, state];
         async move {
-            let res = tokio::task::spawn_blocking(|| {
-                crate::utils::unzip_prism_langs();
-            })
-            .await;
-
             // Here we set the size larger, and bump up the min size in preparation for rendering the main app.
             if state.read().ui.window_maximized {
                 desktop.set_outer_position(LogicalPosition::new(0, 0));
@@ -40,8 +35,6 @@ pub fn use_loaded_assets(cx: &ScopeState) -> &UseFuture<Result<(), tokio::task::
                 desktop.set_inner_size(LogicalSize::new(950.0, 600.0));
             }
             desktop.set_min_inner_size(Some(LogicalSize::new(300.0, 500.0)));
-
-            res
         }
     })
 }
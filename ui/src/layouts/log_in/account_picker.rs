@@ -0,0 +1,120 @@
+use common::icons::outline::Shape as Icon;
+use common::{
+    language::get_local_text,
+    profiles::{self, Profile, Profiles},
+    warp_runner::WarpRunner,
+};
+use dioxus::prelude::*;
+use kit::{
+    components::context_menu::{ContextItem, ContextMenu},
+    elements::{
+        button::Button,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+};
+use tracing::log;
+
+use crate::bootstrap::restart_warp_runner;
+
+fn get_profile_name(profile: &Profile) -> String {
+    if profile.name.is_empty() {
+        get_local_text("unlock.new-account")
+    } else {
+        profile.name.clone()
+    }
+}
+
+/// Lists the profiles of this install. Picking one points the app at that profile's data and restarts warp.
+#[component]
+pub fn AccountPicker(cx: Scope, profile: UseState<String>, disabled: bool) -> Element {
+    let runner = use_shared_state::<WarpRunner>(cx)?;
+    let profiles = use_ref(cx, Profiles::load);
+    // warp is restarting for another profile, which can't be interrupted by another switch
+    let switching = use_state(cx, || false);
+    let disabled = *disabled || *switching.get();
+
+    let switch_profile = move |id: String| {
+        if id == *profile.get() {
+            return;
+        }
+        if let Err(e) = profiles::set_active_profile(&id) {
+            log::error!("failed to switch profile: {e}");
+            return;
+        }
+        switching.set(true);
+        to_owned![runner, profile, switching];
+        // the profile is only picked once warp runs on its data, the login screen would talk to
+        // the previous profile's warp otherwise
+        cx.spawn(async move {
+            restart_warp_runner(runner).await;
+            profile.set(id);
+            switching.set(false);
+        });
+    };
+
+    let profile_list = profiles.read().profiles.clone();
+
+    cx.render(rsx!(
+        div {
+            id: "account-picker",
+            aria_label: "account-picker",
+            profile_list.into_iter().map(|p| {
+                let is_active = p.id == *profile.get();
+                let removable = !is_active && p.id != profiles::DEFAULT_PROFILE;
+                let name = get_profile_name(&p);
+                let id = p.id.clone();
+                let remove_id = p.id.clone();
+                rsx!(
+                    ContextMenu {
+                        key: "{p.id}",
+                        id: format!("account-{}", p.id),
+                        items: cx.render(rsx!(
+                            ContextItem {
+                                icon: Icon::Trash,
+                                danger: true,
+                                aria_label: "remove-account".into(),
+                                text: get_local_text("unlock.remove-account"),
+                                disabled: !removable || disabled,
+                                onpress: move |_| {
+                                    if let Err(e) = profiles.write().remove(&remove_id) {
+                                        log::error!("failed to remove profile: {e}");
+                                    }
+                                }
+                            },
+                        )),
+                        Button {
+                            aria_label: "account-picker-account".into(),
+                            text: name,
+                            icon: Icon::UserCircle,
+                            small: true,
+                            disabled: disabled,
+                            appearance: if is_active { Appearance::Primary } else { Appearance::Secondary },
+                            onpress: move |_| switch_profile(id.clone()),
+                        }
+                    }
+                )
+            }),
+            Button {
+                aria_label: "add-account".into(),
+                icon: Icon::Plus,
+                small: true,
+                disabled: disabled,
+                appearance: Appearance::Secondary,
+                tooltip: cx.render(rsx!(
+                    Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: get_local_text("unlock.add-account"),
+                    }
+                )),
+                onpress: move |_| {
+                    let new_profile = profiles.write().add(String::new());
+                    match new_profile {
+                        Ok(p) => switch_profile(p.id),
+                        Err(e) => log::error!("failed to add profile: {e}"),
+                    }
+                }
+            }
+        }
+    ))
+}
@@ -46,6 +46,13 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>) -> Element {
                         page.set(AuthPages::EnterSeedWords);
                     }
                 },
+                Button {
+                    aria_label: "restore-backup-button".into(),
+                    text: get_local_text("create-or-recover.restore-backup"),
+                    onpress: move |_| {
+                        page.set(AuthPages::RestoreBackup);
+                    }
+                },
             }
         }
     ))
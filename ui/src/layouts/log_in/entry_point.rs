@@ -31,6 +31,7 @@ use common::{
     WARP_CMD_CH,
 };
 
+use super::account_picker::AccountPicker;
 use crate::{bootstrap::create_uplink_dirs, get_app_style, AuthPages};
 
 enum UnlockError {
@@ -50,8 +51,16 @@ impl UnlockError {
 }
 
 // todo: go to the auth page if no account has been created
+// `locked` is set when this page is used as the lock screen. warp is already running then, so the
+// account picker and account reset are hidden and the persisted state is left alone.
 #[component]
-pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Element {
+pub fn Layout(
+    cx: Scope,
+    page: UseState<AuthPages>,
+    pin: UseRef<String>,
+    profile: UseState<String>,
+    locked: bool,
+) -> Element {
     log::trace!("rendering login entry point");
     let validation_failure: &UseState<Option<UnlockError>> =
         use_state(cx, || Some(UnlockError::ValidationError)); // By default no pin is an invalid pin.
@@ -60,7 +69,8 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
     let shown_error = use_state(cx, String::new);
     let desktop = use_window(cx);
 
-    let account_exists: &UseState<Option<bool>> = use_state(cx, || None);
+    let account_exists: &UseState<Option<bool>> =
+        use_state(cx, || if *locked { Some(true) } else { None });
     let cmd_in_progress = use_state(cx, || false);
     let first_render = use_ref(cx, || true);
     let state = use_ref(cx, State::load);
@@ -177,7 +187,7 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
         .unwrap_or_default();
 
     cx.render(rsx!(
-        style {get_app_style(&state.read())},
+        (!*locked).then(|| rsx!(style {get_app_style(&state.read())})),
         div {
             id: "unlock-layout",
             aria_label: "unlock-layout",
@@ -196,6 +206,12 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
                         class: "idle",
                         src: "{image_path}"
                     },
+                    (!*locked).then(|| rsx!(
+                        AccountPicker {
+                            profile: profile.clone(),
+                            disabled: *cmd_in_progress.get(),
+                        }
+                    )),
                     Input {
                         id: "unlock-input".to_owned(),
                         focus: true,
@@ -226,9 +242,11 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
                                 shown_error.set(String::new());
                             }
                             if validation_passed {
-                                let is_maximized = desktop.is_maximized();
-                                state.write_silent().ui.window_maximized = is_maximized;
-                                let _ = state.write_silent().save();
+                                if !*locked {
+                                    let is_maximized = desktop.is_maximized();
+                                    state.write_silent().ui.window_maximized = is_maximized;
+                                    let _ = state.write_silent().save();
+                                }
                                 cmd_in_progress.set(true);
                                 ch.send((val, *account_exists.get()));
                                 validation_failure.set(None);
@@ -279,13 +297,13 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
                             } else if let Some(e) = error.get() {
                                 shown_error.set(e.translation());
                                 reset_input.set(true);
-                            } else {
+                            } else if !*locked {
                                 page.set(AuthPages::CreateOrRecover);
                             }
                             cmd_in_progress.set(false);
                         }
                     },
-                    ContextMenu {
+                    (!*locked).then(|| rsx!(ContextMenu {
                         key: "{key}-menu",
                         id: "unlock-context-menu".into(),
                         devmode: state.read().configuration.developer.developer_mode,
@@ -296,7 +314,7 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
                                 aria_label: "account-reset".into(),
                                 text: get_local_text("uplink.reset-account"),
                                 onpress: |_| {
-                                    let _ = fs::remove_dir_all(&STATIC_ARGS.dot_uplink);
+                                    let _ = fs::remove_dir_all(STATIC_ARGS.uplink_path());
                                     page.set(AuthPages::EntryPoint);
                                     error.set(None);
                                     account_exists.set(Some(false));
@@ -318,7 +336,7 @@ pub fn Layout(cx: Scope, page: UseState<AuthPages>, pin: UseRef<String>) -> Elem
                                 )),
                             }
                         }
-                    }
+                    }))
                 )
             }
         }
@@ -0,0 +1,55 @@
+use common::{
+    profiles::active_profile,
+    state::{Action, State},
+};
+use dioxus::prelude::*;
+use dioxus_desktop::use_window;
+use kit::components::topbar_controls::TopbarControls;
+
+use super::{entry_point, AuthPages};
+
+/// Shown instead of the app while it is locked. This is the unlock page in its `locked` mode: the passphrase
+/// is checked against Tesseract but warp keeps running, so nothing has to be loaded again afterwards.
+#[allow(non_snake_case)]
+pub fn LockScreen(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let page = use_state(cx, || AuthPages::EntryPoint);
+    let pin = use_ref(cx, String::new);
+    let profile = use_state(cx, active_profile);
+    let window = use_window(cx);
+
+    let unlocked = matches!(&*page.current(), AuthPages::Success(_));
+    use_effect(cx, &unlocked, |unlocked| {
+        to_owned![state];
+        async move {
+            if unlocked {
+                state.write().mutate(Action::UnlockApp);
+            }
+        }
+    });
+    if unlocked {
+        return None;
+    }
+
+    cx.render(rsx!(
+        div {
+            id: "lock-screen",
+            aria_label: "lock-screen",
+            div {
+                class: "titlebar disable-select",
+                id: "lockscreen-controls",
+                div {
+                    class: "draggable-topbar",
+                    onmousedown: move |_| { window.drag(); },
+                },
+                TopbarControls {},
+            },
+            entry_point::Layout {
+                page: page.clone(),
+                pin: pin.clone(),
+                profile: profile.clone(),
+                locked: true,
+            }
+        }
+    ))
+}
@@ -1,21 +1,29 @@
+mod account_picker;
 mod copy_seed_words;
 mod create_or_recover;
 mod enter_seed_words;
 mod enter_username;
 mod entry_point;
+mod lock_screen;
 mod recover_account;
+mod restore_backup;
 
+pub use lock_screen::LockScreen;
+
+use common::profiles::active_profile;
 use dioxus::prelude::*;
 use dioxus_desktop::{use_window, DesktopService, LogicalSize};
 use kit::components::topbar_controls::TopbarControls;
 use kit::STYLE as UIKIT_STYLES;
 use warp::multipass;
+
 pub const APP_STYLE: &str = include_str!("../../compiled_styles.css");
 
 // flows:
 // EntryPoint -> login
 // EntryPoint -> CreateOrRecover -> EnterSeedWords -> login or fail
 // EntryPoint -> CreateOrRecover -> CopySeedWords -> EnterUserName -> login
+// EntryPoint -> CreateOrRecover -> RestoreBackup -> login or fail
 // serve as a sort of router while the user logs in]
 #[allow(clippy::large_enum_variant)]
 #[derive(PartialEq, Eq)]
@@ -24,6 +32,7 @@ pub enum AuthPages {
     CreateOrRecover,
     EnterUserName,
     EnterSeedWords,
+    RestoreBackup,
     CopySeedWords,
     Success(multipass::identity::Identity),
 }
@@ -35,6 +44,7 @@ pub fn AuthGuard(cx: Scope, page: UseState<AuthPages>) -> Element {
 
     let pin = use_ref(cx, String::new);
     let user_name = use_ref(cx, String::new);
+    let profile = use_state(cx, active_profile);
     let theme = "";
 
     // make the window smaller while the user authenticates
@@ -59,10 +69,12 @@ pub fn AuthGuard(cx: Scope, page: UseState<AuthPages>) -> Element {
             },
 
             match *page.current() {
-                AuthPages::EntryPoint => rsx!(entry_point::Layout { page: page.clone(), pin: pin.clone() }),
+                // keyed by profile so everything loaded from the previous profile is dropped when switching
+                AuthPages::EntryPoint => rsx!(entry_point::Layout { key: "{profile}", page: page.clone(), pin: pin.clone(), profile: profile.clone(), locked: false }),
                 AuthPages::EnterUserName => rsx!(enter_username::Layout { page: page.clone(), user_name: user_name.clone() }),
                 AuthPages::CreateOrRecover => rsx!(create_or_recover::Layout { page: page.clone() }),
                 AuthPages::EnterSeedWords => rsx!(enter_seed_words::Layout { page: page.clone(), pin: pin.clone(), }),
+                AuthPages::RestoreBackup => rsx!(restore_backup::Layout { page: page.clone(), pin: pin.clone(), }),
                 AuthPages::CopySeedWords => rsx!(copy_seed_words::Layout { page: page.clone(), username: user_name.read().clone(), pin: pin.read().clone() }),
                 _ => unreachable!("this view should disappear when an account is unlocked or created"),
             }
@@ -0,0 +1,199 @@
+use std::path::PathBuf;
+
+use common::{
+    icons::outline::Shape as Icon,
+    language::get_local_text,
+    state::State,
+    warp_runner::{MultiPassCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use dioxus_desktop::use_window;
+use futures::{channel::oneshot, StreamExt};
+use kit::elements::{
+    button::Button,
+    input::{Input, Options},
+    label::Label,
+    Appearance,
+};
+use rfd::FileDialog;
+use tracing::log;
+
+use crate::{get_app_style, layouts::log_in::update_window_size};
+
+use super::AuthPages;
+
+enum RestoreError {
+    // the backup passphrase
+    WrongPassphrase,
+    Unknown,
+}
+
+impl RestoreError {
+    fn translation(&self) -> String {
+        match self {
+            RestoreError::WrongPassphrase => get_local_text("restore-backup.wrong-passphrase"),
+            RestoreError::Unknown => get_local_text("restore-backup.failed"),
+        }
+    }
+}
+
+struct Cmd {
+    backup: PathBuf,
+    backup_passphrase: String,
+    passphrase: String,
+}
+
+// styles for this layout are in layouts/style.scss
+#[component]
+pub fn Layout(cx: Scope, pin: UseRef<String>, page: UseState<AuthPages>) -> Element {
+    let state = use_ref(cx, State::load);
+    let loading = use_state(cx, || false);
+    let backup: &UseState<Option<PathBuf>> = use_state(cx, || None);
+    let backup_passphrase = use_ref(cx, String::new);
+    let restore_error: &UseState<Option<RestoreError>> = use_state(cx, || None);
+
+    let window = use_window(cx);
+
+    if !matches!(&*page.current(), AuthPages::Success(_)) {
+        update_window_size(window, 500.0, 350.0);
+    }
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<Cmd>| {
+        to_owned![loading, page, restore_error];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            while let Some(Cmd {
+                backup,
+                backup_passphrase,
+                passphrase,
+            }) = rx.next().await
+            {
+                let (tx, rx) = oneshot::channel();
+
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::MultiPass(MultiPassCmd::RestoreBackup {
+                    backup,
+                    backup_passphrase,
+                    passphrase,
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send multipass cmd: {e}");
+                    loading.set(false);
+                    continue;
+                }
+
+                let rsp = match rx.await {
+                    Ok(r) => r,
+                    Err(e) => {
+                        log::error!("warp runner cmd cancelled: {e}");
+                        loading.set(false);
+                        continue;
+                    }
+                };
+
+                match rsp {
+                    Ok(ident) => {
+                        page.set(AuthPages::Success(ident));
+                    }
+                    Err(e) => {
+                        log::error!("failed to restore backup: {e}");
+                        loading.set(false);
+                        restore_error.set(Some(match e {
+                            warp::error::Error::DecryptionError => RestoreError::WrongPassphrase,
+                            _ => RestoreError::Unknown,
+                        }));
+                    }
+                };
+            }
+        }
+    });
+
+    let file_name = backup
+        .get()
+        .as_ref()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| get_local_text("restore-backup.select-file"));
+
+    cx.render(rsx!(
+        style {get_app_style(&state.read())},
+        div {
+            id: "restore-backup-layout",
+            aria_label: "restore-backup-layout",
+            Label {
+                aria_label: "restore-backup".into(),
+                text: get_local_text("restore-backup")
+            },
+            div {
+                class: "instructions",
+                aria_label: "instructions",
+                get_local_text("restore-backup.instructions")
+            },
+            Button {
+                aria_label: "select-backup-button".into(),
+                text: file_name,
+                icon: Icon::FolderOpen,
+                appearance: Appearance::Secondary,
+                disabled: *loading.get(),
+                onpress: move |_| {
+                    if let Some(path) = FileDialog::new()
+                        .add_filter("Uplink Backup", &["uplinkbackup"])
+                        .pick_file()
+                    {
+                        restore_error.set(None);
+                        backup.set(Some(path));
+                    }
+                }
+            },
+            Input {
+                id: "backup-passphrase-input".to_owned(),
+                is_password: true,
+                icon: Icon::Key,
+                aria_label: "backup-passphrase-input".into(),
+                disabled: *loading.get(),
+                placeholder: get_local_text("restore-backup.passphrase"),
+                options: Options {
+                    clear_on_submit: false,
+                    ..Default::default()
+                },
+                onchange: move |(val, _): (String, bool)| {
+                    *backup_passphrase.write_silent() = val;
+                    restore_error.set(None);
+                },
+            },
+            restore_error.as_ref().map(|e| rsx!(
+                span {
+                    aria_label: "input-error",
+                    class: "error",
+                    e.translation()
+                }
+            )),
+            div {
+                class: "button-container",
+                Button {
+                    aria_label: "back-button".into(),
+                    text: get_local_text("uplink.go-back"),
+                    icon: Icon::ChevronLeft,
+                    onpress: move |_| page.set(AuthPages::CreateOrRecover),
+                    appearance: Appearance::Secondary
+                },
+                Button {
+                    aria_label: "restore-backup-button".into(),
+                    text: get_local_text("restore-backup.submit"),
+                    loading: *loading.get(),
+                    disabled: *loading.get() || backup.get().is_none() || backup_passphrase.read().is_empty(),
+                    onpress: move |_| {
+                        if let Some(path) = backup.get().clone() {
+                            loading.set(true);
+                            ch.send(Cmd {
+                                backup: path,
+                                backup_passphrase: backup_passphrase.read().clone(),
+                                passphrase: pin.read().clone(),
+                            });
+                        }
+                    }
+                },
+            }
+        }
+    ))
+}
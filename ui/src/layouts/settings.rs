@@ -4,18 +4,10 @@ use crate::{
     components::settings::{
         sidebar::{Page, Sidebar},
         sub_pages::{
-            about::AboutPage,
-            accessibility::AccessibilitySettings,
-            audio::AudioSettings,
-            developer::DeveloperSettings,
-            extensions::ExtensionSettings,
-            general::GeneralSettings,
-            keybinds::KeybindSettings,
-            licenses::Licenses,
-            messages::Messages,
-            notifications::NotificationSettings,
-            // files::FilesSettings,
-            // privacy::PrivacySettings,
+            about::AboutPage, accessibility::AccessibilitySettings, audio::AudioSettings,
+            developer::DeveloperSettings, extensions::ExtensionSettings, files::FilesSettings,
+            general::GeneralSettings, keybinds::KeybindSettings, licenses::Licenses,
+            messages::Messages, notifications::NotificationSettings, privacy::PrivacySettings,
             profile::ProfileSettings,
         },
     },
@@ -49,8 +41,8 @@ pub fn SettingsLayout(cx: Scope) -> Element {
         Page::Accessibility => rsx!(AccessibilitySettings {}),
         Page::Profile => rsx!(ProfileSettings {}),
         Page::Audio => rsx!(AudioSettings {}),
-        // Page::Privacy => rsx!(PrivacySettings {}),
-        // Page::Files => rsx!(FilesSettings {}),
+        Page::Privacy => rsx!(PrivacySettings {}),
+        Page::Files => rsx!(FilesSettings {}),
         Page::Extensions => rsx!(ExtensionSettings {}),
         Page::Keybinds => rsx!(KeybindSettings {}),
         Page::Developer => rsx!(DeveloperSettings {}),
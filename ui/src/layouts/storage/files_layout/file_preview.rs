@@ -2,20 +2,33 @@ use std::path::{Path, PathBuf};
 
 use dioxus::prelude::*;
 
+use humansize::{format_size, DECIMAL};
 use kit::{
-    components::context_menu::{ContextItem, ContextMenu},
-    elements::loader::Loader,
+    components::{
+        context_menu::{ContextItem, ContextMenu},
+        message::highlight::code_block,
+    },
+    elements::{
+        button::Button,
+        loader::Loader,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
     layout::modal::Modal,
 };
+use rfd::FileDialog;
+use tracing::log;
 use warp::constellation::file::File;
 
 use common::{
     get_file_type,
     icons::outline::Shape as Icon,
-    is_audio, is_lang_file, is_video,
-    language::get_local_text,
+    icons::Icon as IconElement,
+    is_archive, is_audio, is_pdf_file, is_video,
+    language::{get_local_text, get_local_text_with_args},
     state::{State, ToastNotification},
     utils::{
+        file_preview::{self, ArchiveKind},
         img_dimensions_preview::{IMAGE_MAX_HEIGHT, IMAGE_MAX_WIDTH},
         local_file_path::get_fixed_path_to_load_local_file,
     },
@@ -76,7 +89,11 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
 
     let is_video = is_video(&cx.props.file.name());
     let is_audio = is_audio(&cx.props.file.name());
-    let is_code = is_lang_file(&cx.props.file.name());
+    // these can take a while to download too
+    let is_large = is_video
+        || is_audio
+        || is_pdf_file(&cx.props.file.name())
+        || is_archive(&cx.props.file.name());
 
     if file_path_in_local_disk.read().to_string_lossy().is_empty() {
         if !temp_dir_with_file_id.exists() && *should_download.get() {
@@ -112,11 +129,11 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 }
                 tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
                 counter += 250;
-                if counter > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD && !is_video && !is_audio {
+                if counter > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD && !is_large {
                     file_loading_counter.with_mut(|i| *i = counter);
                     break;
                 }
-                if counter > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD && (is_video || is_audio) {
+                if counter > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD && is_large {
                     file_loading_counter.with_mut(|i| *i = counter);
                     break;
                 }
@@ -127,11 +144,6 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let local_disk_path_fixed =
         get_fixed_path_to_load_local_file(file_path_in_local_disk.read().clone());
 
-    let code_content = is_code
-        .then(|| std::fs::read_to_string(file_path_in_local_disk.read().clone()).ok())
-        .flatten()
-        .unwrap_or_default();
-
     let file_type = get_file_type(&cx.props.file.name());
     let should_dismiss_on_error = use_ref(cx, || false);
 
@@ -164,13 +176,13 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 },
             )),
             if *file_loading_counter.read() > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD
-                && (is_video || is_audio) {
+                && is_large {
                 // It will show a video player with error, because take much time
                 // to download a video and is not possible to load it
                 rsx!(FileTypeTag {
                     file_type: file_type,
                     source: "".to_string(),
-                    code_content: code_content,
+                    path: PathBuf::new(),
                 })
             } else if !file_path_in_local_disk.read().exists()
                 && *file_loading_counter.read() > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD
@@ -180,7 +192,7 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 rsx!(FileTypeTag {
                     file_type: file_type,
                     source: thumbnail,
-                    code_content: code_content,
+                    path: PathBuf::new(),
                 })
             } else if file_path_in_local_disk.read().exists() {
                 *should_dismiss_on_error.write_silent() = true;
@@ -188,7 +200,7 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 rsx!(FileTypeTag {
                     file_type: file_type,
                     source: local_disk_path_fixed,
-                    code_content: code_content,
+                    path: file_path_in_local_disk.read().clone(),
                 })
             } else if *file_loading_counter.read() <  TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD {
                 if *should_dismiss_on_error.read() {
@@ -218,16 +230,17 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
 #[derive(Props, PartialEq)]
 struct FileTypeTagProps {
     file_type: FileType,
+    // what the webview loads
     source: String,
-    code_content: String,
+    // the downloaded file, empty if it isn't there (yet)
+    path: PathBuf,
 }
 
 #[allow(non_snake_case)]
 fn FileTypeTag(cx: Scope<FileTypeTagProps>) -> Element {
     let file_type = cx.props.file_type.clone();
     let source_path = cx.props.source.clone();
-    let code_content = cx.props.code_content.clone();
-    let code_class = get_language_class(&source_path);
+    let path = cx.props.path.clone();
 
     cx.render(match file_type {
         FileType::Video => rsx!(video {
@@ -239,19 +252,10 @@ fn FileTypeTag(cx: Scope<FileTypeTagProps>) -> Element {
             controls: true,
             src: "{source_path}"
         }),
-        FileType::Audio => rsx!(
-         div {
-             height: "80px",
-             padding_top: "50px",
-             audio {
-                 id: "file_preview_img",
-                 aria_label: "file-preview-image",
-                 autoplay: true,
-                 controls: true,
-                 src: "{source_path}"
-             }
-         }
-        ),
+        FileType::Audio => rsx!(AudioPreview {
+            source: source_path,
+            path: path,
+        }),
         FileType::Image => rsx!(img {
             id: "file_preview_img",
             aria_label: "file-preview-image",
@@ -259,57 +263,318 @@ fn FileTypeTag(cx: Scope<FileTypeTagProps>) -> Element {
             max_width: IMAGE_MAX_WIDTH,
             src: "{source_path}"
         },),
-        FileType::Doc => rsx!(iframe {
-            id: "file_preview_img",
-            aria_label: "file-preview-image",
-            max_height: "80vh",
-            max_width: "80vw",
-            height: "800px",
-            width: "800px",
-            src: "{source_path}"
+        FileType::Doc => rsx!(PdfPreview {
+            source: source_path,
+            path: path,
+        }),
+        FileType::Text => rsx!(TextPreview {
+            path: path,
+            highlight: false,
+        }),
+        FileType::Code => rsx!(TextPreview {
+            path: path,
+            highlight: true,
         }),
-        FileType::Code => rsx!(
+        FileType::Archive => rsx!(ArchivePreview { path: path }),
+        _ => rsx!(div {}),
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct LocalFileProps {
+    source: String,
+    path: PathBuf,
+}
+
+/// One page at a time, rendered by poppler. Without it the webview shows the pdf, with a note
+/// that poppler isn't installed, since it isn't bundled on every platform.
+#[allow(non_snake_case)]
+fn PdfPreview(cx: Scope<LocalFileProps>) -> Element {
+    let page = use_state(cx, || 1u32);
+    let page_count = use_future(cx, &cx.props.path, |path| async move {
+        tokio::task::spawn_blocking(move || file_preview::pdf_page_count(&path))
+            .await
+            .ok()?
+            .map_err(|e| log::warn!("failed to count the pages of a pdf: {e}"))
+            .ok()
+    });
+    let rendered = use_future(
+        cx,
+        (&cx.props.path, page.get()),
+        |(path, page)| async move {
+            let dest = STATIC_ARGS.temp_files.clone();
+            tokio::task::spawn_blocking(move || file_preview::render_pdf_page(&path, page, &dest))
+                .await
+                .ok()?
+                .map_err(|e| log::warn!("failed to render a pdf page: {e}"))
+                .ok()
+        },
+    );
+
+    let source_path = &cx.props.source;
+    cx.render(match (page_count.value(), rendered.value()) {
+        (Some(None), _) | (_, Some(None)) => rsx!(
             div {
-                class: "code-preview",
-                pre {
-                    code {
-                        class: format_args!("{code_class}"),
-                        "{code_content}"
-                    }
+                class: "pdf-preview",
+                onclick: move |e| e.stop_propagation(),
+                p {
+                    class: "file-preview-note",
+                    aria_label: "pdf-preview-fallback",
+                    get_local_text("files.pdf-preview-unavailable")
+                },
+                iframe {
+                    id: "file_preview_img",
+                    aria_label: "file-preview-image",
+                    max_height: "80vh",
+                    max_width: "80vw",
+                    height: "800px",
+                    width: "800px",
+                    src: "{source_path}"
                 }
             }
-            script {
-                r#"
-                    (() => {{
-                        Prism.highlightAll();
-                    }})();
-                    "#
+        ),
+        (Some(Some(count)), Some(Some(png))) => {
+            let count = *count;
+            let png = get_fixed_path_to_load_local_file(png.clone());
+            rsx!(div {
+                class: "pdf-preview",
+                aria_label: "pdf-preview",
+                onclick: move |e| e.stop_propagation(),
+                div {
+                    class: "pdf-preview-nav",
+                    Button {
+                        icon: Icon::ChevronLeft,
+                        small: true,
+                        appearance: Appearance::Secondary,
+                        aria_label: "pdf-previous-page".into(),
+                        disabled: *page.get() <= 1,
+                        onpress: move |_| page.modify(|page| page.saturating_sub(1).max(1)),
+                    },
+                    span {
+                        get_local_text_with_args("files.pdf-page", vec![("page", page.get().to_string()), ("count", count.to_string())])
+                    },
+                    Button {
+                        icon: Icon::ChevronRight,
+                        small: true,
+                        appearance: Appearance::Secondary,
+                        aria_label: "pdf-next-page".into(),
+                        disabled: *page.get() >= count,
+                        onpress: move |_| page.modify(|page| (page + 1).min(count)),
+                    },
+                },
+                img {
+                    id: "file_preview_img",
+                    aria_label: "file-preview-image",
+                    max_height: IMAGE_MAX_HEIGHT,
+                    max_width: IMAGE_MAX_WIDTH,
+                    src: "{png}",
+                }
+            })
+        }
+        _ => rsx!(Loader { spinning: true }),
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct TextPreviewProps {
+    path: PathBuf,
+    highlight: bool,
+}
+
+#[allow(non_snake_case)]
+fn TextPreview(cx: Scope<TextPreviewProps>) -> Element {
+    let text = use_future(
+        cx,
+        (&cx.props.path, &cx.props.highlight),
+        |(path, highlight)| async move {
+            // highlighting a large file takes a moment too
+            tokio::task::spawn_blocking(move || {
+                let (text, truncated) = file_preview::read_text(&path).ok()?;
+                let extension = path.extension().and_then(std::ffi::OsStr::to_str);
+                let hint = if highlight { extension } else { Some("txt") };
+                Some((code_block(&text, hint), truncated))
+            })
+            .await
+            .ok()
+            .flatten()
+        },
+    );
+
+    cx.render(match text.value() {
+        Some(Some((html, truncated))) => rsx!(
+            div {
+                class: "code-preview",
+                aria_label: "text-preview",
+                onclick: move |e| e.stop_propagation(),
+                truncated.then(|| rsx!(p {
+                    class: "file-preview-note",
+                    get_local_text_with_args("files.preview-truncated", vec![("size", format_size(file_preview::MAX_TEXT_PREVIEW_SIZE, DECIMAL))])
+                })),
+                div {
+                    dangerous_inner_html: "{html}"
+                }
             }
         ),
-        _ => rsx!(div {}),
+        Some(None) => rsx!(p {
+            class: "file-preview-note",
+            get_local_text("files.not-possible-to-preview-file")
+        }),
+        None => rsx!(Loader { spinning: true }),
     })
 }
 
-fn get_language_class(file_path: &str) -> String {
-    let extension = Path::new(file_path)
-        .extension()
-        .and_then(std::ffi::OsStr::to_str)
-        .unwrap_or_default();
-
-    let extension_formatted = match extension {
-        "rs" => "rust",
-        "js" => "javascript",
-        "ts" => "typescript",
-        "py" => "python",
-        "html" => "html",
-        "css" => "css",
-        "toml" => "toml",
-        "java" => "java",
-        "cpp" => "cpp",
-        "c" => "c",
-        _ => "plaintext",
-    }
-    .to_string();
+const WAVEFORM_BARS: usize = 120;
+
+#[allow(non_snake_case)]
+fn AudioPreview(cx: Scope<LocalFileProps>) -> Element {
+    let waveform = use_future(cx, &cx.props.path, |path| async move {
+        if !path.exists() {
+            return vec![];
+        }
+        tokio::task::spawn_blocking(move || file_preview::waveform(&path, WAVEFORM_BARS))
+            .await
+            .ok()
+            .and_then(|r| {
+                r.map_err(|e| log::warn!("failed to read a waveform: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default()
+    });
+    let source_path = &cx.props.source;
+
+    cx.render(rsx!(
+        div {
+            class: "audio-preview",
+            aria_label: "audio-preview",
+            onclick: move |e| e.stop_propagation(),
+            div {
+                class: "waveform",
+                aria_label: "audio-waveform",
+                waveform.value().into_iter().flatten().map(|bar| {
+                    // silent parts still get a thin line
+                    let height = (bar * 100.0).max(2.0);
+                    rsx!(div {
+                        class: "waveform-bar",
+                        height: "{height}%",
+                    })
+                })
+            },
+            audio {
+                id: "file_preview_img",
+                aria_label: "file-preview-image",
+                autoplay: true,
+                controls: true,
+                src: "{source_path}"
+            }
+        }
+    ))
+}
 
-    format!("language-{}", extension_formatted)
+#[derive(Props, PartialEq)]
+struct ArchivePreviewProps {
+    path: PathBuf,
+}
+
+/// The entries of an archive. Single files can be extracted.
+#[allow(non_snake_case)]
+fn ArchivePreview(cx: Scope<ArchivePreviewProps>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let kind = ArchiveKind::from_name(&cx.props.path.to_string_lossy());
+    let listing = use_future(cx, &cx.props.path, move |path| async move {
+        let kind = kind?;
+        tokio::task::spawn_blocking(move || file_preview::list_archive(&path, kind))
+            .await
+            .ok()?
+            .map_err(|e| log::warn!("failed to list an archive: {e}"))
+            .ok()
+    });
+
+    let extract = move |entry: String| {
+        let Some(kind) = kind else {
+            return;
+        };
+        let name = Path::new(&entry)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let Some(dest) = FileDialog::new()
+            .set_directory(dirs::download_dir().unwrap_or(".".into()))
+            .set_file_name(&name)
+            .save_file()
+        else {
+            return;
+        };
+        let path = cx.props.path.clone();
+        to_owned![state];
+        cx.spawn(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                file_preview::extract_entry(&path, kind, &entry, &dest)
+            })
+            .await;
+            let text = match result {
+                Ok(Ok(_)) => get_local_text_with_args("files.extracted", vec![("name", name)]),
+                Ok(Err(e)) => {
+                    log::error!("failed to extract {name}: {e}");
+                    get_local_text_with_args("files.extract-failed", vec![("name", name)])
+                }
+                Err(_) => return,
+            };
+            state
+                .write()
+                .mutate(common::state::Action::AddToastNotification(
+                    ToastNotification::init("".into(), text, None, 3),
+                ));
+        });
+    };
+
+    cx.render(match listing.value() {
+        Some(Some((entries, truncated))) => rsx!(
+            div {
+                class: "archive-preview",
+                aria_label: "archive-preview",
+                onclick: move |e| e.stop_propagation(),
+                truncated.then(|| rsx!(p {
+                    class: "file-preview-note",
+                    get_local_text_with_args("files.archive-truncated", vec![("count", file_preview::MAX_ARCHIVE_ENTRIES.to_string())])
+                })),
+                entries.iter().map(|entry| {
+                    let name = entry.name.clone();
+                    rsx!(div {
+                        key: "{entry.name}",
+                        class: "archive-entry",
+                        aria_label: "archive-entry",
+                        IconElement {
+                            icon: if entry.is_dir { Icon::Folder } else { Icon::Document }
+                        },
+                        span {
+                            class: "archive-entry-name",
+                            "{entry.name}"
+                        },
+                        (!entry.is_dir).then(|| rsx!(
+                            span {
+                                class: "archive-entry-size",
+                                format_size(entry.size, DECIMAL)
+                            },
+                            Button {
+                                icon: Icon::ArrowDownTray,
+                                small: true,
+                                appearance: Appearance::Secondary,
+                                aria_label: "archive-extract".into(),
+                                tooltip: cx.render(rsx!(Tooltip {
+                                    arrow_position: ArrowPosition::Right,
+                                    text: get_local_text("files.extract"),
+                                })),
+                                onpress: move |_| extract(name.clone()),
+                            }
+                        ))
+                    })
+                })
+            }
+        ),
+        Some(None) => rsx!(p {
+            class: "file-preview-note",
+            get_local_text("files.not-possible-to-preview-file")
+        }),
+        None => rsx!(Loader { spinning: true }),
+    })
 }
@@ -1,6 +1,6 @@
 use common::{
     language::{get_local_text, get_local_text_with_args},
-    state::{self, data_transfer::TransferTracker, State},
+    state::{self, data_transfer::TransferTracker, utils::typed_lines, State},
 };
 use dioxus::prelude::*;
 use kit::{
@@ -144,7 +144,7 @@ fn ChatsToSelect<'a>(cx: Scope<'a, ChatsToSelectProps<'a>>) -> Element<'a> {
             };
             let is_checked = storage_controller.read().chats_selected_to_send.iter().any(|uuid| {uuid.eq(&chat.id)});
             let unwrapped_message = match chat.messages.iter().last() {Some(m) => m.inner.clone(),None => raygun::Message::default()};
-            let subtext_val = match unwrapped_message.lines().iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
+            let subtext_val = match typed_lines(&unwrapped_message.lines()).iter().map(|x| x.trim()).find(|x| !x.is_empty()) {
                 Some(v) => format_text(v, state.read().ui.should_transform_markdown_text(), state.read().ui.should_transform_ascii_emojis(), Some((&state.read(), &chat.id, true))),
                 _ => match &unwrapped_message.attachments()[..] {
                     [] => get_local_text("sidebar.chat-new"),
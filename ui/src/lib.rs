@@ -17,7 +17,7 @@ use common::state::ui::Layout;
 use common::state::ToastNotification;
 use common::warp_runner::ui_adapter::MessageEvent;
 use common::warp_runner::WarpEvent;
-use common::{get_extras_dir, warp_runner, STATIC_ARGS, WARP_CMD_CH, WARP_EVENT_CH};
+use common::{warp_runner, STATIC_ARGS, WARP_CMD_CH, WARP_EVENT_CH};
 
 use dioxus::prelude::*;
 use dioxus_desktop::tao::dpi::{LogicalPosition, PhysicalPosition};
@@ -50,18 +50,22 @@ use std::time::Instant;
 
 use std::sync::Arc;
 
+use crate::components::code_copy::CodeCopyListener;
 use crate::components::debug_logger::DebugLogger;
+use crate::components::external_link::ExternalLinkConfirmation;
 use crate::components::file_transfer::FileTransferModal;
+use crate::components::notification_center::NotificationCenter;
 use crate::components::toast::Toast;
 use crate::components::topbar::release_info::Release_Info;
 use crate::layouts::community::CommunityLayout;
 use crate::layouts::friends::FriendsLayout;
 use crate::layouts::loading::{use_loaded_assets, LoadingWash};
-use crate::layouts::log_in::{AuthGuard, AuthPages};
+use crate::layouts::log_in::{AuthGuard, AuthPages, LockScreen};
 use crate::layouts::settings::SettingsLayout;
 use crate::layouts::storage::files_layout::FilesLayout;
 use crate::misc_scripts::*;
 use crate::utils::async_task_queue::{ListenerAction, ACTION_LISTENER};
+use crate::utils::auto_lock::use_auto_lock;
 use crate::utils::keyboard::shortcut_handlers::audio::ToggleType;
 use crate::utils::keyboard::KeyboardShortcuts;
 use dioxus_desktop::wry::application::event::Event as WryEvent;
@@ -242,9 +246,19 @@ fn app_layout(cx: Scope) -> Element {
     use_auto_updater(cx)?;
     use_app_coroutines(cx)?;
     use_router_notification_listener(cx)?;
+    use_auto_lock(cx)?;
 
     let state = use_shared_state::<State>(cx)?;
 
+    // nothing of the app is mounted while it's locked, so it can't be reached by keyboard focus,
+    // shortcuts or the dev tools
+    if state.read().ui.locked {
+        return render! {
+            AppStyle {}
+            LockScreen {},
+        };
+    }
+
     render! {
         AppStyle {}
         div { id: "app-wrap",
@@ -266,8 +280,10 @@ fn app_layout(cx: Scope) -> Element {
             },
             Toasts {},
             Outlet::<UplinkRoute>{},
+            ExternalLinkConfirmation {},
+            CodeCopyListener {},
             AppLogger {},
-            PrismScripts {},
+            EditorScripts {},
         },
     }
 }
@@ -331,7 +347,7 @@ pub fn get_app_style(state: &State) -> String {
         "".into()
     };
 
-    format!("{UIKIT_STYLES} {APP_STYLE} {PRISM_STYLE} {PRISM_THEME} {theme} {accent_color} {font_style} {open_dyslexic} {font_scale}")
+    format!("{UIKIT_STYLES} {APP_STYLE} {theme} {accent_color} {font_style} {open_dyslexic} {font_scale}")
 }
 
 // Decide if text should be dark or bright
@@ -1149,6 +1165,7 @@ fn Titlebar(cx: Scope) -> Element {
             span {
                 class: "inline-controls",
                 get_update_icon{},
+                NotificationCenter {},
                 TopbarControls {}
             },
         },
@@ -1182,11 +1199,22 @@ fn use_router_notification_listener(cx: &ScopeState) -> Option<()> {
                         state.write_silent().mutate(Action::ChatWith(&uuid, true));
                         navigator.replace(UplinkRoute::ChatLayout {});
                     }
+                    NotificationAction::DisplayThread(uuid, root) => {
+                        state.write_silent().mutate(Action::ChatWith(&uuid, true));
+                        state.write().mutate(Action::OpenThread(uuid, root));
+                        navigator.replace(UplinkRoute::ChatLayout {});
+                    }
                     NotificationAction::FriendListPending => {
                         // the FriendsLayout subscribes to these events and sets the layout accordingly.
                         // in this case, the layout would be FriendRoute::Pending
                         navigator.replace(UplinkRoute::FriendsLayout {});
                     }
+                    NotificationAction::DisplaySettings => {
+                        navigator.replace(UplinkRoute::SettingsLayout {});
+                    }
+                    NotificationAction::MarkChatRead(uuid) => {
+                        state.write().mutate(Action::ClearUnreads(uuid));
+                    }
                     _ => {}
                 }
             }
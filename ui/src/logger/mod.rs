@@ -107,7 +107,7 @@ impl crate::log::Log for LogGlue {
 
 impl Logger {
     fn load() -> Self {
-        let logger_path = STATIC_ARGS.logger_path.clone();
+        let logger_path = STATIC_ARGS.logger_path();
         Self {
             file_tx: None,
             file_thread: None,
@@ -0,0 +1,81 @@
+//! Locks the app after the configured number of minutes without input, or when the computer wakes
+//! up from suspend. Only the UI is locked, see [`crate::layouts::log_in::LockScreen`].
+
+use std::time::{Duration, Instant, SystemTime};
+
+use common::state::{Action, State};
+use dioxus::prelude::*;
+use tracing::log;
+
+// reports input to the app, at most once every few seconds so the webview isn't flooded
+const ACTIVITY_SCRIPT: &str = r#"
+let lastReport = 0;
+const report = () => {
+    const now = Date.now();
+    if (now - lastReport > 5000) {
+        lastReport = now;
+        dioxus.send(now);
+    }
+};
+["mousemove", "mousedown", "keydown", "wheel", "touchstart"].forEach((event) =>
+    window.addEventListener(event, report, { passive: true, capture: true })
+);
+"#;
+
+// how often the idle time is checked
+const TICK: Duration = Duration::from_secs(15);
+// a tick taking this much longer than it should means the computer was asleep.
+// SystemTime is used for this because Instant doesn't advance during suspend on every platform.
+const SUSPEND_THRESHOLD: Duration = Duration::from_secs(60);
+
+pub fn use_auto_lock(cx: &ScopeState) -> Option<()> {
+    let state = use_shared_state::<State>(cx)?;
+    let eval = use_eval(cx);
+    let last_activity = use_ref(cx, Instant::now);
+
+    use_future(cx, (), |_| {
+        to_owned![eval, last_activity];
+        async move {
+            let eval = match eval(ACTIVITY_SCRIPT) {
+                Ok(r) => r,
+                Err(e) => {
+                    log::error!("failed to track user activity: {:?}", e);
+                    return;
+                }
+            };
+            while eval.recv().await.is_ok() {
+                *last_activity.write_silent() = Instant::now();
+            }
+        }
+    });
+
+    use_future(cx, (), |_| {
+        to_owned![state, last_activity];
+        async move {
+            let mut last_tick = SystemTime::now();
+            loop {
+                tokio::time::sleep(TICK).await;
+                let now = SystemTime::now();
+                let woke_up = now
+                    .duration_since(last_tick)
+                    .map(|elapsed| elapsed > TICK + SUSPEND_THRESHOLD)
+                    .unwrap_or(false);
+                last_tick = now;
+
+                if state.read().ui.locked {
+                    continue;
+                }
+                let security = state.read().configuration.security;
+                let idle = security.auto_lock_minutes > 0
+                    && last_activity.read().elapsed()
+                        >= Duration::from_secs(60 * u64::from(security.auto_lock_minutes));
+                if idle || (woke_up && security.lock_on_suspend) {
+                    log::debug!("locking the app. idle: {idle}, woke up: {woke_up}");
+                    state.write().mutate(Action::LockApp);
+                }
+            }
+        }
+    });
+
+    Some(())
+}
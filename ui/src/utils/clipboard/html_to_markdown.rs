@@ -0,0 +1,310 @@
+//! Turns the html of a rich text paste into the markdown messages are written in. Only what
+//! messages can show is kept: emphasis, links, headings, lists, quotes and code.
+
+use scraper::{node::Node, ElementRef, Html};
+
+pub struct Converted {
+    pub markdown: String,
+    /// the `src` of every image, in order. these aren't in the markdown
+    pub images: Vec<String>,
+    /// false if the html was nothing but plain text wrapped in tags
+    pub has_formatting: bool,
+}
+
+pub fn convert(html: &str) -> Converted {
+    let document = Html::parse_fragment(html);
+    let mut converter = Converter::default();
+    converter.children(document.root_element());
+    Converted {
+        markdown: tidy(&converter.out),
+        images: converter.images,
+        has_formatting: converter.has_formatting,
+    }
+}
+
+#[derive(Default)]
+struct Converter {
+    out: String,
+    images: Vec<String>,
+    has_formatting: bool,
+    // one entry per open list, the number of the next item for ordered lists
+    lists: Vec<Option<usize>>,
+    in_pre: bool,
+}
+
+impl Converter {
+    fn children(&mut self, element: ElementRef) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => self.text(text),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        self.element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_pre {
+            self.out.push_str(text);
+            return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 {
+                collapsed.push(' ');
+            }
+            collapsed.push_str(word);
+        }
+        let starts_with_space = text.starts_with(char::is_whitespace);
+        let ends_with_space = text.ends_with(char::is_whitespace) && !collapsed.is_empty();
+        if starts_with_space && !self.out.is_empty() && !self.out.ends_with([' ', '\n']) {
+            self.out.push(' ');
+        }
+        self.out.push_str(&collapsed);
+        if ends_with_space {
+            self.out.push(' ');
+        }
+    }
+
+    // the children of the element written into their own string
+    fn inner(&mut self, element: ElementRef) -> String {
+        let out = std::mem::take(&mut self.out);
+        self.children(element);
+        std::mem::replace(&mut self.out, out)
+    }
+
+    fn wrap(&mut self, element: ElementRef, marker: &str) {
+        let inner = self.inner(element);
+        let trimmed = inner.trim();
+        if trimmed.is_empty() {
+            self.out.push_str(&inner);
+            return;
+        }
+        // markdown doesn't allow spaces just inside the markers
+        if inner.starts_with(' ') && !self.out.ends_with([' ', '\n']) {
+            self.out.push(' ');
+        }
+        self.out.push_str(marker);
+        self.out.push_str(trimmed);
+        self.out.push_str(marker);
+        if inner.ends_with(' ') {
+            self.out.push(' ');
+        }
+        self.has_formatting = true;
+    }
+
+    fn new_line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn new_block(&mut self) {
+        self.new_line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn element(&mut self, element: ElementRef) {
+        let name = element.value().name();
+        match name {
+            "script" | "style" | "head" | "title" | "meta" | "link" | "template" => {}
+            "br" => self.out.push('\n'),
+            "hr" => {
+                self.new_block();
+                self.out.push_str("---");
+                self.new_block();
+            }
+            "b" | "strong" => self.wrap(element, "**"),
+            "i" | "em" => self.wrap(element, "*"),
+            "s" | "del" | "strike" => self.wrap(element, "~~"),
+            "code" if !self.in_pre => self.wrap(element, "`"),
+            "a" => self.link(element),
+            "img" => {
+                if let Some(src) = element.value().attr("src") {
+                    self.images.push(src.to_string());
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.new_block();
+                let level = name[1..].parse().unwrap_or(1);
+                let inner = self.inner(element);
+                self.out.push_str(&"#".repeat(level));
+                self.out.push(' ');
+                self.out.push_str(inner.trim());
+                self.has_formatting = true;
+                self.new_block();
+            }
+            "ul" | "ol" => {
+                if self.lists.is_empty() {
+                    self.new_block();
+                } else {
+                    self.new_line();
+                }
+                let start = element
+                    .value()
+                    .attr("start")
+                    .and_then(|start| start.parse().ok())
+                    .unwrap_or(1);
+                self.lists.push((name == "ol").then_some(start));
+                self.children(element);
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.new_block();
+                }
+            }
+            "li" => {
+                self.new_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                self.out.push_str(&"  ".repeat(depth));
+                self.out.push_str(&bullet);
+                self.has_formatting = true;
+                self.children(element);
+                self.new_line();
+            }
+            "blockquote" => {
+                self.new_block();
+                let inner = tidy(&self.inner(element));
+                let quoted: Vec<String> = inner.lines().map(|line| format!("> {line}")).collect();
+                self.out.push_str(&quoted.join("\n"));
+                self.has_formatting = true;
+                self.new_block();
+            }
+            "pre" => {
+                self.new_block();
+                // the language is usually a class of the code element inside
+                let language = std::iter::once(element)
+                    .chain(element.children().filter_map(ElementRef::wrap))
+                    .filter_map(|e| e.value().attr("class"))
+                    .flat_map(|class| class.split_whitespace())
+                    .find_map(|class| {
+                        class
+                            .strip_prefix("language-")
+                            .or_else(|| class.strip_prefix("lang-"))
+                    })
+                    .unwrap_or_default()
+                    .to_string();
+                self.in_pre = true;
+                let code = self.inner(element);
+                self.in_pre = false;
+                self.out
+                    .push_str(&format!("```{language}\n{}\n```", code.trim_matches('\n')));
+                self.has_formatting = true;
+                self.new_block();
+            }
+            "tr" => {
+                self.new_line();
+                let cells: Vec<String> = element
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .map(|cell| self.inner(cell).trim().to_string())
+                    .collect();
+                self.out.push_str(&cells.join(" | "));
+                self.new_line();
+            }
+            "p" | "div" | "section" | "article" | "header" | "footer" | "table" | "dl" | "dt"
+            | "dd" | "figure" | "figcaption" => {
+                self.new_block_for(name);
+                self.children(element);
+                self.new_block_for(name);
+            }
+            _ => self.children(element),
+        }
+    }
+
+    // paragraphs are spaced out, other blocks like the divs rich text editors use for each line
+    // just start on their own line
+    fn new_block_for(&mut self, name: &str) {
+        if name == "p" && self.lists.is_empty() {
+            self.new_block();
+        } else {
+            self.new_line();
+        }
+    }
+
+    fn link(&mut self, element: ElementRef) {
+        let href = element.value().attr("href").unwrap_or_default();
+        let inner = self.inner(element);
+        let text = inner.trim();
+        let is_web_link = ["http://", "https://", "mailto:"]
+            .iter()
+            .any(|scheme| href.starts_with(scheme));
+        if !is_web_link || text == href || text.is_empty() {
+            self.out.push_str(if is_web_link && text.is_empty() {
+                href
+            } else {
+                &inner
+            });
+            return;
+        }
+        self.out.push_str(&format!("[{text}]({href})"));
+        self.has_formatting = true;
+    }
+}
+
+// no trailing spaces, no more than one empty line in a row
+fn tidy(markdown: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    for line in markdown.lines().map(str::trim_end) {
+        if line.is_empty() && lines.last().map_or(true, |last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converts_formatting() {
+        let html = r#"<meta charset="utf-8"><p>Some <b>bold</b> and <em>italic </em>text,
+            a <a href="https://example.com">link</a> and <code>code</code>.</p>
+            <h2>Title</h2><ul><li>one</li><li>two<ol><li>nested</li></ol></li></ul>
+            <blockquote><p>quoted</p></blockquote>
+            <pre><code class="language-rust">fn main() {
+    println!("hi");
+}</code></pre>"#;
+        let converted = convert(html);
+        assert!(converted.has_formatting);
+        assert_eq!(
+            converted.markdown,
+            "Some **bold** and *italic* text, a [link](https://example.com) and `code`.\n\n\
+             ## Title\n\n\
+             - one\n- two\n  1. nested\n\n\
+             > quoted\n\n\
+             ```rust\nfn main() {\n    println!(\"hi\");\n}\n```"
+        );
+    }
+
+    #[test]
+    fn keeps_plain_text_and_images() {
+        let converted = convert(
+            "<div>first line</div><div>second <span>line</span></div>\
+             <img src=\"data:image/png;base64,AAAA\"><img src=\"file:///tmp/b.png\">",
+        );
+        assert!(!converted.has_formatting);
+        assert_eq!(converted.markdown, "first line\nsecond line");
+        assert_eq!(
+            converted.images,
+            vec!["data:image/png;base64,AAAA", "file:///tmp/b.png"]
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod clipboard_data;
+pub mod html_to_markdown;
 #[cfg(target_os = "macos")]
 pub mod macos_clipboard;
+pub mod paste;
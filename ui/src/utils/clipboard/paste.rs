@@ -0,0 +1,214 @@
+//! What ends up in the chatbar when text is pasted into it. Rich text is turned into markdown,
+//! code is put in a fenced block and images copied along with the text become attachments.
+//! Pastes too long for a message can be sent as a file instead.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use base64::{engine::general_purpose, Engine};
+use common::STATIC_ARGS;
+use kit::components::message::highlight::detect_language;
+use tracing::log;
+use uuid::Uuid;
+
+use super::html_to_markdown;
+
+// pastes longer than this are offered to be sent as a file
+const LONG_PASTE_CHARS: usize = 800;
+const LONG_PASTE_LINES: usize = 30;
+// share of the lines that have to look like code, a language being detected isn't enough for
+// prose which happens to mention some of its keywords
+const CODE_LINES_RATIO: f64 = 0.3;
+
+pub struct Paste {
+    /// What goes into the draft
+    pub text: String,
+    /// The images which came with the text, saved as files
+    pub images: Vec<PathBuf>,
+    // the text without the fence around code, for when it's sent as a file
+    raw: String,
+    extension: &'static str,
+}
+
+impl Paste {
+    pub fn is_long(&self) -> bool {
+        self.text.chars().count() > LONG_PASTE_CHARS || self.text.lines().count() > LONG_PASTE_LINES
+    }
+
+    /// Writes the paste to a file named after what it is, e.g. pasted-text.rs for rust code.
+    pub fn save_as_file(&self) -> anyhow::Result<PathBuf> {
+        let dir = STATIC_ARGS.temp_files.join(Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("pasted-text.{}", self.extension));
+        std::fs::write(&path, &self.raw)?;
+        Ok(path)
+    }
+}
+
+/// Processes what was pasted. `html` is the rich text version of `text`, if there is one.
+/// Images are written to the disk, so this shouldn't run on the ui thread.
+pub fn process(html: Option<&str>, text: &str) -> Paste {
+    let converted = html.map(html_to_markdown::convert);
+    let images = match &converted {
+        Some(converted) if !converted.images.is_empty() => {
+            let dir = STATIC_ARGS.temp_files.join(Uuid::new_v4().to_string());
+            save_images(&converted.images, &dir)
+        }
+        _ => vec![],
+    };
+    let mut paste = prepare_text(converted, text);
+    paste.images = images;
+    paste
+}
+
+fn prepare_text(converted: Option<html_to_markdown::Converted>, text: &str) -> Paste {
+    let text = text.trim_matches('\n');
+    // editors copy code as html too, with nothing but colored spans in it
+    if let Some(converted) = converted.filter(|c| c.has_formatting) {
+        return Paste {
+            raw: converted.markdown.clone(),
+            text: converted.markdown,
+            images: vec![],
+            extension: "md",
+        };
+    }
+    if let Some(language) = code_language(text) {
+        // fences already in the code would end the block early, a longer one is used then
+        let mut fence = "```".to_string();
+        while text.contains(&fence) {
+            fence.push('`');
+        }
+        return Paste {
+            text: format!("{fence}{language}\n{text}\n{fence}"),
+            raw: text.to_string(),
+            images: vec![],
+            extension: if language.is_empty() { "txt" } else { language },
+        };
+    }
+    Paste {
+        text: text.to_string(),
+        raw: text.to_string(),
+        images: vec![],
+        extension: "txt",
+    }
+}
+
+/// The language of the text if it looks like code. It's empty if it's code but the language
+/// isn't known.
+fn code_language(text: &str) -> Option<&'static str> {
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    if lines.len() < 2 || text.trim_start().starts_with("```") {
+        return None;
+    }
+    let code_lines = lines
+        .iter()
+        .filter(|line| {
+            let trimmed = line.trim();
+            line.starts_with(['\t', ' '])
+                || trimmed.ends_with([';', '{', '}', '(', ')', '[', ']', ',', ':'])
+                || trimmed.starts_with(['}', ')', ']', '#', '<', '@'])
+                || trimmed.starts_with("//")
+        })
+        .count();
+    if (code_lines as f64) < lines.len() as f64 * CODE_LINES_RATIO {
+        return None;
+    }
+    let language = detect_language(text);
+    // indented prose, like a poem, doesn't say which language it's in
+    let is_braced = text.contains(['{', '}', ';', '(']);
+    match language {
+        Some(language) => Some(language),
+        None if is_braced && code_lines * 2 >= lines.len() => Some(""),
+        None => None,
+    }
+}
+
+// only images pasted as data urls are attached. anything on the web isn't downloaded, and local
+// files aren't attached, pasted html could point at any file on the disk
+fn save_images(sources: &[String], dir: &Path) -> Vec<PathBuf> {
+    sources
+        .iter()
+        .enumerate()
+        .filter(|(_, src)| src.starts_with("data:"))
+        .filter_map(|(i, src)| {
+            save_data_url(src, &dir.join(format!("pasted-image-{}", i + 1)))
+                .map_err(|e| log::warn!("failed to save a pasted image: {e}"))
+                .ok()
+        })
+        .collect()
+}
+
+/// Decodes a base64 data url into a file at `path`, with the extension of its mime type.
+fn save_data_url(url: &str, path: &Path) -> anyhow::Result<PathBuf> {
+    let (header, data) = url
+        .strip_prefix("data:")
+        .and_then(|url| url.split_once(','))
+        .context("invalid data url")?;
+    let Some(mime) = header.strip_suffix(";base64") else {
+        bail!("data url isn't base64");
+    };
+    let extension = match mime.strip_prefix("image/") {
+        Some("jpeg") => "jpg",
+        Some("svg+xml") => "svg",
+        Some(subtype) if !subtype.is_empty() && subtype.chars().all(char::is_alphanumeric) => {
+            subtype
+        }
+        _ => bail!("not an image: {mime}"),
+    };
+    let bytes = general_purpose::STANDARD.decode(data.trim())?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let path = path.with_extension(extension);
+    std::fs::write(&path, bytes)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fences_code() {
+        let code = "fn main() {\n    let x = vec![1, 2];\n    println!(\"{x:?}\");\n}";
+        let paste = prepare_text(None, code);
+        assert_eq!(paste.text, format!("```rs\n{code}\n```"));
+        assert_eq!(paste.extension, "rs");
+        assert_eq!(paste.raw, code);
+
+        let prose = "Let me know what you think:\nthe import went fine, print it when you can.";
+        assert_eq!(code_language(prose), None);
+        assert_eq!(prepare_text(None, prose).text, prose);
+    }
+
+    #[test]
+    fn prefers_formatted_html() {
+        let converted = html_to_markdown::convert("<p>some <b>bold</b> text</p>");
+        let paste = prepare_text(Some(converted), "some bold text");
+        assert_eq!(paste.text, "some **bold** text");
+        assert!(!paste.is_long());
+
+        let converted = html_to_markdown::convert("<span>plain</span>");
+        assert_eq!(prepare_text(Some(converted), "plain").text, "plain");
+
+        let long = "line\n".repeat(LONG_PASTE_LINES + 1);
+        assert!(prepare_text(None, &long).is_long());
+    }
+
+    #[test]
+    fn saves_data_urls() {
+        let dir = tempfile::tempdir().unwrap();
+        let sources = vec![
+            format!(
+                "data:image/png;base64,{}",
+                general_purpose::STANDARD.encode(b"png")
+            ),
+            "https://example.com/image.png".to_string(),
+            "file:///etc/hostname".to_string(),
+            "data:text/plain;base64,AAAA".to_string(),
+        ];
+        let saved = save_images(&sources, dir.path());
+        assert_eq!(saved, vec![dir.path().join("pasted-image-1.png")]);
+        assert_eq!(std::fs::read(&saved[0]).unwrap(), b"png");
+    }
+}
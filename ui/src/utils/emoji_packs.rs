@@ -0,0 +1,129 @@
+//! Sharing the custom emoji packs of a group chat and downloading the ones shared by others. See
+//! `common::state::emoji_packs` for how a pack is stored in a pinned message.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context};
+use common::{
+    language::get_local_text_with_args,
+    state::{emoji_packs::EmojiPack, pending_message::FileProgression},
+    warp_runner::{RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use futures::{channel::oneshot, StreamExt};
+use tracing::log;
+use uuid::Uuid;
+use warp::raygun::{AttachmentKind, Location, PinState};
+
+/// Imports the folder or zip at `path` and shares it in the conversation. Only the creator of a
+/// group has their packs used.
+pub async fn share(conv_id: Uuid, path: PathBuf) -> anyhow::Result<EmojiPack> {
+    let pack =
+        tokio::task::spawn_blocking(move || common::state::emoji_packs::import(&path)).await??;
+    let text = get_local_text_with_args(
+        "messages.emoji-pack-shared",
+        vec![("name", pack.name.clone())],
+    );
+    let attachments = pack
+        .emojis
+        .iter()
+        .filter_map(|emoji| pack.path(emoji))
+        .map(|path| Location::Disk { path })
+        .collect();
+
+    let (tx, rx) = oneshot::channel();
+    let cmd = RayGunCmd::SendMessage {
+        conv_id,
+        msg: vec![text, pack.to_line()],
+        attachments,
+        rsp: tx,
+    };
+    send(cmd)?;
+    let (message_id, stream) = rx.await.context("command canceled")??;
+    // the message is only pinned once every image is uploaded, a pack with missing images
+    // couldn't be downloaded
+    if let Some(mut stream) = stream {
+        while let Some(kind) = stream.next().await {
+            if let AttachmentKind::Pending(res) = kind {
+                res?;
+                break;
+            }
+        }
+    }
+    pin(conv_id, message_id, PinState::Pin).await?;
+    Ok(pack)
+}
+
+/// Stops using a pack in the conversation. The images stay in the message.
+pub async fn remove(conv_id: Uuid, message_id: Uuid) -> anyhow::Result<()> {
+    pin(conv_id, message_id, PinState::Unpin).await
+}
+
+fn send(cmd: RayGunCmd) -> anyhow::Result<()> {
+    WARP_CMD_CH
+        .tx
+        .send(WarpCmd::RayGun(cmd))
+        .map_err(|e| anyhow!("failed to send warp command: {e}"))
+}
+
+async fn pin(conversation_id: Uuid, message_id: Uuid, pinstate: PinState) -> anyhow::Result<()> {
+    let (tx, rx) = oneshot::channel();
+    let cmd = RayGunCmd::Pin {
+        conversation_id,
+        message_id,
+        pinstate,
+        rsp: tx,
+    };
+    send(cmd)?;
+    rx.await.context("command canceled")??;
+    Ok(())
+}
+
+/// Downloads the images of the pack which aren't on the disk yet. Each is written next to where
+/// it goes and moved there once complete, so a partial download is never shown.
+pub async fn download_missing(conv_id: Uuid, message_id: Uuid, pack: &EmojiPack) {
+    if let Err(e) = std::fs::create_dir_all(pack.dir()) {
+        log::error!("failed to create the folder of an emoji pack: {e}");
+        return;
+    }
+    // only emoji whose file stays in the folder of the pack have a path
+    for (emoji, path) in pack.missing() {
+        let partial = path.with_extension("part");
+        if let Err(e) = download(conv_id, message_id, emoji.file.clone(), partial.clone())
+            .await
+            .and_then(|_| Ok(std::fs::rename(&partial, &path)?))
+        {
+            log::warn!("failed to download {}: {e}", emoji.code());
+            let _ = std::fs::remove_file(&partial);
+        }
+    }
+}
+
+async fn download(
+    conv_id: Uuid,
+    msg_id: Uuid,
+    file_name: String,
+    file_path_to_download: PathBuf,
+) -> anyhow::Result<()> {
+    let (tx, rx) = oneshot::channel();
+    let cmd = RayGunCmd::DownloadAttachment {
+        conv_id,
+        msg_id,
+        file_name,
+        file_path_to_download,
+        rsp: tx,
+    };
+    send(cmd)?;
+    let mut stream = rx
+        .await
+        .context("command canceled")??
+        .map(FileProgression::from);
+    while let Some(progress) = stream.next().await {
+        match progress {
+            FileProgression::ProgressComplete { .. } => return Ok(()),
+            FileProgression::ProgressFailed { error, .. } => bail!("{error}"),
+            _ => {}
+        }
+    }
+    bail!("the download ended early")
+}